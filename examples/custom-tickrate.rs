@@ -6,11 +6,7 @@ fn main() {
 
   clock.start();
 
-  for _ in 0..5 {
-    let time = clock.time();
-
-    println!("The time is {time}");
-  }
+  clock.for_each_tick(5, |time| println!("The time is {time}")).unwrap();
 
   let final_time = clock.stop();
 
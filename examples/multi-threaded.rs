@@ -9,18 +9,12 @@ fn main() {
   let mut time_receiver = clock.spawn_receiver();
 
   let handle = thread::spawn(move || {
-    for _ in 0..5 {
-      let time = time_receiver.time();
-
-      println!("The time in thread is {time}");
-    }
+    time_receiver
+      .for_each_tick(5, |time| println!("The time in thread is {time}"))
+      .unwrap();
   });
 
-  for _ in 0..5 {
-    let time = clock.time();
-
-    println!("The time in main is {time}");
-  }
+  clock.for_each_tick(5, |time| println!("The time in main is {time}")).unwrap();
 
   let _ = handle.join();
 
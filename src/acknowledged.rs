@@ -0,0 +1,253 @@
+use crate::status::SharedStatus;
+use crate::sync::{Arc, Condvar, Mutex};
+use crate::{ClockError, ClockStatus, Time};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::Notify;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///What a clock that [`acknowledges its ticks`](crate::ClockBuilder::acknowledged_ticks()) does when a
+///critical receiver doesn't acknowledge a tick in time.
+pub enum StragglerPolicy {
+  ///Sends the next tick anyway, the straggler still holds back the ticks after it.
+  Proceed,
+
+  ///Sends the next tick and stops waiting for the straggler, which keeps getting ticks as an ordinary
+  ///receiver would.
+  Evict,
+
+  ///Stops the clock at the tick that wasn't acknowledged.
+  Stop,
+}
+
+#[derive(Debug, Clone, Copy)]
+///How long a clock waits for its critical receivers to acknowledge a tick and what it does after, see
+///[`ClockBuilder::acknowledged_ticks()`](crate::ClockBuilder::acknowledged_ticks()).
+pub(crate) struct AcknowledgedTicks {
+  pub(crate) timeout: Duration,
+  pub(crate) stragglers: StragglerPolicy,
+}
+
+#[derive(Debug)]
+///The ticks a clock sent and the ones its critical receivers acknowledged, shared between the clock task
+///and the receivers.
+pub(crate) struct TickAcknowledgements {
+  settings: AcknowledgedTicks,
+  state: Mutex<AcknowledgementState>,
+  clock_status: Arc<SharedStatus>,
+  ///Wakes up the receivers once a tick is sent or the clock stops.
+  ticked: Condvar,
+  ///Wakes up the clock task once a tick is acknowledged or a receiver goes away.
+  acknowledged: Notify,
+}
+
+#[derive(Debug, Default)]
+struct AcknowledgementState {
+  ///How many ticks have been sent, which is what receivers count their ticks by as times can repeat
+  ///once the clock is started again.
+  sent: u64,
+  latest_time: Option<Time>,
+  final_time: Option<Time>,
+  ///The critical receivers and how many ticks each of them has acknowledged.
+  receivers: HashMap<u64, u64>,
+  next_id: u64,
+}
+
+impl AcknowledgementState {
+  fn is_acknowledged(&self) -> bool {
+    self.receivers.values().all(|acknowledged| *acknowledged >= self.sent)
+  }
+}
+
+impl TickAcknowledgements {
+  pub(crate) fn new(settings: AcknowledgedTicks, clock_status: Arc<SharedStatus>) -> Self {
+    Self {
+      settings,
+      state: Mutex::new(AcknowledgementState::default()),
+      clock_status,
+      ticked: Condvar::new(),
+      acknowledged: Notify::new(),
+    }
+  }
+
+  pub(crate) fn stragglers(&self) -> StragglerPolicy {
+    self.settings.stragglers
+  }
+
+  ///Returns true if there's a critical receiver the clock has to wait for.
+  pub(crate) fn has_receivers(&self) -> bool {
+    !self.state.lock().unwrap().receivers.is_empty()
+  }
+
+  ///Forgets the final time of the clock's previous run, as it's being started again.
+  pub(crate) fn start(&self) {
+    self.state.lock().unwrap().final_time = None;
+  }
+
+  ///Lets the receivers know the tick was sent.
+  pub(crate) fn sent(&self, time: Time) {
+    let mut state = self.state.lock().unwrap();
+
+    state.sent += 1;
+    state.latest_time = Some(time);
+    self.ticked.notify_all();
+  }
+
+  ///Lets the receivers know the clock stopped.
+  pub(crate) fn stop(&self, final_time: Time) {
+    self.state.lock().unwrap().final_time = Some(final_time);
+    self.ticked.notify_all();
+  }
+
+  ///Waits for every critical receiver to acknowledge the latest tick, returning false if one of them
+  ///didn't within the timeout.
+  pub(crate) async fn acknowledged(&self) -> bool {
+    let deadline = tokio::time::Instant::now() + self.settings.timeout;
+
+    loop {
+      // a stored permit wakes the wait up if the tick was acknowledged in between
+      let acknowledged = self.acknowledged.notified();
+
+      if self.state.lock().unwrap().is_acknowledged() {
+        return true;
+      }
+
+      if tokio::time::timeout_at(deadline, acknowledged).await.is_err() {
+        return self.state.lock().unwrap().is_acknowledged();
+      }
+    }
+  }
+
+  ///Stops waiting for the receivers that didn't acknowledge the latest tick.
+  pub(crate) fn evict_stragglers(&self) -> usize {
+    let mut state = self.state.lock().unwrap();
+    let sent = state.sent;
+    let receivers = state.receivers.len();
+
+    state.receivers.retain(|_, acknowledged| *acknowledged >= sent);
+
+    receivers - state.receivers.len()
+  }
+
+  ///Adds a critical receiver, which counts as having acknowledged every tick sent before it.
+  fn add_receiver(&self) -> (u64, u64) {
+    let mut state = self.state.lock().unwrap();
+    let id = state.next_id;
+    let sent = state.sent;
+
+    state.next_id += 1;
+    state.receivers.insert(id, sent);
+
+    (id, sent)
+  }
+
+  fn remove_receiver(&self, id: u64) {
+    self.state.lock().unwrap().receivers.remove(&id);
+    self.acknowledged.notify_one();
+  }
+}
+
+#[derive(Debug)]
+///A receiver the clock waits on before every tick, which has to
+///[`acknowledge`](crate::CriticalReceiver::acknowledge()) each tick it gets before the clock sends the
+///next one.
+///
+///Created with [`Clock::critical_receiver()`](crate::Clock::critical_receiver()) on a clock built with
+///[`ClockBuilder::acknowledged_ticks()`](crate::ClockBuilder::acknowledged_ticks()). Unlike a
+///[`TimeReceiver`](crate::TimeReceiver), which skips the ticks it falls too far behind on, a critical
+///receiver holds the clock back, so a lockstep simulation never skips a tick however long a tick takes it.
+///The clock stops waiting for the receiver once it's dropped.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, StragglerPolicy};
+///use std::time::Duration;
+///
+///let mut clock = Clock::builder()
+///  .tick_rate(1)
+///  .acknowledged_ticks(Duration::from_secs(1), StragglerPolicy::Stop)
+///  .build()
+///  .unwrap();
+///let mut critical_receiver = clock.critical_receiver().unwrap();
+///
+///clock.start();
+///
+///for expected_time in 0..10 {
+///  assert_eq!(critical_receiver.wait_for_tick().unwrap(), expected_time);
+///
+///  critical_receiver.acknowledge();
+///}
+///```
+pub struct CriticalReceiver {
+  acknowledgements: Arc<TickAcknowledgements>,
+  id: u64,
+  ///How many of the clock's ticks this receiver has gotten.
+  seen: u64,
+}
+
+impl CriticalReceiver {
+  pub(crate) fn new(acknowledgements: Arc<TickAcknowledgements>) -> Self {
+    let (id, seen) = acknowledgements.add_receiver();
+
+    Self {
+      acknowledgements,
+      id,
+      seen,
+    }
+  }
+
+  ///Waits for the tick after the last one this receiver got and returns its time.
+  ///
+  ///Any ticks the clock sent without waiting for this receiver, such as after it was
+  ///[`evicted`](crate::StragglerPolicy::Evict), are skipped to get to the latest one.
+  ///
+  ///[`ClockError::NotStarted`](crate::ClockError::NotStarted) is returned if the clock hasn't started,
+  ///and [`ClockError::Stopped`](crate::ClockError::Stopped) once it has stopped.
+  pub fn wait_for_tick(&mut self) -> anyhow::Result<Time> {
+    let acknowledgements = &self.acknowledgements;
+    let mut state = acknowledgements.state.lock().unwrap();
+
+    loop {
+      if state.sent > self.seen {
+        self.seen = state.sent;
+
+        if let Some(time) = state.latest_time {
+          return Ok(time);
+        }
+      }
+
+      // the clock's status changes before it lets the receivers know it stopped
+      match (acknowledgements.clock_status.get(), state.final_time) {
+        (ClockStatus::Created, _) => return Err(ClockError::NotStarted.into()),
+        (ClockStatus::Stopped(_), Some(final_time)) => return Err(ClockError::Stopped(final_time).into()),
+        _ => state = acknowledgements.ticked.wait(state).unwrap(),
+      }
+    }
+  }
+
+  ///Acknowledges the last tick this receiver got, letting the clock send the next one once every other
+  ///critical receiver has acknowledged it too.
+  pub fn acknowledge(&mut self) {
+    let mut state = self.acknowledgements.state.lock().unwrap();
+
+    if let Some(acknowledged) = state.receivers.get_mut(&self.id) {
+      *acknowledged = self.seen;
+      drop(state);
+
+      self.acknowledgements.acknowledged.notify_one();
+    }
+  }
+
+  ///Returns false once the clock has stopped waiting for this receiver, which happens when it doesn't
+  ///acknowledge a tick in time and the clock [`evicts stragglers`](crate::StragglerPolicy::Evict).
+  pub fn is_critical(&self) -> bool {
+    self.acknowledgements.state.lock().unwrap().receivers.contains_key(&self.id)
+  }
+}
+
+impl Drop for CriticalReceiver {
+  fn drop(&mut self) {
+    self.acknowledgements.remove_receiver(self.id);
+  }
+}
@@ -0,0 +1,104 @@
+use crate::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use crate::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+#[derive(Debug)]
+///Tracks whether anything is reading from a clock, so an idle clock task knows when to wake back up.
+pub(crate) struct ClockActivity {
+  readers: AtomicUsize,
+  ///How many reads have finished, whether they got a tick or not.
+  finished_reads: AtomicU64,
+  last_read: Mutex<Instant>,
+  wake_up: Notify,
+  ///How many times the clock stopped sending every tick while its time kept counting, such as by idling,
+  ///which leaves the ticks still in the channels out of date once it's read from again.
+  jumps: AtomicU64,
+}
+
+impl Default for ClockActivity {
+  fn default() -> Self {
+    Self {
+      readers: AtomicUsize::default(),
+      finished_reads: AtomicU64::default(),
+      last_read: Mutex::new(Instant::now()),
+      wake_up: Notify::default(),
+      jumps: AtomicU64::default(),
+    }
+  }
+}
+
+impl ClockActivity {
+  ///Marks a reader as waiting on the clock until the returned guard is dropped.
+  pub(crate) fn start_reading(&self) -> Reading<'_> {
+    self.readers.fetch_add(1, Ordering::SeqCst);
+    self.mark_read();
+
+    Reading { activity: self }
+  }
+
+  ///Records that a tick was just read from the clock, waking the clock task up if it's idle.
+  pub(crate) fn mark_read(&self) {
+    *self.last_read.lock().unwrap() = Instant::now();
+    self.notify();
+  }
+
+  ///Returns true if anything is currently waiting on the clock.
+  pub(crate) fn is_being_read(&self) -> bool {
+    self.readers.load(Ordering::SeqCst) > 0
+  }
+
+  ///Returns how many readers are currently waiting on the clock.
+  pub(crate) fn readers(&self) -> usize {
+    self.readers.load(Ordering::SeqCst)
+  }
+
+  ///Returns how many reads have finished since the clock was created.
+  pub(crate) fn finished_reads(&self) -> u64 {
+    self.finished_reads.load(Ordering::SeqCst)
+  }
+
+  ///Returns true if anything is waiting on the clock or has read a tick from it within the window.
+  pub(crate) fn was_read_within(&self, window: Duration) -> bool {
+    self.is_being_read() || self.last_read.lock().unwrap().elapsed() < window
+  }
+
+  ///Records that the clock's time is about to jump ahead without sending the ticks in between.
+  pub(crate) fn jumped(&self) {
+    self.jumps.fetch_add(1, Ordering::SeqCst);
+  }
+
+  ///Returns how many times the clock's time has jumped ahead, counting one that's about to.
+  pub(crate) fn jumps(&self) -> u64 {
+    self.jumps.load(Ordering::SeqCst)
+  }
+
+  ///Wakes the clock task up if it's idle.
+  ///
+  ///If the task isn't idle it'll check whether it can idle again the next time it tries to.
+  pub(crate) fn notify(&self) {
+    self.wake_up.notify_one();
+  }
+
+  ///Waits until something notifies the clock task.
+  pub(crate) async fn woken(&self) {
+    self.wake_up.notified().await;
+  }
+}
+
+///A reader waiting on the clock, see [`ClockActivity::start_reading()`].
+pub(crate) struct Reading<'a> {
+  activity: &'a ClockActivity,
+}
+
+impl Drop for Reading<'_> {
+  fn drop(&mut self) {
+    *self.activity.last_read.lock().unwrap() = Instant::now();
+    self.activity.readers.fetch_sub(1, Ordering::SeqCst);
+    self.activity.finished_reads.fetch_add(1, Ordering::SeqCst);
+
+    // a simulated clock waits for its readers to be done with a tick before sending the next one
+    self.activity.notify();
+  }
+}
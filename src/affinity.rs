@@ -0,0 +1,47 @@
+use std::io;
+
+///Pins the current thread to a CPU core, so the scheduler only ever runs it on that core.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn pin_to_core(core: usize) -> io::Result<()> {
+  if core >= libc::CPU_SETSIZE as usize {
+    return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("core {core} doesn't exist")));
+  }
+
+  // SAFETY: the set is zeroed before use, the core was checked to fit in it, and a pid of 0 is the calling thread
+  unsafe {
+    let mut cpu_set: libc::cpu_set_t = std::mem::zeroed();
+
+    libc::CPU_SET(core, &mut cpu_set);
+
+    match libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &cpu_set) {
+      0 => Ok(()),
+      _ => Err(io::Error::last_os_error()),
+    }
+  }
+}
+
+///Pins the current thread to a CPU core, so the scheduler only ever runs it on that core.
+#[cfg(windows)]
+pub(crate) fn pin_to_core(core: usize) -> io::Result<()> {
+  use windows_sys::Win32::System::Threading::{GetCurrentThread, SetThreadAffinityMask};
+
+  // a thread can only be pinned to the cores of its processor group
+  let Some(affinity_mask) = 1usize.checked_shl(core.try_into().unwrap_or(u32::MAX)) else {
+    return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("core {core} doesn't exist")));
+  };
+
+  // SAFETY: GetCurrentThread returns a pseudo handle to the calling thread that's always valid
+  match unsafe { SetThreadAffinityMask(GetCurrentThread(), affinity_mask) } {
+    0 => Err(io::Error::last_os_error()),
+    _ => Ok(()),
+  }
+}
+
+///Pins the current thread to a CPU core, which isn't supported on this platform.
+#[cfg(not(any(target_os = "linux", target_os = "android", windows)))]
+pub(crate) fn pin_to_core(_core: usize) -> io::Result<()> {
+  Err(io::Error::new(
+    io::ErrorKind::Unsupported,
+    "pinning threads to a core isn't supported on this platform",
+  ))
+}
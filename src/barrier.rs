@@ -0,0 +1,137 @@
+use crate::{ClockError, Time, TimeReceiver};
+use anyhow::anyhow;
+use crate::sync::{Arc, Condvar, Mutex};
+
+#[derive(Debug, Clone)]
+///A barrier that releases every waiting thread together on the next tick after all of them have arrived.
+///
+///The barrier can be cloned and passed into separate threads, every clone waits on the same barrier.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///use std::thread;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let barrier = clock.tick_barrier(2).unwrap();
+///
+///clock.start();
+///
+///let worker_barrier = barrier.clone();
+///let handle = thread::spawn(move || worker_barrier.wait().unwrap());
+///
+///let release_time = barrier.wait().unwrap();
+///
+///assert_eq!(handle.join().unwrap(), release_time);
+///```
+pub struct TickBarrier {
+  inner: Arc<BarrierInner>,
+}
+
+#[derive(Debug)]
+struct BarrierInner {
+  parties: usize,
+  state: Mutex<BarrierState>,
+  release: Condvar,
+  time_receiver: Mutex<TimeReceiver>,
+}
+
+#[derive(Debug)]
+struct BarrierState {
+  arrived: usize,
+
+  ///The round the parties arriving now wait in.
+  round: Arc<Round>,
+}
+
+#[derive(Debug, Default)]
+///A single release of the barrier, shared by the parties that arrived for it.
+struct Round {
+  ///The time the round was released on, or the error the clock was waited on with if it couldn't be,
+  ///None while the round is still waiting.
+  release: Mutex<Option<Result<Time, Option<ClockError>>>>,
+}
+
+impl TickBarrier {
+  pub(crate) fn new(time_receiver: TimeReceiver, parties: usize) -> Self {
+    let state = BarrierState {
+      arrived: 0,
+      round: Arc::default(),
+    };
+
+    Self {
+      inner: Arc::new(BarrierInner {
+        parties,
+        state: Mutex::new(state),
+        release: Condvar::new(),
+        time_receiver: Mutex::new(time_receiver),
+      }),
+    }
+  }
+
+  ///Returns the amount of threads that have to call [`wait()`](crate::TickBarrier::wait()) before the barrier releases.
+  pub fn parties(&self) -> usize {
+    self.inner.parties
+  }
+
+  ///Blocks until every party has arrived, then releases all of them on the next tick.
+  ///
+  ///Returns the time of the tick the barrier was released on.
+  ///
+  ///An error is returned to every party if the clock couldn't be waited on, such as
+  ///[`ClockError::NotStarted`](crate::ClockError::NotStarted) when it hasn't started.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let barrier = clock.tick_barrier(1).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///let release_time = barrier.wait().unwrap();
+  ///
+  ///assert_eq!(release_time, 0);
+  ///```
+  pub fn wait(&self) -> anyhow::Result<Time> {
+    let inner = &self.inner;
+    let mut state = inner.state.lock().unwrap();
+    let round = Arc::clone(&state.round);
+
+    state.arrived += 1;
+
+    if state.arrived < inner.parties {
+      loop {
+        match *round.release.lock().unwrap() {
+          Some(Ok(release_time)) => return Ok(release_time),
+          Some(Err(Some(error))) => return Err(error.into()),
+          Some(Err(None)) => return Err(anyhow!("The clock couldn't be waited on to release the barrier")),
+          None => (),
+        }
+
+        state = inner.release.wait(state).unwrap();
+      }
+    }
+
+    // the next round starts before the tick is waited for, so the parties arriving in the meantime wait for it
+    state.arrived = 0;
+    state.round = Arc::default();
+    drop(state);
+
+    let mut time_receiver = inner.time_receiver.lock().unwrap();
+    // the ticks sent before the last party arrived are skipped, so the barrier releases on a new one
+    let release_time = time_receiver.next_time();
+    let _state = inner.state.lock().unwrap();
+
+    *round.release.lock().unwrap() = Some(match &release_time {
+      Ok(release_time) => Ok(*release_time),
+      Err(error) => Err(error.downcast_ref::<ClockError>().copied()),
+    });
+    inner.release.notify_all();
+
+    release_time
+  }
+}
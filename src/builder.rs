@@ -0,0 +1,1041 @@
+use crate::acknowledged::AcknowledgedTicks;
+use crate::{
+  Clock, ClockDriver, Precision, StragglerPolicy, Time, TickRecording, DEFAULT_CHANNEL_CAPACITY, DEFAULT_TICKRATE,
+};
+use std::time::Duration;
+use tokio::runtime::Handle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///How a duration is turned into a whole amount of ticks when it isn't a multiple of the tickrate.
+///
+///# Example
+///
+///```
+///use thread_clock::Rounding;
+///use std::time::Duration;
+///
+///let duration = Duration::from_millis(100);
+///
+///assert_eq!(Rounding::Up.ticks_in(duration, 24), 5);
+///assert_eq!(Rounding::Down.ticks_in(duration, 24), 4);
+///assert_eq!(Rounding::Nearest.ticks_in(duration, 24), 4);
+///```
+pub enum Rounding {
+  ///Rounds up to the next whole tick.
+  #[default]
+  Up,
+
+  ///Rounds down to the previous whole tick.
+  Down,
+
+  ///Rounds to the closest amount of ticks.
+  Nearest,
+}
+
+impl Rounding {
+  ///Returns how many ticks of the tickrate fit in the duration.
+  ///
+  ///A tickrate of 0 always returns 0 ticks.
+  pub fn ticks_in(self, duration: Duration, tick_rate: u32) -> u64 {
+    let tick_length = Duration::from_millis(tick_rate.into()).as_nanos();
+    let duration = duration.as_nanos();
+
+    if tick_length == 0 {
+      return 0;
+    }
+
+    let ticks = match self {
+      Self::Up => duration.div_ceil(tick_length),
+      Self::Down => duration / tick_length,
+      Self::Nearest => (duration + tick_length / 2) / tick_length,
+    };
+
+    ticks.try_into().unwrap_or(u64::MAX)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///What a clock does once its time reaches [`Time::MAX`](crate::Time) and can't count any higher.
+///
+///# Example
+///
+///```
+///use thread_clock::{Clock, Overflow, Time};
+///
+///let mut clock = Clock::builder()
+///  .tick_rate(1)
+///  .start_at(Time::MAX)
+///  .overflow(Overflow::Wrap)
+///  .build()
+///  .unwrap();
+///
+///clock.start();
+///
+///// the time wraps around to 0 after its maximum
+///clock.wait_for_time(1).unwrap();
+///
+///assert_eq!(clock.time(), 2);
+///```
+pub enum Overflow {
+  ///Stays at the maximum time, every tick after it has the same time.
+  #[default]
+  Saturate,
+
+  ///Wraps around to 0 and keeps counting.
+  ///
+  ///Waiting compares times as if they were on a circle, a time up to half of the range ahead of the
+  ///current one is in the future and anything else has already occurred. Waiting across the wrap works
+  ///as long as the time waited for is less than `Time::MAX / 2` ticks away.
+  Wrap,
+
+  ///Stops the clock after the tick at the maximum time.
+  ///
+  ///Anything waiting on the clock afterwards gets [`ClockError::Overflowed`](crate::ClockError::Overflowed)
+  ///instead of [`ClockError::Stopped`](crate::ClockError::Stopped).
+  Error,
+}
+
+impl Overflow {
+  ///Returns the time `ticks` ticks after `time`, or None if the clock stops before it.
+  pub(crate) fn advance(self, time: Time, ticks: Time) -> Option<Time> {
+    match self {
+      Self::Saturate => Some(time.saturating_add(ticks)),
+      Self::Wrap => Some(time.wrapping_add(ticks)),
+      Self::Error => time.checked_add(ticks),
+    }
+  }
+
+  ///Returns how many ticks after `from` the time `to` is, or None if it comes before it.
+  pub(crate) fn ticks_between(self, from: Time, to: Time) -> Option<Time> {
+    match self {
+      Self::Wrap => Some(to.wrapping_sub(from)).filter(|ticks| *ticks <= Time::MAX / 2),
+      Self::Saturate | Self::Error => to.checked_sub(from),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///What a clock waits on between its ticks, see [`ClockBuilder::backend()`](crate::ClockBuilder::backend()).
+///
+///# Example
+///
+///```
+///use thread_clock::{Backend, Clock};
+///
+///let clock = Clock::builder().tick_rate(10).backend(Backend::Tokio).build().unwrap();
+///```
+pub enum Backend {
+  ///The timer of the runtime the clock task runs on.
+  #[default]
+  Tokio,
+
+  ///A Linux `timerfd`, set off on absolute deadlines with `TFD_TIMER_ABSTIME`.
+  ///
+  ///The kernel keeps the deadlines from drifting and wakes the clock task up with less jitter than
+  ///tokio's timer, which only fires on whole milliseconds. Needs the `timerfd` feature.
+  #[cfg(all(target_os = "linux", feature = "timerfd"))]
+  TimerFd,
+
+  ///A high-resolution Windows waitable timer, created with `CREATE_WAITABLE_TIMER_HIGH_RESOLUTION`.
+  ///
+  ///Wakes the clock task up within a fraction of a millisecond of its deadlines without spinning, where
+  ///tokio's timer only fires every ~1-15ms on Windows. The clock task blocks its thread while it waits,
+  ///so a clock with this backend always gets a [`dedicated runtime`](crate::ClockBuilder::dedicated_runtime()).
+  ///Needs Windows 10 1803 or later, older versions fall back to tokio's timer.
+  #[cfg(windows)]
+  WaitableTimer,
+
+  ///A macOS kqueue `EVFILT_TIMER`, set for every deadline to the nanosecond.
+  ///
+  ///Wakes the clock task up closer to its deadlines than tokio's timer, which only fires on whole
+  ///milliseconds, while leaving the kernel free to coalesce it with other timers to save power.
+  ///Needs the `kqueue` feature.
+  #[cfg(all(target_os = "macos", feature = "kqueue"))]
+  Kqueue,
+}
+
+impl Backend {
+  ///Returns true if the clock task blocks the thread it runs on while waiting for a tick.
+  pub(crate) fn blocks_thread(self) -> bool {
+    match self {
+      Self::Tokio => false,
+      #[cfg(all(target_os = "linux", feature = "timerfd"))]
+      Self::TimerFd => false,
+      #[cfg(windows)]
+      Self::WaitableTimer => true,
+      #[cfg(all(target_os = "macos", feature = "kqueue"))]
+      Self::Kqueue => false,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+///How a clock lowers its tickrate while nothing reads from it, see
+///[`ClockBuilder::power_saving()`](crate::ClockBuilder::power_saving()).
+pub(crate) struct PowerSaving {
+  pub(crate) window: Duration,
+  pub(crate) tick_rate: u32,
+}
+
+#[derive(Debug, Clone)]
+///A builder for clocks that need more than a custom tickrate.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, Rounding};
+///
+///let mut clock = Clock::builder()
+///  .tick_rate(10)
+///  .rounding(Rounding::Nearest)
+///  .build()
+///  .unwrap();
+///
+///clock.start();
+///```
+pub struct ClockBuilder {
+  pub(crate) tick_rate: u32,
+  pub(crate) channel_capacity: usize,
+  pub(crate) rounding: Rounding,
+  pub(crate) overflow: Overflow,
+  pub(crate) start_time: Time,
+  pub(crate) start_paused: bool,
+  pub(crate) stop_at: Option<Time>,
+  pub(crate) receivers_wait_for_start: bool,
+  pub(crate) alignment: Option<Duration>,
+  pub(crate) precision: Option<Duration>,
+  pub(crate) spin: bool,
+  pub(crate) driver: Option<ClockDriver>,
+  pub(crate) backend: Backend,
+  pub(crate) idle_when_unobserved: bool,
+  pub(crate) power_saving: Option<PowerSaving>,
+  pub(crate) multi_threaded: bool,
+  pub(crate) dedicated_runtime: bool,
+  pub(crate) enclosing_runtime: bool,
+  pub(crate) runtime: Option<Handle>,
+  pub(crate) realtime_priority: bool,
+  pub(crate) core: Option<usize>,
+  pub(crate) max_restarts: u32,
+  pub(crate) replay: Option<TickRecording>,
+  pub(crate) simulated: bool,
+  pub(crate) acknowledged_ticks: Option<AcknowledgedTicks>,
+}
+
+impl Default for ClockBuilder {
+  fn default() -> Self {
+    Self {
+      tick_rate: DEFAULT_TICKRATE,
+      channel_capacity: DEFAULT_CHANNEL_CAPACITY,
+      rounding: Rounding::default(),
+      overflow: Overflow::default(),
+      start_time: 0,
+      start_paused: false,
+      stop_at: None,
+      receivers_wait_for_start: false,
+      alignment: None,
+      precision: None,
+      spin: false,
+      driver: None,
+      backend: Backend::default(),
+      idle_when_unobserved: false,
+      power_saving: None,
+      multi_threaded: false,
+      dedicated_runtime: false,
+      enclosing_runtime: false,
+      runtime: None,
+      realtime_priority: false,
+      core: None,
+      max_restarts: 0,
+      replay: None,
+      simulated: false,
+      acknowledged_ticks: None,
+    }
+  }
+}
+
+///Parses a duration written out the way [`humantime`] does into a tickrate in milliseconds.
+#[cfg(feature = "humantime")]
+pub(crate) fn parse_tick_rate(tick_rate: &str) -> anyhow::Result<u32> {
+  let duration = humantime::parse_duration(tick_rate)
+    .map_err(|error| anyhow::anyhow!("'{tick_rate}' isn't a valid tickrate: {error}"))?;
+
+  if duration.subsec_nanos() % 1_000_000 != 0 {
+    return Err(anyhow::anyhow!("'{tick_rate}' isn't a whole amount of milliseconds"));
+  }
+
+  u32::try_from(duration.as_millis())
+    .map_err(|_| anyhow::anyhow!("'{tick_rate}' is longer than the longest tickrate of {}ms", u32::MAX))
+}
+
+///Returns the value of the environment variable `{prefix}_{name}`, or None if it isn't set.
+fn env_var(prefix: &str, name: &str) -> anyhow::Result<Option<String>> {
+  match std::env::var(format!("{prefix}_{name}")) {
+    Ok(value) => Ok(Some(value)),
+    Err(std::env::VarError::NotPresent) => Ok(None),
+    Err(error) => Err(anyhow::anyhow!("{prefix}_{name} is invalid: {error}")),
+  }
+}
+
+///Parses a tickrate from an environment variable, which is in milliseconds unless it's a duration.
+fn parse_env_tick_rate(tick_rate: &str) -> anyhow::Result<u32> {
+  #[cfg(feature = "humantime")]
+  if tick_rate.parse::<u32>().is_err() {
+    return parse_tick_rate(tick_rate);
+  }
+
+  tick_rate
+    .parse()
+    .map_err(|_| anyhow::anyhow!("'{tick_rate}' isn't an amount of milliseconds"))
+}
+
+impl ClockBuilder {
+  ///Creates a builder with the same settings as [`Clock::new()`](crate::Clock::new()).
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  ///Sets the tickrate of the clock in milliseconds.
+  pub fn tick_rate(mut self, tick_rate: u32) -> Self {
+    self.tick_rate = tick_rate;
+
+    self
+  }
+
+  ///Sets the tickrate of the clock from a duration such as `"16ms"` or `"1.5s"`, as it would be written
+  ///in a config file or passed as a flag. Needs the `humantime` feature.
+  ///
+  ///Returns an error if the duration can't be parsed, or isn't a whole amount of milliseconds that fits
+  ///in a tickrate.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::ClockBuilder;
+  ///
+  ///let clock = ClockBuilder::new().tick_rate_str("1.5s").unwrap().build().unwrap();
+  ///
+  ///assert_eq!(clock.tick_rate(), 1500);
+  ///```
+  #[cfg(feature = "humantime")]
+  pub fn tick_rate_str(self, tick_rate: &str) -> anyhow::Result<Self> {
+    Ok(self.tick_rate(parse_tick_rate(tick_rate)?))
+  }
+
+  ///Sets how many messages the channel between the clock task and its receivers holds, which defaults to
+  ///[`DEFAULT_CHANNEL_CAPACITY`](crate::DEFAULT_CHANNEL_CAPACITY).
+  ///
+  ///A receiver that has gotten a tick carries on from the one after it, so every receiver that keeps up
+  ///sees every tick in order, even when it comes back for the next one up to the capacity's worth of
+  ///ticks late. A receiver that falls further behind, or hasn't gotten a tick yet, skips to the first
+  ///tick sent after it waits, and sees the ticks it skipped as [`gaps`](crate::TimeReceiver::gaps()).
+  ///Ticks left in the channel from before the clock idled or lowered its tickrate are skipped the same way,
+  ///as the clock's time moved on without sending the ones in between. Derived clocks and outputs use the
+  ///same capacity.
+  ///A capacity of 0 makes [`build()`](crate::ClockBuilder::build()) return an error.
+  pub fn channel_capacity(mut self, channel_capacity: usize) -> Self {
+    self.channel_capacity = channel_capacity;
+
+    self
+  }
+
+  ///Overrides the settings of this builder with any of these environment variables that are set, where
+  ///`PREFIX` is `prefix`:
+  ///
+  ///- `PREFIX_TICK_RATE`: the tickrate in milliseconds. With the `humantime` feature, a duration such as
+  ///  `16ms` is accepted as well.
+  ///- `PREFIX_CHANNEL_CAPACITY`: the [`channel capacity`](crate::ClockBuilder::channel_capacity()).
+  ///- `PREFIX_PRECISION`: `sleep` to sleep for whole ticks, `spin` to [`spin`](crate::ClockBuilder::spin()),
+  ///  or how many microseconds to spin for at the end of every tick as with
+  ///  [`precision()`](crate::ClockBuilder::precision()).
+  ///
+  ///Returns an error naming the variable if one of them holds something that can't be parsed.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::ClockBuilder;
+  ///
+  ///std::env::set_var("MY_APP_CLOCK_TICK_RATE", "10");
+  ///
+  ///let clock = ClockBuilder::new().env_overrides("MY_APP_CLOCK").unwrap().build().unwrap();
+  ///
+  ///assert_eq!(clock.tick_rate(), 10);
+  ///```
+  pub fn env_overrides(mut self, prefix: &str) -> anyhow::Result<Self> {
+    if let Some(tick_rate) = env_var(prefix, "TICK_RATE")? {
+      self.tick_rate = parse_env_tick_rate(&tick_rate)
+        .map_err(|error| anyhow::anyhow!("{prefix}_TICK_RATE is invalid: {error}"))?;
+    }
+
+    if let Some(channel_capacity) = env_var(prefix, "CHANNEL_CAPACITY")? {
+      self.channel_capacity = channel_capacity
+        .parse()
+        .map_err(|error| anyhow::anyhow!("{prefix}_CHANNEL_CAPACITY is invalid: {error}"))?;
+    }
+
+    if let Some(precision) = env_var(prefix, "PRECISION")? {
+      let precision = match precision.as_str() {
+        "sleep" => Precision::Sleep,
+        "spin" => Precision::Spin,
+        spin_for => Precision::SpinFor(spin_for.parse().map_err(|_| {
+          anyhow::anyhow!("{prefix}_PRECISION is invalid: '{spin_for}' isn't sleep, spin or an amount of microseconds")
+        })?),
+      };
+
+      self = self.precision_mode(precision);
+    }
+
+    Ok(self)
+  }
+
+  ///Replaces the precision and spinning of the clock with the ones the mode stands for.
+  pub(crate) fn precision_mode(mut self, precision: Precision) -> Self {
+    (self.precision, self.spin) = match precision {
+      Precision::Sleep => (None, false),
+      Precision::SpinFor(spin_for) => (Some(Duration::from_micros(spin_for)), false),
+      Precision::Spin => (None, true),
+    };
+
+    self
+  }
+
+  ///Sets how durations are rounded into ticks when waiting with
+  ///[`wait_for_duration()`](crate::Clock::wait_for_duration()).
+  ///
+  ///Defaults to [`Rounding::Up`](crate::Rounding::Up).
+  pub fn rounding(mut self, rounding: Rounding) -> Self {
+    self.rounding = rounding;
+
+    self
+  }
+
+  ///Sets what the clock does once its time can't count any higher.
+  ///
+  ///Defaults to [`Overflow::Saturate`](crate::Overflow::Saturate).
+  pub fn overflow(mut self, overflow: Overflow) -> Self {
+    self.overflow = overflow;
+
+    self
+  }
+
+  ///Sets the time of the clock's first tick, such as to carry on from where a previous clock stopped.
+  ///
+  ///Defaults to 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::builder().tick_rate(1).start_at(100).build().unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///assert_eq!(clock.time(), 100);
+  ///```
+  pub fn start_at(mut self, time: Time) -> Self {
+    self.start_time = time;
+
+    self
+  }
+
+  ///Makes [`start()`](crate::Clock::start()) set the clock task up paused, so nothing ticks until
+  ///[`resume()`](crate::Clock::resume()) is called.
+  ///
+  ///Receivers spawned before resuming are all waiting on the clock's first tick, instead of racing
+  ///the clock to subscribe before it fires. Until then the clock is started but
+  ///[`paused`](crate::ClockStatus::Paused), so waiting on it blocks instead of returning an error.
+  ///
+  ///Defaults to false.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::thread;
+  ///
+  ///let mut clock = Clock::builder().tick_rate(1).start_paused(true).build().unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///let workers: Vec<_> = (0..4)
+  ///  .map(|_| {
+  ///    let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///    thread::spawn(move || time_receiver.time())
+  ///  })
+  ///  .collect();
+  ///
+  ///clock.resume();
+  ///
+  ///for worker in workers {
+  ///  assert_eq!(worker.join().unwrap(), 0);
+  ///}
+  ///```
+  pub fn start_paused(mut self, start_paused: bool) -> Self {
+    self.start_paused = start_paused;
+
+    self
+  }
+
+  ///Makes the clock stop itself after its tick at `time`, or after the first tick past it if that tick
+  ///was skipped, such as while the clock was [`idle`](crate::ClockBuilder::idle_when_unobserved()).
+  ///
+  ///Anything waiting on the clock afterwards gets [`ClockError::Stopped`](crate::ClockError::Stopped)
+  ///with the time it stopped at, the same as if [`stop()`](crate::Clock::stop()) was called.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::builder().tick_rate(1).stop_at(10).build().unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///assert!(clock.wait_for_time(20).is_err());
+  ///assert_eq!(clock.stop().unwrap(), 10);
+  ///```
+  pub fn stop_at(mut self, time: Time) -> Self {
+    self.stop_at = Some(time);
+
+    self
+  }
+
+  ///Makes waiting on a [`time receiver`](crate::TimeReceiver) before the clock starts wait for
+  ///[`start()`](crate::Clock::start()) instead of returning
+  ///[`ClockError::NotStarted`](crate::ClockError::NotStarted).
+  ///
+  ///This is what worker threads spawned before the clock starts usually want, the same as calling
+  ///[`wait_for_start()`](crate::TimeReceiver::wait_for_start()) first. Dropping the clock without starting
+  ///it still returns the error. The clock's own methods keep returning it, as nothing else could start
+  ///the clock while they wait.
+  ///
+  ///Defaults to false.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::thread;
+  ///
+  ///let mut clock = Clock::builder().tick_rate(1).receivers_wait_for_start(true).build().unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///// without waiting for the start this would return an error
+  ///let worker = thread::spawn(move || time_receiver.safe_time());
+  ///
+  ///clock.start();
+  ///
+  ///assert!(worker.join().unwrap().is_ok());
+  ///```
+  pub fn receivers_wait_for_start(mut self, receivers_wait_for_start: bool) -> Self {
+    self.receivers_wait_for_start = receivers_wait_for_start;
+
+    self
+  }
+
+  ///Lines the ticks of the clock up with the wall-clock.
+  ///
+  ///The first tick fires on the next multiple of the boundary since the
+  ///[`UNIX_EPOCH`](std::time::UNIX_EPOCH), such as the start of the next second or minute.
+  ///Every tick after that is scheduled from the first one, so the ticks stay in phase with
+  ///the boundary instead of drifting away from it.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///// ticks 4 times a second, starting at the next full second
+  ///let mut clock = Clock::builder()
+  ///  .tick_rate(250)
+  ///  .align_to(Duration::from_secs(1))
+  ///  .build()
+  ///  .unwrap();
+  ///```
+  pub fn align_to(mut self, boundary: Duration) -> Self {
+    self.alignment = Some(boundary);
+
+    self
+  }
+
+  ///Makes the clock hit its ticks more precisely by spinning instead of sleeping at the end of every tick.
+  ///
+  ///The clock task sleeps for most of a tick, then spins for the last `spin_for` of it. Sleeping can
+  ///overshoot by the OS's timer granularity, which throws off short tickrates, while spinning doesn't
+  ///but keeps a core busy. Timers are only accurate to a millisecond, so that much is spun on top of
+  ///`spin_for`, a few hundred microseconds is usually enough.
+  ///
+  ///Spinning only pays off with a spare core to spin on. On a machine with a single core the spinning
+  ///clock task competes with the threads waiting on it, and ticks can end up later than with plain sleeping.
+  ///
+  ///Ticks are kept on fixed deadlines the same way as with [`align_to()`](crate::ClockBuilder::align_to()),
+  ///and the two can be combined. A precise clock always gets a
+  ///[`dedicated runtime`](crate::ClockBuilder::dedicated_runtime()) so its spinning can't hold up other clocks.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::builder()
+  ///  .tick_rate(1)
+  ///  .precision(Duration::from_micros(200))
+  ///  .build()
+  ///  .unwrap();
+  ///```
+  pub fn precision(mut self, spin_for: Duration) -> Self {
+    self.precision = Some(spin_for);
+
+    self
+  }
+
+  ///Makes the clock task busy-wait for the whole of every tick instead of sleeping at all.
+  ///
+  ///Where [`precision()`](crate::ClockBuilder::precision()) only spins for the end of a tick, a spinning
+  ///clock never hands its wait to the OS's timers, so its ticks land within microseconds of their
+  ///deadlines. The cost is a core kept fully busy for as long as the clock runs, so this is meant for
+  ///a core set aside for the clock, such as with [`pin_to_core()`](crate::ClockBuilder::pin_to_core()),
+  ///and overrides any precision that's set.
+  ///
+  ///Ticks are kept on fixed deadlines the same way as with a precision. A spinning clock always gets a
+  ///[`dedicated runtime`](crate::ClockBuilder::dedicated_runtime()), and a
+  ///[`driver`](crate::ClockBuilder::driver()) can't spin, so [`build()`](crate::ClockBuilder::build())
+  ///returns an error if both are set.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::builder().tick_rate(1).spin(true).pin_to_core(0).build();
+  ///```
+  pub fn spin(mut self, spin: bool) -> Self {
+    self.spin = spin;
+
+    self
+  }
+
+  ///Has a [`driver`](crate::ClockDriver) wake the clock up on its ticks instead of a timer of its own.
+  ///
+  ///Many clocks with any tickrates can share a driver, which only ever waits on one timer for all of them.
+  ///An [`alignment`](crate::ClockBuilder::align_to()) is kept the same way it is without a driver, but a
+  ///driver can't spin, so [`build()`](crate::ClockBuilder::build()) returns an error if a
+  ///[`precision`](crate::ClockBuilder::precision()) or [`spin`](crate::ClockBuilder::spin()) is set as well.
+  ///A clock on the [`enclosing runtime`](crate::ClockBuilder::enclosing_runtime()) needs a driver
+  ///[`created on it`](crate::ClockDriver::enclosing()), and other clocks one that isn't.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockDriver};
+  ///
+  ///let driver = ClockDriver::new().unwrap();
+  ///let clocks: Vec<Clock> = (1..=10)
+  ///  .map(|tick_rate| Clock::builder().tick_rate(tick_rate).driver(&driver).build().unwrap())
+  ///  .collect();
+  ///```
+  pub fn driver(mut self, driver: &ClockDriver) -> Self {
+    self.driver = Some(driver.clone());
+
+    self
+  }
+
+  ///Replays a [`recording`](crate::TickRecording) of ticks, so the clock ticks with the same delays
+  ///between its ticks as the clock that was recorded.
+  ///
+  ///The clock takes on the tickrate of the recording and starts at the time of its first tick, then
+  ///counts up once for every recorded tick and [`stops itself`](crate::ClockBuilder::stop_at()) after the
+  ///last of them. Combined with the [`enclosing runtime`](crate::ClockBuilder::enclosing_runtime()) of a
+  ///runtime whose time is paused, the recording is replayed instantly in virtual time, with the same
+  ///delays as measured by that runtime.
+  ///
+  ///A replaying clock can't be given a [`precision`](crate::ClockBuilder::precision()), spin, a driver or
+  ///another [`backend`](crate::ClockBuilder::backend()), so [`build()`](crate::ClockBuilder::build())
+  ///returns an error if it is.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, TickRecording};
+  ///
+  ///let path = std::env::temp_dir().join(format!("thread-clock-replay-{}.ticks", std::process::id()));
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///
+  ///clock.record_ticks(&path).unwrap();
+  ///clock.start_from(100);
+  ///clock.wait_for_time(110).unwrap();
+  ///
+  ///let final_time = clock.stop().unwrap();
+  ///let recording = TickRecording::load(&path).unwrap();
+  ///let mut replayed_clock = Clock::builder().replay(recording).build().unwrap();
+  ///
+  ///replayed_clock.start();
+  ///
+  ///assert!(replayed_clock.wait_for_time(final_time + 1).is_err());
+  ///assert_eq!(replayed_clock.stop().unwrap(), final_time);
+  ///```
+  pub fn replay(mut self, recording: TickRecording) -> Self {
+    self.tick_rate = recording.tick_rate();
+    self.start_time = recording.first_time();
+    self.stop_at = Some(recording.first_time().saturating_add(recording.len() as Time - 1));
+    self.replay = Some(recording);
+
+    self
+  }
+
+  ///Sets what the clock waits on between its ticks.
+  ///
+  ///Defaults to [`Backend::Tokio`](crate::Backend::Tokio). With the `timerfd` feature on Linux,
+  ///[`Backend::TimerFd`](crate::Backend) has the kernel keep the clock's deadlines instead, on Windows
+  ///[`Backend::WaitableTimer`](crate::Backend) waits on a high-resolution timer, and with the `kqueue`
+  ///feature on macOS [`Backend::Kqueue`](crate::Backend) waits on a kqueue timer. Their ticks are
+  ///kept on fixed deadlines the same way as with a [`precision`](crate::ClockBuilder::precision()), which
+  ///along with spinning and a [`driver`](crate::ClockBuilder::driver()) can't be combined with them, so
+  ///[`build()`](crate::ClockBuilder::build()) returns an error if any of them are set. A clock that can't
+  ///get its timer, such as one on an [`enclosing runtime`](crate::ClockBuilder::enclosing_runtime())
+  ///without its IO enabled for a `timerfd` or kqueue, falls back to tokio's timer.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Backend, Clock};
+  ///
+  ///let mut clock = Clock::builder().tick_rate(1).backend(Backend::default()).build().unwrap();
+  ///
+  ///clock.start();
+  ///clock.wait_for_time(5).unwrap();
+  ///```
+  pub fn backend(mut self, backend: Backend) -> Self {
+    self.backend = backend;
+
+    self
+  }
+
+  ///Lets the clock task stop waking up every tick while nothing is listening to the clock.
+  ///
+  ///The clock counts as unobserved while it has no [`time receivers`](crate::TimeReceiver), derived clocks
+  ///or rate limiters, and nothing is waiting on the clock itself. The time keeps counting while idle,
+  ///so waiting on the clock or spawning a receiver picks up at the time it would've been anyway.
+  ///
+  ///Defaults to false. Useful on battery-powered devices where a clock is often left unobserved.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::builder()
+  ///  .tick_rate(1)
+  ///  .idle_when_unobserved(true)
+  ///  .build()
+  ///  .unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///// the clock idles until it's waited on
+  ///std::thread::sleep(std::time::Duration::from_millis(20));
+  ///
+  ///assert!(clock.time() >= 10);
+  ///```
+  pub fn idle_when_unobserved(mut self, idle_when_unobserved: bool) -> Self {
+    self.idle_when_unobserved = idle_when_unobserved;
+
+    self
+  }
+
+  ///Lowers the clock to ticking every `low_tick_rate` milliseconds once nothing has read a tick from it
+  ///for the window, snapping back to its full tickrate as soon as anything does.
+  ///
+  ///The time keeps counting at the full tickrate while the rate is lowered, so every lowered tick skips
+  ///ahead to the tick the clock would've been on, and reading the clock picks up at the time it would've
+  ///been anyway. Unlike [`idle_when_unobserved()`](crate::ClockBuilder::idle_when_unobserved()) the clock
+  ///keeps ticking, just less often, and receivers that exist but aren't being read don't keep it at its
+  ///full rate.
+  ///
+  ///Anything created from the clock that's notified of every tick, such as a derived clock or a countdown,
+  ///keeps the clock at its full rate while it exists.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///// ticks every millisecond while being read, and only every 100ms after a second of not being read
+  ///let mut clock = Clock::builder()
+  ///  .tick_rate(1)
+  ///  .power_saving(Duration::from_secs(1), 100)
+  ///  .build()
+  ///  .unwrap();
+  ///
+  ///clock.start();
+  ///```
+  pub fn power_saving(mut self, window: Duration, low_tick_rate: u32) -> Self {
+    self.power_saving = Some(PowerSaving {
+      window,
+      tick_rate: low_tick_rate,
+    });
+
+    self
+  }
+
+  ///Runs the clock on its own multi-threaded tokio runtime with a worker for every core.
+  ///
+  ///By default clocks share a single thread to run their ticks on, which is all they need.
+  ///This brings back the runtime clocks used to be created with, at the cost of a thread for every core.
+  ///
+  ///Defaults to false.
+  pub fn multi_threaded(mut self, multi_threaded: bool) -> Self {
+    self.multi_threaded = multi_threaded;
+
+    self
+  }
+
+  ///Gives the clock a runtime of its own instead of sharing one with every other clock.
+  ///
+  ///Clocks share a single lazily created thread by default, so many clocks only cost a timer each.
+  ///A dedicated runtime runs on its own thread, so the clock's ticks can't be delayed by other clocks.
+  ///Like the shared one, it's only created once the clock is started or first waited on.
+  ///
+  ///Defaults to false.
+  pub fn dedicated_runtime(mut self, dedicated_runtime: bool) -> Self {
+    self.dedicated_runtime = dedicated_runtime;
+
+    self
+  }
+
+  ///Runs the clock on the tokio runtime it's built in instead of one of the crate's own.
+  ///
+  ///The clock then ticks on that runtime's timer, so a runtime whose time is paused, such as in a
+  ///`#[tokio::test(start_paused = true)]` test, advances the clock on its mocked time instead of the wall
+  ///clock. Ticks that would take seconds then happen as soon as the runtime has nothing else to do.
+  ///Anything else measuring time from within that runtime, such as the tick frequency, follows it too.
+  ///
+  ///A current thread runtime can't be blocked on, so the clock should be waited on with the asynchronous
+  ///methods such as [`next_tick()`](crate::TimeReceiver::next_tick()) or a [`Deadline`](crate::Deadline).
+  ///The runtime needs its timer enabled. Everything that ticks the clock from a thread of its own can't be
+  ///combined with this, so [`build()`](crate::ClockBuilder::build()) returns an error if the clock is also
+  ///given real-time priority, a core, a precision, spinning, or a runtime of its own, as well as when it's
+  ///built outside of a runtime. A driver has to be
+  ///[`created on the enclosing runtime`](crate::ClockDriver::enclosing()) as well.
+  ///
+  ///Defaults to false.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///#[tokio::main(flavor = "current_thread", start_paused = true)]
+  ///async fn main() {
+  ///  let mut clock = Clock::builder().tick_rate(1000).enclosing_runtime(true).build().unwrap();
+  ///  let mut time_receiver = clock.spawn_receiver();
+  ///  let started_at = tokio::time::Instant::now();
+  ///
+  ///  clock.start();
+  ///
+  ///  for _ in 0..10 {
+  ///    time_receiver.next_tick().await.unwrap();
+  ///  }
+  ///
+  ///  // ten seconds of ticks, without having waited ten seconds
+  ///  assert!(started_at.elapsed() >= Duration::from_secs(10));
+  ///}
+  ///```
+  pub fn enclosing_runtime(mut self, enclosing_runtime: bool) -> Self {
+    self.enclosing_runtime = enclosing_runtime;
+
+    self
+  }
+
+  ///Runs the clock on the tokio runtime of the handle instead of one of the crate's own.
+  ///
+  ///This is the same as the [`enclosing runtime`](crate::ClockBuilder::enclosing_runtime()), except the
+  ///clock can be built anywhere. The runtime has to be kept running by whatever owns it, which a
+  ///multi-threaded runtime does on its own, and needs its timer enabled. [`build()`](crate::ClockBuilder::build())
+  ///returns an error if the clock is also on the enclosing runtime or given real-time priority, a core, a
+  ///precision, spinning, a driver, or a runtime of its own.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let runtime = tokio::runtime::Runtime::new().unwrap();
+  ///let mut clock = Clock::builder().tick_rate(1).runtime(runtime.handle().clone()).build().unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///clock.wait_for_time(10).unwrap();
+  ///
+  ///assert!(clock.time() > 10);
+  ///```
+  pub fn runtime(mut self, handle: Handle) -> Self {
+    self.runtime = Some(handle);
+
+    self
+  }
+
+  ///Ticks the clock as fast as its receivers take the ticks instead of on its tickrate, see
+  ///[`Clock::simulated()`](crate::Clock::simulated()).
+  ///
+  ///The tickrate is still used for converting durations into ticks. A simulated clock can't be given a
+  ///[`precision`](crate::ClockBuilder::precision()), spin, a driver, another backend or a
+  ///[`recording`](crate::ClockBuilder::replay()) to replay, so [`build()`](crate::ClockBuilder::build())
+  ///returns an error if it is.
+  ///
+  ///Defaults to false.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::builder().tick_rate(1000).simulated(true).build().unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///clock.start();
+  ///
+  ///// a hundred seconds' worth of ticks without waiting for them
+  ///time_receiver.wait_for_time(100).unwrap();
+  ///
+  ///assert_eq!(time_receiver.time(), 101);
+  ///```
+  pub fn simulated(mut self, simulated: bool) -> Self {
+    self.simulated = simulated;
+
+    self
+  }
+
+  ///Holds back every tick of the clock until each [`critical receiver`](crate::CriticalReceiver) has
+  ///acknowledged the one before it, for lockstep simulations that can't have the clock run ahead of them.
+  ///
+  ///The clock waits up to `timeout` for the acknowledgements of every tick, then deals with the receivers
+  ///that didn't acknowledge it as the [`straggler policy`](crate::StragglerPolicy) says. Only receivers
+  ///created with [`Clock::critical_receiver()`](crate::Clock::critical_receiver()) hold the clock back,
+  ///ordinary ones get the ticks whenever they're sent. A clock without any critical receivers ticks as
+  ///it normally would. Since [`stop()`](crate::Clock::stop()) waits for the next tick, it can take until
+  ///the timeout while a critical receiver hasn't acknowledged the latest one.
+  ///
+  ///A timeout of zero, or a [`simulated`](crate::ClockBuilder::simulated()) clock, which already waits
+  ///for its receivers, makes [`build()`](crate::ClockBuilder::build()) return an error.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, StragglerPolicy};
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::builder()
+  ///  .tick_rate(1)
+  ///  .acknowledged_ticks(Duration::from_millis(100), StragglerPolicy::Evict)
+  ///  .build()
+  ///  .unwrap();
+  ///let mut critical_receiver = clock.critical_receiver().unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///let time = critical_receiver.wait_for_tick().unwrap();
+  ///critical_receiver.acknowledge();
+  ///
+  ///assert_eq!(critical_receiver.wait_for_tick().unwrap(), time + 1);
+  ///```
+  pub fn acknowledged_ticks(mut self, timeout: Duration, stragglers: StragglerPolicy) -> Self {
+    self.acknowledged_ticks = Some(AcknowledgedTicks { timeout, stragglers });
+
+    self
+  }
+
+  ///Runs the clock on a dedicated thread with real-time scheduling priority, so its ticks don't jitter
+  ///while the rest of the system is under load.
+  ///
+  ///The thread is scheduled with `SCHED_FIFO` on unix and `THREAD_PRIORITY_TIME_CRITICAL` on Windows.
+  ///Raising the priority usually needs elevated permissions, such as `CAP_SYS_NICE` or an `rtprio` limit
+  ///on Linux, and [`build()`](crate::ClockBuilder::build()) returns an error if it couldn't be raised.
+  ///
+  ///A clock with real-time priority always gets a [`dedicated runtime`](crate::ClockBuilder::dedicated_runtime()),
+  ///even if it's [`multi_threaded`](crate::ClockBuilder::multi_threaded()), as only its own thread is raised.
+  ///Combined with [`precision()`](crate::ClockBuilder::precision()) the clock spins at that priority,
+  ///which keeps a core from running anything else for the end of every tick.
+  ///
+  ///Defaults to false.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///match Clock::builder().tick_rate(1).realtime_priority(true).build() {
+  ///  Ok(mut clock) => clock.start(),
+  ///  Err(error) => eprintln!("running without real-time priority: {error}"),
+  ///}
+  ///```
+  pub fn realtime_priority(mut self, realtime_priority: bool) -> Self {
+    self.realtime_priority = realtime_priority;
+
+    self
+  }
+
+  ///Pins the clock to a CPU core, so its ticks aren't delayed by whatever else is scheduled on the others.
+  ///
+  ///Cores are numbered from 0 in the order the OS lists them. Pinning is supported on Linux, Android and
+  ///Windows, where only the cores of the thread's processor group can be pinned to. On other platforms,
+  ///or if the core doesn't exist, [`build()`](crate::ClockBuilder::build()) returns an error.
+  ///
+  ///Like [`realtime_priority()`](crate::ClockBuilder::realtime_priority()), a pinned clock always gets
+  ///a [`dedicated runtime`](crate::ClockBuilder::dedicated_runtime()) whose thread is the one pinned.
+  ///Isolating the core from everything else, such as with `isolcpus` on Linux, is up to the system.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::builder().tick_rate(1).pin_to_core(0).build();
+  ///
+  ///# #[cfg(target_os = "linux")]
+  ///assert!(clock.is_ok());
+  ///```
+  pub fn pin_to_core(mut self, core: usize) -> Self {
+    self.core = Some(core);
+
+    self
+  }
+
+  ///Restarts the clock task up to `max_restarts` times if it panics, such as from a
+  ///[`hook`](crate::ClockHook) or a job of a [`tick executor`](crate::TickExecutor), instead of stopping
+  ///the clock.
+  ///
+  ///The restarted task carries on from the tick after the one it panicked on. Every receiver gets
+  ///[`ClockError::Restarted`](crate::ClockError::Restarted) once for every restart, so it can find out
+  ///that a tick may have been cut short. Once every restart has been used up the next panic stops the
+  ///clock at the tick it panicked on, and receivers get [`ClockError::Stopped`](crate::ClockError::Stopped)
+  ///with that time.
+  ///
+  ///Defaults to 0, which never restarts the task.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::builder().tick_rate(1).restart_on_panic(3).build().unwrap();
+  ///
+  ///clock.start();
+  ///```
+  pub fn restart_on_panic(mut self, max_restarts: u32) -> Self {
+    self.max_restarts = max_restarts;
+
+    self
+  }
+
+  ///Creates the clock.
+  pub fn build(self) -> anyhow::Result<Clock> {
+    Clock::new_clock(self)
+  }
+}
@@ -0,0 +1,73 @@
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::Arc;
+use tokio::sync::Notify;
+
+#[derive(Debug, Clone, Default)]
+///A handle that cancels the waits it's passed to.
+///
+///Every clone shares the same state, so one thread can cancel waits happening on another.
+///Once cancelled a handle stays cancelled, any wait it's passed to after that returns
+///[`ClockError::Cancelled`](crate::ClockError::Cancelled) right away.
+///
+///# Usage
+///
+///```
+///use thread_clock::{CancelHandle, Clock, ClockError};
+///use std::thread;
+///
+///let mut clock = Clock::new().unwrap();
+///clock.start();
+///
+///let mut time_receiver = clock.spawn_receiver();
+///let cancel_handle = CancelHandle::new();
+///let worker_cancel_handle = cancel_handle.clone();
+///
+///let handle = thread::spawn(move || {
+///  time_receiver.wait_for_time_cancellable(1_000_000, &worker_cancel_handle)
+///});
+///
+///cancel_handle.cancel();
+///
+///let error = handle.join().unwrap().unwrap_err();
+///
+///assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Cancelled));
+///```
+pub struct CancelHandle {
+  inner: Arc<CancelInner>,
+}
+
+#[derive(Debug, Default)]
+struct CancelInner {
+  cancelled: AtomicBool,
+  notify: Notify,
+}
+
+impl CancelHandle {
+  ///Creates a handle that hasn't been cancelled.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  ///Cancels every wait using this handle.
+  pub fn cancel(&self) {
+    self.inner.cancelled.store(true, Ordering::SeqCst);
+    self.inner.notify.notify_waiters();
+  }
+
+  ///Returns true if the handle has been cancelled.
+  pub fn is_cancelled(&self) -> bool {
+    self.inner.cancelled.load(Ordering::SeqCst)
+  }
+
+  ///Waits until the handle is cancelled.
+  pub(crate) async fn cancelled(&self) {
+    // a notified future receives notify_waiters as soon as it's created, so no cancel can be missed
+    let notified = self.inner.notify.notified();
+
+    if self.is_cancelled() {
+      return;
+    }
+
+    notified.await;
+  }
+}
@@ -0,0 +1,89 @@
+use crate::logging::log_debug;
+use crate::Time;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio::sync::broadcast::Receiver;
+
+// the items here are public so `--cfg loom` builds can hand them to the model checks in the tests,
+// the crate itself only exposes them to its own modules
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///The messages the clock task broadcasts to every receiver.
+pub enum ClockMessage {
+  Tick(Time),
+  Stopped(Time),
+}
+
+///Waits for the first message sent on the channel after this is called, which is received with a single
+///receive no matter what was already in the channel.
+///
+///The receiver is moved to the end of the channel first, so none of the messages queued in it are
+///received. A clock's status changes before it sends its final message, so `final_time` is checked once
+///the receiver has moved, in case that message was among the ones skipped. Reading starts after the
+///move, as a simulated clock ticks as soon as its receivers are reading, and lasts until the message
+///is received. Messages are received through `recv`, which is how the wait is made interruptible.
+///
+///A receiver that lags behind while it waits [`catches up`](catch_up) to the newest message instead of
+///getting the oldest one the channel still holds.
+pub fn next_message<Reading>(
+  time_receiver: &mut Receiver<ClockMessage>,
+  start_reading: impl FnOnce() -> Reading,
+  final_time: impl FnOnce() -> Option<Time>,
+  mut recv: impl FnMut(&mut Receiver<ClockMessage>) -> anyhow::Result<Result<ClockMessage, RecvError>>,
+) -> anyhow::Result<ClockMessage> {
+  *time_receiver = time_receiver.resubscribe();
+
+  let _reading = start_reading();
+
+  if let Some(final_time) = final_time() {
+    return Ok(ClockMessage::Stopped(final_time));
+  }
+
+  loop {
+    match recv(time_receiver)? {
+      Err(RecvError::Lagged(missed_ticks)) => {
+        log_debug!("A time receiver lagged behind the clock and skipped {missed_ticks} ticks");
+
+        if let Some(message) = catch_up(time_receiver) {
+          return Ok(message);
+        }
+      }
+      message => return Ok(message?),
+    }
+  }
+}
+
+///Returns the message right after the last one the receiver got, or None if there isn't one yet or the
+///receiver fell behind by more than the channel holds.
+///
+///A receiver that keeps up carries on from there instead of waiting for the first message sent after
+///the call, so it sees every tick in order even when it comes back for the next one a little late.
+pub(crate) fn message_after_last(time_receiver: &mut Receiver<ClockMessage>) -> Option<ClockMessage> {
+  match time_receiver.try_recv() {
+    Ok(message) => Some(message),
+    Err(TryRecvError::Lagged(missed_ticks)) => {
+      log_debug!("A time receiver fell further behind the clock than its channel holds, skipping {missed_ticks} ticks");
+
+      None
+    }
+    Err(TryRecvError::Empty | TryRecvError::Closed) => None,
+  }
+}
+
+///Moves a receiver that lagged behind to the end of the channel, returning the newest message that was
+///in it, or None if there wasn't one.
+///
+///The clock got further ahead than the channel holds before the receiver was woken up, so the messages
+///left in it are old by now. Every one of them was still sent after the wait started though, so unlike
+///resubscribing, the newest one is kept instead of waiting for another. The ticks skipped are seen by
+///the receiver as a gap in the times it gets.
+pub(crate) fn catch_up(time_receiver: &mut Receiver<ClockMessage>) -> Option<ClockMessage> {
+  let mut newest_message = None;
+
+  loop {
+    match time_receiver.try_recv() {
+      Ok(message) => newest_message = Some(message),
+      Err(TryRecvError::Lagged(_)) => (),
+      Err(TryRecvError::Empty | TryRecvError::Closed) => return newest_message,
+    }
+  }
+}
@@ -0,0 +1,92 @@
+use crate::{ClockBuilder, Time, DEFAULT_CHANNEL_CAPACITY, DEFAULT_TICKRATE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+///How precisely a clock configured by a [`ClockConfig`](crate::ClockConfig) hits its ticks.
+pub enum Precision {
+  ///Sleeps for the whole of every tick.
+  #[default]
+  Sleep,
+
+  ///Spins for this many microseconds at the end of every tick, see
+  ///[`ClockBuilder::precision()`](crate::ClockBuilder::precision()).
+  SpinFor(u64),
+
+  ///Spins for the whole of every tick, see [`ClockBuilder::spin()`](crate::ClockBuilder::spin()).
+  Spin,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+///The settings of a clock as they'd be kept in a config file, to be created with
+///[`Clock::from_config()`](crate::Clock::from_config()).
+///
+///With the `serde` feature this can be deserialized from any format serde supports, every field that's
+///left out keeps the same default as [`Clock::new()`](crate::Clock::new()).
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockConfig, Precision};
+///
+///let config = ClockConfig {
+///  tick_rate: 10,
+///  precision: Precision::SpinFor(200),
+///  ..ClockConfig::default()
+///};
+///
+///let clock = Clock::from_config(config).unwrap();
+///
+///assert_eq!(clock.tick_rate(), 10);
+///```
+///
+///As TOML, this would be:
+///
+///```toml
+///tick_rate = 10
+///channel_capacity = 4
+///precision = { spin_for = 200 }
+///stop_at = 1000
+///```
+pub struct ClockConfig {
+  ///The tickrate in milliseconds.
+  pub tick_rate: u32,
+
+  ///How many messages the channel to the clock's receivers holds, see
+  ///[`ClockBuilder::channel_capacity()`](crate::ClockBuilder::channel_capacity()).
+  pub channel_capacity: usize,
+
+  ///How precisely the clock hits its ticks.
+  pub precision: Precision,
+
+  ///The tick the clock stops itself after, see [`ClockBuilder::stop_at()`](crate::ClockBuilder::stop_at()).
+  pub stop_at: Option<Time>,
+}
+
+impl Default for ClockConfig {
+  fn default() -> Self {
+    Self {
+      tick_rate: DEFAULT_TICKRATE,
+      channel_capacity: DEFAULT_CHANNEL_CAPACITY,
+      precision: Precision::default(),
+      stop_at: None,
+    }
+  }
+}
+
+impl From<ClockConfig> for ClockBuilder {
+  ///Creates a builder with the settings of the config, which can then be configured further.
+  fn from(config: ClockConfig) -> Self {
+    let builder = ClockBuilder::new()
+      .tick_rate(config.tick_rate)
+      .channel_capacity(config.channel_capacity)
+      .precision_mode(config.precision);
+
+    match config.stop_at {
+      Some(stop_at) => builder.stop_at(stop_at),
+      None => builder,
+    }
+  }
+}
@@ -0,0 +1,176 @@
+use crate::status::SharedStatus;
+use crate::listener::TickListener;
+use crate::{ClockError, ClockStatus, Time};
+use crate::sync::{Arc, Condvar, Mutex, Weak};
+use tokio::sync::Notify;
+
+#[derive(Debug, Clone)]
+///Counts down a set amount of ticks of a clock.
+///
+///Every clone shares the same countdown, so it can be waited on from several threads at once,
+///either by blocking with [`wait()`](crate::Countdown::wait()) or by awaiting
+///[`finished()`](crate::Countdown::finished()).
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let countdown = clock.countdown(5);
+///
+///clock.start();
+///
+///countdown.wait().unwrap();
+///
+///assert!(countdown.is_finished());
+///```
+pub struct Countdown {
+  inner: Arc<CountdownInner>,
+}
+
+#[derive(Debug)]
+struct CountdownInner {
+  remaining: Mutex<u64>,
+  ticked: Condvar,
+  notify: Notify,
+  clock_status: Arc<SharedStatus>,
+}
+
+impl Countdown {
+  ///Creates the countdown along with the listener that counts it down from the clock task.
+  pub(crate) fn new(ticks: u64, clock_status: Arc<SharedStatus>) -> (Self, CountdownTicker) {
+    let inner = Arc::new(CountdownInner {
+      remaining: Mutex::new(ticks),
+      ticked: Condvar::new(),
+      notify: Notify::new(),
+      clock_status,
+    });
+    let ticker = CountdownTicker {
+      countdown: Arc::downgrade(&inner),
+    };
+
+    (Self { inner }, ticker)
+  }
+
+  ///Returns how many ticks are left until the countdown finishes.
+  pub fn remaining(&self) -> u64 {
+    *self.inner.remaining.lock().unwrap()
+  }
+
+  ///Returns true once the countdown has reached 0.
+  pub fn is_finished(&self) -> bool {
+    self.remaining() == 0
+  }
+
+  ///Blocks until the countdown finishes.
+  ///
+  ///An error is returned if the countdown can't finish because the clock isn't running.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let countdown = clock.countdown(3);
+  ///
+  ///clock.start();
+  ///countdown.wait().unwrap();
+  ///
+  ///assert_eq!(countdown.remaining(), 0);
+  ///```
+  pub fn wait(&self) -> anyhow::Result<()> {
+    let mut remaining = self.inner.remaining.lock().unwrap();
+
+    while *remaining > 0 {
+      self.inner.check_clock()?;
+
+      remaining = self.inner.ticked.wait(remaining).unwrap();
+    }
+
+    Ok(())
+  }
+
+  ///Waits for the countdown to finish without blocking the thread.
+  ///
+  ///An error is returned if the countdown can't finish because the clock isn't running.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///#[tokio::main]
+  ///async fn main() {
+  ///  let mut clock = Clock::custom(1).unwrap();
+  ///  let countdown = clock.countdown(3);
+  ///
+  ///  clock.start();
+  ///  countdown.finished().await.unwrap();
+  ///
+  ///  assert!(countdown.is_finished());
+  ///}
+  ///```
+  pub async fn finished(&self) -> anyhow::Result<()> {
+    loop {
+      // a notified future receives notify_waiters as soon as it's created, so no tick can be missed
+      let notified = self.inner.notify.notified();
+
+      if self.is_finished() {
+        return Ok(());
+      }
+
+      self.inner.check_clock()?;
+
+      notified.await;
+    }
+  }
+}
+
+impl CountdownInner {
+  ///Returns an error if the clock isn't running, as the countdown would never finish.
+  fn check_clock(&self) -> anyhow::Result<()> {
+    match self.clock_status.get() {
+      ClockStatus::Created => Err(ClockError::NotStarted.into()),
+      ClockStatus::Stopped(final_time) => Err(ClockError::Stopped(final_time).into()),
+      ClockStatus::Running | ClockStatus::Paused => Ok(()),
+    }
+  }
+
+  ///Wakes up everything waiting on the countdown.
+  fn wake_waiters(&self) {
+    self.ticked.notify_all();
+    self.notify.notify_waiters();
+  }
+}
+
+#[derive(Debug)]
+///Counts a countdown down from within the clock task.
+pub(crate) struct CountdownTicker {
+  countdown: Weak<CountdownInner>,
+}
+
+impl TickListener for CountdownTicker {
+  ///Counts down a tick, the ticker is removed once the countdown finishes or every clone of it is dropped.
+  fn tick(&mut self, _time: Time) -> bool {
+    let Some(countdown) = self.countdown.upgrade() else {
+      return false;
+    };
+    let mut remaining = countdown.remaining.lock().unwrap();
+
+    *remaining = remaining.saturating_sub(1);
+    countdown.wake_waiters();
+
+    *remaining > 0
+  }
+
+  ///Wakes every waiter so they can see the clock has stopped.
+  fn stop(&mut self, _final_time: Time) {
+    if let Some(countdown) = self.countdown.upgrade() {
+      let _remaining = countdown.remaining.lock().unwrap();
+
+      countdown.wake_waiters();
+    }
+  }
+}
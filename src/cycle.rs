@@ -0,0 +1,170 @@
+use crate::status::SharedStatus;
+use crate::listener::TickListener;
+use crate::{ClockError, ClockStatus, Time};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use crate::sync::{Arc, Mutex, Weak};
+
+///How many events a cycle holds on to before the newer ones are dropped, as long as none are read.
+const EVENT_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///The start or end of a cycle, returned by [`Cycle::next_event()`](crate::Cycle::next_event()).
+///
+///Both contain which cycle it is, counting from the one starting at time 0.
+pub enum CycleEvent {
+  ///The cycle started on this tick, it's at phase 0.
+  Start(u64),
+
+  ///The cycle ended on this tick, it's at its last phase.
+  End(u64),
+}
+
+#[derive(Debug)]
+///Splits the ticks of a clock into cycles of a set length, such as the days of a game or the rounds
+///of a simulation.
+///
+///Cycles are counted from time 0 of the clock, so the phase of a tick is its time modulo the length of
+///the cycle, whenever the cycle was created. The cycle is followed from within the clock task, so reading
+///the phase never blocks and no start or end of a cycle is missed.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, CycleEvent};
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let day = clock.cycle(10).unwrap();
+///
+///clock.start();
+///
+///assert_eq!(day.next_event().unwrap(), CycleEvent::Start(0));
+///assert_eq!(day.next_event().unwrap(), CycleEvent::End(0));
+///assert_eq!(day.phase(), 9);
+///```
+pub struct Cycle {
+  inner: Arc<CycleInner>,
+  events: Receiver<CycleEvent>,
+}
+
+#[derive(Debug)]
+struct CycleInner {
+  length: u64,
+  latest_time: Mutex<Option<Time>>,
+  clock_status: Arc<SharedStatus>,
+}
+
+impl Cycle {
+  ///Creates the cycle along with the listener that follows it from the clock task.
+  pub(crate) fn new(length: u64, clock_status: Arc<SharedStatus>) -> (Self, CycleTicker) {
+    let inner = Arc::new(CycleInner {
+      length,
+      latest_time: Mutex::new(None),
+      clock_status,
+    });
+    let (event_sender, events) = mpsc::sync_channel(EVENT_CAPACITY);
+    let ticker = CycleTicker {
+      cycle: Arc::downgrade(&inner),
+      event_sender: Some(event_sender),
+    };
+
+    (Self { inner, events }, ticker)
+  }
+
+  ///Returns how many ticks a cycle lasts.
+  pub fn length(&self) -> u64 {
+    self.inner.length
+  }
+
+  ///Returns how far into its cycle the latest tick of the clock is, from 0 up to the length of the cycle.
+  ///
+  ///The phase is 0 until the clock ticks.
+  pub fn phase(&self) -> u64 {
+    self.inner.latest_time().map_or(0, |time| time % self.inner.length)
+  }
+
+  ///Returns how many cycles have ended by the latest tick of the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let round = clock.cycle(3).unwrap();
+  ///
+  ///clock.start();
+  ///clock.wait_for_time(7).unwrap();
+  ///
+  ///assert!(round.cycles_completed() >= 2);
+  ///```
+  pub fn cycles_completed(&self) -> u64 {
+    let length = self.inner.length;
+
+    // the same as (time + 1) / length, without overflowing at the maximum time
+    self
+      .inner
+      .latest_time()
+      .map_or(0, |time| time / length + (time % length + 1) / length)
+  }
+
+  ///Blocks until the next cycle starts or ends and returns which it was.
+  ///
+  ///Events that haven't been read yet are returned first, up to 64 of them are held on to.
+  ///An error is returned if the clock hasn't started or has stopped and every event has been read.
+  pub fn next_event(&self) -> anyhow::Result<CycleEvent> {
+    if self.inner.clock_status.get() == ClockStatus::Created {
+      return Err(ClockError::NotStarted.into());
+    }
+
+    self.events.recv().map_err(|_| match self.inner.clock_status.get() {
+      ClockStatus::Stopped(final_time) => ClockError::Stopped(final_time).into(),
+      _ => ClockError::NotStarted.into(),
+    })
+  }
+
+  ///Returns the oldest event that hasn't been read yet without waiting, or None if there isn't one.
+  pub fn try_next_event(&self) -> Option<CycleEvent> {
+    self.events.try_recv().ok()
+  }
+}
+
+impl CycleInner {
+  fn latest_time(&self) -> Option<Time> {
+    *self.latest_time.lock().unwrap()
+  }
+}
+
+#[derive(Debug)]
+///Follows a cycle from within the clock task.
+pub(crate) struct CycleTicker {
+  cycle: Weak<CycleInner>,
+  event_sender: Option<SyncSender<CycleEvent>>,
+}
+
+impl TickListener for CycleTicker {
+  ///Moves the cycle to the tick, the ticker is removed once the cycle is dropped.
+  fn tick(&mut self, time: Time) -> bool {
+    let (Some(cycle), Some(event_sender)) = (self.cycle.upgrade(), &self.event_sender) else {
+      return false;
+    };
+    let phase = time % cycle.length;
+    let cycle_number = time / cycle.length;
+
+    *cycle.latest_time.lock().unwrap() = Some(time);
+
+    if phase == 0 {
+      let _ = event_sender.try_send(CycleEvent::Start(cycle_number));
+    }
+
+    if phase == cycle.length - 1 {
+      let _ = event_sender.try_send(CycleEvent::End(cycle_number));
+    }
+
+    true
+  }
+
+  ///Lets anything waiting on the next event see that the clock has stopped.
+  fn stop(&mut self, _final_time: Time) {
+    self.event_sender = None;
+  }
+}
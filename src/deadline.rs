@@ -0,0 +1,182 @@
+use crate::status::SharedStatus;
+use crate::listener::TickListener;
+use crate::{Clock, ClockError, ClockStatus, Overflow, Time};
+use std::future::Future;
+use std::pin::Pin;
+use crate::sync::{Arc, Condvar, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug)]
+///A tick of a clock that can be waited for, either by blocking with [`wait()`](crate::Deadline::wait())
+///or by awaiting the deadline itself.
+///
+///The deadline finishes on the first tick of the clock at or after its own, returning that tick's time,
+///so it never misses its tick however late it's waited on. If the tick has already passed when the
+///deadline is created, it finishes on the next tick.
+///As a future it composes with `tokio::time::timeout()` and `tokio::select!`.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, Deadline};
+///
+///#[tokio::main]
+///async fn main() {
+///  let mut clock = Clock::custom(1).unwrap();
+///  let deadline = Deadline::at(&clock, 10);
+///
+///  clock.start();
+///
+///  assert_eq!(deadline.await.unwrap(), 10);
+///}
+///```
+pub struct Deadline {
+  inner: Arc<DeadlineInner>,
+}
+
+#[derive(Debug)]
+struct DeadlineInner {
+  tick: Time,
+  state: Mutex<DeadlineState>,
+  reached: Condvar,
+  clock_status: Arc<SharedStatus>,
+}
+
+#[derive(Debug, Default)]
+struct DeadlineState {
+  ///The time of the tick the deadline finished on, or why it can't finish.
+  result: Option<Result<Time, ClockError>>,
+  waker: Option<Waker>,
+}
+
+impl Deadline {
+  ///Creates a deadline that finishes on `tick` of the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, Deadline};
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let deadline = Deadline::at(&clock, 5);
+  ///
+  ///clock.start();
+  ///
+  ///assert_eq!(deadline.wait().unwrap(), 5);
+  ///assert!(deadline.is_reached());
+  ///```
+  pub fn at(clock: &Clock, tick: Time) -> Self {
+    let inner = Arc::new(DeadlineInner {
+      tick,
+      state: Mutex::new(DeadlineState::default()),
+      reached: Condvar::new(),
+      clock_status: clock.clock_status(),
+    });
+
+    clock.add_tick_listener(DeadlineTicker {
+      deadline: Arc::downgrade(&inner),
+      overflow: clock.overflow(),
+      sent_before: clock.latest_sent_time(),
+    });
+
+    Self { inner }
+  }
+
+  ///Returns the tick the deadline finishes on.
+  pub fn tick(&self) -> Time {
+    self.inner.tick
+  }
+
+  ///Returns true once the clock has reached the deadline.
+  pub fn is_reached(&self) -> bool {
+    matches!(self.inner.state.lock().unwrap().result, Some(Ok(_)))
+  }
+
+  ///Blocks until the clock reaches the deadline and returns the time of the tick it did on.
+  ///
+  ///An error is returned if the clock hasn't started, or stopped before reaching the deadline.
+  pub fn wait(&self) -> anyhow::Result<Time> {
+    let mut state = self.inner.state.lock().unwrap();
+
+    loop {
+      if let Some(result) = state.result {
+        return Ok(result?);
+      }
+
+      if self.inner.clock_status.get() == ClockStatus::Created {
+        return Err(ClockError::NotStarted.into());
+      }
+
+      state = self.inner.reached.wait(state).unwrap();
+    }
+  }
+}
+
+impl Future for Deadline {
+  type Output = anyhow::Result<Time>;
+
+  ///Finishes once the clock reaches the deadline, or with an error if it stops before then.
+  ///
+  ///A clock that hasn't started yet is waited on until it starts.
+  fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    let mut state = self.inner.state.lock().unwrap();
+
+    match state.result {
+      Some(result) => Poll::Ready(Ok(result?)),
+      None => {
+        state.waker = Some(context.waker().clone());
+
+        Poll::Pending
+      }
+    }
+  }
+}
+
+impl DeadlineInner {
+  ///Finishes the deadline, waking up everything waiting on it.
+  fn finish(&self, result: Result<Time, ClockError>) {
+    let mut state = self.state.lock().unwrap();
+
+    state.result = Some(result);
+    self.reached.notify_all();
+
+    if let Some(waker) = state.waker.take() {
+      waker.wake();
+    }
+  }
+}
+
+#[derive(Debug)]
+///Finishes a deadline from within the clock task.
+struct DeadlineTicker {
+  deadline: Weak<DeadlineInner>,
+  overflow: Overflow,
+
+  ///The latest tick the clock had sent when the deadline was created. Listeners only hear about a tick
+  ///after it was sent, so the deadline can be added in between and has to skip it.
+  sent_before: Option<Time>,
+}
+
+impl TickListener for DeadlineTicker {
+  ///Finishes the deadline once the clock reaches it, the ticker is removed then or once the deadline is dropped.
+  fn tick(&mut self, time: Time) -> bool {
+    let Some(deadline) = self.deadline.upgrade() else {
+      return false;
+    };
+
+    if self.sent_before.take() == Some(time) || self.overflow.ticks_between(deadline.tick, time).is_none() {
+      return true;
+    }
+
+    deadline.finish(Ok(time));
+
+    false
+  }
+
+  ///Lets anything waiting on the deadline see that it can't be reached anymore.
+  fn stop(&mut self, final_time: Time) {
+    if let Some(deadline) = self.deadline.upgrade() {
+      deadline.finish(Err(ClockError::Stopped(final_time)));
+    }
+  }
+}
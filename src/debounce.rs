@@ -0,0 +1,111 @@
+use crate::listener::TickListener;
+use crate::Time;
+use crate::sync::{Arc, Mutex, Weak};
+
+#[derive(Debug, Clone)]
+///Waits for a burst of events to settle down, until `n` ticks of a clock pass without another one.
+///
+///Every clone shares the same debounce, so events can be triggered from one thread and
+///checked for on another.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let debounce = clock.debounce(3);
+///
+///clock.start();
+///
+///// a burst of key presses
+///for _ in 0..5 {
+///  debounce.trigger();
+///}
+///
+///assert!(!debounce.settled());
+///
+///clock.wait_for_x_ticks(4).unwrap();
+///
+///// the burst is handled once
+///assert!(debounce.settled());
+///assert!(!debounce.settled());
+///```
+pub struct Debounce {
+  inner: Arc<DebounceInner>,
+}
+
+#[derive(Debug)]
+struct DebounceInner {
+  ticks: u64,
+  state: Mutex<DebounceState>,
+}
+
+#[derive(Debug, Default)]
+struct DebounceState {
+  pending: bool,
+  quiet_ticks: u64,
+}
+
+impl Debounce {
+  ///Creates the debounce along with the listener that counts ticks for it from the clock task.
+  pub(crate) fn new(ticks: u64) -> (Self, DebounceTicker) {
+    let inner = Arc::new(DebounceInner {
+      ticks,
+      state: Mutex::new(DebounceState::default()),
+    });
+    let ticker = DebounceTicker {
+      debounce: Arc::downgrade(&inner),
+    };
+
+    (Self { inner }, ticker)
+  }
+
+  ///Returns how many ticks have to pass without an event before the events settle.
+  pub fn ticks(&self) -> u64 {
+    self.inner.ticks
+  }
+
+  ///Records an event, which restarts the count of quiet ticks.
+  pub fn trigger(&self) {
+    let mut state = self.inner.state.lock().unwrap();
+
+    state.pending = true;
+    state.quiet_ticks = 0;
+  }
+
+  ///Returns true once `n` ticks have passed since the last event.
+  ///
+  ///Only returns true once for every burst of events.
+  pub fn settled(&self) -> bool {
+    let mut state = self.inner.state.lock().unwrap();
+
+    if state.pending && state.quiet_ticks >= self.inner.ticks {
+      state.pending = false;
+
+      true
+    } else {
+      false
+    }
+  }
+}
+
+#[derive(Debug)]
+///Counts ticks for a debounce from within the clock task.
+pub(crate) struct DebounceTicker {
+  debounce: Weak<DebounceInner>,
+}
+
+impl TickListener for DebounceTicker {
+  ///Counts the tick, the ticker is removed once every clone of the debounce is dropped.
+  fn tick(&mut self, _time: Time) -> bool {
+    let Some(debounce) = self.debounce.upgrade() else {
+      return false;
+    };
+    let mut state = debounce.state.lock().unwrap();
+
+    state.quiet_ticks = state.quiet_ticks.saturating_add(1);
+
+    true
+  }
+}
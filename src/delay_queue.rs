@@ -0,0 +1,155 @@
+use crate::timer_wheel::{TimerKey, TimerWheel};
+use crate::{Time, TimeReceiver};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug)]
+///A queue of keyed entries that each expire on a tick of a clock.
+///
+///Entries are kept in a [`TimerWheel`](crate::TimerWheel), so inserting, removing and taking expired
+///entries stay fast with hundreds of thousands of entries in the queue.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let mut delay_queue = clock.delay_queue();
+///
+///clock.start();
+///
+///let time = clock.time();
+///
+///delay_queue.insert("fireball", 10, time + 5);
+///delay_queue.insert("arrow", 2, time + 2);
+///
+///assert_eq!(delay_queue.wait_for_expired().unwrap(), Some(("arrow", 2)));
+///assert_eq!(delay_queue.wait_for_expired().unwrap(), Some(("fireball", 10)));
+///assert_eq!(delay_queue.wait_for_expired().unwrap(), None);
+///```
+pub struct TickDelayQueue<K, V> {
+  time_receiver: TimeReceiver,
+  timer_wheel: TimerWheel<K>,
+  entries: HashMap<K, DelayedEntry<V>>,
+}
+
+#[derive(Debug)]
+struct DelayedEntry<V> {
+  value: V,
+  timer_key: TimerKey,
+}
+
+impl<K, V> TickDelayQueue<K, V>
+where
+  K: Hash + Eq + Clone,
+{
+  pub(crate) fn new(time_receiver: TimeReceiver) -> Self {
+    Self {
+      time_receiver,
+      timer_wheel: TimerWheel::new(),
+      entries: HashMap::new(),
+    }
+  }
+
+  ///Adds an entry that expires once the clock reaches `at_tick`.
+  ///
+  ///If the key was already in the queue its entry is replaced and the old value is returned.
+  pub fn insert(&mut self, key: K, value: V, at_tick: Time) -> Option<V> {
+    let old_value = self.remove(&key);
+    let timer_key = self.timer_wheel.insert(key.clone(), at_tick);
+
+    self.entries.insert(key, DelayedEntry { value, timer_key });
+
+    old_value
+  }
+
+  ///Removes an entry before it expires, returning its value.
+  pub fn remove(&mut self, key: &K) -> Option<V> {
+    let entry = self.entries.remove(key)?;
+
+    self.timer_wheel.cancel(entry.timer_key);
+
+    Some(entry.value)
+  }
+
+  ///Moves an entry to expire on a different tick.
+  ///
+  ///Returns false if the key isn't in the queue.
+  pub fn reset(&mut self, key: &K, at_tick: Time) -> bool {
+    match self.remove(key) {
+      Some(value) => {
+        self.insert(key.clone(), value, at_tick);
+
+        true
+      }
+      None => false,
+    }
+  }
+
+  ///Returns the tick an entry expires on.
+  pub fn deadline(&self, key: &K) -> Option<Time> {
+    self.timer_wheel.deadline(self.entries.get(key)?.timer_key)
+  }
+
+  ///Returns the tick the next entry to expire expires on.
+  pub fn next_deadline(&self) -> Option<Time> {
+    self.timer_wheel.next_deadline()
+  }
+
+  ///Returns how many entries are in the queue.
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  ///Returns true if the queue has no entries.
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  ///Takes the next entry that has expired without waiting.
+  ///
+  ///Returns None if no entry has expired yet.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut delay_queue = clock.delay_queue();
+  ///
+  ///delay_queue.insert(1, "spawn enemy", 3);
+  ///
+  ///clock.start();
+  ///clock.wait_for_time(3).unwrap();
+  ///
+  ///assert_eq!(delay_queue.poll_expired(), Some((1, "spawn enemy")));
+  ///assert_eq!(delay_queue.poll_expired(), None);
+  ///```
+  pub fn poll_expired(&mut self) -> Option<(K, V)> {
+    let current_time = self.time_receiver.latest_time()?;
+    let key = self.timer_wheel.poll(current_time)?;
+    let value = self.entries.remove(&key)?.value;
+
+    Some((key, value))
+  }
+
+  ///Blocks until the next entry expires and takes it.
+  ///
+  ///Returns None if the queue is empty.
+  ///An error is returned if something went wrong with the clock.
+  pub fn wait_for_expired(&mut self) -> anyhow::Result<Option<(K, V)>> {
+    loop {
+      if let Some(expired) = self.poll_expired() {
+        return Ok(Some(expired));
+      }
+
+      let Some(next_deadline) = self.next_deadline() else {
+        return Ok(None);
+      };
+
+      self.time_receiver.wait_until_at_least(next_deadline)?;
+    }
+  }
+}
@@ -0,0 +1,69 @@
+use crate::ClockMessage;
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///The order every tick is sent to receivers in, see
+///[`Clock::spawn_receiver_with_priority()`](crate::Clock::spawn_receiver_with_priority()).
+pub enum ReceiverPriority {
+  ///Sent every tick after every other receiver, such as for logging.
+  Low,
+
+  ///Sent every tick after high priority receivers and before low priority ones, which is what receivers
+  ///are created with by default.
+  #[default]
+  Normal,
+
+  ///Sent every tick before every other receiver, such as for physics.
+  High,
+}
+
+#[derive(Debug, Clone)]
+///The channels a clock sends its messages on, one for every receiver priority.
+///
+///Every message is sent to the highest priority first, right before the lower ones, without waiting on the
+///receivers in between. Receivers of the same priority share a channel and are sent messages together.
+pub(crate) struct TickSenders {
+  high: Sender<ClockMessage>,
+  normal: Sender<ClockMessage>,
+  low: Sender<ClockMessage>,
+}
+
+impl TickSenders {
+  ///Creates the channels along with a receiver of normal priority.
+  pub(crate) fn new(capacity: usize) -> (Self, Receiver<ClockMessage>) {
+    let (normal, time_receiver) = broadcast::channel(capacity);
+    let tick_senders = Self {
+      high: broadcast::channel(capacity).0,
+      normal,
+      low: broadcast::channel(capacity).0,
+    };
+
+    (tick_senders, time_receiver)
+  }
+
+  ///Returns the channel of receivers with the priority.
+  pub(crate) fn sender(&self, priority: ReceiverPriority) -> &Sender<ClockMessage> {
+    match priority {
+      ReceiverPriority::High => &self.high,
+      ReceiverPriority::Normal => &self.normal,
+      ReceiverPriority::Low => &self.low,
+    }
+  }
+
+  pub(crate) fn subscribe(&self, priority: ReceiverPriority) -> Receiver<ClockMessage> {
+    self.sender(priority).subscribe()
+  }
+
+  ///Sends the message to every receiver, from the highest priority down.
+  pub(crate) fn send(&self, message: ClockMessage) {
+    for sender in [&self.high, &self.normal, &self.low] {
+      // a channel without receivers has nothing to wake up
+      let _ = sender.send(message);
+    }
+  }
+
+  ///Returns how many receivers there are across every priority.
+  pub(crate) fn receiver_count(&self) -> usize {
+    self.high.receiver_count() + self.normal.receiver_count() + self.low.receiver_count()
+  }
+}
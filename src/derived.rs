@@ -0,0 +1,186 @@
+use crate::listener::TickListener;
+use crate::{ClockMessage, Overflow, Time, TimeReceiver};
+use std::time::Duration;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Debug)]
+///A clock that ticks once for every `divisor` ticks of the clock it was derived from.
+///
+///Derived clocks share the task of their parent, so a 240Hz clock can drive 60Hz and 1Hz
+///children without creating more timers.
+///
+///A derived clock counts from the time its parent started from, whether it was derived before or after
+///the start, counts over once its parent is started again, and stops when its parent stops.
+///
+///# Creation
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let mut derived_clock = clock.derive(4).unwrap();
+///
+///clock.start();
+///
+///let time = derived_clock.time();
+///
+///assert_eq!(time, 0);
+///```
+pub struct DerivedClock {
+  divisor: u32,
+  clock_sender: Sender<ClockMessage>,
+  time_receiver: TimeReceiver,
+}
+
+impl DerivedClock {
+  pub(crate) fn new(
+    parent_receiver: &TimeReceiver,
+    clock_sender: Sender<ClockMessage>,
+    parent_tick_rate: u32,
+    divisor: u32,
+  ) -> Self {
+    let tick_rate = parent_tick_rate.saturating_mul(divisor);
+    let time_receiver = parent_receiver.with_receiver(clock_sender.subscribe(), tick_rate);
+
+    Self {
+      divisor,
+      clock_sender,
+      time_receiver,
+    }
+  }
+
+  ///Returns how many ticks of the parent clock make up one tick of this clock.
+  pub fn divisor(&self) -> u32 {
+    self.divisor
+  }
+
+  ///Returns the tickrate of this clock in milliseconds, which is the parent's tickrate times the divisor.
+  pub fn tick_rate(&self) -> u32 {
+    self.time_receiver.tick_rate()
+  }
+
+  ///Waits for the next tick and returns the time.
+  ///
+  ///Works the same as [`TimeReceiver::time()`](crate::TimeReceiver::time()).
+  pub fn time(&mut self) -> Time {
+    self.time_receiver.time()
+  }
+
+  ///Works the same as [`TimeReceiver::safe_time()`](crate::TimeReceiver::safe_time()).
+  pub fn safe_time(&mut self) -> anyhow::Result<Time> {
+    self.time_receiver.safe_time()
+  }
+
+  ///Works the same as [`TimeReceiver::wait_for_tick()`](crate::TimeReceiver::wait_for_tick()).
+  pub fn wait_for_tick(&mut self) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_tick()
+  }
+
+  ///Works the same as [`TimeReceiver::wait_for_x_ticks()`](crate::TimeReceiver::wait_for_x_ticks()).
+  pub fn wait_for_x_ticks(&mut self, x: u32) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_x_ticks(x)
+  }
+
+  ///Works the same as [`TimeReceiver::wait_for_time()`](crate::TimeReceiver::wait_for_time()).
+  pub fn wait_for_time(&mut self, time: Time) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_time(time)
+  }
+
+  ///Works the same as [`TimeReceiver::wait_for_duration()`](crate::TimeReceiver::wait_for_duration()),
+  ///using the tickrate of this derived clock.
+  pub fn wait_for_duration(&mut self, duration: Duration) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_duration(duration)
+  }
+
+  ///Creates a [`time receiver`](crate::TimeReceiver) that receives the ticks of this derived clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let derived_clock = clock.derive(2).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///let mut time_receiver = derived_clock.spawn_receiver();
+  ///
+  ///let time = time_receiver.time();
+  ///
+  ///assert_eq!(time, 0);
+  ///```
+  pub fn spawn_receiver(&self) -> TimeReceiver {
+    self.time_receiver.with_receiver(self.clock_sender.subscribe(), self.tick_rate())
+  }
+}
+
+#[derive(Debug)]
+///The sending half of a derived clock which lives in the parent's task.
+pub(crate) struct DerivedOutput {
+  divisor: u32,
+  clock_sender: Sender<ClockMessage>,
+  overflow: Overflow,
+
+  ///The time the parent's current run started from, None until the parent has been started.
+  start_time: Option<Time>,
+}
+
+impl DerivedOutput {
+  pub(crate) fn new(divisor: u32, clock_sender: Sender<ClockMessage>, overflow: Overflow) -> Self {
+    Self {
+      divisor,
+      clock_sender,
+      overflow,
+      start_time: None,
+    }
+  }
+
+  ///Returns how many ticks the parent has made by the time since it started, or None if it hasn't started
+  ///or the time comes before its start.
+  ///
+  ///A parent that started from 0 and reached `Time::MAX` has made one more tick than a time can count.
+  fn elapsed_ticks(&self, parent_time: Time) -> Option<u128> {
+    let ticks = self.overflow.ticks_between(self.start_time?, parent_time)?;
+
+    Some(u128::from(ticks) + 1)
+  }
+}
+
+impl TickListener for DerivedOutput {
+  ///Sends a tick if the parent time completes a full period of this output.
+  ///
+  ///The output is removed once the derived clock and all of its receivers are dropped.
+  fn tick(&mut self, parent_time: Time) -> bool {
+    let divisor = u128::from(self.divisor);
+
+    if let Some(elapsed_parent_ticks) = self.elapsed_ticks(parent_time) {
+      if elapsed_parent_ticks.is_multiple_of(divisor) {
+        let _ = self.clock_sender.send(ClockMessage::Tick(to_time(elapsed_parent_ticks / divisor - 1)));
+      }
+    }
+
+    self.clock_sender.receiver_count() > 0
+  }
+
+  ///Counts the output's ticks from the time the parent starts from.
+  fn start(&mut self, start_time: Time) {
+    self.start_time = Some(start_time);
+  }
+
+  ///Sends the final time of this output based on the final time of the parent.
+  ///
+  ///A parent that stopped before its first tick leaves the output at 0.
+  fn stop(&mut self, parent_final_time: Time) {
+    let final_time = self
+      .elapsed_ticks(parent_final_time)
+      .map_or(0, |elapsed_parent_ticks| (elapsed_parent_ticks / u128::from(self.divisor)).saturating_sub(1));
+
+    let _ = self.clock_sender.send(ClockMessage::Stopped(to_time(final_time)));
+  }
+}
+
+///The elapsed ticks divided by a divisor of at least 1 and less one always fit a time.
+fn to_time(ticks: u128) -> Time {
+  Time::try_from(ticks).unwrap_or(Time::MAX)
+}
@@ -0,0 +1,178 @@
+use crate::listener::TickListener;
+use crate::Time;
+use std::fmt;
+use crate::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::Receiver;
+use crate::sync::Arc;
+use std::time::{Duration, Instant};
+
+///The most a disciplined clock speeds up or slows down its tickrate by, as a fraction of it.
+pub(crate) const MAX_SLEW: f64 = 0.05;
+
+///How much of the difference to the reference is corrected for, per tick of difference.
+const PROPORTIONAL_GAIN: f64 = 0.02;
+
+///How much the difference to the reference accumulated over every tick is corrected for, which cancels
+///out steady drift.
+const INTEGRAL_GAIN: f64 = 0.0001;
+
+///Returns the newest tick a reference reported since it was last polled, along with when the reference
+///was on it.
+pub(crate) type PollReports = Box<dyn FnMut() -> Option<(Time, Instant)> + Send>;
+
+///A source of authoritative ticks that a clock can be [`synced to`](crate::Clock::sync_to()).
+///
+///The clock polls the reference once every tick from within its task, so polling can't block.
+///Between reports the reference is assumed to keep ticking at the clock's own tickrate.
+///
+///A [`std::sync::mpsc::Receiver<Time>`] is a reference out of the box, every tick sent on its channel is a report.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, Time, TickReference};
+///
+///struct GameServer {
+///  latest_tick: Option<Time>,
+///}
+///
+///impl TickReference for GameServer {
+///  fn poll_reference(&mut self) -> Option<Time> {
+///    self.latest_tick.take()
+///  }
+///}
+///
+///let clock = Clock::custom(16).unwrap();
+///
+///clock.sync_to(GameServer { latest_tick: Some(100) });
+///```
+pub trait TickReference: Send + 'static {
+  ///Returns the tick the reference is at if it has reported one since it was last polled.
+  fn poll_reference(&mut self) -> Option<Time>;
+}
+
+impl TickReference for Receiver<Time> {
+  ///Returns the newest tick sent on the channel.
+  fn poll_reference(&mut self) -> Option<Time> {
+    self.try_iter().last()
+  }
+}
+
+#[derive(Debug, Default)]
+///How much the clock task currently speeds its ticks up by, shared between the clock and its task.
+pub(crate) struct RateAdjustment {
+  ///The adjustment as the bits of an f64, positive values tick faster.
+  adjustment: AtomicU64,
+  ///Bumped whenever the clock is synced to a new reference or wall clock, so the previous one is dropped.
+  generation: AtomicU64,
+}
+
+impl RateAdjustment {
+  ///Returns the fraction of the tickrate the clock currently speeds up by.
+  pub(crate) fn get(&self) -> f64 {
+    f64::from_bits(self.adjustment.load(Ordering::SeqCst))
+  }
+
+  pub(crate) fn set(&self, adjustment: f64) {
+    self.adjustment.store(adjustment.to_bits(), Ordering::SeqCst);
+  }
+
+  ///Returns the generation of syncing the clock is currently on.
+  pub(crate) fn generation(&self) -> u64 {
+    self.generation.load(Ordering::SeqCst)
+  }
+
+  ///Returns true once the clock has been synced to a reference or wall clock.
+  pub(crate) fn is_synced(&self) -> bool {
+    self.generation() > 0
+  }
+
+  ///Starts a new generation of syncing, returning it.
+  pub(crate) fn next_generation(&self) -> u64 {
+    self.set(0.0);
+
+    self.generation.fetch_add(1, Ordering::SeqCst) + 1
+  }
+}
+
+///Slews the rate of a clock towards a reference from within the clock task.
+///
+///This works as a phase-locked loop, every tick the difference between where the reference is estimated
+///to be and the clock is fed through a proportional and an integral term to decide how much faster or
+///slower the clock ticks.
+pub(crate) struct Discipline {
+  poll_reports: PollReports,
+  rate_adjustment: Arc<RateAdjustment>,
+  generation: u64,
+  tick_length: Duration,
+  latest_report: Option<(Time, Instant)>,
+  accumulated_difference: f64,
+}
+
+impl Discipline {
+  pub(crate) fn new(poll_reports: PollReports, rate_adjustment: Arc<RateAdjustment>, tick_rate: u32) -> Self {
+    let generation = rate_adjustment.next_generation();
+
+    Self {
+      poll_reports,
+      rate_adjustment,
+      generation,
+      tick_length: Duration::from_millis(tick_rate.into()),
+      latest_report: None,
+      accumulated_difference: 0.0,
+    }
+  }
+
+  ///Returns the tick the reference is estimated to be on by now.
+  fn estimated_reference_time(&self) -> Option<f64> {
+    let (reported_time, reported_at) = self.latest_report?;
+
+    if self.tick_length.is_zero() {
+      return None;
+    }
+
+    Some(reported_time as f64 + reported_at.elapsed().as_secs_f64() / self.tick_length.as_secs_f64())
+  }
+}
+
+impl fmt::Debug for Discipline {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Discipline")
+      .field("generation", &self.generation)
+      .field("latest_report", &self.latest_report)
+      .field("accumulated_difference", &self.accumulated_difference)
+      .finish()
+  }
+}
+
+impl TickListener for Discipline {
+  ///Adjusts the rate towards the reference, the discipline is removed once the clock is synced to
+  ///another reference.
+  fn tick(&mut self, time: Time) -> bool {
+    if self.rate_adjustment.generation() != self.generation {
+      return false;
+    }
+
+    if let Some(report) = (self.poll_reports)() {
+      self.latest_report = Some(report);
+    }
+
+    let Some(reference_time) = self.estimated_reference_time() else {
+      return true;
+    };
+
+    // positive when the clock is behind the reference and has to speed up
+    let difference = reference_time - time as f64;
+    let accumulated_difference = self.accumulated_difference + difference;
+    let adjustment = PROPORTIONAL_GAIN * difference + INTEGRAL_GAIN * accumulated_difference;
+
+    // the difference only accumulates while the adjustment isn't capped, so it can't wind up
+    if adjustment.abs() < MAX_SLEW {
+      self.accumulated_difference = accumulated_difference;
+    }
+
+    self.rate_adjustment.set(adjustment.clamp(-MAX_SLEW, MAX_SLEW));
+
+    true
+  }
+}
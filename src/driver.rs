@@ -0,0 +1,258 @@
+use crate::runtime::ClockRuntime;
+use std::fmt;
+use crate::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+#[derive(Clone)]
+///A single timer that drives the ticks of every clock registered with it.
+///
+///Every clock normally waits on a timer of its own. Clocks built with
+///[`ClockBuilder::driver()`](crate::ClockBuilder::driver()) wait on their driver instead, which sleeps until
+///the earliest deadline of any of them and wakes up whichever clocks are due. Applications with dozens
+///of clocks then only pay for one timer, whatever the tickrates of the clocks are.
+///
+///The driver runs on the runtime clocks share by default, and keeps running for as long as the driver
+///or any clock built with it exists. Cloning the driver gives another handle to the same timer.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockDriver};
+///
+///let driver = ClockDriver::new().unwrap();
+///let mut fast_clock = Clock::builder().tick_rate(1).driver(&driver).build().unwrap();
+///let mut slow_clock = Clock::builder().tick_rate(5).driver(&driver).build().unwrap();
+///
+///fast_clock.start();
+///slow_clock.start();
+///
+///slow_clock.wait_for_x_ticks(2).unwrap();
+///
+///assert!(fast_clock.time() > slow_clock.time());
+///```
+pub struct ClockDriver {
+  inner: Arc<DriverInner>,
+}
+
+///The clocks of a driver, shared between the driver's handles and its task.
+struct DriverInner {
+  slots: Mutex<Vec<Weak<SlotState>>>,
+  wake_up: Arc<Notify>,
+  ///True if the driver runs on the runtime it was created in, along with the clocks it drives.
+  is_enclosing: bool,
+  _runtime: Arc<ClockRuntime>,
+}
+
+///The deadlines of a clock registered with a driver.
+struct SlotState {
+  times: Mutex<SlotTimes>,
+  due: Notify,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SlotTimes {
+  tick_length: Duration,
+  ///When the driver next wakes the clock up.
+  deadline: Instant,
+  ///The deadline the clock was last woken up for.
+  due_deadline: Instant,
+}
+
+///A clock's place in its driver, the clock is unregistered once this is dropped.
+pub(crate) struct DriverSlot {
+  state: Arc<SlotState>,
+  wake_up: Arc<Notify>,
+}
+
+impl ClockDriver {
+  ///Creates a driver, starting its timer.
+  pub fn new() -> anyhow::Result<Self> {
+    Ok(Self::on_runtime(ClockRuntime::shared()?, false))
+  }
+
+  ///Creates a driver on the tokio runtime it's created in, starting its timer there.
+  ///
+  ///The clocks built with this driver have to be on the
+  ///[`enclosing runtime`](crate::ClockBuilder::enclosing_runtime()) as well, and clocks on the enclosing
+  ///runtime can only be given a driver created this way, so [`build()`](crate::ClockBuilder::build())
+  ///returns an error otherwise. Inside of a runtime whose time is paused, the driver and its clocks tick
+  ///in virtual time.
+  ///
+  ///An error is returned if this isn't called from within a tokio runtime.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockDriver};
+  ///
+  ///#[tokio::main(flavor = "current_thread", start_paused = true)]
+  ///async fn main() {
+  ///  let driver = ClockDriver::enclosing().unwrap();
+  ///  let mut clock = Clock::builder().tick_rate(1000).driver(&driver).enclosing_runtime(true).build().unwrap();
+  ///  let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///  clock.start();
+  ///
+  ///  assert_eq!(time_receiver.next_tick().await.unwrap(), 0);
+  ///  assert_eq!(time_receiver.next_tick().await.unwrap(), 1);
+  ///}
+  ///```
+  pub fn enclosing() -> anyhow::Result<Self> {
+    Ok(Self::on_runtime(Arc::new(ClockRuntime::enclosing()?), true))
+  }
+
+  ///Creates a driver whose timer runs on the runtime.
+  fn on_runtime(runtime: Arc<ClockRuntime>, is_enclosing: bool) -> Self {
+    let wake_up = Arc::new(Notify::new());
+    let inner = Arc::new(DriverInner {
+      slots: Mutex::new(Vec::new()),
+      wake_up: Arc::clone(&wake_up),
+      is_enclosing,
+      _runtime: Arc::clone(&runtime),
+    });
+
+    runtime.spawn(drive(Arc::downgrade(&inner), wake_up));
+
+    Self { inner }
+  }
+
+  ///Returns true if the driver runs on the runtime it was created in.
+  pub(crate) fn is_enclosing(&self) -> bool {
+    self.inner.is_enclosing
+  }
+
+  ///Returns how many clocks the driver is driving, which are the clocks built with it that are running.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockDriver};
+  ///
+  ///let driver = ClockDriver::new().unwrap();
+  ///let mut clock = Clock::builder().tick_rate(1).driver(&driver).build().unwrap();
+  ///
+  ///assert_eq!(driver.clock_count(), 0);
+  ///
+  ///clock.start();
+  ///clock.wait_for_tick().unwrap();
+  ///
+  ///assert_eq!(driver.clock_count(), 1);
+  ///
+  ///clock.stop().unwrap();
+  ///
+  ///assert_eq!(driver.clock_count(), 0);
+  ///```
+  pub fn clock_count(&self) -> usize {
+    let mut slots = self.inner.slots.lock().unwrap();
+
+    slots.retain(|slot| slot.strong_count() > 0);
+    slots.len()
+  }
+
+  ///Registers a clock whose first tick is due on the deadline.
+  pub(crate) fn register(&self, tick_length: Duration, first_deadline: Instant) -> DriverSlot {
+    let state = Arc::new(SlotState {
+      times: Mutex::new(SlotTimes {
+        tick_length,
+        deadline: first_deadline,
+        due_deadline: first_deadline,
+      }),
+      due: Notify::new(),
+    });
+
+    self.inner.slots.lock().unwrap().push(Arc::downgrade(&state));
+    self.inner.wake_up.notify_one();
+
+    DriverSlot {
+      state,
+      wake_up: Arc::clone(&self.inner.wake_up),
+    }
+  }
+}
+
+impl fmt::Debug for ClockDriver {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ClockDriver")
+      .field("clock_count", &self.clock_count())
+      .finish()
+  }
+}
+
+impl Drop for DriverInner {
+  ///Lets the driver's task see that it has nothing left to drive.
+  fn drop(&mut self) {
+    self.wake_up.notify_one();
+  }
+}
+
+impl DriverSlot {
+  ///Waits until the driver wakes the clock up, returning how long after its deadline that happened.
+  pub(crate) async fn tick(&mut self) -> Duration {
+    self.state.due.notified().await;
+
+    let due_deadline = self.state.times.lock().unwrap().due_deadline;
+
+    Instant::now().saturating_duration_since(due_deadline)
+  }
+
+  ///Changes how long every tick after the next one lasts.
+  pub(crate) fn set_tick_length(&mut self, new_tick_length: Duration) {
+    let mut times = self.state.times.lock().unwrap();
+
+    // the deadline was already moved ahead by the old tick length
+    times.deadline = times.deadline - times.tick_length + new_tick_length;
+    times.tick_length = new_tick_length;
+    self.wake_up.notify_one();
+  }
+}
+
+///Sleeps until the earliest deadline of the driver's clocks and wakes up every clock that's due,
+///until neither the driver nor any of its clocks exist anymore.
+async fn drive(inner: Weak<DriverInner>, wake_up: Arc<Notify>) {
+  loop {
+    let Some(driver) = inner.upgrade() else {
+      break;
+    };
+    let now = Instant::now();
+    let mut next_deadline = None;
+
+    driver.slots.lock().unwrap().retain(|slot| {
+      let Some(slot) = slot.upgrade() else {
+        return false;
+      };
+      let times = &mut *slot.times.lock().unwrap();
+
+      if times.deadline <= now {
+        times.due_deadline = times.deadline;
+        times.deadline += times.tick_length;
+
+        // ticks that were missed are skipped instead of being rushed through
+        if times.deadline <= now {
+          times.deadline = now + times.tick_length;
+        }
+
+        slot.due.notify_one();
+      }
+
+      next_deadline = Some(next_deadline.map_or(times.deadline, |next_deadline: Instant| {
+        next_deadline.min(times.deadline)
+      }));
+
+      true
+    });
+
+    drop(driver);
+
+    match next_deadline {
+      Some(next_deadline) => {
+        tokio::select! {
+          _ = tokio::time::sleep_until(next_deadline) => (),
+          _ = wake_up.notified() => (),
+        }
+      }
+      None => wake_up.notified().await,
+    }
+  }
+}
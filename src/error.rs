@@ -0,0 +1,85 @@
+use crate::Time;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+///The errors that can be returned from the clock and its receivers.
+///
+///Every method still returns an [`anyhow::Result`](anyhow::Result), the specific error can be
+///checked by downcasting it.
+///
+///# Example
+///
+///```
+///use thread_clock::{Clock, ClockError};
+///
+///let mut clock = Clock::new().unwrap();
+///
+///let error = clock.safe_time().unwrap_err();
+///
+///assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+///```
+pub enum ClockError {
+  ///The clock hasn't been started yet.
+  NotStarted,
+
+  ///The clock has been stopped, contains the final time it reached.
+  Stopped(Time),
+
+  ///The time being waited for has already occurred.
+  TimeHasOccurred,
+
+  ///The clock was waited on from within a current thread tokio runtime, which can't be blocked.
+  ///
+  ///Waiting from within a multi-threaded tokio runtime is allowed.
+  CalledFromAsyncContext,
+
+  ///The wait was cancelled through a [`CancelHandle`](crate::CancelHandle).
+  Cancelled,
+
+  ///The wait was interrupted through the receiver's [`InterruptHandle`](crate::InterruptHandle).
+  Interrupted,
+
+  ///The clock stopped as its time reached [`Time::MAX`](crate::Time), see [`Overflow::Error`](crate::Overflow::Error).
+  Overflowed,
+
+  ///The time being waited for didn't arrive within the longest the wait was allowed to take.
+  TimedOut,
+
+  ///The clock task panicked and was restarted, contains the time it carried on from.
+  ///
+  ///Every receiver gets this once for each restart, see
+  ///[`ClockBuilder::restart_on_panic()`](crate::ClockBuilder::restart_on_panic()).
+  Restarted(Time),
+
+  ///A receiver got a tick that isn't the one right after the last one it got, see
+  ///[`TimeReceiver::error_on_gaps()`](crate::TimeReceiver::error_on_gaps()).
+  MissedTicks {
+    ///The first tick that was skipped.
+    first_missed: Time,
+
+    ///The tick that was received after the skipped ones.
+    time: Time,
+  },
+}
+
+impl fmt::Display for ClockError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::NotStarted => write!(f, "The clock hasn't started yet"),
+      Self::Stopped(final_time) => write!(f, "The clock has stopped at time {final_time}"),
+      Self::TimeHasOccurred => write!(f, "This time has already occurred"),
+      Self::CalledFromAsyncContext => write!(f, "The clock can't be waited on from within a current thread runtime"),
+      Self::Cancelled => write!(f, "The wait was cancelled"),
+      Self::Interrupted => write!(f, "The wait was interrupted"),
+      Self::Overflowed => write!(f, "The clock stopped as its time overflowed"),
+      Self::TimedOut => write!(f, "The wait timed out"),
+      Self::Restarted(time) => write!(f, "The clock task panicked and was restarted at time {time}"),
+      Self::MissedTicks { first_missed, time } => {
+        write!(f, "The ticks from {first_missed} up to {time} were missed")
+      }
+    }
+  }
+}
+
+impl std::error::Error for ClockError {}
@@ -0,0 +1,114 @@
+use crate::logging::log_warn;
+use crate::runtime::LazyRuntime;
+use crate::sync::Arc;
+use crate::Time;
+use anyhow::anyhow;
+use std::time::Duration;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio::sync::broadcast::Receiver;
+
+///How many events an [`EventReceiver`] can fall behind by before it misses the oldest ones.
+pub(crate) const EVENT_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+///Something that happened to a clock over its lifetime, received through an [`EventReceiver`].
+pub enum ClockEvent {
+  ///The clock was started, which is also sent when it's started again after stopping.
+  Started,
+
+  ///The clock was paused, which is also sent right after it starts if it starts paused.
+  Paused,
+
+  ///The clock was resumed after being paused.
+  Resumed,
+
+  ///The length of the clock's ticks changed, such as from a rate schedule or syncing to a reference,
+  ///contains the new tick length.
+  RateChanged(Duration),
+
+  ///The clock stopped, contains the final time it reached.
+  Stopped(Time),
+
+  ///The tick was late by a whole tick or more, so the clock can't keep up with its tickrate.
+  TickMissed(Time),
+}
+
+#[derive(Debug)]
+///Receives the [`events`](crate::ClockEvent) of a clock in the order they happened, for anything that
+///manages a clock separately from what consumes its ticks.
+///
+///Created with [`Clock::events()`](crate::Clock::events()), and only gets the events that happen after
+///it was created. A receiver that falls more than 64 events behind misses the oldest ones.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockEvent};
+///
+///let mut clock = Clock::custom(100).unwrap();
+///let mut events = clock.events();
+///
+///clock.start();
+///clock.pause();
+///clock.resume();
+///clock.pause();
+///
+///let final_time = clock.stop().unwrap();
+///
+///assert_eq!(events.recv().unwrap(), ClockEvent::Started);
+///assert_eq!(events.recv().unwrap(), ClockEvent::Paused);
+///assert_eq!(events.recv().unwrap(), ClockEvent::Resumed);
+///assert_eq!(events.recv().unwrap(), ClockEvent::Paused);
+///assert_eq!(events.recv().unwrap(), ClockEvent::Stopped(final_time));
+///```
+pub struct EventReceiver {
+  runtime: Arc<LazyRuntime>,
+  events: Receiver<ClockEvent>,
+}
+
+impl EventReceiver {
+  pub(crate) fn new(runtime: Arc<LazyRuntime>, events: Receiver<ClockEvent>) -> Self {
+    Self { runtime, events }
+  }
+
+  ///Waits for the next event of the clock.
+  ///
+  ///An error is returned once the clock and every one of its receivers have been dropped, as there
+  ///can't be any more events, or if this is called from within a current thread tokio runtime.
+  pub fn recv(&mut self) -> anyhow::Result<ClockEvent> {
+    self.runtime.block_on(next_event(&mut self.events))?
+  }
+
+  ///Waits for the next event of the clock without blocking the thread.
+  ///
+  ///The asynchronous version of [`recv()`](crate::EventReceiver::recv()).
+  pub async fn next_event(&mut self) -> anyhow::Result<ClockEvent> {
+    next_event(&mut self.events).await
+  }
+
+  ///Returns the next event that already happened without waiting, or None if there isn't one.
+  pub fn try_recv(&mut self) -> Option<ClockEvent> {
+    loop {
+      match self.events.try_recv() {
+        Ok(event) => return Some(event),
+        Err(TryRecvError::Lagged(missed_events)) => log_missed(missed_events),
+        Err(TryRecvError::Empty | TryRecvError::Closed) => return None,
+      }
+    }
+  }
+}
+
+async fn next_event(events: &mut Receiver<ClockEvent>) -> anyhow::Result<ClockEvent> {
+  loop {
+    match events.recv().await {
+      Ok(event) => return Ok(event),
+      Err(RecvError::Lagged(missed_events)) => log_missed(missed_events),
+      Err(RecvError::Closed) => return Err(anyhow!("A clock was dropped, so it has no more events")),
+    }
+  }
+}
+
+fn log_missed(missed_events: u64) {
+  log_warn!("An event receiver fell behind its clock and missed {missed_events} events");
+}
@@ -0,0 +1,103 @@
+use crate::{ClockError, Time, TimeReceiver};
+
+#[derive(Debug)]
+///Waits for only every `n`th tick of a receiver, created with
+///[`TimeReceiver::every()`](crate::TimeReceiver::every()).
+///
+///The first tick waited for is let through, then every tick at least `n` ticks after the last one that
+///was, so ticks the receiver skips don't throw the spacing off. A tick with a time before the last one,
+///such as after the clock is started again, starts the count over.
+///
+///Unlike [`set_divisor()`](crate::TimeReceiver::set_divisor()) the times are the receiver's own, and the
+///receiver goes back to getting every tick once this is dropped.
+///
+///Iterating yields the time of every tick let through, or the error if something went wrong with the
+///clock, such as [`missed ticks`](crate::ClockError::MissedTicks) on a receiver that
+///[`errors on gaps`](crate::TimeReceiver::error_on_gaps()). It ends only once the clock
+///[`stops`](crate::ClockError::Stopped), which isn't yielded as an error.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(5).unwrap();
+///let mut time_receiver = clock.spawn_receiver();
+///
+///clock.start();
+///
+///let times: Vec<_> = time_receiver.every(10).unwrap().take(3).collect::<Result<_, _>>().unwrap();
+///
+///assert_eq!(times, [0, 10, 20]);
+///```
+pub struct EveryNthTick<'a> {
+  time_receiver: &'a mut TimeReceiver,
+  n: u32,
+  last_time: Option<Time>,
+}
+
+impl<'a> EveryNthTick<'a> {
+  pub(crate) fn new(time_receiver: &'a mut TimeReceiver, n: u32) -> Self {
+    Self {
+      time_receiver,
+      n,
+      last_time: None,
+    }
+  }
+
+  ///Returns how many ticks there are between the ones that are let through.
+  pub fn n(&self) -> u32 {
+    self.n
+  }
+
+  ///Waits for the next tick that's let through and returns its time.
+  ///
+  ///An error is returned if something went wrong with the clock.
+  pub fn next_time(&mut self) -> anyhow::Result<Time> {
+    loop {
+      let time = self.time_receiver.safe_time()?;
+
+      if self.let_through(time) {
+        return Ok(time);
+      }
+    }
+  }
+
+  ///Waits for the next tick that's let through without blocking the thread and returns its time.
+  ///
+  ///The asynchronous version of [`next_time()`](crate::EveryNthTick::next_time()).
+  pub async fn next_tick(&mut self) -> anyhow::Result<Time> {
+    loop {
+      let time = self.time_receiver.next_tick().await?;
+
+      if self.let_through(time) {
+        return Ok(time);
+      }
+    }
+  }
+
+  ///Returns true and counts from the time if it's at least `n` ticks after the last one let through.
+  fn let_through(&mut self, time: Time) -> bool {
+    let overflow = self.time_receiver.overflow;
+    let is_due = self.last_time.is_none_or(|last_time| {
+      overflow.ticks_between(last_time, time).is_none_or(|ticks| ticks >= Time::from(self.n))
+    });
+
+    if is_due {
+      self.last_time = Some(time);
+    }
+
+    is_due
+  }
+}
+
+impl Iterator for EveryNthTick<'_> {
+  type Item = anyhow::Result<Time>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    match self.next_time() {
+      Err(error) if matches!(error.downcast_ref::<ClockError>(), Some(ClockError::Stopped(_))) => None,
+      next_time => Some(next_time),
+    }
+  }
+}
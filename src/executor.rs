@@ -0,0 +1,214 @@
+use crate::listener::TickListener;
+use crate::Time;
+use std::future::Future;
+use std::pin::Pin;
+use crate::sync::atomic::{AtomicU64, Ordering};
+use crate::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug, Clone)]
+///Runs closures and polls futures from within the clock task once every tick.
+///
+///This turns the clock into a small cooperative scheduler, everything registered on the executor runs
+///in the order it was registered on every tick. Anything registered has to return quickly, and can't
+///wait on the clock it runs on as that would block the tick it's running in.
+///
+///Every clone registers onto the same executor.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///use std::sync::atomic::{AtomicU64, Ordering};
+///use std::sync::Arc;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let tick_executor = clock.tick_executor();
+///let ticks_seen = Arc::new(AtomicU64::new(0));
+///let task_ticks_seen = Arc::clone(&ticks_seen);
+///
+///tick_executor.run_every_tick(move |_time| {
+///  // stops running after 5 ticks
+///  task_ticks_seen.fetch_add(1, Ordering::SeqCst) < 4
+///});
+///
+///clock.start();
+///clock.wait_for_x_ticks(10).unwrap();
+///
+///assert_eq!(ticks_seen.load(Ordering::SeqCst), 5);
+///```
+pub struct TickExecutor {
+  inner: Arc<ExecutorInner>,
+}
+
+#[derive(Debug, Default)]
+struct ExecutorInner {
+  state: Mutex<ExecutorState>,
+  next_id: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct ExecutorState {
+  tasks: Vec<ExecutorTask>,
+  running: bool,
+  // tasks deregistered while they were taken out to run
+  deregistered: Vec<u64>,
+}
+
+struct ExecutorTask {
+  id: u64,
+  job: Job,
+}
+
+enum Job {
+  Closure(Box<dyn FnMut(Time) -> bool + Send>),
+  Future(Pin<Box<dyn Future<Output = ()> + Send>>),
+}
+
+impl std::fmt::Debug for ExecutorTask {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let job = match self.job {
+      Job::Closure(_) => "Closure",
+      Job::Future(_) => "Future",
+    };
+
+    f.debug_struct("ExecutorTask").field("id", &self.id).field("job", &job).finish()
+  }
+}
+
+impl ExecutorTask {
+  ///Runs the task for a tick, returns false once the task is done.
+  fn run(&mut self, time: Time) -> bool {
+    match &mut self.job {
+      Job::Closure(closure) => closure(time),
+      Job::Future(future) => {
+        // every future is polled on every tick, so wake ups aren't needed
+        let mut context = Context::from_waker(Waker::noop());
+
+        future.as_mut().poll(&mut context) == Poll::Pending
+      }
+    }
+  }
+}
+
+impl TickExecutor {
+  ///Creates the executor along with the listener that runs it from the clock task.
+  pub(crate) fn new() -> (Self, ExecutorTicker) {
+    let inner = Arc::new(ExecutorInner::default());
+    let ticker = ExecutorTicker {
+      executor: Arc::downgrade(&inner),
+    };
+
+    (Self { inner }, ticker)
+  }
+
+  ///Runs the closure on every tick with the time of the tick, until it returns false.
+  ///
+  ///The returned [`handle`](crate::TickTaskHandle) can deregister the closure early.
+  pub fn run_every_tick<F>(&self, closure: F) -> TickTaskHandle
+  where
+    F: FnMut(Time) -> bool + Send + 'static,
+  {
+    self.register(Job::Closure(Box::new(closure)))
+  }
+
+  ///Polls the future once every tick until it completes.
+  ///
+  ///The returned [`handle`](crate::TickTaskHandle) can deregister the future early, which drops it.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let tick_executor = clock.tick_executor();
+  ///
+  ///let task_handle = tick_executor.spawn(async {
+  ///  // polled once a tick while pending
+  ///  std::future::pending::<()>().await;
+  ///});
+  ///
+  ///clock.start();
+  ///clock.wait_for_x_ticks(3).unwrap();
+  ///
+  ///task_handle.deregister();
+  ///```
+  pub fn spawn<F>(&self, future: F) -> TickTaskHandle
+  where
+    F: Future<Output = ()> + Send + 'static,
+  {
+    self.register(Job::Future(Box::pin(future)))
+  }
+
+  fn register(&self, job: Job) -> TickTaskHandle {
+    let id = self.inner.next_id.fetch_add(1, Ordering::SeqCst);
+
+    self.inner.state.lock().unwrap().tasks.push(ExecutorTask { id, job });
+
+    TickTaskHandle {
+      id,
+      executor: Arc::downgrade(&self.inner),
+    }
+  }
+}
+
+#[derive(Debug)]
+///A handle to a task registered on a [`TickExecutor`](crate::TickExecutor).
+///
+///Dropping the handle leaves the task running.
+pub struct TickTaskHandle {
+  id: u64,
+  executor: Weak<ExecutorInner>,
+}
+
+impl TickTaskHandle {
+  ///Removes the task from the executor so it's never run again.
+  pub fn deregister(self) {
+    let Some(executor) = self.executor.upgrade() else {
+      return;
+    };
+    let mut state = executor.state.lock().unwrap();
+
+    if let Some(index) = state.tasks.iter().position(|task| task.id == self.id) {
+      state.tasks.remove(index);
+    } else if state.running {
+      state.deregistered.push(self.id);
+    }
+  }
+}
+
+#[derive(Debug)]
+///Runs an executor from within the clock task.
+pub(crate) struct ExecutorTicker {
+  executor: Weak<ExecutorInner>,
+}
+
+impl TickListener for ExecutorTicker {
+  ///Runs every task, the ticker is removed once every clone of the executor is dropped.
+  fn tick(&mut self, time: Time) -> bool {
+    let Some(executor) = self.executor.upgrade() else {
+      return false;
+    };
+
+    // the tasks are run without holding the lock so they can register and deregister tasks themselves
+    let mut tasks = {
+      let mut state = executor.state.lock().unwrap();
+
+      state.running = true;
+      std::mem::take(&mut state.tasks)
+    };
+
+    tasks.retain_mut(|task| task.run(time));
+
+    let mut state = executor.state.lock().unwrap();
+    let deregistered = std::mem::take(&mut state.deregistered);
+
+    tasks.retain(|task| !deregistered.contains(&task.id));
+    tasks.append(&mut state.tasks);
+    state.tasks = tasks;
+    state.running = false;
+
+    true
+  }
+}
@@ -0,0 +1,106 @@
+use crate::status::SharedStatus;
+use crate::ClockStatus;
+use std::fmt;
+use crate::sync::Arc;
+use tokio::sync::Semaphore;
+
+#[derive(Clone)]
+///Ticks a clock created with [`Clock::external()`](crate::Clock::external()) whenever it's told to.
+///
+///An external clock has no timer of its own, it only ticks when [`tick()`](crate::ExternalDriver::tick())
+///is called, such as from a vsync callback, a bridge to a hardware interrupt, or whenever a network frame
+///arrives. Everything that waits on the clock or receives its time works the same as with any other clock.
+///
+///Cloning the driver gives another handle that ticks the same clock.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let (mut clock, driver) = Clock::external().unwrap();
+///
+///clock.start();
+///
+///std::thread::spawn(move || {
+///  for _ in 0..100 {
+///    std::thread::sleep(std::time::Duration::from_millis(2));
+///    driver.tick();
+///  }
+///});
+///
+///assert!(clock.wait_until_at_least(4).unwrap() >= 4);
+///```
+pub struct ExternalDriver {
+  inner: Arc<ExternalTicks>,
+}
+
+///The ticks of an external clock that its task hasn't emitted yet.
+struct ExternalTicks {
+  pending: Semaphore,
+  clock_status: Arc<SharedStatus>,
+}
+
+impl ExternalDriver {
+  pub(crate) fn new(clock_status: Arc<SharedStatus>) -> Self {
+    Self {
+      inner: Arc::new(ExternalTicks {
+        pending: Semaphore::new(0),
+        clock_status,
+      }),
+    }
+  }
+
+  ///Ticks the clock once.
+  ///
+  ///Every call is one tick, even if several are made before the clock gets to emit them.
+  ///Nothing happens unless the clock is running, so ticks while it's paused or stopped are skipped.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let (mut clock, driver) = Clock::external().unwrap();
+  ///
+  ///driver.tick();
+  ///clock.start();
+  ///
+  ///driver.tick();
+  ///driver.tick();
+  ///
+  ///std::thread::sleep(std::time::Duration::from_millis(20));
+  ///
+  ///assert_eq!(clock.stop().unwrap(), 1);
+  ///```
+  pub fn tick(&self) {
+    let clock_status = self.inner.clock_status.lock();
+
+    if clock_status.get() == ClockStatus::Running {
+      self.inner.pending.add_permits(1);
+    }
+  }
+
+  ///Waits until the clock is ticked.
+  pub(crate) async fn next_tick(&self) {
+    if let Ok(tick) = self.inner.pending.acquire().await {
+      tick.forget();
+    }
+  }
+
+  ///Throws away the ticks the clock didn't get to before it stopped, so they don't carry over to
+  ///the next time it's started.
+  ///
+  ///Has to be called while the clock's status is locked.
+  pub(crate) fn discard_ticks(&self) {
+    self.inner.pending.forget_permits(usize::MAX);
+  }
+}
+
+impl fmt::Debug for ExternalDriver {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ExternalDriver")
+      .field("pending", &self.inner.pending.available_permits())
+      .finish()
+  }
+}
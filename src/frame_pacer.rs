@@ -0,0 +1,151 @@
+use crate::{Time, TimeReceiver};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Information about a frame returned by [`FramePacer::wait_for_next_frame()`](crate::FramePacer::wait_for_next_frame()).
+pub struct FrameInfo {
+  ///The number of the frame, starting at 0.
+  pub frame_number: u64,
+
+  ///The time of the tick the frame started on.
+  pub time: Time,
+
+  ///The wall-clock time since the previous frame started.
+  pub delta: Duration,
+
+  ///How far past its scheduled tick the frame started.
+  pub late_by: Duration,
+}
+
+#[derive(Debug)]
+///Paces a loop to a target amount of frames per second using the ticks of a clock.
+///
+///Frames are scheduled on the tick closest to when they're due, so the highest reachable
+///frame rate is one frame per tick.
+///If a frame starts more than a whole frame late, the schedule restarts from that frame
+///instead of rushing to catch up.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let mut frame_pacer = clock.frame_pacer(200.0).unwrap();
+///
+///clock.start();
+///
+///for _ in 0..3 {
+///  let frame_info = frame_pacer.wait_for_next_frame().unwrap();
+///
+///  println!("frame {} took {:?}", frame_info.frame_number, frame_info.delta);
+///}
+///```
+pub struct FramePacer {
+  time_receiver: TimeReceiver,
+  frame_length: Duration,
+  next_frame_number: u64,
+  anchor: Option<FrameAnchor>,
+  last_frame: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+///The frame and tick the frame schedule is counted from.
+struct FrameAnchor {
+  frame_number: u64,
+  time: Time,
+}
+
+impl FramePacer {
+  pub(crate) fn new(time_receiver: TimeReceiver, frame_length: Duration) -> Self {
+    Self {
+      time_receiver,
+      frame_length,
+      next_frame_number: 0,
+      anchor: None,
+      last_frame: None,
+    }
+  }
+
+  ///Returns the length of a frame at the target frame rate.
+  pub fn frame_length(&self) -> Duration {
+    self.frame_length
+  }
+
+  ///Blocks until the next frame is due and returns information about it.
+  ///
+  ///The first frame starts on the next tick.
+  ///
+  ///An error is returned if something went wrong with the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut frame_pacer = clock.frame_pacer(100.0).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///let first_frame = frame_pacer.wait_for_next_frame().unwrap();
+  ///let second_frame = frame_pacer.wait_for_next_frame().unwrap();
+  ///
+  ///assert_eq!(first_frame.frame_number, 0);
+  ///assert_eq!(second_frame.frame_number, 1);
+  ///assert_eq!(second_frame.time, first_frame.time + 10);
+  ///```
+  pub fn wait_for_next_frame(&mut self) -> anyhow::Result<FrameInfo> {
+    let frame_number = self.next_frame_number;
+    let mut time = self.time_receiver.safe_time()?;
+
+    let anchor = *self.anchor.get_or_insert(FrameAnchor { frame_number, time });
+    let scheduled_time = anchor.time + self.ticks_until(frame_number - anchor.frame_number);
+
+    if time < scheduled_time {
+      self.time_receiver.wait_for_ticks(scheduled_time - time)?;
+      time = scheduled_time;
+    }
+
+    let late_by = self.tick_length() * (time - scheduled_time).try_into().unwrap_or(u32::MAX);
+    let now = Instant::now();
+    let delta = self
+      .last_frame
+      .map(|last_frame| now.duration_since(last_frame))
+      .unwrap_or_default();
+
+    if late_by >= self.frame_length {
+      self.anchor = Some(FrameAnchor { frame_number, time });
+    }
+
+    self.next_frame_number += 1;
+    self.last_frame = Some(now);
+
+    Ok(FrameInfo {
+      frame_number,
+      time,
+      delta,
+      late_by,
+    })
+  }
+
+  fn tick_length(&self) -> Duration {
+    Duration::from_millis(self.time_receiver.tick_rate().into())
+  }
+
+  ///Returns the amount of ticks after the anchor the frame is scheduled for.
+  fn ticks_until(&self, frames: u64) -> u64 {
+    let tick_length = self.tick_length().as_nanos();
+
+    if tick_length == 0 {
+      return frames;
+    }
+
+    let frames_length = self.frame_length.as_nanos() * u128::from(frames);
+
+    ((frames_length + tick_length / 2) / tick_length)
+      .try_into()
+      .unwrap_or(u64::MAX)
+  }
+}
@@ -0,0 +1,77 @@
+use crate::{Clock, ClockBuilder};
+use anyhow::anyhow;
+use std::sync::{Mutex, OnceLock};
+
+///How the global clock is built, set by [`configure_global()`](crate::configure_global()).
+static GLOBAL_BUILDER: Mutex<Option<ClockBuilder>> = Mutex::new(None);
+
+///The global clock, created by the first call to [`global()`](crate::global()).
+static GLOBAL_CLOCK: OnceLock<Clock> = OnceLock::new();
+
+///Returns the process-wide clock, creating and starting it the first time it's asked for.
+///
+///Deeply nested code can grab a [`time receiver`](crate::TimeReceiver) from the global clock without a
+///clock being passed down to it. The clock is built the way [`configure_global()`](crate::configure_global())
+///was told to, or the same as [`Clock::new()`](crate::Clock::new()) if it wasn't configured.
+///
+///The global clock runs for as long as the process does, so it can't be stopped or paused. Anything
+///that needs mutable access to a clock, such as [`time()`](crate::Clock::time()), goes through a
+///receiver instead.
+///
+///An error is returned if the clock couldn't be created, in which case the next call tries again.
+///
+///# Example
+///
+///```
+///let mut time_receiver = thread_clock::global().unwrap().spawn_receiver();
+///
+///time_receiver.wait_for_x_ticks(2).unwrap();
+///```
+pub fn global() -> anyhow::Result<&'static Clock> {
+  if let Some(clock) = GLOBAL_CLOCK.get() {
+    return Ok(clock);
+  }
+
+  // holding the builder's lock makes sure only one clock is ever created
+  let global_builder = GLOBAL_BUILDER.lock().unwrap();
+
+  if let Some(clock) = GLOBAL_CLOCK.get() {
+    return Ok(clock);
+  }
+
+  let mut clock = global_builder.clone().unwrap_or_default().build()?;
+
+  clock.start();
+
+  Ok(GLOBAL_CLOCK.get_or_init(|| clock))
+}
+
+///Configures how the [`global clock`](crate::global()) is built, which has to happen before it's first used.
+///
+///An error is returned if the global clock was already configured or has already been created.
+///
+///# Example
+///
+///```
+///use thread_clock::Clock;
+///
+///thread_clock::configure_global(Clock::builder().tick_rate(1)).unwrap();
+///
+///assert_eq!(thread_clock::global().unwrap().tick_rate(), 1);
+///assert!(thread_clock::configure_global(Clock::builder().tick_rate(5)).is_err());
+///```
+pub fn configure_global(builder: ClockBuilder) -> anyhow::Result<()> {
+  let mut global_builder = GLOBAL_BUILDER.lock().unwrap();
+
+  if GLOBAL_CLOCK.get().is_some() {
+    return Err(anyhow!("The global clock has already been created"));
+  }
+
+  if global_builder.is_some() {
+    return Err(anyhow!("The global clock has already been configured"));
+  }
+
+  *global_builder = Some(builder);
+
+  Ok(())
+}
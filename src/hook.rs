@@ -0,0 +1,83 @@
+use crate::Time;
+use std::fmt;
+use crate::sync::{self, Arc, Mutex};
+use std::time::Duration;
+
+///Callbacks that the clock task runs around every tick it sends out.
+///
+///Hooks are added with [`Clock::add_hook()`](crate::Clock::add_hook()) and run in the order they were added.
+///They run within the clock task and delay the tick while they run, so they have to return quickly.
+///This makes them a place for metrics, logging, or injecting faults into tests without changing the clock.
+///
+///Both callbacks do nothing by default, so a hook only needs to implement the ones it uses.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockHook, Time};
+///use std::sync::atomic::{AtomicU64, Ordering};
+///use std::sync::Arc;
+///use std::time::Duration;
+///
+///struct SlowestTick(Arc<AtomicU64>);
+///
+///impl ClockHook for SlowestTick {
+///  fn after_tick(&mut self, _time: Time, took: Duration) {
+///    self.0.fetch_max(took.as_nanos() as u64, Ordering::SeqCst);
+///  }
+///}
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let slowest_tick = Arc::new(AtomicU64::new(0));
+///
+///clock.add_hook(SlowestTick(Arc::clone(&slowest_tick)));
+///clock.start();
+///clock.wait_for_x_ticks(5).unwrap();
+///
+///println!("the slowest tick took {}ns", slowest_tick.load(Ordering::SeqCst));
+///```
+pub trait ClockHook: Send + 'static {
+  ///Called right before a tick is sent to the receivers.
+  fn before_tick(&mut self, _time: Time) {}
+
+  ///Called once a tick has been sent and everything created from the clock has run for it,
+  ///along with how long that took.
+  fn after_tick(&mut self, _time: Time, _took: Duration) {}
+}
+
+#[derive(Clone, Default)]
+///The hooks of a clock, shared between the clock and its task.
+pub(crate) struct ClockHooks {
+  hooks: Arc<Mutex<Vec<Box<dyn ClockHook>>>>,
+}
+
+impl ClockHooks {
+  pub(crate) fn add(&self, hook: impl ClockHook) {
+    self.hooks.lock().unwrap().push(Box::new(hook));
+  }
+
+  ///Lets the hooks be used again after one of them panicked while they were locked.
+  pub(crate) fn clear_poison(&self) {
+    sync::clear_poison(&self.hooks);
+  }
+
+  pub(crate) fn before_tick(&self, time: Time) {
+    for hook in self.hooks.lock().unwrap().iter_mut() {
+      hook.before_tick(time);
+    }
+  }
+
+  pub(crate) fn after_tick(&self, time: Time, took: Duration) {
+    for hook in self.hooks.lock().unwrap().iter_mut() {
+      hook.after_tick(time, took);
+    }
+  }
+}
+
+impl fmt::Debug for ClockHooks {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ClockHooks")
+      .field("hooks", &self.hooks.lock().unwrap().len())
+      .finish()
+  }
+}
@@ -0,0 +1,70 @@
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::Arc;
+use tokio::sync::Notify;
+
+#[derive(Debug, Clone, Default)]
+///A handle that interrupts whatever a [`time receiver`](crate::TimeReceiver) is waiting on.
+///
+///Created with [`TimeReceiver::interrupt_handle()`](crate::TimeReceiver::interrupt_handle()), and can be
+///sent to any other thread.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockError};
+///use std::thread;
+///
+///let mut clock = Clock::new().unwrap();
+///clock.start();
+///
+///let mut time_receiver = clock.spawn_receiver();
+///let interrupt_handle = time_receiver.interrupt_handle();
+///
+///let handle = thread::spawn(move || time_receiver.wait_for_time(1_000_000));
+///
+///interrupt_handle.interrupt();
+///
+///let error = handle.join().unwrap().unwrap_err();
+///
+///assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Interrupted));
+///```
+pub struct InterruptHandle {
+  inner: Arc<InterruptInner>,
+}
+
+#[derive(Debug, Default)]
+struct InterruptInner {
+  interrupted: AtomicBool,
+  notify: Notify,
+}
+
+impl InterruptHandle {
+  ///Interrupts the wait the receiver is blocked on, which returns
+  ///[`ClockError::Interrupted`](crate::ClockError::Interrupted).
+  ///
+  ///If the receiver isn't waiting, its next wait is interrupted instead.
+  ///Only a single wait is interrupted no matter how many times this is called before it.
+  pub fn interrupt(&self) {
+    self.inner.interrupted.store(true, Ordering::SeqCst);
+    self.inner.notify.notify_waiters();
+  }
+
+  ///Clears the interrupt and returns true if there was one.
+  pub(crate) fn take_interrupt(&self) -> bool {
+    self.inner.interrupted.swap(false, Ordering::SeqCst)
+  }
+
+  ///Waits until the receiver is interrupted, clearing the interrupt.
+  pub(crate) async fn interrupted(&self) {
+    loop {
+      // a notified future receives notify_waiters as soon as it's created, so no interrupt can be missed
+      let notified = self.inner.notify.notified();
+
+      if self.take_interrupt() {
+        return;
+      }
+
+      notified.await;
+    }
+  }
+}
@@ -0,0 +1,73 @@
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::ptr;
+use tokio::io::unix::AsyncFd;
+use tokio::time::Instant;
+
+///The identifier of the timer within its kqueue, which only ever holds the one timer.
+const TIMER_IDENT: libc::uintptr_t = 1;
+
+///A kqueue with an `EVFILT_TIMER` set for every deadline, which wakes the clock task up to the
+///nanosecond instead of on tokio's whole milliseconds, while still letting the kernel coalesce it with
+///other timers to save power.
+pub(crate) struct KqueueTimer {
+  fd: AsyncFd<OwnedFd>,
+}
+
+impl KqueueTimer {
+  ///Creates a timer.
+  ///
+  ///Has to be called from within a runtime with its IO driver enabled.
+  pub(crate) fn new() -> io::Result<Self> {
+    // SAFETY: kqueue has no preconditions, the descriptor it returns is owned from here on
+    let fd = unsafe { libc::kqueue() };
+
+    if fd == -1 {
+      return Err(io::Error::last_os_error());
+    }
+
+    // SAFETY: the descriptor was just created and nothing else owns it
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    Ok(Self { fd: AsyncFd::new(fd)? })
+  }
+
+  ///Waits until the deadline.
+  pub(crate) async fn wait_until(&self, deadline: Instant) -> io::Result<()> {
+    let wait_for = deadline.saturating_duration_since(Instant::now());
+    let timer = libc::kevent {
+      ident: TIMER_IDENT,
+      filter: libc::EVFILT_TIMER,
+      flags: libc::EV_ADD | libc::EV_ONESHOT,
+      fflags: libc::NOTE_NSECONDS,
+      data: wait_for.as_nanos().try_into().unwrap_or(libc::intptr_t::MAX),
+      udata: ptr::null_mut(),
+    };
+
+    // SAFETY: the descriptor is a kqueue owned by this timer, and the change outlives the call
+    if unsafe { libc::kevent(self.fd.as_raw_fd(), &timer, 1, ptr::null_mut(), 0, ptr::null()) } == -1 {
+      return Err(io::Error::last_os_error());
+    }
+
+    loop {
+      let mut guard = self.fd.readable().await?;
+      let fired = guard.try_io(|fd| {
+        // SAFETY: a kevent is plain data, for which all zeroes is valid
+        let mut event: libc::kevent = unsafe { std::mem::zeroed() };
+        let no_wait = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+        // SAFETY: the kqueue only writes the one event it's given room for, and doesn't wait at all
+        let events = unsafe { libc::kevent(fd.as_raw_fd(), ptr::null(), 0, &mut event, 1, &no_wait) };
+
+        match events {
+          -1 => Err(io::Error::last_os_error()),
+          0 => Err(io::ErrorKind::WouldBlock.into()),
+          _ => Ok(()),
+        }
+      });
+
+      if let Ok(fired) = fired {
+        return fired;
+      }
+    }
+  }
+}
@@ -1,28 +1,252 @@
 use anyhow::anyhow;
-use std::sync::{Arc, Mutex};
-use tokio::runtime::Runtime;
+use std::future::Future;
+use std::fmt;
+use std::hash::Hash;
+use std::panic;
+use std::path::Path;
+use std::ops::Range;
+use crate::sync::{Arc, Mutex};
 use tokio::sync::{
   broadcast,
-  broadcast::{Receiver, Sender},
+  broadcast::{
+    error::{RecvError, TryRecvError},
+    Receiver, Sender,
+  },
 };
 use tokio::sync::{
   oneshot,
   oneshot::{Receiver as OneReceiver, Sender as OneSender},
 };
 use tokio::task::JoinHandle;
-use tokio::time::Duration;
+use tokio::time::Instant;
+use std::time::{Duration, SystemTime};
+
+pub use acknowledged::{CriticalReceiver, StragglerPolicy};
+pub use barrier::TickBarrier;
+pub use builder::{Backend, ClockBuilder, Overflow, Rounding};
+pub use cancel::CancelHandle;
+pub use config::{ClockConfig, Precision};
+pub use countdown::Countdown;
+pub use cycle::{Cycle, CycleEvent};
+pub use deadline::Deadline;
+pub use debounce::Debounce;
+pub use delivery::ReceiverPriority;
+pub use delay_queue::TickDelayQueue;
+pub use derived::DerivedClock;
+pub use discipline::TickReference;
+pub use driver::ClockDriver;
+pub use error::ClockError;
+pub use events::{ClockEvent, EventReceiver};
+pub use every::EveryNthTick;
+pub use executor::{TickExecutor, TickTaskHandle};
+pub use external::ExternalDriver;
+pub use frame_pacer::{FrameInfo, FramePacer};
+pub use global::{configure_global, global};
+pub use hook::ClockHook;
+pub use interrupt::InterruptHandle;
+pub use metronome::{BeatEvent, Metronome};
+#[cfg(feature = "net")]
+pub use net::{ClockFollower, ClockLeader};
+pub use rate_limiter::RateLimiter;
+pub use recording::TickRecording;
+pub use registry::ClockRegistry;
+pub use scoped::ScopedClock;
+#[cfg(target_os = "linux")]
+pub use shared_memory::{SharedMemoryPublisher, SharedTimeReceiver};
+#[cfg(feature = "websocket")]
+pub use server::ClockServer;
+pub use stats::ClockHealth;
+pub use stopwatch::Stopwatch;
+pub use throttle::Throttle;
+pub use tick::{Tick, Tick128, Tick32};
+pub use timecode::{FrameRate, Timecode};
+pub use timer_wheel::{TimerKey, TimerWheel};
+#[cfg(all(unix, not(loom)))]
+pub use unix_socket::{RemoteTimeReceiver, UnixSocketPublisher};
+pub use wall_clock::{CorrectionEvent, WallClock};
+pub use watchdog::{StallEvent, Watchdog};
+
+///The internals the loom tests model check, which only exist when the crate is built with `--cfg loom`.
+#[cfg(loom)]
+#[doc(hidden)]
+pub mod model {
+  pub use crate::channel::{next_message, ClockMessage};
+}
+
+use acknowledged::TickAcknowledgements;
+use activity::ClockActivity;
+use delivery::TickSenders;
+use builder::PowerSaving;
+pub(crate) use channel::ClockMessage;
+use derived::DerivedOutput;
+use discipline::{Discipline, PollReports, RateAdjustment};
+use hook::ClockHooks;
+use listener::{TickListener, TickListeners};
+use logging::{log_debug, log_warn};
+use runtime::{ClockRuntime, LazyRuntime, ThreadOptions};
+use schedule::RateSchedule;
+use stats::{SharedTickStats, TickStats};
+use lifecycle::ClockLifecycle;
+use recording::TickRecorder;
+use simulated::SimulatedTicks;
+use status::SharedStatus;
+use supervisor::{catch_unwind, Restarts};
+use ticker::Ticker;
+use timer_resolution::TimerResolution;
+use wall_clock::WallClockDiscipline;
+
+mod acknowledged;
+mod activity;
+mod affinity;
+mod barrier;
+mod builder;
+mod cancel;
+mod channel;
+mod config;
+mod countdown;
+mod cycle;
+mod deadline;
+mod debounce;
+mod delay_queue;
+mod delivery;
+mod derived;
+mod discipline;
+mod driver;
+mod error;
+mod events;
+mod every;
+mod executor;
+mod external;
+mod frame_pacer;
+mod global;
+mod hook;
+mod interrupt;
+#[cfg(all(target_os = "macos", feature = "kqueue"))]
+mod kqueue_timer;
+mod listener;
+mod logging;
+#[cfg(feature = "metrics")]
+mod metrics_exporter;
+mod metronome;
+#[cfg(feature = "net")]
+mod net;
+mod priority;
+#[cfg(any(all(unix, not(loom)), feature = "websocket", feature = "net"))]
+mod publisher;
+mod rate_limiter;
+mod recording;
+mod registry;
+mod runtime;
+mod schedule;
+mod scoped;
+mod simulated;
+#[cfg(target_os = "linux")]
+mod shared_memory;
+#[cfg(feature = "websocket")]
+mod server;
+mod lifecycle;
+mod stats;
+mod status;
+mod stopwatch;
+mod supervisor;
+mod sync;
+mod throttle;
+mod tick;
+mod ticker;
+mod timecode;
+mod timer_resolution;
+#[cfg(all(target_os = "linux", feature = "timerfd"))]
+mod timerfd;
+#[cfg(windows)]
+mod waitable_timer;
+mod timer_wheel;
+#[cfg(all(unix, not(loom)))]
+mod unix_socket;
+mod wall_clock;
+mod watchdog;
 
 ///The deafult tickrate in milliseconds that the clock runs at when [`Clock::new()`](crate::Clock::new()) is called.
 pub const DEFAULT_TICKRATE: u32 = 24;
 
+///How many ticks the channel between a clock and its receivers holds by default, see
+///[`ClockBuilder::channel_capacity()`](crate::ClockBuilder::channel_capacity()).
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 4;
+
+///How long [`Clock::stop()`](crate::Clock::stop()) waits for the clock task to finish before aborting it.
+const STOP_TIMEOUT: Duration = Duration::from_secs(1);
+
 ///A type for the time that the clock returns.
+///
+///Every clock counts in a `u64`. [`Tick`](crate::Tick) wraps a time in a type that can't be mixed up with
+///a tickrate or milliseconds, and [`Tick32`](crate::Tick32) and [`Tick128`](crate::Tick128) store one in
+///other widths.
 pub type Time = u64;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///The stage of its lifetime a clock is in.
+///
+///The status can be checked from both the [`Clock`](crate::Clock) and its [`time receivers`](crate::TimeReceiver),
+///and followed as it changes with [`watch_status()`](crate::TimeReceiver::watch_status()).
+///
+///A clock moves between its statuses like this:
+///
+///```text
+///Created        -> Running          start()
+///Created        -> Paused           start() on a clock built to start paused
+///Running       <-> Paused           pause() and resume()
+///Running/Paused -> Stopped          any of the stops, or the clock stopping itself
+///Stopped        -> Running/Paused   start() or start_from() after stop_in_place()
+///```
+///
+///[`stop()`](crate::Clock::stop()), [`stop_now()`](crate::Clock::stop_now()) and
+///[`stop_async()`](crate::Clock::stop_async()) take the clock, so `Stopped` is the last status it's
+///seen in. A clock stopped with [`stop_in_place()`](crate::Clock::stop_in_place()) is kept around and
+///leaves `Stopped` once it's [`started`](crate::Clock::start()) again. A clock that stopped itself,
+///such as at its [`stop time`](crate::ClockBuilder::stop_at()), stays `Stopped` until `stop_in_place()`
+///collects its final time, after which it can be started again too.
+///
+///# Example
+///
+///```
+///use thread_clock::{Clock, ClockStatus};
+///
+///let mut clock = Clock::new().unwrap();
+///
+///assert_eq!(clock.status(), ClockStatus::Created);
+///
+///clock.start();
+///
+///assert_eq!(clock.status(), ClockStatus::Running);
+///
+///let final_time = clock.stop_in_place().unwrap();
+///
+///assert_eq!(clock.status(), ClockStatus::Stopped(final_time));
+///
+///clock.start();
+///
+///assert_eq!(clock.status(), ClockStatus::Running);
+///```
+pub enum ClockStatus {
+  ///The clock has been created but hasn't started yet.
+  Created,
+
+  ///The clock is ticking.
+  Running,
+
+  ///The clock has started but is paused, waiting on it blocks until it resumes.
+  Paused,
+
+  ///The clock has stopped, contains the final time it reached.
+  Stopped(Time),
+}
+
 #[derive(Debug)]
 /// The time receiver is a reduced part of the clock that can be passed into separate threads.
 ///
-/// The time receiver can do anything the clock can except starting, stopping, and creating time
-/// receivers.
+/// The time receiver can do anything the clock can except starting and stopping.
+///
+/// Once a receiver has gotten a tick it gets every tick after it in order, as long as it keeps up with
+/// the clock within the [`channel capacity`](crate::ClockBuilder::channel_capacity()).
 ///
 /// # Creation
 /// ```
@@ -40,12 +264,120 @@ pub type Time = u64;
 ///assert_eq!(final_time, time + 1);
 /// ```
 pub struct TimeReceiver {
-  runtime: Arc<Runtime>,
-  time_receiver: Receiver<Time>,
-  clock_is_active: Arc<Mutex<bool>>,
+  runtime: Arc<LazyRuntime>,
+  time_receiver: Receiver<ClockMessage>,
+  clock_status: Arc<SharedStatus>,
+  activity: Arc<ClockActivity>,
+  restarts: Arc<Restarts>,
+  seen_restarts: u64,
+  lifecycle: Arc<ClockLifecycle>,
+  seen_runs: u64,
+  waits_for_start: bool,
+  tick_rate: u32,
+  rounding: Rounding,
+  overflow: Overflow,
+  final_time: Option<Time>,
+  latest_time: Option<Time>,
+  last_call_time: Option<Time>,
+  interrupt_handle: InterruptHandle,
+  divisor: u32,
+  multiplier: u32,
+  sub_tick: Option<SubTick>,
+  missed_ticks_callback: Option<MissedTicksCallback>,
+  channel_capacity: usize,
+  priority: ReceiverPriority,
+  ///How many times the clock's time had jumped ahead when the receiver last got a message, it carries on
+  ///from the message after it as long as the time hasn't jumped since.
+  seen_jumps: Option<u64>,
+  gaps: u64,
+  error_on_gaps: bool,
+}
+
+///The callback a receiver calls with the ticks it missed, see
+///[`TimeReceiver::on_missed_ticks()`](crate::TimeReceiver::on_missed_ticks()).
+struct MissedTicksCallback(Mutex<Box<dyn FnMut(Range<Time>) + Send>>);
+
+impl fmt::Debug for MissedTicksCallback {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("MissedTicksCallback").finish_non_exhaustive()
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+///Where a receiver with a multiplier is within a tick of its clock.
+struct SubTick {
+  clock_time: Time,
+  index: u32,
+  started_at: Instant,
 }
 
 impl TimeReceiver {
+  #[allow(clippy::too_many_arguments)]
+  pub(crate) fn new(
+    runtime: Arc<LazyRuntime>,
+    time_receiver: Receiver<ClockMessage>,
+    clock_status: Arc<SharedStatus>,
+    activity: Arc<ClockActivity>,
+    restarts: Arc<Restarts>,
+    lifecycle: Arc<ClockLifecycle>,
+    tick_rate: u32,
+    channel_capacity: usize,
+    rounding: Rounding,
+    overflow: Overflow,
+  ) -> Self {
+    // a new receiver only hears about restarts that happen after it was created
+    let seen_restarts = restarts.count();
+    let seen_runs = lifecycle.runs();
+    let waits_for_start = lifecycle.receivers_wait;
+
+    Self {
+      runtime,
+      time_receiver,
+      clock_status,
+      activity,
+      restarts,
+      seen_restarts,
+      lifecycle,
+      seen_runs,
+      waits_for_start,
+      tick_rate,
+      rounding,
+      overflow,
+      final_time: None,
+      latest_time: None,
+      last_call_time: None,
+      interrupt_handle: InterruptHandle::default(),
+      divisor: 1,
+      multiplier: 1,
+      sub_tick: None,
+      missed_ticks_callback: None,
+      channel_capacity,
+      priority: ReceiverPriority::Normal,
+      seen_jumps: None,
+      gaps: 0,
+      error_on_gaps: false,
+    }
+  }
+
+  ///Creates a new time receiver sharing this one's clock state but listening on another channel.
+  pub(crate) fn with_receiver(&self, time_receiver: Receiver<ClockMessage>, tick_rate: u32) -> Self {
+    // an idle clock has to start ticking again for the new receiver
+    self.activity.notify();
+
+    Self::new(
+      Arc::clone(&self.runtime),
+      time_receiver,
+      Arc::clone(&self.clock_status),
+      Arc::clone(&self.activity),
+      Arc::clone(&self.restarts),
+      Arc::clone(&self.lifecycle),
+      tick_rate,
+      self.channel_capacity,
+      self.rounding,
+      self.overflow,
+    )
+  }
+
   ///Waits for the next tick and returns the time.
   ///
   ///If any problems arise when this is called the clock will panic.
@@ -68,7 +400,7 @@ impl TimeReceiver {
   ///assert_eq!(time, 0);
   ///```
   pub fn time(&mut self) -> Time {
-    Clock::get_time(&self.runtime, &mut self.time_receiver, &self.clock_is_active).unwrap()
+    self.get_time().unwrap()
   }
 
   ///A way to get the time with error handling instead of panicking
@@ -88,249 +420,179 @@ impl TimeReceiver {
   ///assert_eq!(time, 0);
   ///```
   pub fn safe_time(&mut self) -> anyhow::Result<Time> {
-    Clock::get_time(&self.runtime, &mut self.time_receiver, &self.clock_is_active)
+    self.get_time()
   }
 
-  ///Waits for the next tick.
+  ///Waits for the next tick without blocking the thread and returns the time.
   ///
-  ///An error is returned if something went wrong.
+  ///The future is cancellation safe, dropping it before it finishes doesn't lose a tick, so it can be
+  ///raced against other futures in `tokio::select!`. Like [`safe_time()`](crate::TimeReceiver::safe_time()),
+  ///a receiver that has gotten a tick carries on from the one after it, even if that tick arrived before
+  ///this was called, see [`channel_capacity()`](crate::ClockBuilder::channel_capacity()).
+  ///
+  ///An error is returned if something went wrong with the clock.
   ///
   ///# Example
   ///
   ///```
   ///use thread_clock::Clock;
+  ///use std::time::Duration;
   ///
-  ///let mut clock = Clock::new().unwrap();
-  ///clock.start();
-  ///
-  ///let mut time_receiver = clock.spawn_receiver();
+  ///#[tokio::main]
+  ///async fn main() {
+  ///  let mut clock = Clock::custom(1).unwrap();
+  ///  clock.start();
   ///
-  ///time_receiver.wait_for_tick().unwrap_or_else(|error| panic!("{error}"));
+  ///  let mut time_receiver = clock.spawn_receiver();
+  ///  let shutdown = tokio::time::sleep(Duration::from_millis(20));
   ///
-  ///let time = time_receiver.time();
+  ///  tokio::pin!(shutdown);
   ///
-  ///assert_eq!(time, 1);
+  ///  loop {
+  ///    tokio::select! {
+  ///      time = time_receiver.next_tick() => println!("tick {}", time.unwrap()),
+  ///      _ = &mut shutdown => break,
+  ///    }
+  ///  }
+  ///}
   ///```
-  pub fn wait_for_tick(&mut self) -> anyhow::Result<()> {
-    if let Err(error) = Clock::get_time(&self.runtime, &mut self.time_receiver, &self.clock_is_active) {
-      Err(error)
+  pub async fn next_tick(&mut self) -> anyhow::Result<Time> {
+    let time = if self.multiplier > 1 {
+      match self.next_sub_tick_due() {
+        Some((due, sub_tick)) => {
+          tokio::time::sleep_until(due).await;
+
+          self.move_to_sub_tick(sub_tick)
+        }
+        None => {
+          let clock_time = self.next_clock_tick_async().await?;
+
+          self.start_sub_ticks(clock_time)
+        }
+      }
     } else {
-      Ok(())
-    }
-  }
+      loop {
+        let clock_time = self.next_clock_tick_async().await?;
 
-  ///Waits for the input amount of ticks.
-  ///
-  ///An error is returned if something went wrong.
-  ///
-  ///# Example
-  ///
-  ///```
-  ///use thread_clock::Clock;
-  ///
-  ///let mut clock = Clock::new().unwrap();
-  ///clock.start();
-  ///
-  ///let mut time_receiver = clock.spawn_receiver();
-  ///
-  ///time_receiver.wait_for_x_ticks(5).unwrap_or_else(|error| panic!("{error}"));
-  ///
-  ///let time = time_receiver.time();
-  ///
-  ///assert_eq!(time, 5);
-  ///```
-  pub fn wait_for_x_ticks(&mut self, x: u32) -> anyhow::Result<()> {
-    Clock::wait_for_ticks(&self.runtime, &mut self.time_receiver, &self.clock_is_active, x)
+        if let Some(time) = self.divided_time(clock_time) {
+          break time;
+        }
+      }
+    };
+
+    self.record_time(time)?;
+
+    Ok(time)
   }
 
-  ///Waits until the imput time.
+  ///Waits for the input amount of ticks without blocking the thread.
   ///
-  ///An error is returned if something went wrong.
-  ///Such as if the time has already occurred.
+  ///The asynchronous version of [`wait_for_x_ticks()`](crate::TimeReceiver::wait_for_x_ticks()),
+  ///which can be used from within any tokio runtime.
+  ///
+  ///An error is returned if something went wrong with the clock.
   ///
   ///# Example
   ///
   ///```
   ///use thread_clock::Clock;
   ///
-  ///let mut clock = Clock::new().unwrap();
-  ///clock.start();
-  ///
-  ///let mut time_receiver = clock.spawn_receiver();
-  ///
-  ///time_receiver.wait_for_time(9).unwrap_or_else(|error| panic!("{error}"));
-  ///
-  ///let time = time_receiver.time();
+  ///#[tokio::main(flavor = "current_thread")]
+  ///async fn main() {
+  ///  let mut clock = Clock::spawn_on_current(1).unwrap();
+  ///  let mut time_receiver = clock.spawn_receiver();
   ///
-  ///assert_eq!(time, 10);
+  ///  time_receiver.wait_for_x_ticks_async(5).await.unwrap();
+  ///}
   ///```
-  pub fn wait_for_time(&mut self, time: Time) -> anyhow::Result<()> {
-    Clock::wait_until(&self.runtime, &mut self.time_receiver, &self.clock_is_active, time)
-  }
-}
-
-#[derive(Debug)]
-///The clock can be started, stopped, and receive the current time.
-///
-///Using the clock is as simple as starting it with [`clock.start()`](crate::Clock::start())
-///and calling [`clock.time()`](crate::Clock::time()) to get the time.
-///
-///# Usage
-///
-///```
-///use thread_clock::Clock;
-///
-///let mut clock = Clock::new().unwrap();
-///
-///clock.start();
-///
-///let time = clock.time();
-///
-///let final_time = clock.stop().unwrap();
-///
-///assert_eq!(final_time, time + 1);
-///```
-pub struct Clock {
-  runtime: Arc<Runtime>,
-  clock_handle: Option<JoinHandle<()>>,
-  clock_stopper: Option<OneSender<()>>,
-  time_receiver: Receiver<Time>,
-  clock_sender: Sender<Time>,
-  clock_is_active: Arc<Mutex<bool>>,
-  tick_rate: u32,
-}
+  pub async fn wait_for_x_ticks_async(&mut self, x: u32) -> anyhow::Result<()> {
+    for _ in 0..x {
+      self.next_tick().await?;
+    }
 
-impl Clock {
-  ///Creates a new clock with a default tickrate of 24ms.
-  ///
-  ///If you want a custom tickrate, create the clock with [`Clock::custom`](crate::Clock::custom()) instead.
-  ///
-  ///Counting doesn't start until you call [`clock.start()`](crate::Clock::start()) on the clock.
-  ///
-  ///```
-  ///use thread_clock::Clock;
-  ///
-  ///let mut clock = Clock::new().unwrap();
-  ///
-  ///clock.start();
-  ///```
-  pub fn new() -> anyhow::Result<Self> {
-    Clock::new_clock(None)
+    Ok(())
   }
 
-  ///Creates a new clock with a custom tickrate.
+  ///Waits until the input time without blocking the thread.
+  ///
+  ///The asynchronous version of [`wait_for_time()`](crate::TimeReceiver::wait_for_time()), returning an
+  ///error the same way if the time has already occurred.
   ///
   ///# Example
   ///
   ///```
   ///use thread_clock::Clock;
   ///
-  ///let mut clock = Clock::custom(10).unwrap();
-  ///```
-  pub fn custom(tick_rate: u32) -> anyhow::Result<Self> {
-    Clock::new_clock(Some(tick_rate))
-  }
-
-  ///Creates a new clock.
-  fn new_clock(tick_rate: Option<u32>) -> anyhow::Result<Self> {
-    let runtime = Arc::new(Runtime::new()?);
-    let clock_handle = None;
-    let clock_stopper = None;
-    let (clock_sender, time_receiver) = broadcast::channel::<Time>(1);
-    let clock_is_active = Arc::new(Mutex::new(false));
-    let tick_rate = match tick_rate {
-      Some(tick_rate) => tick_rate,
-      None => DEFAULT_TICKRATE,
-    };
-
-    Ok(Clock {
-      runtime,
-      clock_handle,
-      clock_stopper,
-      time_receiver,
-      clock_sender,
-      clock_is_active,
-      tick_rate,
-    })
-  }
-
-  ///Starts the clock.
-  ///
-  ///# Example
-  ///```
-  ///use thread_clock::Clock;
+  ///#[tokio::main(flavor = "current_thread")]
+  ///async fn main() {
+  ///  let mut clock = Clock::spawn_on_current(1).unwrap();
+  ///  let mut time_receiver = clock.spawn_receiver();
   ///
-  ///let mut clock = Clock::new().unwrap();
+  ///  time_receiver.wait_for_time_async(9).await.unwrap();
   ///
-  ///clock.start();
+  ///  assert_eq!(time_receiver.next_tick().await.unwrap(), 10);
+  ///}
   ///```
-  pub fn start(&mut self) {
-    if self.clock_handle.is_none() && self.clock_stopper.is_none() {
-      let (clock_stopper, stopper_receiver) = oneshot::channel();
-      let handle = self.create_clock_thread(stopper_receiver);
-      let mut clock_is_active = self.clock_is_active.lock().unwrap();
+  pub async fn wait_for_time_async(&mut self, time: Time) -> anyhow::Result<()> {
+    let current_time = self.next_tick().await?;
 
-      self.clock_handle = Some(handle);
-      self.clock_stopper = Some(clock_stopper);
-      *clock_is_active = true;
+    match self.overflow.ticks_between(current_time, time) {
+      Some(time_to_wait) if time_to_wait > 0 => {
+        for _ in 0..time_to_wait {
+          self.next_tick().await?;
+        }
+
+        Ok(())
+      }
+      _ => Err(ClockError::TimeHasOccurred.into()),
     }
   }
 
-  ///Stops the clock and returns the final time.
+  ///Waits until the clock has reached at least the input time without blocking the thread,
+  ///and returns the current time.
   ///
-  ///If the clock hasn't been started yet an error will be returned.
+  ///The asynchronous version of [`wait_until_at_least()`](crate::TimeReceiver::wait_until_at_least()),
+  ///which returns right away if the time has already occurred.
   ///
   ///# Example
+  ///
   ///```
   ///use thread_clock::Clock;
   ///
-  ///let mut clock = Clock::new().unwrap();
-  ///clock.start();
-  ///
-  ///let final_time = clock.stop().unwrap();
+  ///#[tokio::main(flavor = "current_thread")]
+  ///async fn main() {
+  ///  let mut clock = Clock::spawn_on_current(1).unwrap();
+  ///  let mut time_receiver = clock.spawn_receiver();
   ///
-  ///assert_eq!(final_time, 0);
+  ///  assert_eq!(time_receiver.wait_until_at_least_async(5).await.unwrap(), 5);
+  ///}
   ///```
-  pub fn stop(mut self) -> anyhow::Result<Time> {
-    match self.clock_stopper {
-      Some(clock_stopper) => {
-        let time = Self::get_time(&self.runtime, &mut self.time_receiver, &self.clock_is_active);
-        let mut clock_is_active = self.clock_is_active.lock().unwrap();
-
-        *clock_is_active = false;
-        let _ = clock_stopper.send(());
-
-        time
+  pub async fn wait_until_at_least_async(&mut self, time: Time) -> anyhow::Result<Time> {
+    if let Some(latest_time) = self.latest_tick()? {
+      if self.has_reached(latest_time, time) {
+        return Ok(latest_time);
       }
+    }
 
-      None => Err(anyhow!("The clock hasn't started.")),
+    let mut current_time = self.next_tick().await?;
+
+    while !self.has_reached(current_time, time) {
+      current_time = self.next_tick().await?;
     }
+
+    Ok(current_time)
   }
 
-  ///Waits for the next tick and returns the time.
-  ///
-  ///If any problems arise when this is called the clock will panic.
-  ///
-  ///Use this if you don't want to deal with unwrapping every time you get the time.
-  ///Otherwise use [`safe_time()`](crate::Clock::safe_time()) for error handling.
-  ///
-  ///# Example
-  ///
-  ///```
-  ///use thread_clock::Clock;
+  ///Waits for the next tick and returns the time it was.
   ///
-  ///let mut clock = Clock::new().unwrap();
-  ///clock.start();
+  ///Use this instead of calling [`wait_for_tick()`](crate::TimeReceiver::wait_for_tick()) followed by
+  ///[`time()`](crate::TimeReceiver::time()), which waits for two ticks instead of one.
   ///
-  ///let time = clock.time();
+  ///Unlike [`time()`](crate::TimeReceiver::time()), which carries on from the last tick this receiver got,
+  ///the ticks that were sent before this was called are skipped and the first one sent after it is
+  ///waited for. The ticks skipped are counted as [`gaps`](crate::TimeReceiver::gaps()).
   ///
-  ///assert_eq!(time, 0);
-  ///```
-  pub fn time(&mut self) -> Time {
-    Self::get_time(&self.runtime, &mut self.time_receiver, &self.clock_is_active).unwrap()
-  }
-
-  ///A way to get the time with error handling instead of panicking
+  ///An error is returned if something went wrong.
   ///
   ///# Example
   ///
@@ -340,16 +602,21 @@ impl Clock {
   ///let mut clock = Clock::new().unwrap();
   ///clock.start();
   ///
-  ///let time = clock.safe_time().unwrap_or_else(|error| panic!("error: {error}"));
+  ///let mut time_receiver = clock.spawn_receiver();
   ///
-  ///assert_eq!(time, 0);
+  ///assert_eq!(time_receiver.next_time().unwrap(), 0);
+  ///assert_eq!(time_receiver.next_time().unwrap(), 1);
   ///```
-  pub fn safe_time(&mut self) -> anyhow::Result<Time> {
-    Self::get_time(&self.runtime, &mut self.time_receiver, &self.clock_is_active)
+  pub fn next_time(&mut self) -> anyhow::Result<Time> {
+    self.get_fresh_time()
   }
 
   ///Waits for the next tick.
   ///
+  ///The tick that was waited for isn't returned, so a [`time()`](crate::TimeReceiver::time()) after this
+  ///waits for the tick after it. Use [`next_time()`](crate::TimeReceiver::next_time()) to get the time
+  ///of the tick instead. Like it, the first tick sent after this was called is waited for.
+  ///
   ///An error is returned if something went wrong.
   ///
   ///# Example
@@ -360,14 +627,16 @@ impl Clock {
   ///let mut clock = Clock::new().unwrap();
   ///clock.start();
   ///
-  ///clock.wait_for_tick().unwrap_or_else(|error| panic!("{error}"));
+  ///let mut time_receiver = clock.spawn_receiver();
   ///
-  ///let time = clock.time();
+  ///time_receiver.wait_for_tick().unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///let time = time_receiver.time();
   ///
   ///assert_eq!(time, 1);
   ///```
   pub fn wait_for_tick(&mut self) -> anyhow::Result<()> {
-    if let Err(error) = Clock::get_time(&self.runtime, &mut self.time_receiver, &self.clock_is_active) {
+    if let Err(error) = self.get_fresh_time() {
       Err(error)
     } else {
       Ok(())
@@ -386,14 +655,16 @@ impl Clock {
   ///let mut clock = Clock::new().unwrap();
   ///clock.start();
   ///
-  ///clock.wait_for_x_ticks(5).unwrap_or_else(|error| panic!("{error}"));
+  ///let mut time_receiver = clock.spawn_receiver();
   ///
-  ///let time = clock.time();
+  ///time_receiver.wait_for_x_ticks(5).unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///let time = time_receiver.time();
   ///
   ///assert_eq!(time, 5);
   ///```
   pub fn wait_for_x_ticks(&mut self, x: u32) -> anyhow::Result<()> {
-    Self::wait_for_ticks(&self.runtime, &mut self.time_receiver, &self.clock_is_active, x)
+    self.wait_for_ticks(x.into())
   }
 
   ///Waits until the imput time.
@@ -409,114 +680,3843 @@ impl Clock {
   ///let mut clock = Clock::new().unwrap();
   ///clock.start();
   ///
-  ///clock.wait_for_time(9);
+  ///let mut time_receiver = clock.spawn_receiver();
   ///
-  ///let time = clock.time();
+  ///time_receiver.wait_for_time(9).unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///let time = time_receiver.time();
   ///
   ///assert_eq!(time, 10);
   ///```
   pub fn wait_for_time(&mut self, time: Time) -> anyhow::Result<()> {
-    Self::wait_until(&self.runtime, &mut self.time_receiver, &self.clock_is_active, time)
+    self.wait_until(time, None)
   }
 
-  ///Creates a [`time receiver`](crate::TimeReceiver) which has every method the clock does except starting,
-  ///stopping, and creating new time receivers.
+  ///Waits until the input time, unless the [`cancel handle`](crate::CancelHandle) is cancelled first.
   ///
-  ///The time receiver can be passed into other threads.
+  ///A cancelled wait returns [`ClockError::Cancelled`](crate::ClockError::Cancelled),
+  ///otherwise this works the same as [`wait_for_time()`](crate::TimeReceiver::wait_for_time()).
   ///
   ///# Example
   ///
   ///```
-  ///use thread_clock::Clock;
+  ///use thread_clock::{CancelHandle, Clock};
   ///
-  ///let mut clock = Clock::new().unwrap();
+  ///let mut clock = Clock::custom(1).unwrap();
   ///clock.start();
   ///
   ///let mut time_receiver = clock.spawn_receiver();
+  ///let cancel_handle = CancelHandle::new();
   ///
-  ///let time = time_receiver.time();
+  ///time_receiver.wait_for_time_cancellable(5, &cancel_handle).unwrap();
   ///
-  ///assert_eq!(time, 0);
+  ///cancel_handle.cancel();
+  ///
+  ///assert!(time_receiver.wait_for_time_cancellable(10, &cancel_handle).is_err());
   ///```
-  pub fn spawn_receiver(&self) -> TimeReceiver {
-    TimeReceiver {
-      runtime: Arc::clone(&self.runtime),
-      time_receiver: self.clock_sender.subscribe(),
-      clock_is_active: Arc::clone(&self.clock_is_active),
-    }
+  pub fn wait_for_time_cancellable(&mut self, time: Time, cancel_handle: &CancelHandle) -> anyhow::Result<()> {
+    self.wait_until(time, Some(cancel_handle))
   }
 
-  fn create_clock_thread(&self, mut stopper_receiver: OneReceiver<()>) -> JoinHandle<()> {
-    let time_sender = self.clock_sender.clone();
-    let tick_rate = self.tick_rate.into();
+  ///Waits for x ticks, unless the [`cancel handle`](crate::CancelHandle) is cancelled first.
+  ///
+  ///A cancelled wait returns [`ClockError::Cancelled`](crate::ClockError::Cancelled),
+  ///otherwise this works the same as [`wait_for_x_ticks()`](crate::TimeReceiver::wait_for_x_ticks()).
+  pub fn wait_for_x_ticks_cancellable(&mut self, x: u32, cancel_handle: &CancelHandle) -> anyhow::Result<()> {
+    self.wait_for_ticks_cancellable(x.into(), Some(cancel_handle))
+  }
 
-    self.runtime.spawn(async move {
-      let mut time = 0;
+  ///Waits until the input time, giving up once `max_wait` has passed.
+  ///
+  ///A wait that runs out of time returns [`ClockError::TimedOut`](crate::ClockError::TimedOut), so a
+  ///clock that stalls by being paused or falling behind can't block forever. Otherwise this works the
+  ///same as [`wait_for_time()`](crate::TimeReceiver::wait_for_time()).
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockError};
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///clock.pause();
+  ///
+  ///let error = time_receiver.wait_for_time_or_timeout(100, Duration::from_millis(50)).unwrap_err();
+  ///
+  ///assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::TimedOut));
+  ///```
+  pub fn wait_for_time_or_timeout(&mut self, time: Time, max_wait: Duration) -> anyhow::Result<()> {
+    let cancel_handle = CancelHandle::new();
+    let timeout = self.runtime.spawn({
+      let cancel_handle = cancel_handle.clone();
 
-      while stopper_receiver.try_recv().is_err() {
-        tokio::time::sleep(Duration::from_millis(tick_rate)).await;
+      async move {
+        tokio::time::sleep(max_wait).await;
+        cancel_handle.cancel();
+      }
+    })?;
+    let waited = self.wait_until(time, Some(&cancel_handle));
 
-        let _ = time_sender.send(time);
+    timeout.abort();
 
-        time += 1;
-      }
+    waited.map_err(|error| match error.downcast_ref::<ClockError>() {
+      Some(ClockError::Cancelled) => ClockError::TimedOut.into(),
+      _ => error,
     })
   }
 
-  // shared function split
+  ///Waits until the clock has reached at least the input time and returns the current time.
+  ///
+  ///Unlike [`wait_for_time()`](crate::TimeReceiver::wait_for_time()), a time that has already
+  ///occurred isn't an error, the current time is returned right away instead.
+  ///
+  ///An error is returned if something went wrong with the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///let time = time_receiver.wait_until_at_least(5).unwrap();
+  ///assert_eq!(time, 5);
+  ///
+  ///// time 2 has already occurred, so this returns right away
+  ///let time = time_receiver.wait_until_at_least(2).unwrap();
+  ///assert!(time >= 5);
+  ///```
+  pub fn wait_until_at_least(&mut self, time: Time) -> anyhow::Result<Time> {
+    if let Some(latest_time) = self.latest_tick()? {
+      if self.has_reached(latest_time, time) {
+        return Ok(latest_time);
+      }
+    }
 
-  fn get_time(runtime: &Runtime, time_receiver: &mut Receiver<Time>, clock_status: &Arc<Mutex<bool>>) -> anyhow::Result<Time> {
-    let lock = clock_status.lock().unwrap();
+    let mut current_time = self.get_time()?;
 
-    if !*lock {
-      return Err(anyhow!("The clock hasn't started yet"));
+    while !self.has_reached(current_time, time) {
+      current_time = self.get_time()?;
     }
 
-    drop(lock);
+    Ok(current_time)
+  }
 
-    let channel_was_empty = time_receiver.is_empty();
-    let time = runtime.block_on(time_receiver.recv());
+  ///Waits for the next tick whose time is a multiple of `n` and returns it.
+  ///
+  ///Useful for jobs that have to run on set ticks, such as every 10th tick starting from 0.
+  ///
+  ///An error is returned if `n` is 0 or something went wrong with the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///let time = time_receiver.wait_for_multiple_of(10).unwrap();
+  ///
+  ///assert_eq!(time % 10, 0);
+  ///```
+  pub fn wait_for_multiple_of(&mut self, n: Time) -> anyhow::Result<Time> {
+    if n == 0 {
+      return Err(anyhow!("Can't wait for a multiple of 0"));
+    }
 
-    if let (Ok(time), true) = (time, channel_was_empty) {
-      Ok(time)
-    } else if !time_receiver.is_empty() {
-      let _ = runtime.block_on(time_receiver.recv()); // remove old time from channel
+    loop {
+      let time = self.get_time()?;
 
-      Ok(runtime.block_on(time_receiver.recv())?)
-    } else {
-      Ok(runtime.block_on(time_receiver.recv())?)
+      if time.is_multiple_of(n) {
+        return Ok(time);
+      }
     }
   }
 
-  fn wait_for_ticks(
-    runtime: &Runtime,
-    time_receiver: &mut Receiver<Time>,
-    clock_status: &Arc<Mutex<bool>>,
-    x: u32,
-  ) -> anyhow::Result<()> {
-    for _ in 0..x {
-      Self::get_time(runtime, time_receiver, clock_status)?;
+  ///Waits for as many ticks as fit in the duration.
+  ///
+  ///The duration is turned into ticks with the [`rounding`](crate::Rounding) the clock was built with,
+  ///which rounds up by default.
+  ///
+  ///An error is returned if something went wrong.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///time_receiver.wait_for_duration(Duration::from_millis(5)).unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///let time = time_receiver.time();
+  ///
+  ///assert_eq!(time, 5);
+  ///```
+  pub fn wait_for_duration(&mut self, duration: Duration) -> anyhow::Result<()> {
+    let ticks = self.rounding.ticks_in(
+      duration.saturating_mul(self.multiplier),
+      self.tick_rate.saturating_mul(self.divisor),
+    );
+
+    self.wait_for_ticks(ticks)
+  }
+
+  ///Waits until the tick that lines up with the wall-clock time.
+  ///
+  ///The time left until then is turned into ticks the same way as
+  ///[`wait_for_duration()`](crate::TimeReceiver::wait_for_duration()).
+  ///
+  ///An error is returned if something went wrong.
+  ///Such as if the time has already passed.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::{Duration, SystemTime};
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///let deadline = SystemTime::now() + Duration::from_millis(10);
+  ///
+  ///time_receiver.wait_until_system_time(deadline).unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///assert!(time_receiver.wait_until_system_time(deadline).is_err());
+  ///```
+  pub fn wait_until_system_time(&mut self, system_time: SystemTime) -> anyhow::Result<()> {
+    match system_time.duration_since(SystemTime::now()) {
+      Ok(duration) => self.wait_for_duration(duration),
+      Err(_) => Err(ClockError::TimeHasOccurred.into()),
+    }
+  }
+
+  ///Waits until the tick that lines up with the date and time.
+  ///
+  ///Works the same as [`wait_until_system_time()`](crate::TimeReceiver::wait_until_system_time()).
+  #[cfg(feature = "chrono")]
+  pub fn schedule_at_datetime(&mut self, datetime: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
+    self.wait_until_system_time(datetime.into())
+  }
+
+  ///Returns how many ticks the clock has made since the last time this was called, without waiting.
+  ///
+  ///The first call returns every tick made since the clock started, counted from the time it started from,
+  ///such as with [`start_from()`](crate::Clock::start_from()).
+  ///This lets a consumer that was busy find out how many ticks it missed, such as to run that many
+  ///simulation steps to catch up.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::thread;
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///time_receiver.ticks_since_last_call();
+  ///thread::sleep(Duration::from_millis(10));
+  ///
+  ///assert!(time_receiver.ticks_since_last_call() > 0);
+  ///```
+  pub fn ticks_since_last_call(&mut self) -> u64 {
+    let Some(latest_time) = self.latest_time() else {
+      return 0;
+    };
+    let ticks = match self.last_call_time {
+      Some(last_call_time) => self.overflow.ticks_between(last_call_time, latest_time),
+      // the first tick of the run is counted as well
+      None => self
+        .overflow
+        .ticks_between(self.first_time(), latest_time)
+        .map(|ticks| ticks.saturating_add(1)),
+    };
+
+    self.last_call_time = Some(latest_time);
+
+    ticks.unwrap_or(0)
+  }
+
+  ///Returns the first time this receiver gets in the clock's current run, which counts from the time the
+  ///clock started from.
+  fn first_time(&self) -> Time {
+    let start_time = self.lifecycle.started_from();
+
+    // a tick of this receiver that ended before the clock's start time belongs to an earlier run
+    start_time
+      .checked_sub(1)
+      .and_then(|time_before_start| self.scale_clock_time(time_before_start))
+      .map_or(0, |time| time.saturating_add(Time::from(self.multiplier)))
+  }
+
+  ///Runs a fixed timestep loop on the ticks of the clock.
+  ///
+  ///`update` is called once for every tick with its time, ticks that passed while rendering are caught up
+  ///on in order. `render` is called as often as possible in between with how far the clock is into the
+  ///current tick, from 0.0 up to 1.0, so rendering can interpolate between the last two updates.
+  ///
+  ///The loop ends once `update` returns false or the clock stops.
+  ///An error is returned if the clock hasn't started or something went wrong with the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(5).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///let mut updates = 0;
+  ///
+  ///time_receiver
+  ///  .run_fixed_timestep(
+  ///    |_time| {
+  ///      updates += 1;
+  ///
+  ///      updates < 10
+  ///    },
+  ///    |alpha| assert!((0.0..=1.0).contains(&alpha)),
+  ///  )
+  ///  .unwrap();
+  ///
+  ///assert_eq!(updates, 10);
+  ///```
+  pub fn run_fixed_timestep<U, R>(&mut self, mut update: U, mut render: R) -> anyhow::Result<()>
+  where
+    U: FnMut(Time) -> bool,
+    R: FnMut(f32),
+  {
+    let tick_length = self.tick_length().as_secs_f32();
+    let mut last_update = match self.get_time() {
+      Ok(time) => time,
+      Err(error) => return ended_by_stop(error),
+    };
+    let mut last_tick = Instant::now();
+
+    if !update(last_update) {
+      return Ok(());
+    }
+
+    loop {
+      match self.latest_tick() {
+        Ok(Some(time)) if time > last_update => {
+          for time in last_update + 1..=time {
+            if !update(time) {
+              return Ok(());
+            }
+          }
+
+          last_update = time;
+          last_tick = Instant::now();
+        }
+        Ok(_) => (),
+        Err(error) => return ended_by_stop(error),
+      }
+
+      let alpha = if tick_length > 0.0 {
+        (last_tick.elapsed().as_secs_f32() / tick_length).min(1.0)
+      } else {
+        1.0
+      };
+
+      render(alpha);
+    }
+  }
+
+  ///Calls `on_tick` with the time of each of the next `n` ticks.
+  ///
+  ///An error is returned if something went wrong with the clock before all of the ticks were seen,
+  ///including the clock stopping.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///let mut times = vec![];
+  ///
+  ///time_receiver.for_each_tick(5, |time| times.push(time)).unwrap();
+  ///
+  ///assert_eq!(times, [0, 1, 2, 3, 4]);
+  ///```
+  pub fn for_each_tick<F>(&mut self, n: u32, mut on_tick: F) -> anyhow::Result<()>
+  where
+    F: FnMut(Time),
+  {
+    for _ in 0..n {
+      on_tick(self.get_time()?);
     }
 
     Ok(())
   }
 
-  fn wait_until(
-    runtime: &Runtime,
-    time_receiver: &mut Receiver<Time>,
-    clock_status: &Arc<Mutex<bool>>,
-    wait_for_time: Time,
-  ) -> anyhow::Result<()> {
-    let current_time = Clock::get_time(runtime, time_receiver, clock_status)?;
+  ///Calls `on_tick` with the time of every tick for the duration, waiting out whatever is left of it.
+  ///
+  ///The loop also ends if the clock stops first. An error is returned if the clock hasn't started or
+  ///something else went wrong with the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(10).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///let mut ticks = 0;
+  ///
+  ///time_receiver.run_for(Duration::from_millis(100), |_time| ticks += 1).unwrap();
+  ///
+  ///assert!(ticks > 0 && ticks <= 10);
+  ///```
+  pub fn run_for<F>(&mut self, duration: Duration, mut on_tick: F) -> anyhow::Result<()>
+  where
+    F: FnMut(Time),
+  {
+    let cancel_handle = CancelHandle::new();
+    let timeout = self.runtime.spawn({
+      let cancel_handle = cancel_handle.clone();
+
+      async move {
+        tokio::time::sleep(duration).await;
+        cancel_handle.cancel();
+      }
+    })?;
+
+    let ran = loop {
+      match self.get_time_cancellable(Some(&cancel_handle)) {
+        Ok(time) => on_tick(time),
+        Err(error) => break error,
+      }
+    };
+
+    timeout.abort();
 
-    if current_time < wait_for_time {
-      let time_to_wait = wait_for_time - current_time;
+    match ran.downcast_ref::<ClockError>() {
+      Some(ClockError::Cancelled) => Ok(()),
+      _ => ended_by_stop(ran),
+    }
+  }
 
-      Self::wait_for_ticks(runtime, time_receiver, clock_status, time_to_wait as u32)?;
-    } else {
-      return Err(anyhow!("This time has already occurred"));
+  ///Returns an iterator over only every `n`th tick of this receiver, see
+  ///[`EveryNthTick`](crate::EveryNthTick).
+  ///
+  ///An error is returned if `n` is 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(5).unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///clock.start();
+  ///
+  ///for time in time_receiver.every(5).unwrap().take(4) {
+  ///  assert_eq!(time.unwrap() % 5, 0);
+  ///}
+  ///```
+  pub fn every(&mut self, n: u32) -> anyhow::Result<EveryNthTick<'_>> {
+    if n == 0 {
+      return Err(anyhow!("A time receiver can't let through every 0th tick"));
+    }
+
+    Ok(EveryNthTick::new(self, n))
+  }
+
+  ///Makes this receiver tick once every `divisor` ticks of its clock, counting its own time from 0.
+  ///
+  ///This lets a single clock feed consumers that want a slower tick stream without creating another clock.
+  ///Every wait on the receiver counts its own ticks from then on, the clock and its other receivers
+  ///aren't affected. A divisor of 1 gives the clock's own ticks back, and replaces any multiplier.
+  ///
+  ///An error is returned if the divisor is 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///time_receiver.set_divisor(10).unwrap();
+  ///
+  ///// every tick of the receiver takes 10 ticks of the clock
+  ///let time = time_receiver.time();
+  ///time_receiver.wait_for_tick().unwrap();
+  ///
+  ///assert_eq!(time_receiver.time(), time + 2);
+  ///```
+  pub fn set_divisor(&mut self, divisor: u32) -> anyhow::Result<()> {
+    if divisor == 0 {
+      return Err(anyhow!("A time receiver can't have a divisor of 0"));
+    }
+
+    self.set_scale(divisor, 1);
+
+    Ok(())
+  }
+
+  ///Makes this receiver tick `multiplier` times for every tick of its clock.
+  ///
+  ///The extra ticks are interpolated, evenly spread out between two ticks of the clock, and the receiver
+  ///counts its own time as the clock's time times the multiplier plus the ticks in between.
+  ///Every wait on the receiver counts its own ticks from then on, the clock and its other receivers
+  ///aren't affected. A multiplier of 1 gives the clock's own ticks back, and replaces any divisor.
+  ///
+  ///An error is returned if the multiplier is 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(20).unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///time_receiver.set_multiplier(4).unwrap();
+  ///
+  ///let time = time_receiver.time();
+  ///
+  ///// the next 3 ticks fall in between this tick of the clock and the next one
+  ///assert_eq!(time % 4, 0);
+  ///assert_eq!(time_receiver.time(), time + 1);
+  ///```
+  pub fn set_multiplier(&mut self, multiplier: u32) -> anyhow::Result<()> {
+    if multiplier == 0 {
+      return Err(anyhow!("A time receiver can't have a multiplier of 0"));
     }
 
+    self.set_scale(1, multiplier);
+
     Ok(())
   }
+
+  ///Returns how many ticks of the clock each tick of this receiver takes, 1 unless a divisor was set.
+  pub fn divisor(&self) -> u32 {
+    self.divisor
+  }
+
+  ///Returns how many ticks this receiver makes for each tick of the clock, 1 unless a multiplier was set.
+  pub fn multiplier(&self) -> u32 {
+    self.multiplier
+  }
+
+  ///Creates another time receiver listening to the same clock as this one.
+  ///
+  ///This lets a thread that only holds a time receiver hand out receivers of its own.
+  ///The new receiver gets the clock's own ticks, whatever this one was scaled to.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::thread;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///let time_receiver = clock.spawn_receiver();
+  ///
+  ///let handle = thread::spawn(move || {
+  ///  let mut worker_receiver = time_receiver.spawn_receiver();
+  ///
+  ///  worker_receiver.time()
+  ///});
+  ///
+  ///assert_eq!(handle.join().unwrap(), 0);
+  ///```
+  pub fn spawn_receiver(&self) -> TimeReceiver {
+    let mut time_receiver = self.with_receiver(self.time_receiver.resubscribe(), self.tick_rate);
+
+    // the new receiver listens on the same channel, so it's woken up along with this one
+    time_receiver.priority = self.priority;
+
+    time_receiver
+  }
+
+  ///Blocks until the clock has started, returning right away if it already has.
+  ///
+  ///Worker threads spawned before the clock starts can call this first, instead of every call
+  ///returning [`ClockError::NotStarted`](crate::ClockError::NotStarted) until it does. A paused start
+  ///counts as starting. Waiting can be cut short with an [`interrupt handle`](crate::InterruptHandle).
+  ///
+  ///An error is returned if the clock is dropped without being started.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::thread;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///let worker = thread::spawn(move || {
+  ///  time_receiver.wait_for_start().unwrap();
+  ///
+  ///  time_receiver.safe_time()
+  ///});
+  ///
+  ///clock.start();
+  ///
+  ///assert!(worker.join().unwrap().is_ok());
+  ///```
+  pub fn wait_for_start(&mut self) -> anyhow::Result<()> {
+    let started = self.lifecycle.started(&self.clock_status);
+
+    Ok(block_on_interruptible(&self.runtime, &self.interrupt_handle, started, None)??)
+  }
+
+  ///Switches this receiver over to another clock, so a long-lived worker can follow a different clock
+  ///without being torn down and spawned again.
+  ///
+  ///The receiver then works the same as one spawned from the other clock. Its
+  ///[`interrupt handles`](crate::InterruptHandle), [`missed tick callback`](crate::TimeReceiver::on_missed_ticks()),
+  ///[`priority`](crate::ReceiverPriority) and whether it [`errors on gaps`](crate::TimeReceiver::error_on_gaps())
+  ///carry over, while a divisor or multiplier doesn't, as it was chosen for the tickrate of the old clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut menu_clock = Clock::custom(100).unwrap();
+  ///let mut gameplay_clock = Clock::custom(8).unwrap();
+  ///let mut time_receiver = menu_clock.spawn_receiver();
+  ///
+  ///menu_clock.start();
+  ///time_receiver.wait_for_tick().unwrap();
+  ///
+  ///gameplay_clock.start_from(1000);
+  ///time_receiver.rebind(&gameplay_clock);
+  ///
+  ///assert_eq!(time_receiver.tick_rate(), 8);
+  ///assert!(time_receiver.time() >= 1000);
+  ///```
+  pub fn rebind(&mut self, clock: &Clock) {
+    let mut time_receiver = clock.spawn_receiver_with_priority(self.priority);
+
+    time_receiver.interrupt_handle = self.interrupt_handle.clone();
+    time_receiver.missed_ticks_callback = self.missed_ticks_callback.take();
+    time_receiver.error_on_gaps = self.error_on_gaps;
+
+    *self = time_receiver;
+  }
+
+  ///Creates an [`interrupt handle`](crate::InterruptHandle) that can unblock this receiver's waits
+  ///from another thread.
+  ///
+  ///Every handle from the same receiver interrupts it, receivers spawned from this one have their own.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///let interrupt_handle = time_receiver.interrupt_handle();
+  ///
+  ///interrupt_handle.interrupt();
+  ///
+  ///assert!(time_receiver.wait_for_tick().is_err());
+  ///assert!(time_receiver.wait_for_tick().is_ok());
+  ///```
+  pub fn interrupt_handle(&self) -> InterruptHandle {
+    self.interrupt_handle.clone()
+  }
+
+  ///Calls back with the range of ticks this receiver skipped whenever it gets a tick that isn't the one
+  ///right after the last one it got, replacing any callback set before.
+  ///
+  ///A receiver that keeps up gets every tick in order, while one that reads less often than the clock
+  ///ticks, or falls further behind it than its channel holds, skips the ticks in between. The callback
+  ///runs on the thread reading the receiver, right before the tick is returned.
+  ///Receivers spawned from this one don't share the callback.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::sync::mpsc;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///let (missed_sender, missed_ticks) = mpsc::channel();
+  ///
+  ///time_receiver.on_missed_ticks(move |missed| missed_sender.send(missed).unwrap());
+  ///clock.start();
+  ///
+  ///let time = time_receiver.time();
+  ///
+  ///clock.wait_for_time(time + 10).unwrap();
+  ///
+  ///let later_time = time_receiver.time();
+  ///
+  ///assert_eq!(missed_ticks.recv().unwrap(), time + 1..later_time);
+  ///```
+  pub fn on_missed_ticks(&mut self, callback: impl FnMut(Range<Time>) + Send + 'static) {
+    self.missed_ticks_callback = Some(MissedTicksCallback(Mutex::new(Box::new(callback))));
+  }
+
+  ///Returns how many ticks this receiver has skipped in total, counted the same way as the ticks passed
+  ///to [`on_missed_ticks()`](crate::TimeReceiver::on_missed_ticks()).
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///clock.start();
+  ///
+  ///let time = time_receiver.time();
+  ///
+  ///clock.wait_for_time(time + 10).unwrap();
+  ///
+  ///let later_time = time_receiver.time();
+  ///
+  ///assert_eq!(time_receiver.gaps(), later_time - time - 1);
+  ///```
+  pub fn gaps(&self) -> u64 {
+    self.gaps
+  }
+
+  ///Sets whether waiting on this receiver returns [`ClockError::MissedTicks`](crate::ClockError::MissedTicks)
+  ///when the tick it gets isn't the one right after the last one it got, for consumers that have to
+  ///process every tick. Off by default.
+  ///
+  ///The tick that revealed the gap is still counted as seen, so waiting again carries on after it.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockError};
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///time_receiver.error_on_gaps(true);
+  ///clock.start();
+  ///
+  ///let time = time_receiver.time();
+  ///
+  ///clock.wait_for_time(time + 10).unwrap();
+  ///
+  ///let error = time_receiver.safe_time().unwrap_err();
+  ///
+  ///assert!(matches!(
+  ///  error.downcast_ref::<ClockError>(),
+  ///  Some(ClockError::MissedTicks { first_missed, .. }) if *first_missed == time + 1
+  ///));
+  ///```
+  pub fn error_on_gaps(&mut self, error_on_gaps: bool) {
+    self.error_on_gaps = error_on_gaps;
+  }
+
+  ///Returns the tickrate in milliseconds of the clock this receiver belongs to.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::custom(16).unwrap();
+  ///let time_receiver = clock.spawn_receiver();
+  ///
+  ///assert_eq!(time_receiver.tick_rate(), 16);
+  ///```
+  pub fn tick_rate(&self) -> u32 {
+    self.tick_rate
+  }
+
+  ///Returns the [`priority`](crate::ReceiverPriority) this receiver is sent every tick with.
+  pub fn priority(&self) -> ReceiverPriority {
+    self.priority
+  }
+
+  ///Returns the current [`status`](crate::ClockStatus) of the clock this receiver belongs to.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockStatus};
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///let time_receiver = clock.spawn_receiver();
+  ///
+  ///clock.start();
+  ///
+  ///assert_eq!(time_receiver.status(), ClockStatus::Running);
+  ///```
+  pub fn status(&self) -> ClockStatus {
+    self.clock_status.get()
+  }
+
+  ///Returns true if the clock this receiver belongs to is ticking.
+  pub fn is_running(&self) -> bool {
+    self.status() == ClockStatus::Running
+  }
+
+  ///Returns true if the clock this receiver belongs to is paused.
+  pub fn is_paused(&self) -> bool {
+    self.status() == ClockStatus::Paused
+  }
+
+  ///Returns the wall time since the clock this receiver belongs to started, including the time it spent
+  ///paused.
+  ///
+  ///The time counts from the clock's latest start, stops counting once it stops, and is zero if it hasn't
+  ///started yet. Subtracting [`paused_duration()`](TimeReceiver::paused_duration()) gives the time the
+  ///clock spent ticking.
+  pub fn elapsed(&self) -> Duration {
+    self.lifecycle.elapsed()
+  }
+
+  ///Returns how long the clock this receiver belongs to has been paused for since it started, including
+  ///a pause it's still in.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use std::{thread, time::Duration};
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///let time_receiver = clock.spawn_receiver();
+  ///
+  ///clock.start();
+  ///clock.pause();
+  ///thread::sleep(Duration::from_millis(20));
+  ///clock.resume();
+  ///
+  ///assert!(time_receiver.paused_duration() >= Duration::from_millis(20));
+  ///assert!(time_receiver.elapsed() >= time_receiver.paused_duration());
+  ///```
+  pub fn paused_duration(&self) -> Duration {
+    self.lifecycle.paused_duration()
+  }
+
+  ///Returns a [`watch`](tokio::sync::watch) channel holding the [`status`](crate::ClockStatus) of the
+  ///clock this receiver belongs to, so a thread can react to the clock starting, pausing, resuming or
+  ///stopping instead of checking for it.
+  ///
+  ///The channel starts out with the current status marked as seen. A status that changes twice before
+  ///it's read is only seen as the latest one, as with any watch channel.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockStatus};
+  ///
+  ///#[tokio::main]
+  ///async fn main() {
+  ///  let mut clock = Clock::custom(1).unwrap();
+  ///  let mut status = clock.spawn_receiver().watch_status();
+  ///
+  ///  let watcher = tokio::spawn(async move {
+  ///    status.wait_for(|status| matches!(status, ClockStatus::Stopped(_))).await.map(|status| *status)
+  ///  });
+  ///
+  ///  clock.start();
+  ///
+  ///  let final_time = clock.stop().unwrap();
+  ///
+  ///  assert_eq!(watcher.await.unwrap().unwrap(), ClockStatus::Stopped(final_time));
+  ///}
+  ///```
+  pub fn watch_status(&self) -> tokio::sync::watch::Receiver<ClockStatus> {
+    self.lifecycle.watch()
+  }
+
+  ///Returns a receiver of the [`events`](crate::ClockEvent) that happen to the clock from now on, such as it
+  ///being paused, changing its rate or missing a tick.
+  ///
+  ///Unlike [`watch_status()`](crate::TimeReceiver::watch_status()), which only holds the latest status,
+  ///every event is received in the order it happened. See [`EventReceiver`](crate::EventReceiver).
+  pub fn events(&self) -> EventReceiver {
+    EventReceiver::new(Arc::clone(&self.runtime), self.lifecycle.events())
+  }
+
+  fn get_time(&mut self) -> anyhow::Result<Time> {
+    self.get_time_cancellable(None)
+  }
+
+  ///Waits for the first tick sent after this is called, skipping the ones already in the channel.
+  fn get_fresh_time(&mut self) -> anyhow::Result<Time> {
+    // a receiver that hasn't gotten a message doesn't carry on from one, see next_clock_message
+    self.seen_jumps = None;
+
+    self.get_time()
+  }
+
+  fn get_time_cancellable(&mut self, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<Time> {
+    let time = if self.multiplier > 1 {
+      self.next_sub_tick(cancel_handle)?
+    } else {
+      self.next_divided_tick(cancel_handle)?
+    };
+
+    self.record_time(time)?;
+
+    Ok(time)
+  }
+
+  ///Makes the time the newest one this receiver has seen, counting and calling back with the ticks that
+  ///were skipped to get to it.
+  ///
+  ///Returns [`ClockError::MissedTicks`](crate::ClockError::MissedTicks) if ticks were skipped and the
+  ///receiver errors on gaps, the time is still recorded so the next wait carries on from it.
+  fn record_time(&mut self, time: Time) -> Result<(), ClockError> {
+    let first_missed = self.latest_time.map(|latest_time| latest_time.saturating_add(1));
+    self.latest_time = Some(time);
+
+    match first_missed {
+      Some(first_missed) if time > first_missed => {
+        self.gaps = self.gaps.saturating_add(time - first_missed);
+
+        if let Some(callback) = &self.missed_ticks_callback {
+          (callback.0.lock().unwrap())(first_missed..time);
+        }
+
+        if self.error_on_gaps {
+          return Err(ClockError::MissedTicks { first_missed, time });
+        }
+
+        Ok(())
+      }
+      _ => Ok(()),
+    }
+  }
+
+  ///Waits for the next tick of a receiver that isn't scaled up, which is every tick of the clock unless
+  ///a divisor was set.
+  fn next_divided_tick(&mut self, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<Time> {
+    loop {
+      let clock_time = self.next_clock_tick(cancel_handle)?;
+
+      if let Some(time) = self.divided_time(clock_time) {
+        return Ok(time);
+      }
+    }
+  }
+
+  ///Returns the time of this receiver for a tick of its clock, or None if it doesn't finish a tick of
+  ///this receiver.
+  fn divided_time(&self, clock_time: Time) -> Option<Time> {
+    if self.divisor == 1 {
+      return Some(clock_time);
+    }
+
+    self
+      .scale_clock_time(clock_time)
+      .filter(|time| self.latest_time.is_none_or(|latest_time| self.is_later(*time, latest_time)))
+  }
+
+  ///Waits for the next tick of a receiver with a multiplier, which either falls on a tick of the clock
+  ///or in between two of them.
+  fn next_sub_tick(&mut self, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<Time> {
+    if let Some((due, sub_tick)) = self.next_sub_tick_due() {
+      self.sleep_until(due, cancel_handle)?;
+
+      return Ok(self.move_to_sub_tick(sub_tick));
+    }
+
+    let clock_time = self.next_clock_tick(cancel_handle)?;
+
+    Ok(self.start_sub_ticks(clock_time))
+  }
+
+  ///Returns the next tick in between two ticks of the clock and when it's due, or None if the next tick
+  ///of this receiver is the next tick of the clock.
+  fn next_sub_tick_due(&self) -> Option<(Instant, SubTick)> {
+    let clock_tick_length = Duration::from_millis(self.tick_rate.into());
+    let sub_tick = self.sub_tick?;
+    let index = sub_tick.index + 1;
+    let next_clock_tick = sub_tick.started_at + clock_tick_length;
+
+    // once the next tick of the clock is due, whatever ticks are left in between are skipped
+    if index >= self.multiplier || Instant::now() >= next_clock_tick {
+      return None;
+    }
+
+    let due = sub_tick.started_at + clock_tick_length * index / self.multiplier;
+
+    Some((due, SubTick { index, ..sub_tick }))
+  }
+
+  ///Moves this receiver to a tick in between two ticks of the clock, returning its time.
+  fn move_to_sub_tick(&mut self, sub_tick: SubTick) -> Time {
+    self.sub_tick = Some(sub_tick);
+
+    sub_tick
+      .clock_time
+      .saturating_mul(Time::from(self.multiplier))
+      .saturating_add(Time::from(sub_tick.index))
+  }
+
+  ///Starts counting the ticks in between the tick of the clock and the one after it, returning the time
+  ///of this receiver for the tick of the clock.
+  fn start_sub_ticks(&mut self, clock_time: Time) -> Time {
+    self.move_to_sub_tick(SubTick {
+      clock_time,
+      index: 0,
+      started_at: Instant::now(),
+    })
+  }
+
+  ///Turns a time of the clock into the time of this receiver.
+  ///
+  ///Returns None if a divisor was set and the receiver hasn't finished its first tick by then.
+  fn scale_clock_time(&self, clock_time: Time) -> Option<Time> {
+    let divisor = Time::from(self.divisor);
+
+    // the same as (clock_time + 1) / divisor, without overflowing at the maximum time
+    (clock_time / divisor + (clock_time % divisor + 1) / divisor)
+      .checked_sub(1)
+      .map(|time| time.saturating_mul(Time::from(self.multiplier)))
+  }
+
+  ///Changes how this receiver's ticks are counted, which restarts its own bookkeeping.
+  fn set_scale(&mut self, divisor: u32, multiplier: u32) {
+    self.divisor = divisor;
+    self.multiplier = multiplier;
+    self.latest_time = None;
+    self.last_call_time = None;
+    self.sub_tick = None;
+  }
+
+  ///Returns how long a tick of this receiver lasts.
+  fn tick_length(&self) -> Duration {
+    Duration::from_millis(self.tick_rate.into()) * self.divisor / self.multiplier
+  }
+
+  ///Blocks until the deadline, or until the receiver is interrupted or the cancel handle is cancelled.
+  fn sleep_until(&mut self, deadline: Instant, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<()> {
+    block_on_interruptible(
+      &self.runtime,
+      &self.interrupt_handle,
+      // the sleep has to be created from within the runtime
+      async move { tokio::time::sleep_until(deadline).await },
+      cancel_handle,
+    )
+  }
+
+  ///Waits for the next tick of the clock itself.
+  fn next_clock_tick(&mut self, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<Time> {
+    loop {
+      let message = self.next_clock_message(cancel_handle)?;
+
+      if !self.is_left_over(message) {
+        return self.receive(message);
+      }
+    }
+  }
+
+  ///Waits for the next message from the clock.
+  ///
+  ///A receiver that has gotten a message before carries on from it while the channel still holds the one
+  ///after it, otherwise the messages that were already in the channel are skipped.
+  fn next_clock_message(&mut self, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<ClockMessage> {
+    self.check_status()?;
+    self.check_restarts()?;
+
+    let jumps = self.activity.jumps();
+
+    if self.keeps_up(jumps) {
+      if let Some(message) = channel::message_after_last(&mut self.time_receiver) {
+        return Ok(message);
+      }
+    }
+
+    let activity = Arc::clone(&self.activity);
+    let clock_status = Arc::clone(&self.clock_status);
+
+    let message = channel::next_message(
+      &mut self.time_receiver,
+      || activity.start_reading(),
+      || clock_status.final_time(),
+      // blocks until the next message is received, or until the receiver is interrupted or the
+      // cancel handle is cancelled
+      |time_receiver| {
+        block_on_interruptible(
+          &self.runtime,
+          &self.interrupt_handle,
+          time_receiver.recv(),
+          cancel_handle,
+        )
+      },
+    )?;
+    self.seen_jumps = Some(jumps);
+
+    Ok(message)
+  }
+
+  ///Returns true if the receiver can carry on from the last message it got, which it can once it has
+  ///gotten one and as long as the clock's time hasn't jumped ahead since.
+  ///
+  ///Whether the receiver fell further behind than the channel holds is up to the channel, see
+  ///[`channel::message_after_last()`]. A clock that idles doesn't send the ticks that would make the
+  ///channel lag, so it counts a jump as it goes idle, which is checked on top of it. The jumps are read
+  ///before a message is received, so a jump during the wait counts as one after it.
+  fn keeps_up(&self, jumps: u64) -> bool {
+    self.seen_jumps == Some(jumps)
+  }
+
+  ///Waits for the next tick of the clock itself without blocking the thread.
+  ///
+  ///Dropping the future before it finishes doesn't lose any tick, as receiving from the channel is
+  ///cancellation safe.
+  async fn next_clock_tick_async(&mut self) -> anyhow::Result<Time> {
+    // waiting for the start here keeps check_status from blocking the runtime on it
+    if self.waits_for_start {
+      self.lifecycle.started(&self.clock_status).await?;
+    }
+
+    self.check_status()?;
+    self.check_restarts()?;
+
+    // the same as when blocking, see next_clock_message
+    let jumps = self.activity.jumps();
+
+    if self.keeps_up(jumps) {
+      while let Some(message) = channel::message_after_last(&mut self.time_receiver) {
+        if !self.is_left_over(message) {
+          return self.receive(message);
+        }
+      }
+    }
+
+    // ticks already in the channel are old ones, see channel::next_message
+    self.time_receiver = self.time_receiver.resubscribe();
+
+    let activity = Arc::clone(&self.activity);
+    let _reading = activity.start_reading();
+
+    if let Some(final_time) = self.clock_status.final_time() {
+      return self.receive(ClockMessage::Stopped(final_time));
+    }
+
+    let message = loop {
+      let message = match self.time_receiver.recv().await {
+        Err(RecvError::Lagged(missed_ticks)) => {
+          log_debug!("A time receiver lagged behind the clock and skipped {missed_ticks} ticks");
+
+          match channel::catch_up(&mut self.time_receiver) {
+            Some(message) => message,
+            None => continue,
+          }
+        }
+        message => message?,
+      };
+
+      if !self.is_left_over(message) {
+        break message;
+      }
+    };
+    self.seen_jumps = Some(jumps);
+
+    self.receive(message)
+  }
+
+  ///Returns an error if the clock isn't ticking, as waiting for it would never finish.
+  fn check_status(&mut self) -> anyhow::Result<()> {
+    self.check_runs();
+
+    let clock_status = self.clock_status.get();
+
+    match clock_status {
+      ClockStatus::Created if self.waits_for_start => self.wait_for_start(),
+      ClockStatus::Created => Err(ClockError::NotStarted.into()),
+      ClockStatus::Stopped(final_time) => {
+        let final_time = self.final_time(final_time);
+
+        Err(self.stopped_error(final_time).into())
+      }
+      ClockStatus::Running | ClockStatus::Paused => Ok(()),
+    }
+  }
+
+  ///Forgets what this receiver saw of the clock's previous run if the clock has been started again since.
+  fn check_runs(&mut self) {
+    let runs = self.lifecycle.runs();
+
+    if runs > self.seen_runs {
+      // the clock was started again, so nothing this receiver saw of its previous run applies anymore
+      self.seen_runs = runs;
+      self.final_time = None;
+      self.latest_time = None;
+      self.last_call_time = None;
+      self.sub_tick = None;
+    }
+  }
+
+  ///Returns true if the message is a stop left over from before the clock was started again.
+  ///
+  ///The clock's status changes before its stop is sent, so a stop of the current run always finds it stopped.
+  fn is_left_over(&self, message: ClockMessage) -> bool {
+    matches!(message, ClockMessage::Stopped(_)) && !matches!(self.status(), ClockStatus::Stopped(_))
+  }
+
+  ///Returns an error once for every time the clock task was restarted since this was last checked.
+  fn check_restarts(&mut self) -> anyhow::Result<()> {
+    match self.restarts.since(self.seen_restarts) {
+      Some((restarts, restarted_at)) => {
+        self.seen_restarts = restarts;
+
+        Err(ClockError::Restarted(restarted_at).into())
+      }
+      None => Ok(()),
+    }
+  }
+
+  ///Turns a message from the clock into its time, or the error for the clock having stopped or restarted.
+  fn receive(&mut self, message: ClockMessage) -> anyhow::Result<Time> {
+    self.check_restarts()?;
+
+    match message {
+      ClockMessage::Tick(time) => Ok(time),
+      ClockMessage::Stopped(final_time) => {
+        self.final_time = Some(final_time);
+
+        Err(self.stopped_error(final_time).into())
+      }
+    }
+  }
+
+  ///Returns the error for waiting on the clock after it stopped at the final time.
+  fn stopped_error(&self, final_time: Time) -> ClockError {
+    if self.overflow == Overflow::Error && final_time == Time::MAX {
+      ClockError::Overflowed
+    } else {
+      ClockError::Stopped(final_time)
+    }
+  }
+
+  ///Returns true if the clock has reached the target by the time.
+  fn has_reached(&self, time: Time, target: Time) -> bool {
+    self.overflow.ticks_between(target, time).is_some()
+  }
+
+  ///Returns true if the time comes after the other one.
+  fn is_later(&self, time: Time, than: Time) -> bool {
+    self.overflow.ticks_between(than, time).is_some_and(|ticks| ticks > 0)
+  }
+
+  ///Returns the final time of the channel this receiver listens on.
+  ///
+  ///The stop message is looked for in the channel in case it hasn't been received yet,
+  ///otherwise the final time of the clock is used.
+  fn final_time(&mut self, clock_final_time: Time) -> Time {
+    while self.final_time.is_none() {
+      match self.time_receiver.try_recv() {
+        Ok(ClockMessage::Stopped(final_time)) => self.final_time = Some(final_time),
+        Ok(ClockMessage::Tick(_)) | Err(TryRecvError::Lagged(_)) => (),
+        Err(_) => self.final_time = Some(clock_final_time),
+      }
+    }
+
+    self.final_time.unwrap_or(clock_final_time)
+  }
+
+  ///Returns the time of the newest tick this receiver has seen without waiting,
+  ///or None if the clock hasn't ticked yet.
+  pub(crate) fn latest_time(&mut self) -> Option<Time> {
+    // a stopped clock still leaves its final time behind
+    let _ = self.latest_tick();
+
+    self.latest_time
+  }
+
+  ///Returns the newest tick in the channel without waiting, or None if there isn't a new one.
+  fn latest_tick(&mut self) -> anyhow::Result<Option<Time>> {
+    let mut latest_time = None;
+
+    self.check_runs();
+    self.activity.mark_read();
+
+    loop {
+      match self.time_receiver.try_recv() {
+        Ok(ClockMessage::Tick(clock_time)) => {
+          if self.multiplier > 1 {
+            self.sub_tick = Some(SubTick {
+              clock_time,
+              index: 0,
+              started_at: Instant::now(),
+            });
+          }
+
+          if let Some(time) = self.scale_clock_time(clock_time) {
+            if self.latest_time.is_none_or(|previous_time| self.is_later(time, previous_time)) {
+              latest_time = Some(time);
+              // only waits error on gaps, checking for the latest tick just counts them
+              let _ = self.record_time(time);
+            }
+          }
+        }
+        Ok(message) if self.is_left_over(message) => (),
+        Ok(ClockMessage::Stopped(final_time)) => {
+          self.final_time = Some(final_time);
+          self.latest_time = self.latest_time.max(self.scale_clock_time(final_time));
+
+          return Err(self.stopped_error(final_time).into());
+        }
+        Err(TryRecvError::Lagged(missed_ticks)) => {
+          log_debug!("A time receiver lagged behind the clock and skipped {missed_ticks} old ticks");
+        }
+        Err(TryRecvError::Empty) => return Ok(latest_time),
+        Err(TryRecvError::Closed) => return self.get_time().map(Some),
+      }
+    }
+  }
+
+  pub(crate) fn wait_for_ticks(&mut self, x: u64) -> anyhow::Result<()> {
+    self.wait_for_ticks_cancellable(x, None)
+  }
+
+  fn wait_for_ticks_cancellable(&mut self, x: u64, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<()> {
+    for _ in 0..x {
+      self.get_time_cancellable(cancel_handle)?;
+    }
+
+    Ok(())
+  }
+
+  fn wait_until(&mut self, wait_for_time: Time, cancel_handle: Option<&CancelHandle>) -> anyhow::Result<()> {
+    let current_time = self.get_time_cancellable(cancel_handle)?;
+
+    match self.overflow.ticks_between(current_time, wait_for_time) {
+      Some(time_to_wait) if time_to_wait > 0 => self.wait_for_ticks_cancellable(time_to_wait, cancel_handle),
+      _ => Err(ClockError::TimeHasOccurred.into()),
+    }
+  }
+}
+
+///Blocks until the future completes, or until the receiver is interrupted or the cancel handle is cancelled.
+fn block_on_interruptible<F: Future>(
+  runtime: &LazyRuntime,
+  interrupt_handle: &InterruptHandle,
+  future: F,
+  cancel_handle: Option<&CancelHandle>,
+) -> anyhow::Result<F::Output> {
+  if interrupt_handle.take_interrupt() {
+    return Err(ClockError::Interrupted.into());
+  }
+
+  if cancel_handle.is_some_and(CancelHandle::is_cancelled) {
+    return Err(ClockError::Cancelled.into());
+  }
+
+  let cancelled = async move {
+    match cancel_handle {
+      Some(cancel_handle) => cancel_handle.cancelled().await,
+      None => std::future::pending().await,
+    }
+  };
+
+  runtime.block_on(async move {
+    tokio::select! {
+      output = future => Ok(output),
+      _ = interrupt_handle.interrupted() => Err(ClockError::Interrupted.into()),
+      _ = cancelled => Err(ClockError::Cancelled.into()),
+    }
+  })?
+}
+
+///Counts the ticks that passed without the clock ticking for them, returning the time of the latest one
+///and of the tick after it, or None if the time overflowed a clock that stops on overflow.
+fn count_passed_ticks(overflow: Overflow, time: Time, passed_ticks: u128) -> Option<(Time, Time)> {
+  let passed_ticks = Time::try_from(passed_ticks).unwrap_or(Time::MAX);
+  let latest_time = overflow.advance(time, passed_ticks - 1)?;
+
+  Some((latest_time, overflow.advance(latest_time, 1)?))
+}
+
+///Returns the time the clock stops itself at if the ticks counted from the time up to the latest time
+///reached its stop time, which is the stop time unless the time was already past it.
+fn reached_stop_time(overflow: Overflow, stop_at: Option<Time>, time: Time, latest_time: Time) -> Option<Time> {
+  let stop_at = stop_at?;
+
+  overflow.ticks_between(stop_at, latest_time)?;
+
+  match overflow.ticks_between(time, stop_at) {
+    Some(_) => Some(stop_at),
+    None => Some(time),
+  }
+}
+
+///Treats the clock stopping as the natural end of a loop running on its ticks.
+fn ended_by_stop(error: anyhow::Error) -> anyhow::Result<()> {
+  match error.downcast_ref::<ClockError>() {
+    Some(ClockError::Stopped(_)) => Ok(()),
+    _ => Err(error),
+  }
+}
+
+#[derive(Debug)]
+///The clock can be started, stopped, and receive the current time.
+///
+///Using the clock is as simple as starting it with [`clock.start()`](crate::Clock::start())
+///and calling [`clock.time()`](crate::Clock::time()) to get the time.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::new().unwrap();
+///
+///clock.start();
+///
+///let time = clock.time();
+///
+///let final_time = clock.stop().unwrap();
+///
+///assert_eq!(final_time, time + 1);
+///```
+pub struct Clock {
+  runtime: Arc<LazyRuntime>,
+  clock_handle: Option<JoinHandle<Time>>,
+  clock_stopper: Option<OneSender<()>>,
+  time_receiver: TimeReceiver,
+  tick_senders: TickSenders,
+  clock_status: Arc<SharedStatus>,
+  tick_rate: u32,
+  overflow: Overflow,
+  start_time: Time,
+  start_paused: bool,
+  stop_at: Option<Time>,
+  max_restarts: u32,
+  restarts: Arc<Restarts>,
+  alignment: Option<Duration>,
+  precision: Option<Duration>,
+  spin: bool,
+  driver: Option<ClockDriver>,
+  backend: Backend,
+  injected_ticks: ExternalDriver,
+  is_external: bool,
+  simulated: bool,
+  replay: Option<TickRecording>,
+  idle_when_unobserved: bool,
+  power_saving: Option<PowerSaving>,
+  acknowledgements: Option<Arc<TickAcknowledgements>>,
+  activity: Arc<ClockActivity>,
+  tick_listeners: TickListeners,
+  clock_hooks: ClockHooks,
+  rate_adjustment: Arc<RateAdjustment>,
+  rate_schedule: Arc<RateSchedule>,
+  tick_stats: SharedTickStats,
+  timer_resolution: Option<TimerResolution>,
+}
+
+impl Clock {
+  ///Creates a new clock with a default tickrate of 24ms.
+  ///
+  ///If you want a custom tickrate, create the clock with [`Clock::custom`](crate::Clock::custom()) instead.
+  ///
+  ///Counting doesn't start until you call [`clock.start()`](crate::Clock::start()) on the clock.
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///
+  ///clock.start();
+  ///```
+  pub fn new() -> anyhow::Result<Self> {
+    ClockBuilder::new().build()
+  }
+
+  ///Creates a new clock with a custom tickrate.
+  ///
+  ///Windows' timers only fire every ~15ms by default, so shorter tickrates end up ticking at that rate.
+  ///Enabling the `windows-timer-resolution` feature raises the timer resolution while a clock with a
+  ///shorter tickrate is running, and restores it once the clock is stopped or dropped.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(10).unwrap();
+  ///```
+  pub fn custom(tick_rate: u32) -> anyhow::Result<Self> {
+    ClockBuilder::new().tick_rate(tick_rate).build()
+  }
+
+  ///Creates a new clock with a tickrate written as a duration, such as `"16ms"` or `"1.5s"`.
+  ///Needs the `humantime` feature.
+  ///
+  ///See [`ClockBuilder::tick_rate_str()`](crate::ClockBuilder::tick_rate_str()) for which durations are
+  ///accepted.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::from_str_rate("16ms").unwrap();
+  ///
+  ///assert_eq!(clock.tick_rate(), 16);
+  ///```
+  #[cfg(feature = "humantime")]
+  pub fn from_str_rate(tick_rate: &str) -> anyhow::Result<Self> {
+    ClockBuilder::new().tick_rate_str(tick_rate)?.build()
+  }
+
+  ///Creates a new clock configured by the `THREAD_CLOCK_TICK_RATE`, `THREAD_CLOCK_CHANNEL_CAPACITY` and
+  ///`THREAD_CLOCK_PRECISION` environment variables, using the defaults of [`Clock::new()`](crate::Clock::new())
+  ///for any that aren't set. This lets the tickrate of a deployed binary be retuned without recompiling it.
+  ///
+  ///See [`ClockBuilder::env_overrides()`](crate::ClockBuilder::env_overrides()) for what the variables hold,
+  ///and [`from_env_with_prefix()`](crate::Clock::from_env_with_prefix()) to use variables of your own.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///std::env::set_var("THREAD_CLOCK_TICK_RATE", "16");
+  ///
+  ///let clock = Clock::from_env().unwrap();
+  ///
+  ///assert_eq!(clock.tick_rate(), 16);
+  ///```
+  pub fn from_env() -> anyhow::Result<Self> {
+    Self::from_env_with_prefix("THREAD_CLOCK")
+  }
+
+  ///Creates a new clock configured by environment variables starting with `prefix`, such as
+  ///`{prefix}_TICK_RATE`, the same way as [`from_env()`](crate::Clock::from_env()).
+  pub fn from_env_with_prefix(prefix: &str) -> anyhow::Result<Self> {
+    ClockBuilder::new().env_overrides(prefix)?.build()
+  }
+
+  ///Creates a new clock with the settings of a [`config`](crate::ClockConfig), such as one deserialized
+  ///from a config file with the `serde` feature.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockConfig};
+  ///
+  ///let mut clock = Clock::from_config(ClockConfig {
+  ///  tick_rate: 1,
+  ///  stop_at: Some(5),
+  ///  ..ClockConfig::default()
+  ///})
+  ///.unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///assert!(clock.wait_for_time(10).is_err());
+  ///assert_eq!(clock.stop().unwrap(), 5);
+  ///```
+  pub fn from_config(config: ClockConfig) -> anyhow::Result<Self> {
+    ClockBuilder::from(config).build()
+  }
+
+  ///Creates a [`builder`](crate::ClockBuilder) for configuring a clock beyond its tickrate.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, Rounding};
+  ///
+  ///let mut clock = Clock::builder().tick_rate(10).rounding(Rounding::Down).build().unwrap();
+  ///```
+  pub fn builder() -> ClockBuilder {
+    ClockBuilder::new()
+  }
+
+  ///Creates and starts a clock with a custom tickrate on the tokio runtime this is called from.
+  ///
+  ///The clock ticks on that runtime's timer, paused or not, the same as a clock built with
+  ///[`enclosing_runtime()`](crate::ClockBuilder::enclosing_runtime()). This lets a `#[tokio::test]`
+  ///drive and observe the clock with the asynchronous methods, such as
+  ///[`next_tick()`](crate::Clock::next_tick()) and [`stop_async()`](crate::Clock::stop_async()),
+  ///without a thread or runtime of the clock's own.
+  ///
+  ///An error is returned if this isn't called from within a tokio runtime.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///#[tokio::main(flavor = "current_thread")]
+  ///async fn main() {
+  ///  let mut clock = Clock::spawn_on_current(1).unwrap();
+  ///
+  ///  clock.wait_for_time_async(5).await.unwrap();
+  ///
+  ///  assert!(clock.stop_async().await.unwrap() > 5);
+  ///}
+  ///```
+  pub fn spawn_on_current(tick_rate: u32) -> anyhow::Result<Self> {
+    let mut clock = ClockBuilder::new().tick_rate(tick_rate).enclosing_runtime(true).build()?;
+
+    clock.start();
+
+    Ok(clock)
+  }
+
+  ///Creates a clock that ticks as fast as its receivers take the ticks, without waiting between them at all.
+  ///
+  ///A tick is sent as soon as every receiver [`spawned`](crate::Clock::spawn_receiver()) from the clock is
+  ///waiting for the next one, and the tick after it once they've all received it, so each of them still
+  ///gets every tick in order. Tests that would take thousands of ticks' worth of wall time finish as soon
+  ///as the work between ticks is done. The clock's own receiver is only waited on when there are no others.
+  ///
+  ///Every spawned receiver has to keep reading, the clock doesn't tick while one of them isn't waiting.
+  ///The clock keeps the [`default tickrate`](crate::DEFAULT_TICKRATE), which only matters for converting
+  ///durations into ticks. A simulated clock with another tickrate is built with
+  ///[`ClockBuilder::simulated()`](crate::ClockBuilder::simulated()).
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::{Duration, Instant};
+  ///
+  ///let mut clock = Clock::simulated().unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///let started_at = Instant::now();
+  ///
+  ///clock.start();
+  ///
+  ///for expected_time in 0..1000 {
+  ///  assert_eq!(time_receiver.time(), expected_time);
+  ///}
+  ///
+  ///assert!(started_at.elapsed() < Duration::from_millis(1000 * 24));
+  ///```
+  pub fn simulated() -> anyhow::Result<Self> {
+    ClockBuilder::new().simulated(true).build()
+  }
+
+  ///Creates a clock that only ticks when its [`driver`](crate::ExternalDriver) is told to.
+  ///
+  ///The clock has no timer of its own, so its ticks can follow anything outside of it, such as a
+  ///display's vsync or the frames of a network connection. It has no tickrate either, so
+  ///[`tick_rate()`](crate::Clock::tick_rate()) returns 0. Everything else works the same as with any
+  ///other clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let (mut clock, driver) = Clock::external().unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///for _ in 0..3 {
+  ///  driver.tick();
+  ///}
+  ///
+  ///std::thread::sleep(std::time::Duration::from_millis(20));
+  ///
+  ///assert_eq!(clock.stop().unwrap(), 2);
+  ///```
+  pub fn external() -> anyhow::Result<(Self, ExternalDriver)> {
+    let mut clock = ClockBuilder::new().tick_rate(0).build()?;
+
+    clock.is_external = true;
+
+    // the driver ticks the clock the same way as tick_now(), with no timer to tick it otherwise
+    let external_driver = clock.injected_ticks.clone();
+
+    Ok((clock, external_driver))
+  }
+
+  ///Creates a new clock.
+  pub(crate) fn new_clock(builder: ClockBuilder) -> anyhow::Result<Self> {
+    if builder.driver.is_some() && builder.precision.is_some() {
+      return Err(anyhow!("A clock driven by a ClockDriver can't be given a precision"));
+    }
+
+    if builder.driver.is_some() && builder.spin {
+      return Err(anyhow!("A clock driven by a ClockDriver can't spin"));
+    }
+
+    if builder.channel_capacity == 0 {
+      return Err(anyhow!("A clock needs a channel capacity of at least 1"));
+    }
+
+    let has_own_timer = builder.precision.is_some() || builder.spin || builder.driver.is_some();
+
+    if builder.replay.is_some() && (has_own_timer || builder.backend != Backend::Tokio) {
+      return Err(anyhow!(
+        "A clock replaying a recording can't be given a precision, spin, a driver or another backend"
+      ));
+    }
+
+    if builder.simulated && (has_own_timer || builder.backend != Backend::Tokio || builder.replay.is_some()) {
+      return Err(anyhow!(
+        "A simulated clock can't be given a precision, spin, a driver, another backend or a recording"
+      ));
+    }
+
+    if let Some(acknowledged_ticks) = builder.acknowledged_ticks {
+      if acknowledged_ticks.timeout.is_zero() {
+        return Err(anyhow!("A clock acknowledging its ticks needs a timeout longer than zero"));
+      }
+
+      if builder.simulated {
+        return Err(anyhow!("A simulated clock can't acknowledge its ticks, as it already waits for its receivers"));
+      }
+    }
+
+    if builder.backend != Backend::Tokio && has_own_timer {
+      return Err(anyhow!(
+        "A clock with the {:?} backend can't be given a precision, spin or a driver",
+        builder.backend
+      ));
+    }
+
+    let ticks_on_own_thread = builder.realtime_priority
+      || builder.core.is_some()
+      || builder.precision.is_some()
+      || builder.spin
+      || builder.backend.blocks_thread()
+      || builder.multi_threaded
+      || builder.dedicated_runtime;
+
+    if builder.enclosing_runtime && ticks_on_own_thread {
+      return Err(anyhow!("A clock on the enclosing runtime can't be given a thread or runtime of its own"));
+    }
+
+    // a driver wakes its clocks up on its own runtime's timer, which has to be the one the clocks tick on
+    if builder.driver.as_ref().is_some_and(|driver| driver.is_enclosing() != builder.enclosing_runtime) {
+      return Err(anyhow!("A driven clock has to be on the enclosing runtime exactly when its driver is"));
+    }
+
+    if builder.runtime.is_some() && (builder.enclosing_runtime || ticks_on_own_thread || builder.driver.is_some()) {
+      return Err(anyhow!(
+        "A clock on a given runtime can't be on the enclosing one or be given a thread, runtime or driver of its own"
+      ));
+    }
+
+    let thread_options = ThreadOptions {
+      realtime_priority: builder.realtime_priority,
+      core: builder.core,
+    };
+    // runtimes are only created once the clock is started or waited on, except for a thread that's set up
+    // in a way that can fail, which has to be known when the clock is built
+    let runtime = Arc::new(if let Some(handle) = &builder.runtime {
+      LazyRuntime::created(ClockRuntime::owned_elsewhere(handle.clone()))
+    } else if builder.enclosing_runtime {
+      LazyRuntime::created(ClockRuntime::enclosing()?)
+    } else if builder.realtime_priority || builder.core.is_some() {
+      LazyRuntime::created(ClockRuntime::new(thread_options)?)
+    } else if builder.multi_threaded {
+      LazyRuntime::new(ClockRuntime::multi_threaded)
+    } else if builder.dedicated_runtime
+      || builder.precision.is_some()
+      || builder.spin
+      || builder.backend.blocks_thread()
+    {
+      LazyRuntime::new(ClockRuntime::dedicated)
+    } else {
+      LazyRuntime::new(ClockRuntime::shared)
+    });
+    let clock_handle = None;
+    let clock_stopper = None;
+    let (tick_senders, time_receiver) = TickSenders::new(builder.channel_capacity);
+    let clock_status = Arc::new(SharedStatus::new());
+    let tick_rate = builder.tick_rate;
+    let activity = Arc::new(ClockActivity::default());
+    let restarts = Arc::new(Restarts::default());
+    let lifecycle = Arc::new(ClockLifecycle::new(builder.receivers_wait_for_start));
+    let mut time_receiver = TimeReceiver::new(
+      Arc::clone(&runtime),
+      time_receiver,
+      Arc::clone(&clock_status),
+      Arc::clone(&activity),
+      Arc::clone(&restarts),
+      lifecycle,
+      tick_rate,
+      builder.channel_capacity,
+      builder.rounding,
+      builder.overflow,
+    );
+
+    // nothing else can start the clock while it's being waited on through its own receiver
+    time_receiver.waits_for_start = false;
+    let tick_listeners = Arc::new(Mutex::new(Vec::new()));
+    let tick_stats = Arc::new(Mutex::new(TickStats::default()));
+    let injected_ticks = ExternalDriver::new(Arc::clone(&clock_status));
+    let acknowledgements = builder
+      .acknowledged_ticks
+      .map(|acknowledged_ticks| Arc::new(TickAcknowledgements::new(acknowledged_ticks, Arc::clone(&clock_status))));
+
+    Ok(Clock {
+      runtime,
+      clock_handle,
+      clock_stopper,
+      time_receiver,
+      tick_senders,
+      clock_status,
+      tick_rate,
+      overflow: builder.overflow,
+      start_time: builder.start_time,
+      start_paused: builder.start_paused,
+      stop_at: builder.stop_at,
+      max_restarts: builder.max_restarts,
+      restarts,
+      alignment: builder.alignment,
+      precision: builder.precision,
+      spin: builder.spin,
+      driver: builder.driver,
+      backend: builder.backend,
+      injected_ticks,
+      is_external: false,
+      simulated: builder.simulated,
+      replay: builder.replay,
+      idle_when_unobserved: builder.idle_when_unobserved,
+      power_saving: builder.power_saving,
+      acknowledgements,
+      activity,
+      tick_listeners,
+      clock_hooks: ClockHooks::default(),
+      rate_adjustment: Arc::default(),
+      rate_schedule: Arc::default(),
+      tick_stats,
+      timer_resolution: None,
+    })
+  }
+
+  ///Starts the clock.
+  ///
+  ///A clock built with [`start_paused()`](crate::ClockBuilder::start_paused()) starts out paused,
+  ///and only ticks once it's [`resumed`](crate::Clock::resume()).
+  ///
+  ///The clock's runtime is created here if nothing has run on it yet. If it couldn't be, which only
+  ///happens if its thread couldn't be spawned, a warning is logged and the clock stays stopped, so
+  ///anything waiting on it gets an error instead.
+  ///
+  ///# Example
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///
+  ///clock.start();
+  ///```
+  pub fn start(&mut self) {
+    self.start_from(self.start_time);
+  }
+
+  ///Starts the clock counting from the time, instead of the [`start time`](crate::ClockBuilder::start_at())
+  ///it was built with.
+  ///
+  ///Mostly useful to continue the count of a clock that was [`stopped in place`](crate::Clock::stop_in_place()),
+  ///whose receivers carry on with the new ticks either way.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///
+  ///clock.start_from(50);
+  ///
+  ///assert_eq!(clock.time(), 50);
+  ///```
+  pub fn start_from(&mut self, time: Time) {
+    if self.clock_handle.is_none() && self.clock_stopper.is_none() {
+      let runtime = match self.runtime.get() {
+        Ok(runtime) => Arc::clone(runtime),
+        Err(error) => {
+          log_warn!("The clock couldn't be started: {error}");
+
+          return;
+        }
+      };
+      let (clock_stopper, stopper_receiver) = oneshot::channel();
+
+      self.time_receiver.lifecycle.start_from(time);
+
+      for tick_listener in self.tick_listeners.lock().unwrap().iter_mut() {
+        tick_listener.start(time);
+      }
+
+      let handle = self.create_clock_thread(&runtime, stopper_receiver, time);
+      let mut clock_status = self.clock_status.lock();
+
+      // an external or simulated clock's ticks don't depend on the system's timers
+      self.timer_resolution =
+        (!self.is_external && !self.simulated).then(|| TimerResolution::raise_for(self.tick_rate));
+      self.clock_handle = Some(handle);
+      self.clock_stopper = Some(clock_stopper);
+
+      let lifecycle = &self.time_receiver.lifecycle;
+
+      if self.start_paused {
+        lifecycle.transition(&mut clock_status, ClockStatus::Paused);
+
+        log_debug!("Started a paused clock ticking every {}ms", self.tick_rate);
+      } else {
+        lifecycle.transition(&mut clock_status, ClockStatus::Running);
+
+        log_debug!("Started a clock ticking every {}ms", self.tick_rate);
+      }
+    }
+  }
+
+  ///Pauses the clock.
+  ///
+  ///While paused the time doesn't advance and anything waiting on the clock blocks until it's resumed.
+  ///Nothing happens if the clock isn't running.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///clock.pause();
+  ///
+  ///assert!(clock.is_paused());
+  ///```
+  pub fn pause(&mut self) {
+    let mut clock_status = self.clock_status.lock();
+
+    if clock_status.get() == ClockStatus::Running {
+      self.time_receiver.lifecycle.transition(&mut clock_status, ClockStatus::Paused);
+
+      log_debug!("Paused a clock ticking every {}ms", self.tick_rate);
+    }
+  }
+
+  ///Resumes a paused clock, counting continues from where it was paused.
+  ///
+  ///Nothing happens if the clock isn't paused.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///clock.pause();
+  ///clock.resume();
+  ///
+  ///assert!(clock.is_running());
+  ///```
+  pub fn resume(&mut self) {
+    let mut clock_status = self.clock_status.lock();
+
+    if clock_status.get() == ClockStatus::Paused {
+      self.time_receiver.lifecycle.transition(&mut clock_status, ClockStatus::Running);
+
+      log_debug!("Resumed a clock ticking every {}ms", self.tick_rate);
+    }
+  }
+
+  ///Ticks the clock right away, on top of the ticks of its timer.
+  ///
+  ///The time advances and is sent to every receiver the same as with any other tick, while the timed
+  ///ticks keep coming when they're due. Useful for skipping ahead while debugging, or for tests that
+  ///have to nudge the time forward. Nothing happens unless the clock is running.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1000).unwrap();
+  ///clock.start();
+  ///
+  ///clock.tick_now();
+  ///clock.tick_now();
+  ///clock.tick_now();
+  ///
+  ///assert!(clock.wait_until_at_least(2).unwrap() >= 2);
+  ///```
+  pub fn tick_now(&self) {
+    self.injected_ticks.tick();
+  }
+
+  ///Creates an [`interrupt handle`](crate::InterruptHandle) that can unblock the clock's own waits
+  ///from another thread.
+  ///
+  ///Works the same as [`TimeReceiver::interrupt_handle()`](crate::TimeReceiver::interrupt_handle()).
+  pub fn interrupt_handle(&self) -> InterruptHandle {
+    self.time_receiver.interrupt_handle()
+  }
+
+  ///Calls back with the range of ticks the clock's own waits skipped.
+  ///
+  ///Works the same as [`TimeReceiver::on_missed_ticks()`](crate::TimeReceiver::on_missed_ticks()).
+  pub fn on_missed_ticks(&mut self, callback: impl FnMut(Range<Time>) + Send + 'static) {
+    self.time_receiver.on_missed_ticks(callback);
+  }
+
+  ///Returns how many ticks the clock's own waits have skipped in total.
+  ///
+  ///Works the same as [`TimeReceiver::gaps()`](crate::TimeReceiver::gaps()).
+  pub fn gaps(&self) -> u64 {
+    self.time_receiver.gaps()
+  }
+
+  ///Sets whether the clock's own waits return an error when they skip ticks.
+  ///
+  ///Works the same as [`TimeReceiver::error_on_gaps()`](crate::TimeReceiver::error_on_gaps()).
+  pub fn error_on_gaps(&mut self, error_on_gaps: bool) {
+    self.time_receiver.error_on_gaps(error_on_gaps);
+  }
+
+  ///Returns the tickrate of the clock in milliseconds.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::custom(16).unwrap();
+  ///
+  ///assert_eq!(clock.tick_rate(), 16);
+  ///```
+  pub fn tick_rate(&self) -> u32 {
+    self.tick_rate
+  }
+
+  ///Returns whether the clock task is alive, the time of its latest tick, and whether that tick missed
+  ///its deadline.
+  ///
+  ///A clock task that panicked, such as from a [`hook`](crate::ClockHook), stops the clock the same as
+  ///[`stop()`](crate::Clock::stop()) would, so anything waiting on it gets
+  ///[`ClockError::Stopped`](crate::ClockError::Stopped). This is how to find out the task isn't alive
+  ///without waiting on the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///
+  ///assert!(!clock.health().task_alive);
+  ///
+  ///clock.start();
+  ///clock.wait_for_time(3).unwrap();
+  ///
+  ///let health = clock.health();
+  ///
+  ///assert!(health.task_alive);
+  ///assert!(health.last_tick >= Some(3));
+  ///```
+  pub fn health(&self) -> ClockHealth {
+    let (last_tick, missed_deadline) = self.tick_stats.lock().unwrap().latest_tick();
+
+    ClockHealth {
+      task_alive: self.clock_handle.as_ref().is_some_and(|clock_handle| !clock_handle.is_finished()),
+      last_tick,
+      missed_deadline,
+      restarts: self.restarts.count(),
+    }
+  }
+
+  ///Returns how many times a second the clock has actually ticked, measured over the last second.
+  ///
+  ///This can be compared against the tickrate to find out if the clock is keeping up, as the
+  ///ticks of a short tickrate can take longer than asked for on some platforms.
+  ///Returns 0 until the clock has ticked at least twice, the measurement restarts whenever the clock is resumed.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(10).unwrap();
+  ///
+  ///assert_eq!(clock.actual_tps(), 0.0);
+  ///
+  ///clock.start();
+  ///clock.wait_for_x_ticks(5).unwrap();
+  ///
+  ///// a 10ms tickrate is at most 100 ticks per second
+  ///assert!(clock.actual_tps() > 0.0);
+  ///```
+  pub fn actual_tps(&self) -> f64 {
+    self.tick_stats.lock().unwrap().ticks_per_second()
+  }
+
+  ///Returns the amount of [`time receivers`](crate::TimeReceiver) currently listening to the clock.
+  ///
+  ///Receivers held by [`tick barriers`](crate::TickBarrier) are included, receivers of
+  ///[`derived clocks`](crate::DerivedClock) are not.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///
+  ///let time_receiver = clock.spawn_receiver();
+  ///
+  ///assert_eq!(clock.receiver_count(), 1);
+  ///
+  ///drop(time_receiver);
+  ///
+  ///assert_eq!(clock.receiver_count(), 0);
+  ///```
+  pub fn receiver_count(&self) -> usize {
+    // the clock's own receiver isn't counted
+    self.tick_senders.receiver_count() - 1
+  }
+
+  ///Returns the current [`status`](crate::ClockStatus) of the clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockStatus};
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///
+  ///assert_eq!(clock.status(), ClockStatus::Created);
+  ///```
+  pub fn status(&self) -> ClockStatus {
+    self.time_receiver.status()
+  }
+
+  ///Returns true if the clock is ticking.
+  pub fn is_running(&self) -> bool {
+    self.time_receiver.is_running()
+  }
+
+  ///Returns true if the clock is paused.
+  pub fn is_paused(&self) -> bool {
+    self.time_receiver.is_paused()
+  }
+
+  ///Returns the wall time since the clock started, including the time it spent paused.
+  ///
+  ///Works the same as [`TimeReceiver::elapsed()`](crate::TimeReceiver::elapsed()).
+  pub fn elapsed(&self) -> Duration {
+    self.time_receiver.elapsed()
+  }
+
+  ///Returns how long the clock has been paused for since it started, including a pause it's still in.
+  ///
+  ///Works the same as [`TimeReceiver::paused_duration()`](crate::TimeReceiver::paused_duration()).
+  pub fn paused_duration(&self) -> Duration {
+    self.time_receiver.paused_duration()
+  }
+
+  ///Returns a [`watch`](tokio::sync::watch) channel holding the [`status`](crate::ClockStatus) of the clock.
+  ///
+  ///Works the same as [`TimeReceiver::watch_status()`](crate::TimeReceiver::watch_status()).
+  pub fn watch_status(&self) -> tokio::sync::watch::Receiver<ClockStatus> {
+    self.time_receiver.watch_status()
+  }
+
+  ///Returns a receiver of the [`events`](crate::ClockEvent) that happen to the clock from now on.
+  ///
+  ///Works the same as [`TimeReceiver::events()`](crate::TimeReceiver::events()).
+  pub fn events(&self) -> EventReceiver {
+    self.time_receiver.events()
+  }
+
+  ///Stops the clock and returns the final time.
+  ///
+  ///The clock waits for its next tick, then waits for the clock task to finish so the
+  ///final time is the last tick the clock emitted. If the task doesn't finish within a second
+  ///it's aborted and an error is returned. A paused or [`external`](crate::Clock::external()) clock
+  ///is stopped without waiting for a tick.
+  ///
+  ///Waiting for the next tick blocks for up to a whole tick length, which for a clock ticking every
+  ///few seconds is a long time. [`stop_now()`](crate::Clock::stop_now()) stops it without waiting.
+  ///
+  ///Every [`time receiver`](crate::TimeReceiver) waiting on the clock is woken up, and any call
+  ///made on them afterwards returns [`ClockError::Stopped`](crate::ClockError::Stopped).
+  ///
+  ///If the clock hasn't been started yet an error will be returned.
+  ///
+  ///# Example
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///let final_time = clock.stop().unwrap();
+  ///
+  ///assert_eq!(final_time, 0);
+  ///```
+  pub fn stop(mut self) -> anyhow::Result<Time> {
+    self.stop_in_place()
+  }
+
+  ///Stops the clock right away and returns the final time, which is the last tick the clock emitted.
+  ///
+  ///Unlike [`stop()`](crate::Clock::stop()) the clock doesn't wait for its next tick, the wait for it is
+  ///cut short instead. Receivers are woken up and see the clock stop the same way. A clock whose
+  ///[`backend`](crate::Backend) blocks its thread, or that [`spins`](crate::ClockBuilder::spin()), can't be
+  ///interrupted in the middle of a tick, so it still finishes the one it's on first.
+  ///
+  ///If the clock hasn't been started yet an error will be returned.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::{Duration, Instant};
+  ///
+  ///let mut clock = Clock::custom(10_000).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///let stopping_at = Instant::now();
+  ///
+  ///assert_eq!(clock.stop_now().unwrap(), 0);
+  ///assert!(stopping_at.elapsed() < Duration::from_secs(1));
+  ///```
+  pub fn stop_now(mut self) -> anyhow::Result<Time> {
+    self.stop_task(false)
+  }
+
+  ///Stops the clock and returns the final time, keeping the clock around to be started again.
+  ///
+  ///The clock stops the same way as with [`stop()`](crate::Clock::stop()), and every receiver sees it stop.
+  ///Starting the clock again afterwards reuses its channel, so the receivers that were already spawned
+  ///carry on with the new ticks instead of having to be spawned again. The count starts over from the
+  ///start time with [`start()`](crate::Clock::start()), or continues with [`start_from()`](crate::Clock::start_from()).
+  ///
+  ///If the clock hasn't been started since it was last stopped an error will be returned.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///clock.start();
+  ///
+  ///let final_time = clock.stop_in_place().unwrap();
+  ///
+  ///assert!(time_receiver.safe_time().is_err());
+  ///
+  ///clock.start_from(final_time + 1);
+  ///
+  ///assert!(time_receiver.time() > final_time);
+  ///```
+  pub fn stop_in_place(&mut self) -> anyhow::Result<Time> {
+    self.stop_task(true)
+  }
+
+  ///Stops the clock task, first waiting for the clock's next tick if asked to.
+  fn stop_task(&mut self, wait_for_tick: bool) -> anyhow::Result<Time> {
+    match (self.clock_stopper.take(), self.clock_handle.take()) {
+      (Some(clock_stopper), Some(clock_handle)) => {
+        // a clock that stopped itself has no tick left to wait for, an external clock's next tick may
+        // never come, and a simulated one's only comes once its receivers wait for it
+        //
+        // the clock is stopped and joined whatever the wait returns, such as the task restarting or the
+        // receiver having missed ticks, as the stopper and join handle have been taken either way
+        if wait_for_tick && self.is_running() && !self.is_external && !self.simulated {
+          // an interrupt meant for an earlier wait would cut this one short
+          self.time_receiver.interrupt_handle.take_interrupt();
+
+          let _ = self.time_receiver.safe_time();
+        }
+
+        let _ = clock_stopper.send(());
+        self.timer_resolution = None;
+
+        self.join_clock_thread(clock_handle)
+      }
+
+      _ => Err(ClockError::NotStarted.into()),
+    }
+  }
+
+  ///Stops the clock without blocking the thread, returning the final time.
+  ///
+  ///The asynchronous version of [`stop()`](crate::Clock::stop()), which can be used from within any
+  ///tokio runtime, such as to stop a clock created with [`spawn_on_current()`](crate::Clock::spawn_on_current()).
+  ///
+  ///If the clock hasn't been started yet an error will be returned.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///#[tokio::main(flavor = "current_thread")]
+  ///async fn main() {
+  ///  let clock = Clock::spawn_on_current(1).unwrap();
+  ///
+  ///  assert_eq!(clock.stop_async().await.unwrap(), 0);
+  ///}
+  ///```
+  pub async fn stop_async(mut self) -> anyhow::Result<Time> {
+    let (Some(clock_stopper), Some(mut clock_handle)) = (self.clock_stopper.take(), self.clock_handle.take()) else {
+      return Err(ClockError::NotStarted.into());
+    };
+
+    // the same as when blocking, see stop_task
+    if self.is_running() && !self.is_external && !self.simulated {
+      self.time_receiver.interrupt_handle.take_interrupt();
+
+      let _ = self.time_receiver.next_tick().await;
+    }
+
+    let _ = clock_stopper.send(());
+
+    match tokio::time::timeout(STOP_TIMEOUT, &mut clock_handle).await {
+      Ok(final_time) => Ok(final_time?),
+      Err(_) => {
+        clock_handle.abort();
+
+        Err(anyhow!("The clock task didn't stop within {STOP_TIMEOUT:?} and was aborted"))
+      }
+    }
+  }
+
+  ///Wraps the clock in a [`guard`](crate::ScopedClock) that stops it once the guard is dropped, passing
+  ///the result of [`stop()`](crate::Clock::stop()) to the callback.
+  ///
+  ///Stopping a clock that was never started reports
+  ///[`ClockError::NotStarted`](crate::ClockError::NotStarted) to the callback.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///fn count_to_five() -> anyhow::Result<()> {
+  ///  let mut clock = Clock::custom(1)?.scoped(|final_time| println!("stopped at {final_time:?}"));
+  ///
+  ///  clock.start();
+  ///  // the clock is stopped even if this returns early
+  ///  clock.wait_for_time(5)?;
+  ///
+  ///  Ok(())
+  ///}
+  ///
+  ///count_to_five().unwrap();
+  ///```
+  pub fn scoped(self, on_stop: impl FnOnce(anyhow::Result<Time>) + Send + 'static) -> ScopedClock {
+    ScopedClock::new(self, on_stop)
+  }
+
+  ///Waits for the next tick and returns the time.
+  ///
+  ///If any problems arise when this is called the clock will panic.
+  ///
+  ///Use this if you don't want to deal with unwrapping every time you get the time.
+  ///Otherwise use [`safe_time()`](crate::Clock::safe_time()) for error handling.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///let time = clock.time();
+  ///
+  ///assert_eq!(time, 0);
+  ///```
+  pub fn time(&mut self) -> Time {
+    self.time_receiver.time()
+  }
+
+  ///A way to get the time with error handling instead of panicking
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///let time = clock.safe_time().unwrap_or_else(|error| panic!("error: {error}"));
+  ///
+  ///assert_eq!(time, 0);
+  ///```
+  pub fn safe_time(&mut self) -> anyhow::Result<Time> {
+    self.time_receiver.safe_time()
+  }
+
+  ///Waits for the next tick without blocking the thread and returns the time.
+  ///
+  ///Works the same as [`TimeReceiver::next_tick()`](crate::TimeReceiver::next_tick()).
+  pub async fn next_tick(&mut self) -> anyhow::Result<Time> {
+    self.time_receiver.next_tick().await
+  }
+
+  ///Waits for the input amount of ticks without blocking the thread.
+  ///
+  ///Works the same as [`TimeReceiver::wait_for_x_ticks_async()`](crate::TimeReceiver::wait_for_x_ticks_async()).
+  pub async fn wait_for_x_ticks_async(&mut self, x: u32) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_x_ticks_async(x).await
+  }
+
+  ///Waits until the input time without blocking the thread.
+  ///
+  ///Works the same as [`TimeReceiver::wait_for_time_async()`](crate::TimeReceiver::wait_for_time_async()).
+  pub async fn wait_for_time_async(&mut self, time: Time) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_time_async(time).await
+  }
+
+  ///Waits until the clock has reached at least the input time without blocking the thread,
+  ///and returns the current time.
+  ///
+  ///Works the same as [`TimeReceiver::wait_until_at_least_async()`](crate::TimeReceiver::wait_until_at_least_async()).
+  pub async fn wait_until_at_least_async(&mut self, time: Time) -> anyhow::Result<Time> {
+    self.time_receiver.wait_until_at_least_async(time).await
+  }
+
+  ///Waits for the next tick and returns the time it was.
+  ///
+  ///Works the same as [`TimeReceiver::next_time()`](crate::TimeReceiver::next_time()).
+  pub fn next_time(&mut self) -> anyhow::Result<Time> {
+    self.time_receiver.next_time()
+  }
+
+  ///Waits for the next tick.
+  ///
+  ///The tick that was waited for isn't returned, see [`next_time()`](crate::Clock::next_time()) to get it.
+  ///
+  ///An error is returned if something went wrong.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///clock.wait_for_tick().unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///let time = clock.time();
+  ///
+  ///assert_eq!(time, 1);
+  ///```
+  pub fn wait_for_tick(&mut self) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_tick()
+  }
+
+  ///Waits for the input amount of ticks.
+  ///
+  ///An error is returned if something went wrong.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///clock.wait_for_x_ticks(5).unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///let time = clock.time();
+  ///
+  ///assert_eq!(time, 5);
+  ///```
+  pub fn wait_for_x_ticks(&mut self, x: u32) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_x_ticks(x)
+  }
+
+  ///Waits until the imput time.
+  ///
+  ///An error is returned if something went wrong.
+  ///Such as if the time has already occurred.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///clock.wait_for_time(9);
+  ///
+  ///let time = clock.time();
+  ///
+  ///assert_eq!(time, 10);
+  ///```
+  pub fn wait_for_time(&mut self, time: Time) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_time(time)
+  }
+
+  ///Waits until the input time, unless the [`cancel handle`](crate::CancelHandle) is cancelled first.
+  ///
+  ///Works the same as [`TimeReceiver::wait_for_time_cancellable()`](crate::TimeReceiver::wait_for_time_cancellable()).
+  pub fn wait_for_time_cancellable(&mut self, time: Time, cancel_handle: &CancelHandle) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_time_cancellable(time, cancel_handle)
+  }
+
+  ///Waits for x ticks, unless the [`cancel handle`](crate::CancelHandle) is cancelled first.
+  ///
+  ///Works the same as [`TimeReceiver::wait_for_x_ticks_cancellable()`](crate::TimeReceiver::wait_for_x_ticks_cancellable()).
+  pub fn wait_for_x_ticks_cancellable(&mut self, x: u32, cancel_handle: &CancelHandle) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_x_ticks_cancellable(x, cancel_handle)
+  }
+
+  ///Waits until the input time, giving up once `max_wait` has passed.
+  ///
+  ///Works the same as [`TimeReceiver::wait_for_time_or_timeout()`](crate::TimeReceiver::wait_for_time_or_timeout()).
+  pub fn wait_for_time_or_timeout(&mut self, time: Time, max_wait: Duration) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_time_or_timeout(time, max_wait)
+  }
+
+  ///Waits until the clock has reached at least the input time and returns the current time.
+  ///
+  ///Works the same as [`TimeReceiver::wait_until_at_least()`](crate::TimeReceiver::wait_until_at_least()).
+  pub fn wait_until_at_least(&mut self, time: Time) -> anyhow::Result<Time> {
+    self.time_receiver.wait_until_at_least(time)
+  }
+
+  ///Waits for the next tick whose time is a multiple of `n` and returns it.
+  ///
+  ///Works the same as [`TimeReceiver::wait_for_multiple_of()`](crate::TimeReceiver::wait_for_multiple_of()).
+  pub fn wait_for_multiple_of(&mut self, n: Time) -> anyhow::Result<Time> {
+    self.time_receiver.wait_for_multiple_of(n)
+  }
+
+  ///Waits for as many ticks as fit in the duration.
+  ///
+  ///The duration is turned into ticks with the [`rounding`](crate::Rounding) the clock was built with,
+  ///which rounds up by default.
+  ///
+  ///An error is returned if something went wrong.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///clock.wait_for_duration(Duration::from_millis(5)).unwrap_or_else(|error| panic!("{error}"));
+  ///
+  ///let time = clock.time();
+  ///
+  ///assert_eq!(time, 5);
+  ///```
+  pub fn wait_for_duration(&mut self, duration: Duration) -> anyhow::Result<()> {
+    self.time_receiver.wait_for_duration(duration)
+  }
+
+  ///Waits until the tick that lines up with the wall-clock time.
+  ///
+  ///The time left until then is turned into ticks the same way as
+  ///[`wait_for_duration()`](crate::Clock::wait_for_duration()).
+  ///
+  ///An error is returned if something went wrong.
+  ///Such as if the time has already passed.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::{Duration, SystemTime};
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///clock.start();
+  ///
+  ///clock
+  ///  .wait_until_system_time(SystemTime::now() + Duration::from_millis(10))
+  ///  .unwrap_or_else(|error| panic!("{error}"));
+  ///```
+  pub fn wait_until_system_time(&mut self, system_time: SystemTime) -> anyhow::Result<()> {
+    self.time_receiver.wait_until_system_time(system_time)
+  }
+
+  ///Waits until the tick that lines up with the date and time.
+  ///
+  ///Works the same as [`wait_until_system_time()`](crate::Clock::wait_until_system_time()).
+  #[cfg(feature = "chrono")]
+  pub fn schedule_at_datetime(&mut self, datetime: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
+    self.time_receiver.schedule_at_datetime(datetime)
+  }
+
+  ///Returns how many ticks the clock has made since the last time this was called, without waiting.
+  ///
+  ///Works the same as [`TimeReceiver::ticks_since_last_call()`](crate::TimeReceiver::ticks_since_last_call()).
+  pub fn ticks_since_last_call(&mut self) -> u64 {
+    self.time_receiver.ticks_since_last_call()
+  }
+
+  ///Runs a fixed timestep loop on the ticks of the clock.
+  ///
+  ///Works the same as [`TimeReceiver::run_fixed_timestep()`](crate::TimeReceiver::run_fixed_timestep()),
+  ///the loop can only be ended by `update` returning false as the clock can't be stopped while it runs.
+  pub fn run_fixed_timestep<U, R>(&mut self, update: U, render: R) -> anyhow::Result<()>
+  where
+    U: FnMut(Time) -> bool,
+    R: FnMut(f32),
+  {
+    self.time_receiver.run_fixed_timestep(update, render)
+  }
+
+  ///Calls `on_tick` with the time of each of the next `n` ticks.
+  ///
+  ///Works the same as [`TimeReceiver::for_each_tick()`](crate::TimeReceiver::for_each_tick()).
+  pub fn for_each_tick<F>(&mut self, n: u32, on_tick: F) -> anyhow::Result<()>
+  where
+    F: FnMut(Time),
+  {
+    self.time_receiver.for_each_tick(n, on_tick)
+  }
+
+  ///Calls `on_tick` with the time of every tick for the duration.
+  ///
+  ///Works the same as [`TimeReceiver::run_for()`](crate::TimeReceiver::run_for()).
+  pub fn run_for<F>(&mut self, duration: Duration, on_tick: F) -> anyhow::Result<()>
+  where
+    F: FnMut(Time),
+  {
+    self.time_receiver.run_for(duration, on_tick)
+  }
+
+  ///Returns an iterator over only every `n`th tick of the clock.
+  ///
+  ///Works the same as [`TimeReceiver::every()`](crate::TimeReceiver::every()).
+  pub fn every(&mut self, n: u32) -> anyhow::Result<EveryNthTick<'_>> {
+    self.time_receiver.every(n)
+  }
+
+  ///Creates a [`time receiver`](crate::TimeReceiver) which has every method the clock does except starting
+  ///and stopping.
+  ///
+  ///The time receiver can be passed into other threads.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///clock.start();
+  ///
+  ///let mut time_receiver = clock.spawn_receiver();
+  ///
+  ///let time = time_receiver.time();
+  ///
+  ///assert_eq!(time, 0);
+  ///```
+  pub fn spawn_receiver(&self) -> TimeReceiver {
+    self.spawn_receiver_with_priority(ReceiverPriority::Normal)
+  }
+
+  ///Creates a [`time receiver`](crate::TimeReceiver) that's sent every tick in the order of its
+  ///[`priority`](crate::ReceiverPriority), so a tick is sent to high priority work such as physics
+  ///before low priority work such as logging.
+  ///
+  ///Only the sending is ordered. Every priority is sent the tick right after the one above it, without
+  ///waiting for anything, and the system then runs the receivers' threads or tasks as it sees fit. Tasks
+  ///on a single threaded runtime are woken up in priority order, but on other runtimes or threads a
+  ///lower priority receiver can get going first, and nothing makes it wait for the higher ones to finish.
+  ///Receivers spawned from the new one have the same priority.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ReceiverPriority};
+  ///
+  ///let mut clock = Clock::new().unwrap();
+  ///let mut physics = clock.spawn_receiver_with_priority(ReceiverPriority::High);
+  ///let logging = clock.spawn_receiver_with_priority(ReceiverPriority::Low);
+  ///
+  ///clock.start();
+  ///
+  ///assert_eq!(physics.time(), 0);
+  ///assert_eq!(logging.priority(), ReceiverPriority::Low);
+  ///```
+  pub fn spawn_receiver_with_priority(&self, priority: ReceiverPriority) -> TimeReceiver {
+    let mut time_receiver = self
+      .time_receiver
+      .with_receiver(self.tick_senders.subscribe(priority), self.tick_rate);
+
+    time_receiver.priority = priority;
+
+    time_receiver
+  }
+
+  ///Creates a [`derived clock`](crate::DerivedClock) that ticks once for every `divisor` ticks of this clock.
+  ///
+  ///The derived clock is driven by this clock's task, so no extra timers are created.
+  ///
+  ///An error is returned if the divisor is 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let mut derived_clock = clock.derive(10).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///derived_clock.wait_for_tick().unwrap();
+  ///
+  ///let time = clock.time();
+  ///
+  ///assert!(time >= 10);
+  ///```
+  pub fn derive(&self, divisor: u32) -> anyhow::Result<DerivedClock> {
+    if divisor == 0 {
+      return Err(anyhow!("A derived clock can't have a divisor of 0"));
+    }
+
+    let clock_sender = self.add_derived_output(divisor);
+
+    Ok(DerivedClock::new(&self.time_receiver, clock_sender, self.tick_rate, divisor))
+  }
+
+  ///Creates a [`time receiver`](crate::TimeReceiver) that ticks once every `period` milliseconds,
+  ///counting its own time from 0 at the time the clock started from, such as with
+  ///[`start_from()`](crate::Clock::start_from()).
+  ///
+  ///Outputs let a single clock serve several rates, such as 16ms, 100ms and 1000ms out of a 2ms clock,
+  ///without every rate needing a clock and task of its own. The output is driven by this clock's task,
+  ///and is removed from it once the receiver and every receiver spawned from it are dropped.
+  ///
+  ///An error is returned if the period isn't a multiple of this clock's tickrate.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(2).unwrap();
+  ///let mut frame_output = clock.output(16).unwrap();
+  ///let mut second_output = clock.output(1000).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///assert_eq!(frame_output.time(), 0);
+  ///assert_eq!(frame_output.tick_rate(), 16);
+  ///assert_eq!(second_output.tick_rate(), 1000);
+  ///```
+  pub fn output(&self, period: u32) -> anyhow::Result<TimeReceiver> {
+    if period == 0 || self.tick_rate == 0 || !period.is_multiple_of(self.tick_rate) {
+      return Err(anyhow!(
+        "An output's period has to be a multiple of the clock's tickrate of {}ms, got {period}ms",
+        self.tick_rate
+      ));
+    }
+
+    let clock_sender = self.add_derived_output(period / self.tick_rate);
+
+    Ok(self.time_receiver.with_receiver(clock_sender.subscribe(), period))
+  }
+
+  ///Creates a [`tick barrier`](crate::TickBarrier) which releases `parties` threads together
+  ///on the next tick after all of them have called [`wait()`](crate::TickBarrier::wait()).
+  ///
+  ///An error is returned if the amount of parties is 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let barrier = clock.tick_barrier(4).unwrap();
+  ///
+  ///assert_eq!(barrier.parties(), 4);
+  ///```
+  pub fn tick_barrier(&self, parties: usize) -> anyhow::Result<TickBarrier> {
+    if parties == 0 {
+      return Err(anyhow!("A tick barrier needs at least 1 party"));
+    }
+
+    Ok(TickBarrier::new(self.spawn_receiver(), parties))
+  }
+
+  ///Creates a [`critical receiver`](crate::CriticalReceiver), which the clock waits on to acknowledge
+  ///every tick before it sends the next one.
+  ///
+  ///An error is returned if the clock wasn't built with
+  ///[`ClockBuilder::acknowledged_ticks()`](crate::ClockBuilder::acknowledged_ticks()).
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, StragglerPolicy};
+  ///use std::time::Duration;
+  ///
+  ///let clock = Clock::builder()
+  ///  .acknowledged_ticks(Duration::from_secs(1), StragglerPolicy::Proceed)
+  ///  .build()
+  ///  .unwrap();
+  ///
+  ///assert!(clock.critical_receiver().unwrap().is_critical());
+  ///assert!(Clock::new().unwrap().critical_receiver().is_err());
+  ///```
+  pub fn critical_receiver(&self) -> anyhow::Result<CriticalReceiver> {
+    let Some(acknowledgements) = &self.acknowledgements else {
+      return Err(anyhow!("A clock has to acknowledge its ticks to have critical receivers"));
+    };
+
+    // an idle clock has to start ticking again for the new receiver
+    self.activity.notify();
+
+    Ok(CriticalReceiver::new(Arc::clone(acknowledgements)))
+  }
+
+  ///Creates a [`rate limiter`](crate::RateLimiter) holding up to `capacity` tokens,
+  ///which gains `refill_per_tick` tokens every tick of this clock.
+  ///
+  ///The bucket starts out full.
+  ///
+  ///An error is returned if the capacity is 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let rate_limiter = clock.rate_limiter(10, 2).unwrap();
+  ///
+  ///assert_eq!(rate_limiter.available(), 10);
+  ///```
+  pub fn rate_limiter(&self, capacity: u64, refill_per_tick: u64) -> anyhow::Result<RateLimiter> {
+    if capacity == 0 {
+      return Err(anyhow!("A rate limiter needs a capacity of at least 1"));
+    }
+
+    let (rate_limiter, refill) = RateLimiter::new(capacity, refill_per_tick, Arc::clone(&self.clock_status));
+
+    self.tick_listeners.lock().unwrap().push(Box::new(refill));
+    self.activity.notify();
+
+    Ok(rate_limiter)
+  }
+
+  ///Creates a [`frame pacer`](crate::FramePacer) which paces a loop to the target frames per second
+  ///using the ticks of this clock.
+  ///
+  ///An error is returned if the target frame rate isn't a positive number.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///let clock = Clock::custom(1).unwrap();
+  ///let frame_pacer = clock.frame_pacer(50.0).unwrap();
+  ///
+  ///assert_eq!(frame_pacer.frame_length(), Duration::from_millis(20));
+  ///```
+  pub fn frame_pacer(&self, target_fps: f64) -> anyhow::Result<FramePacer> {
+    if !target_fps.is_finite() || target_fps <= 0.0 {
+      return Err(anyhow!("The target frame rate has to be a positive number, got {target_fps}"));
+    }
+
+    Ok(FramePacer::new(
+      self.spawn_receiver(),
+      Duration::from_secs_f64(1.0 / target_fps),
+    ))
+  }
+
+  ///Creates a [`metronome`](crate::Metronome) that turns the ticks of this clock into beats and bars.
+  ///
+  ///An error is returned if the tempo isn't a positive number, a bar has no beats, or this clock has a
+  ///tickrate of 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::custom(10).unwrap();
+  ///let metronome = clock.metronome(128.0, 4).unwrap();
+  ///
+  ///assert_eq!(metronome.beats_per_bar(), 4);
+  ///```
+  pub fn metronome(&self, bpm: f64, beats_per_bar: u32) -> anyhow::Result<Metronome> {
+    if !bpm.is_finite() || bpm <= 0.0 {
+      return Err(anyhow!("The tempo has to be a positive number, got {bpm}"));
+    }
+
+    if beats_per_bar == 0 {
+      return Err(anyhow!("A bar needs at least 1 beat"));
+    }
+
+    if self.tick_rate == 0 {
+      return Err(anyhow!("A metronome can't follow a clock with a tickrate of 0"));
+    }
+
+    Ok(Metronome::new(self.spawn_receiver(), bpm, beats_per_bar))
+  }
+
+  ///Creates a [`countdown`](crate::Countdown) that finishes after `ticks` more ticks of this clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let countdown = clock.countdown(10);
+  ///
+  ///assert_eq!(countdown.remaining(), 10);
+  ///```
+  pub fn countdown(&self, ticks: u64) -> Countdown {
+    let (countdown, ticker) = Countdown::new(ticks, Arc::clone(&self.clock_status));
+
+    self.tick_listeners.lock().unwrap().push(Box::new(ticker));
+    self.activity.notify();
+
+    countdown
+  }
+
+  ///Creates a [`cycle`](crate::Cycle) that splits the ticks of this clock into cycles of `length_ticks` ticks.
+  ///
+  ///An error is returned if the length is 0.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let day = clock.cycle(1000).unwrap();
+  ///
+  ///assert_eq!(day.phase(), 0);
+  ///```
+  pub fn cycle(&self, length_ticks: u64) -> anyhow::Result<Cycle> {
+    if length_ticks == 0 {
+      return Err(anyhow!("A cycle has to last at least 1 tick"));
+    }
+
+    let (cycle, ticker) = Cycle::new(length_ticks, Arc::clone(&self.clock_status));
+
+    self.tick_listeners.lock().unwrap().push(Box::new(ticker));
+    self.activity.notify();
+
+    Ok(cycle)
+  }
+
+  ///Creates a [`watchdog`](crate::Watchdog) that calls back whenever the gap between two ticks of this clock
+  ///grows longer than the threshold.
+  ///
+  ///The callback runs on the watchdog's own thread. An error is returned if the thread couldn't be spawned.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::sync::mpsc;
+  ///use std::time::Duration;
+  ///
+  ///let clock = Clock::custom(10).unwrap();
+  ///let (stall_sender, stalls) = mpsc::channel();
+  ///let watchdog = clock
+  ///  .watchdog(Duration::from_millis(50), move |stall| {
+  ///    let _ = stall_sender.send(stall);
+  ///  })
+  ///  .unwrap();
+  ///
+  ///assert_eq!(watchdog.threshold(), Duration::from_millis(50));
+  ///```
+  pub fn watchdog(
+    &self,
+    threshold: Duration,
+    callback: impl FnMut(StallEvent) + Send + 'static,
+  ) -> anyhow::Result<Watchdog> {
+    let (watchdog, ticker) = Watchdog::new(threshold, Arc::clone(&self.clock_status), callback)?;
+
+    self.add_tick_listener(ticker);
+
+    Ok(watchdog)
+  }
+
+  ///Creates a [`stopwatch`](crate::Stopwatch) that counts the ticks of this clock once it's started.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let stopwatch = clock.stopwatch();
+  ///
+  ///assert_eq!(stopwatch.elapsed_ticks(), 0);
+  ///```
+  pub fn stopwatch(&self) -> Stopwatch {
+    let (stopwatch, ticker) = Stopwatch::new(self.tick_rate);
+
+    self.tick_listeners.lock().unwrap().push(Box::new(ticker));
+    self.activity.notify();
+
+    stopwatch
+  }
+
+  ///Creates a [`throttle`](crate::Throttle) that lets an event through at most once every `ticks` ticks.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let throttle = clock.throttle(10);
+  ///
+  ///assert_eq!(throttle.ticks(), 10);
+  ///```
+  pub fn throttle(&self, ticks: u64) -> Throttle {
+    let (throttle, ticker) = Throttle::new(ticks);
+
+    self.tick_listeners.lock().unwrap().push(Box::new(ticker));
+    self.activity.notify();
+
+    throttle
+  }
+
+  ///Creates a [`debounce`](crate::Debounce) that settles once `ticks` ticks pass without an event.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let debounce = clock.debounce(10);
+  ///
+  ///assert_eq!(debounce.ticks(), 10);
+  ///```
+  pub fn debounce(&self, ticks: u64) -> Debounce {
+    let (debounce, ticker) = Debounce::new(ticks);
+
+    self.tick_listeners.lock().unwrap().push(Box::new(ticker));
+    self.activity.notify();
+
+    debounce
+  }
+
+  ///Creates a [`tick executor`](crate::TickExecutor) which runs tasks from within this clock's task on every tick.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let tick_executor = clock.tick_executor();
+  ///
+  ///tick_executor.run_every_tick(|time| {
+  ///  println!("tick {time}");
+  ///
+  ///  true
+  ///});
+  ///
+  ///clock.start();
+  ///```
+  pub fn tick_executor(&self) -> TickExecutor {
+    let (tick_executor, ticker) = TickExecutor::new();
+
+    self.tick_listeners.lock().unwrap().push(Box::new(ticker));
+    self.activity.notify();
+
+    tick_executor
+  }
+
+  ///Disciplines this clock to a [`reference`](crate::TickReference) that reports authoritative ticks,
+  ///such as the tick a game server is on.
+  ///
+  ///Rather than jumping to the reference's time, the clock slightly speeds up or slows down its ticks,
+  ///by at most 5% of its tickrate, until it converges on the reference. Steady drift between the two
+  ///is slewed away the same way. The reference is polled once every tick from within the clock task.
+  ///
+  ///Once the rate is first adjusted the clock ticks on deadlines of its own, so an
+  ///[`alignment`](crate::ClockBuilder::align_to()) isn't kept. The deadlines the clock misses are caught up
+  ///on right away instead of being skipped, as skipping them would leave it behind faster than slewing
+  ///makes up for. Syncing to another reference replaces the previous one.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::sync::mpsc;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let (reference_sender, reference) = mpsc::channel();
+  ///
+  ///clock.sync_to(reference);
+  ///clock.start();
+  ///
+  ///// the server reports it's 5 ticks ahead, so the clock ticks slightly faster until it catches up
+  ///reference_sender.send(clock.time() + 5).unwrap();
+  ///```
+  pub fn sync_to(&self, mut reference: impl TickReference) {
+    self.sync_to_reports(Box::new(move || {
+      reference
+        .poll_reference()
+        .map(|reference_time| (reference_time, std::time::Instant::now()))
+    }));
+  }
+
+  ///Disciplines this clock to the reports of a reference, which say when the reference was on a tick
+  ///for references that know how late their reports arrive.
+  pub(crate) fn sync_to_reports(&self, poll_reports: PollReports) {
+    let discipline = Discipline::new(poll_reports, Arc::clone(&self.rate_adjustment), self.tick_rate);
+
+    self.tick_listeners.lock().unwrap().push(Box::new(discipline));
+    self.activity.notify();
+  }
+
+  ///Disciplines this clock to a [`wall clock`](crate::WallClock), such as [`SystemTime::now`](std::time::SystemTime::now())
+  ///or a source corrected by NTP, so its ticks stay lined up with real time over hours of running.
+  ///
+  ///Every `check_every` the time the clock has counted since it was first checked is compared against how
+  ///much time passed on the wall clock, and the clock slightly speeds up or slows down its ticks, by at
+  ///most 5% of its tickrate, to slew away the offset by the next check. Steady drift between the two is
+  ///learned over the checks. Each check is reported to `on_correction` from within the clock task.
+  ///
+  ///Time spent paused or stopped isn't caught up on, the clock is lined up with the wall clock again once
+  ///it resumes. Like [`sync_to()`](crate::Clock::sync_to()) an [`alignment`](crate::ClockBuilder::align_to())
+  ///isn't kept, missed deadlines are caught up on, and syncing to another reference or wall clock replaces
+  ///the previous one.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::{Duration, SystemTime};
+  ///
+  ///let mut clock = Clock::custom(10).unwrap();
+  ///
+  ///clock.sync_to_wall_clock(SystemTime::now, Duration::from_secs(1), |correction| {
+  ///  println!("{} ticks off real time on tick {}", correction.offset, correction.time);
+  ///});
+  ///clock.start();
+  ///```
+  pub fn sync_to_wall_clock(
+    &self,
+    wall_clock: impl WallClock,
+    check_every: Duration,
+    on_correction: impl FnMut(CorrectionEvent) + Send + 'static,
+  ) {
+    let discipline = WallClockDiscipline::new(
+      wall_clock,
+      on_correction,
+      Arc::clone(&self.rate_adjustment),
+      self.time_receiver.lifecycle.watch(),
+      self.tick_rate,
+      check_every,
+    );
+
+    self.tick_listeners.lock().unwrap().push(Box::new(discipline));
+    self.activity.notify();
+  }
+
+  ///Schedules the tickrate to change to `new_rate` milliseconds once the clock reaches a tick.
+  ///
+  ///Every tick after `at_tick` is `new_rate` milliseconds apart, until another change takes over.
+  ///A change scheduled for a tick that has already passed takes effect after the next tick.
+  ///If several changes are scheduled for the same tick, the one scheduled last is used.
+  ///
+  ///[`tick_rate()`](crate::Clock::tick_rate()) keeps returning the rate the clock was created with, so
+  ///anything that converts durations into ticks, such as
+  ///[`wait_for_duration()`](crate::Clock::wait_for_duration()), still uses that rate.
+  ///Like [`syncing`](crate::Clock::sync_to()), the clock ticks on deadlines of its own once its rate
+  ///has changed.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(20).unwrap();
+  ///
+  ///// an accelerando, ticks are twice as fast after tick 8
+  ///clock.schedule_rate_change(8, 10);
+  ///clock.start();
+  ///```
+  pub fn schedule_rate_change(&self, at_tick: Time, new_rate: u32) {
+    self.rate_schedule.schedule(at_tick, at_tick, new_rate, new_rate);
+  }
+
+  ///Schedules the tickrate to ramp linearly from `from_rate` to `to_rate` milliseconds over a span of ticks.
+  ///
+  ///The ticks after the start of the span are `from_rate` milliseconds apart, and every tick after that is
+  ///a step closer to `to_rate`, which is reached at the end of the span and kept after it.
+  ///The ramp otherwise behaves the same as a [`scheduled change`](crate::Clock::schedule_rate_change()).
+  ///
+  ///An error is returned if the span is empty.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(50).unwrap();
+  ///
+  ///// the difficulty ramps up over 100 ticks, until a tick is only 10ms long
+  ///clock.schedule_rate_ramp(0..100, 50, 10).unwrap();
+  ///clock.start();
+  ///```
+  pub fn schedule_rate_ramp(&self, ticks: Range<Time>, from_rate: u32, to_rate: u32) -> anyhow::Result<()> {
+    if ticks.is_empty() {
+      return Err(anyhow!(
+        "Can't ramp the tickrate over the empty span of ticks {ticks:?}, schedule a rate change instead"
+      ));
+    }
+
+    self.rate_schedule.schedule(ticks.start, ticks.end, from_rate, to_rate);
+
+    Ok(())
+  }
+
+  ///Adds a [`hook`](crate::ClockHook) that the clock task calls around every tick it sends out.
+  ///
+  ///Hooks can be added before or while the clock is running, and are kept for as long as the clock is.
+  ///A hook can't add other hooks to the clock from within its callbacks.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, ClockHook, Time};
+  ///
+  ///struct PrintTicks;
+  ///
+  ///impl ClockHook for PrintTicks {
+  ///  fn before_tick(&mut self, time: Time) {
+  ///    println!("about to send tick {time}");
+  ///  }
+  ///}
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///
+  ///clock.add_hook(PrintTicks);
+  ///clock.start();
+  ///```
+  pub fn add_hook(&self, hook: impl ClockHook) {
+    self.clock_hooks.add(hook);
+  }
+
+  ///Records how the clock ticks with the [`metrics`] recorder that's currently installed, such as a
+  ///prometheus exporter, so it shows up on existing dashboards. Needs the `metrics` feature.
+  ///
+  ///Every metric is labelled with `clock` set to `clock_name`:
+  ///
+  ///- `thread_clock_ticks_total`: a counter of the ticks sent.
+  ///- `thread_clock_ticks_per_second`: a gauge of the [`actual tps`](crate::Clock::actual_tps()).
+  ///- `thread_clock_tick_jitter_seconds`: a histogram of how far the time between two ticks was off the
+  ///  tickrate, which prometheus exporters turn into percentiles.
+  ///- `thread_clock_receivers`: a gauge of the [`receiver count`](crate::Clock::receiver_count()).
+  ///- `thread_clock_missed_deadlines_total`: a counter of the ticks that were late by a whole tick or more.
+  ///
+  ///The recorder has to be installed before this is called, metrics are recorded from within the clock
+  ///task as a [`hook`](crate::ClockHook) right before every tick is sent.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///// a recorder such as metrics_exporter_prometheus::PrometheusBuilder is installed first
+  ///let mut clock = Clock::custom(16).unwrap();
+  ///
+  ///clock.export_metrics("game_loop");
+  ///clock.start();
+  ///```
+  #[cfg(feature = "metrics")]
+  pub fn export_metrics(&self, clock_name: impl Into<String>) {
+    self.clock_hooks.add(metrics_exporter::MetricsExporter::new(
+      clock_name.into(),
+      Arc::clone(&self.tick_stats),
+      self.tick_senders.clone(),
+      self.time_receiver.lifecycle.watch(),
+      self.tick_rate,
+    ));
+  }
+
+  ///Publishes the ticks of this clock on a unix domain socket at the path, so processes on the same host
+  ///can follow them with a [`RemoteTimeReceiver`](crate::RemoteTimeReceiver).
+  ///
+  ///Every process that connects is sent each tick and the final time whenever the clock stops, until
+  ///the returned [`publisher`](crate::UnixSocketPublisher) is dropped, which removes the socket file.
+  ///
+  ///Returns an error if the socket couldn't be bound, such as when something already exists at the path.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let path = std::env::temp_dir().join(format!("thread-clock-example-{}.sock", std::process::id()));
+  ///let mut clock = Clock::custom(16).unwrap();
+  ///let publisher = clock.publish_unix_socket(&path).unwrap();
+  ///
+  ///assert_eq!(publisher.path(), path);
+  ///
+  ///clock.start();
+  ///```
+  #[cfg(all(unix, not(loom)))]
+  pub fn publish_unix_socket(&self, path: impl AsRef<Path>) -> anyhow::Result<UnixSocketPublisher> {
+    UnixSocketPublisher::bind(path.as_ref(), self)
+  }
+
+  ///Makes this process the leader of a shared memory segment with the name, keeping the ticks of this
+  ///clock in it for processes on the same host to follow with a [`SharedTimeReceiver`](crate::SharedTimeReceiver).
+  ///Only available on Linux.
+  ///
+  ///The ticks are written atomically to the segment and receivers are woken with a futex, so followers
+  ///don't go through sockets or the leader's threads at all. The segment is removed once the returned
+  ///[`publisher`](crate::SharedMemoryPublisher) is dropped.
+  ///
+  ///Returns an error if the name has a slash in it, or a segment with the name already exists and its
+  ///leader is still running. A segment left behind by a leader that exited without dropping its
+  ///publisher is removed and created again.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let name = format!("thread-clock-example-{}", std::process::id());
+  ///let mut clock = Clock::custom(16).unwrap();
+  ///let publisher = clock.publish_shared_memory(&name).unwrap();
+  ///
+  ///assert_eq!(publisher.name(), format!("/{name}"));
+  ///
+  ///clock.start();
+  ///```
+  #[cfg(target_os = "linux")]
+  pub fn publish_shared_memory(&self, name: &str) -> anyhow::Result<SharedMemoryPublisher> {
+    SharedMemoryPublisher::create(name, self)
+  }
+
+  ///Writes every tick of this clock from now on to a [`recording`](crate::TickRecording) at the path,
+  ///which a clock can [`replay`](crate::ClockBuilder::replay()) with the same delays between its ticks to
+  ///reproduce bugs that depend on the timing of the ticks.
+  ///
+  ///Each tick is written as its own line from within the clock task, so a recording holds every tick up
+  ///until a crash. A file that already exists at the path is overwritten.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let path = std::env::temp_dir().join(format!("thread-clock-example-{}.ticks", std::process::id()));
+  ///let mut clock = Clock::custom(16).unwrap();
+  ///
+  ///clock.record_ticks(&path).unwrap();
+  ///clock.start();
+  ///```
+  pub fn record_ticks(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+    self.add_tick_listener(TickRecorder::create(path.as_ref(), self.tick_rate)?);
+
+    Ok(())
+  }
+
+  ///Creates a [`delay queue`](crate::TickDelayQueue) whose entries expire on the ticks of this clock.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Clock, TickDelayQueue};
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let delay_queue: TickDelayQueue<u32, String> = clock.delay_queue();
+  ///
+  ///assert!(delay_queue.is_empty());
+  ///```
+  pub fn delay_queue<K, V>(&self) -> TickDelayQueue<K, V>
+  where
+    K: Hash + Eq + Clone,
+  {
+    TickDelayQueue::new(self.spawn_receiver())
+  }
+
+  ///Adds a listener to the clock task, waking the clock up if it's idle.
+  pub(crate) fn add_tick_listener(&self, tick_listener: impl TickListener + 'static) {
+    self.tick_listeners.lock().unwrap().push(Box::new(tick_listener));
+    self.activity.notify();
+  }
+
+  pub(crate) fn clock_status(&self) -> Arc<SharedStatus> {
+    Arc::clone(&self.clock_status)
+  }
+
+  pub(crate) fn overflow(&self) -> Overflow {
+    self.overflow
+  }
+
+  ///Returns the time of the latest tick the clock task sent.
+  pub(crate) fn latest_sent_time(&self) -> Option<Time> {
+    self.tick_stats.lock().unwrap().latest_tick().0
+  }
+
+  ///Adds a channel to the clock task that's sent a tick once every `divisor` ticks of this clock.
+  fn add_derived_output(&self, divisor: u32) -> Sender<ClockMessage> {
+    let (clock_sender, _) = broadcast::channel::<ClockMessage>(self.time_receiver.channel_capacity);
+    let mut derived_output = DerivedOutput::new(divisor, clock_sender.clone(), self.overflow);
+
+    // an output created while the clock runs counts from the start of the run, like the ones created before it
+    if self.clock_handle.is_some() {
+      derived_output.start(self.time_receiver.lifecycle.started_from());
+    }
+
+    self.tick_listeners.lock().unwrap().push(Box::new(derived_output));
+    self.activity.notify();
+
+    clock_sender
+  }
+
+  fn create_clock_thread(
+    &self,
+    runtime: &ClockRuntime,
+    mut stopper_receiver: OneReceiver<()>,
+    start_time: Time,
+  ) -> JoinHandle<Time> {
+    if let Some(acknowledgements) = &self.acknowledgements {
+      acknowledgements.start();
+    }
+
+    let tick_senders = self.tick_senders.clone();
+    let clock_status = Arc::clone(&self.clock_status);
+    let lifecycle = Arc::clone(&self.time_receiver.lifecycle);
+    let tick_listeners = Arc::clone(&self.tick_listeners);
+    let clock_hooks = self.clock_hooks.clone();
+    let rate_adjustment = Arc::clone(&self.rate_adjustment);
+    let rate_schedule = Arc::clone(&self.rate_schedule);
+    let tick_stats = Arc::clone(&self.tick_stats);
+    let tick_rate = self.tick_rate;
+    let overflow = self.overflow;
+    let stop_at = self.stop_at;
+    let alignment = self.alignment;
+    let precision = self.precision;
+    let spin = self.spin;
+    let driver = self.driver.clone();
+    let injected_ticks = self.injected_ticks.clone();
+    let external = self.is_external.then(|| self.injected_ticks.clone());
+    let replay = self.replay.clone();
+    let simulated = self.simulated;
+    let backend = self.backend;
+    let idle_when_unobserved = self.idle_when_unobserved;
+    let power_saving = self.power_saving;
+    let acknowledgements = self.acknowledgements.clone();
+    let activity = Arc::clone(&self.activity);
+    let max_restarts = self.max_restarts;
+    let restarts = Arc::clone(&self.restarts);
+
+    runtime.spawn(async move {
+      let mut time = start_time;
+      let mut final_time = start_time.saturating_sub(1);
+      let mut restarts_left = max_restarts;
+      let new_ticker = || match &replay {
+        Some(recording) => Ticker::replay(recording),
+        None if simulated => Ticker::Simulated(SimulatedTicks::new(Arc::clone(&activity), tick_senders.clone())),
+        None => Ticker::new(
+          tick_rate,
+          alignment,
+          precision,
+          spin,
+          driver.as_ref(),
+          external.as_ref(),
+          backend,
+        ),
+      };
+
+      loop {
+        let ran = catch_unwind(async {
+          let base_tick_length = Duration::from_millis(tick_rate.into());
+          let mut tick_length = base_tick_length;
+          let mut ticker = new_ticker();
+          let mut adjusted_tick_length = tick_length;
+          let mut last_tick = Instant::now();
+          let mut is_saving_power = false;
+
+          loop {
+            let has_critical_receivers =
+              || acknowledgements.as_ref().is_some_and(|acknowledgements| acknowledgements.has_receivers());
+            let is_unobserved = || {
+              tick_senders.receiver_count() <= 1 // the clock's own receiver
+                && tick_listeners.lock().unwrap().is_empty()
+                && !activity.is_being_read()
+                && !has_critical_receivers()
+            };
+
+            if idle_when_unobserved && is_unobserved() {
+              tick_stats.lock().unwrap().reset();
+              activity.jumped();
+              log_debug!("The clock is idle at tick {time} as nothing is observing it");
+
+              tokio::select! {
+                _ = &mut stopper_receiver => break,
+                _ = activity.woken() => (),
+              }
+
+              // the ticks that passed while idle are counted as if they happened
+              if clock_status.get() == ClockStatus::Running && !tick_length.is_zero() {
+                let missed_ticks = last_tick.elapsed().as_nanos() / tick_length.as_nanos();
+
+                if missed_ticks > 0 {
+                  let Some((latest_time, next_time)) = count_passed_ticks(overflow, time, missed_ticks) else {
+                    final_time = Time::MAX;
+                    log_warn!("The clock stopped as its time overflowed while it was idle");
+
+                    break;
+                  };
+
+                  if let Some(stop_time) = reached_stop_time(overflow, stop_at, time, latest_time) {
+                    final_time = stop_time;
+                    log_debug!("The clock stopped itself at tick {stop_time} while it was idle");
+
+                    break;
+                  }
+
+                  final_time = latest_time;
+                  time = next_time;
+                }
+              }
+
+              log_debug!("The clock woke up from being idle at tick {time}");
+              ticker = new_ticker();
+              adjusted_tick_length = base_tick_length;
+              last_tick = Instant::now();
+
+              continue;
+            }
+
+            let is_dormant = |power_saving: &PowerSaving| {
+              tick_listeners.lock().unwrap().is_empty()
+                && !activity.was_read_within(power_saving.window)
+                && !has_critical_receivers()
+            };
+
+            if let Some(power_saving) = power_saving.filter(is_dormant) {
+              if !is_saving_power {
+                is_saving_power = true;
+                tick_stats.lock().unwrap().reset();
+                activity.jumped();
+                log_debug!(
+                  "The clock lowered its tickrate to {}ms at tick {time} as nothing has read from it",
+                  power_saving.tick_rate
+                );
+              }
+
+              let woken = tokio::select! {
+                _ = &mut stopper_receiver => break,
+                _ = tokio::time::sleep(Duration::from_millis(power_saving.tick_rate.into())) => false,
+                _ = activity.woken() => true,
+              };
+              let now = Instant::now();
+
+              if clock_status.get() != ClockStatus::Running || tick_length.is_zero() {
+                last_tick = now;
+
+                continue;
+              }
+
+              // the ticks that passed at the lowered rate are counted as if they happened, keeping their phase
+              let elapsed = now.duration_since(last_tick).as_nanos();
+              let passed_ticks = elapsed / tick_length.as_nanos();
+              let since_latest_tick = elapsed % tick_length.as_nanos();
+
+              last_tick = now - Duration::from_nanos(since_latest_tick.try_into().unwrap_or(u64::MAX));
+
+              if passed_ticks == 0 {
+                continue;
+              }
+
+              let Some((latest_time, next_time)) = count_passed_ticks(overflow, time, passed_ticks) else {
+                final_time = Time::MAX;
+                log_warn!("The clock stopped as its time overflowed while its tickrate was lowered");
+
+                break;
+              };
+
+              // the ticks past the stop time aren't counted, the same as a clock at its full tickrate
+              let stop_time = reached_stop_time(overflow, stop_at, time, latest_time);
+              let latest_time = stop_time.unwrap_or(latest_time);
+
+              final_time = latest_time;
+              time = next_time;
+
+              if !woken {
+                tick_stats.lock().unwrap().record_sent(latest_time, false);
+                clock_hooks.before_tick(latest_time);
+
+                let sent_at = Instant::now();
+                tick_senders.send(ClockMessage::Tick(latest_time));
+
+                clock_hooks.after_tick(latest_time, sent_at.elapsed());
+              }
+
+              if stop_time.is_some() {
+                log_debug!("The clock stopped itself at tick {latest_time} while its tickrate was lowered");
+
+                break;
+              }
+
+              continue;
+            }
+
+            if is_saving_power {
+              is_saving_power = false;
+              log_debug!("The clock snapped back to its full tickrate at tick {time}");
+              ticker = new_ticker();
+              adjusted_tick_length = base_tick_length;
+            }
+
+            let late_by = tokio::select! {
+              _ = &mut stopper_receiver => break,
+              // a synced clock keeps to its reference, so it can't fall behind by skipping deadlines
+              late_by = ticker.tick(rate_adjustment.is_synced()) => late_by,
+              // an injected tick leaves the deadlines of the timed ones as they were
+              _ = injected_ticks.next_tick() => Duration::ZERO,
+            };
+
+            if clock_status.get() == ClockStatus::Paused {
+              tick_stats.lock().unwrap().reset();
+
+              continue;
+            }
+
+            // a tick later than a whole tick length means the deadline of the tick after it was missed too
+            let missed_deadline = !tick_length.is_zero() && late_by >= tick_length;
+
+            if missed_deadline {
+              log_warn!("Tick {time} missed its deadline by {late_by:?}, the clock can't keep up with its tickrate");
+              lifecycle.emit(ClockEvent::TickMissed(time));
+            }
+
+            last_tick = Instant::now();
+
+            {
+              let mut tick_stats = tick_stats.lock().unwrap();
+
+              tick_stats.record(last_tick);
+              tick_stats.record_sent(time, missed_deadline);
+            }
+
+            clock_hooks.before_tick(time);
+
+            let sent_at = Instant::now();
+            tick_senders.send(ClockMessage::Tick(time));
+
+            tick_listeners
+              .lock()
+              .unwrap()
+              .retain_mut(|tick_listener| tick_listener.tick(time));
+
+            if let Some(acknowledgements) = &acknowledgements {
+              acknowledgements.sent(time);
+            }
+
+            clock_hooks.after_tick(time, sent_at.elapsed());
+
+            tick_length = rate_schedule.tick_length_after(time).unwrap_or(base_tick_length);
+
+            // a clock synced to a reference slews its rate instead of jumping to the reference's time
+            let new_tick_length = tick_length.div_f64(1.0 + rate_adjustment.get());
+
+            if new_tick_length != adjusted_tick_length {
+              ticker.set_tick_length(new_tick_length);
+              adjusted_tick_length = new_tick_length;
+              lifecycle.emit(ClockEvent::RateChanged(new_tick_length));
+            }
+
+            final_time = time;
+
+            if stop_at.is_some_and(|stop_at| overflow.ticks_between(stop_at, time).is_some()) {
+              log_debug!("The clock stopped itself at tick {time}");
+
+              break;
+            }
+
+            if let Some(acknowledgements) = &acknowledgements {
+              let acknowledged = tokio::select! {
+                _ = &mut stopper_receiver => break,
+                acknowledged = acknowledgements.acknowledged() => acknowledged,
+              };
+
+              if !acknowledged {
+                match acknowledgements.stragglers() {
+                  StragglerPolicy::Proceed => {
+                    log_warn!("Tick {time} wasn't acknowledged in time, the clock is sending the next one anyway");
+                  }
+                  StragglerPolicy::Evict => {
+                    let evicted = acknowledgements.evict_stragglers();
+
+                    log_warn!(
+                      "Tick {time} wasn't acknowledged in time, the clock stopped waiting for {evicted} receivers"
+                    );
+                  }
+                  StragglerPolicy::Stop => {
+                    log_warn!("The clock stopped at tick {time} as it wasn't acknowledged in time");
+
+                    break;
+                  }
+                }
+              }
+            }
+
+            let Some(next_time) = overflow.advance(time, 1) else {
+              log_warn!("The clock stopped at tick {time} as its time can't count any higher");
+
+              break;
+            };
+
+            time = next_time;
+          }
+        })
+        .await;
+
+        if ran.is_ok() {
+          break;
+        }
+
+        sync::clear_poison(&tick_listeners);
+        clock_hooks.clear_poison();
+
+        // the tick the task panicked on counts as having happened, whether or not it's restarted
+        let (latest_time, _) = tick_stats.lock().unwrap().latest_tick();
+
+        if let Some(latest_time) = latest_time {
+          final_time = latest_time;
+        }
+
+        if restarts_left == 0 {
+          log_warn!("The clock task panicked at tick {final_time} and stopped the clock");
+
+          break;
+        }
+
+        restarts_left -= 1;
+
+        // the clock carries on after the tick it panicked on
+        if let Some(latest_time) = latest_time {
+          let Some(next_time) = overflow.advance(latest_time, 1) else {
+            break;
+          };
+
+          time = next_time;
+        }
+
+        restarts.record(time);
+        log_warn!("The clock task panicked and was restarted at tick {time}");
+      }
+
+      log_debug!("Stopped a clock at tick {final_time}");
+      {
+        let mut clock_status = clock_status.lock();
+
+        lifecycle.transition(&mut clock_status, ClockStatus::Stopped(final_time));
+
+        injected_ticks.discard_ticks();
+      }
+
+      tick_senders.send(ClockMessage::Stopped(final_time));
+
+      if let Some(acknowledgements) = &acknowledgements {
+        acknowledgements.stop(final_time);
+      }
+
+      for tick_listener in tick_listeners.lock().unwrap().iter_mut() {
+        tick_listener.stop(final_time);
+      }
+
+      final_time
+    })
+  }
+
+  ///Waits for the clock task to finish and returns the final time it reached.
+  ///
+  ///The task is aborted if it doesn't finish within [`STOP_TIMEOUT`].
+  fn join_clock_thread(&self, mut clock_handle: JoinHandle<Time>) -> anyhow::Result<Time> {
+    let joined = self
+      .runtime
+      .block_on(async { tokio::time::timeout(STOP_TIMEOUT, &mut clock_handle).await });
+
+    match joined {
+      Ok(Ok(final_time)) => Ok(final_time?),
+      Ok(Err(_)) => {
+        clock_handle.abort();
+
+        Err(anyhow!("The clock task didn't stop within {STOP_TIMEOUT:?} and was aborted"))
+      }
+      Err(error) => {
+        clock_handle.abort();
+
+        Err(error)
+      }
+    }
+  }
+}
+
+impl Drop for Clock {
+  ///Stops the clock task if the clock was dropped while running.
+  ///
+  ///Every [`time receiver`](crate::TimeReceiver) is notified the same way as with [`stop()`](crate::Clock::stop()).
+  fn drop(&mut self) {
+    // receivers waiting for a clock that never started would otherwise wait forever
+    self.time_receiver.lifecycle.abandon();
+
+    if let Some(clock_stopper) = self.clock_stopper.take() {
+      let _ = clock_stopper.send(());
+    }
+
+    if let Some(clock_handle) = self.clock_handle.take() {
+      let _ = self.join_clock_thread(clock_handle);
+    }
+  }
 }
@@ -0,0 +1,200 @@
+use crate::events::{ClockEvent, EVENT_CAPACITY};
+use crate::status::{SharedStatus, StatusLock};
+use crate::{ClockError, ClockStatus, Time};
+use crate::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use crate::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use tokio::sync::{broadcast, watch, Notify};
+use tokio::time::Instant;
+
+#[derive(Debug)]
+///Follows the status of a clock through its lifetime, for everything that has to react to it changing
+///instead of checking it.
+pub(crate) struct ClockLifecycle {
+  status: watch::Sender<ClockStatus>,
+  started: Notify,
+
+  ///Where the clock's lifecycle events are sent, along with the ones of its task.
+  events: broadcast::Sender<ClockEvent>,
+
+  ///How many times the clock has been started, which is more than once if it was stopped in place.
+  runs: AtomicU64,
+
+  ///The time the clock's current or last run started from.
+  started_from: AtomicU64,
+
+  ///Set once the clock is dropped, so nothing waits forever on a clock that never started.
+  abandoned: AtomicBool,
+
+  ///When the clock's current run started, stopped and how long it's been paused for.
+  run_times: Mutex<RunTimes>,
+
+  ///Whether receivers wait for the clock to start instead of returning an error.
+  pub(crate) receivers_wait: bool,
+}
+
+impl ClockLifecycle {
+  pub(crate) fn new(receivers_wait: bool) -> Self {
+    Self {
+      status: watch::Sender::new(ClockStatus::Created),
+      started: Notify::new(),
+      events: broadcast::channel(EVENT_CAPACITY).0,
+      runs: AtomicU64::new(0),
+      started_from: AtomicU64::new(0),
+      abandoned: AtomicBool::new(false),
+      run_times: Mutex::new(RunTimes::default()),
+      receivers_wait,
+    }
+  }
+
+  ///Moves the clock on to the status, letting everything watching it know.
+  ///
+  ///The clock's status has to be locked while it's changed, so changes are seen in the order they happened.
+  pub(crate) fn transition(&self, clock_status: &mut StatusLock<'_>, new_status: ClockStatus) {
+    let old_status = clock_status.get();
+    let was_created = old_status == ClockStatus::Created;
+    let is_starting = matches!(old_status, ClockStatus::Created | ClockStatus::Stopped(_))
+      && !matches!(new_status, ClockStatus::Stopped(_));
+
+    if is_starting {
+      self.runs.fetch_add(1, Ordering::SeqCst);
+    }
+
+    self.run_times().update(old_status, new_status, is_starting);
+
+    clock_status.set(new_status);
+    self.status.send_replace(new_status);
+
+    if is_starting {
+      self.emit(ClockEvent::Started);
+    }
+
+    match (old_status, new_status) {
+      (_, ClockStatus::Stopped(final_time)) => self.emit(ClockEvent::Stopped(final_time)),
+      (_, ClockStatus::Paused) => self.emit(ClockEvent::Paused),
+      (ClockStatus::Paused, ClockStatus::Running) => self.emit(ClockEvent::Resumed),
+      _ => (),
+    }
+
+    if was_created {
+      self.started.notify_waiters();
+    }
+  }
+
+  ///Returns how many times the clock has been started.
+  pub(crate) fn runs(&self) -> u64 {
+    self.runs.load(Ordering::SeqCst)
+  }
+
+  ///Sets the time the clock's next run starts from, before it's started.
+  pub(crate) fn start_from(&self, time: Time) {
+    self.started_from.store(time, Ordering::SeqCst);
+  }
+
+  ///Returns the time the clock's current or last run started from, 0 if it never started.
+  pub(crate) fn started_from(&self) -> Time {
+    self.started_from.load(Ordering::SeqCst)
+  }
+
+  ///Returns the wall time since the clock's current run started, pauses included.
+  ///
+  ///Stops counting once the clock stops, and is zero if it never started.
+  pub(crate) fn elapsed(&self) -> Duration {
+    let run_times = self.run_times();
+
+    run_times
+      .started_at
+      .map(|started_at| run_times.stopped_at.unwrap_or_else(Instant::now) - started_at)
+      .unwrap_or_default()
+  }
+
+  ///Returns how long the clock has been paused for during its current run, including a pause that hasn't
+  ///ended yet.
+  pub(crate) fn paused_duration(&self) -> Duration {
+    let run_times = self.run_times();
+
+    run_times.paused_for + run_times.paused_at.map(|paused_at| paused_at.elapsed()).unwrap_or_default()
+  }
+
+  fn run_times(&self) -> crate::sync::MutexGuard<'_, RunTimes> {
+    self.run_times.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+
+  ///Sends the event to every [`EventReceiver`](crate::EventReceiver) of the clock.
+  pub(crate) fn emit(&self, event: ClockEvent) {
+    // nothing has to hear about an event without receivers
+    let _ = self.events.send(event);
+  }
+
+  ///Returns a channel of the events that happen to the clock from now on.
+  pub(crate) fn events(&self) -> broadcast::Receiver<ClockEvent> {
+    self.events.subscribe()
+  }
+
+  ///Returns a channel holding the latest status of the clock.
+  pub(crate) fn watch(&self) -> watch::Receiver<ClockStatus> {
+    self.status.subscribe()
+  }
+
+  ///Wakes up everything waiting for a clock that was dropped, whether or not it started.
+  pub(crate) fn abandon(&self) {
+    self.abandoned.store(true, Ordering::SeqCst);
+    self.started.notify_waiters();
+  }
+
+  ///Waits until the clock has started, returning right away if it already has.
+  ///
+  ///[`ClockError::NotStarted`](crate::ClockError::NotStarted) is returned if the clock is dropped first.
+  pub(crate) async fn started(&self, clock_status: &SharedStatus) -> Result<(), ClockError> {
+    loop {
+      // a notified future receives notify_waiters as soon as it's created, so the start can't be missed
+      let notified = self.started.notified();
+
+      if clock_status.get() != ClockStatus::Created {
+        return Ok(());
+      }
+
+      if self.abandoned.load(Ordering::SeqCst) {
+        return Err(ClockError::NotStarted);
+      }
+
+      notified.await;
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+///When a run of the clock started and stopped, and how long it was paused for in between.
+struct RunTimes {
+  started_at: Option<Instant>,
+  stopped_at: Option<Instant>,
+
+  ///The time spent in pauses that have already ended.
+  paused_for: Duration,
+
+  ///When the pause the clock is in started.
+  paused_at: Option<Instant>,
+}
+
+impl RunTimes {
+  fn update(&mut self, old_status: ClockStatus, new_status: ClockStatus, is_starting: bool) {
+    let now = Instant::now();
+
+    if is_starting {
+      *self = Self {
+        started_at: Some(now),
+        ..Self::default()
+      };
+    } else if let Some(paused_at) = self.paused_at.filter(|_| old_status == ClockStatus::Paused) {
+      self.paused_for += now - paused_at;
+      self.paused_at = None;
+    }
+
+    match new_status {
+      ClockStatus::Paused => self.paused_at = Some(now),
+      ClockStatus::Stopped(_) if self.started_at.is_some() => self.stopped_at = Some(now),
+      _ => (),
+    }
+  }
+}
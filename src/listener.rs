@@ -0,0 +1,20 @@
+use crate::Time;
+use std::fmt::Debug;
+use crate::sync::{Arc, Mutex};
+
+///Something the clock task notifies from within its loop.
+pub(crate) trait TickListener: Debug + Send {
+  ///Called after every tick is sent.
+  ///
+  ///Returns false once the listener isn't needed anymore, which removes it from the clock.
+  fn tick(&mut self, time: Time) -> bool;
+
+  ///Called before the first tick every time the clock is started, with the time it starts from.
+  fn start(&mut self, _start_time: Time) {}
+
+  ///Called once after the clock has stopped.
+  fn stop(&mut self, _final_time: Time) {}
+}
+
+///The listeners of a clock, shared between the clock and its task.
+pub(crate) type TickListeners = Arc<Mutex<Vec<Box<dyn TickListener>>>>;
@@ -0,0 +1,36 @@
+// records are forwarded to the `log` crate when the `log` feature is enabled, without it the
+// arguments are still type checked but nothing is formatted or emitted
+
+#[cfg(feature = "log")]
+macro_rules! log_debug {
+  ($($argument:tt)+) => {
+    ::log::debug!(target: "thread_clock", $($argument)+)
+  };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! log_debug {
+  ($($argument:tt)+) => {
+    if false {
+      let _ = format_args!($($argument)+);
+    }
+  };
+}
+
+#[cfg(feature = "log")]
+macro_rules! log_warn {
+  ($($argument:tt)+) => {
+    ::log::warn!(target: "thread_clock", $($argument)+)
+  };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! log_warn {
+  ($($argument:tt)+) => {
+    if false {
+      let _ = format_args!($($argument)+);
+    }
+  };
+}
+
+pub(crate) use {log_debug, log_warn};
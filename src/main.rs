@@ -0,0 +1,170 @@
+use clap::{Parser, ValueEnum};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Instant;
+use thread_clock::{ClockBuilder, ClockError, InterruptHandle, Time, DEFAULT_TICKRATE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+  ///Prints the tick number on its own line.
+  Line,
+
+  ///Prints a JSON object per line with the tick number and the milliseconds since the clock started.
+  Json,
+}
+
+#[derive(Debug, Parser)]
+#[command(version)]
+///Ticks at a fixed rate and prints every tick, so it can pace shell scripts and pipelines.
+///
+///Stopping it with Ctrl-C or SIGTERM stops the clock cleanly and prints the final tick to stderr.
+struct Options {
+  ///How long each tick lasts, in milliseconds or as a duration such as `16ms` or `1.5s`
+  #[arg(
+    short = 'r',
+    long = "rate",
+    value_name = "DURATION",
+    default_value = DEFAULT_TICKRATE.to_string(),
+    value_parser = parse_rate
+  )]
+  clock: ClockBuilder,
+
+  ///How many ticks to print before exiting, runs forever if not set
+  #[arg(short = 'n', long, value_name = "COUNT")]
+  ticks: Option<u64>,
+
+  ///How each tick is printed
+  #[arg(short, long, value_enum, default_value_t = OutputFormat::Line)]
+  format: OutputFormat,
+
+  ///Prints nothing, only waiting for the ticks to pass before exiting
+  #[arg(short, long)]
+  quiet: bool,
+}
+
+///Parses a tickrate into a builder for the clock, a bare number being in milliseconds.
+fn parse_rate(rate: &str) -> anyhow::Result<ClockBuilder> {
+  match rate.parse() {
+    Ok(tick_rate) => Ok(ClockBuilder::new().tick_rate(tick_rate)),
+    Err(_) => ClockBuilder::new().tick_rate_str(rate),
+  }
+}
+
+fn main() {
+  if let Err(error) = run(Options::parse()) {
+    eprintln!("thread-clock: {error}");
+
+    std::process::exit(1);
+  }
+}
+
+///Runs the clock, printing every tick until enough ticks have been printed.
+fn run(options: Options) -> anyhow::Result<()> {
+  let mut clock = options.clock.build()?;
+  let mut stdout = io::stdout().lock();
+  let mut printed_ticks = 0;
+  let mut interrupted = false;
+
+  handle_shutdown_signals(clock.interrupt_handle())?;
+  clock.start();
+
+  let started_at = Instant::now();
+
+  while options.ticks.is_none_or(|ticks| printed_ticks < ticks) {
+    let time = match clock.safe_time() {
+      Ok(time) => time,
+      Err(error) if error.downcast_ref::<ClockError>() == Some(&ClockError::Interrupted) => {
+        interrupted = true;
+
+        break;
+      }
+      Err(error) => return Err(error),
+    };
+
+    if options.quiet {
+      printed_ticks += 1;
+
+      continue;
+    }
+
+    match print_tick(&mut stdout, options.format, time, started_at) {
+      Ok(()) => printed_ticks += 1,
+      // whatever was reading the ticks has gone away, such as `head` in a pipeline
+      Err(error) if error.kind() == io::ErrorKind::BrokenPipe => break,
+      Err(error) => return Err(error.into()),
+    }
+  }
+
+  let final_time = clock.stop()?;
+
+  if interrupted && !options.quiet {
+    eprintln!("thread-clock: stopped at tick {final_time}");
+  }
+
+  Ok(())
+}
+
+///Interrupts the clock once the process is asked to stop, so it can be stopped cleanly instead of
+///being killed mid-tick.
+fn handle_shutdown_signals(interrupt_handle: InterruptHandle) -> anyhow::Result<()> {
+  let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+  // the handlers are installed before returning so no signal sent after can be missed
+  let shutdown_signal = {
+    let _runtime_context = runtime.enter();
+
+    shutdown_signal()?
+  };
+
+  thread::Builder::new().name("thread-clock-signals".into()).spawn(move || {
+    runtime.block_on(shutdown_signal);
+    interrupt_handle.interrupt();
+  })?;
+
+  Ok(())
+}
+
+///Returns a future that completes on the first Ctrl-C or SIGTERM.
+#[cfg(unix)]
+fn shutdown_signal() -> anyhow::Result<impl std::future::Future<Output = ()>> {
+  use tokio::signal::unix::{signal, SignalKind};
+
+  let mut interrupt = signal(SignalKind::interrupt())?;
+  let mut terminate = signal(SignalKind::terminate())?;
+
+  Ok(async move {
+    tokio::select! {
+      _ = interrupt.recv() => (),
+      _ = terminate.recv() => (),
+    }
+  })
+}
+
+///Returns a future that completes on the first Ctrl-C or SIGTERM.
+#[cfg(windows)]
+fn shutdown_signal() -> anyhow::Result<impl std::future::Future<Output = ()>> {
+  use tokio::signal::windows::{ctrl_c, ctrl_close};
+
+  let mut interrupt = ctrl_c()?;
+  let mut close = ctrl_close()?;
+
+  Ok(async move {
+    tokio::select! {
+      _ = interrupt.recv() => (),
+      _ = close.recv() => (),
+    }
+  })
+}
+
+///Writes a single tick, flushing it right away so pipelines see every tick as it happens.
+fn print_tick(output: &mut impl Write, format: OutputFormat, time: Time, started_at: Instant) -> io::Result<()> {
+  match format {
+    OutputFormat::Line => writeln!(output, "{time}")?,
+    OutputFormat::Json => writeln!(
+      output,
+      "{{\"tick\":{time},\"elapsed_ms\":{}}}",
+      started_at.elapsed().as_millis()
+    )?,
+  }
+
+  output.flush()
+}
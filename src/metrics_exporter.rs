@@ -0,0 +1,94 @@
+use crate::delivery::TickSenders;
+use crate::hook::ClockHook;
+use crate::stats::SharedTickStats;
+use crate::{ClockStatus, Time};
+use metrics::{Counter, Gauge, Histogram, Unit};
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+const TICKS: &str = "thread_clock_ticks_total";
+const TICKS_PER_SECOND: &str = "thread_clock_ticks_per_second";
+const JITTER: &str = "thread_clock_tick_jitter_seconds";
+const RECEIVERS: &str = "thread_clock_receivers";
+const MISSED_DEADLINES: &str = "thread_clock_missed_deadlines_total";
+
+///Records the behavior of a clock with whichever [`metrics`] recorder was installed when it was created,
+///such as a prometheus exporter, labelled with the clock's name.
+pub(crate) struct MetricsExporter {
+  tick_stats: SharedTickStats,
+  tick_senders: TickSenders,
+  clock_status: watch::Receiver<ClockStatus>,
+  tick_length: Duration,
+  previous_tick: Option<Instant>,
+  ticks: Counter,
+  ticks_per_second: Gauge,
+  jitter: Histogram,
+  receivers: Gauge,
+  missed_deadlines: Counter,
+}
+
+impl MetricsExporter {
+  pub(crate) fn new(
+    clock_name: String,
+    tick_stats: SharedTickStats,
+    tick_senders: TickSenders,
+    clock_status: watch::Receiver<ClockStatus>,
+    tick_rate: u32,
+  ) -> Self {
+    metrics::describe_counter!(TICKS, "The ticks the clock has sent");
+    metrics::describe_gauge!(TICKS_PER_SECOND, "The ticks per second the clock measured over the past second");
+    metrics::describe_histogram!(JITTER, Unit::Seconds, "How far the time between ticks was off the tickrate");
+    metrics::describe_gauge!(RECEIVERS, "The time receivers listening to the clock");
+    metrics::describe_counter!(MISSED_DEADLINES, "The ticks that were late by a whole tick or more");
+
+    let labels = [("clock", clock_name)];
+
+    Self {
+      tick_stats,
+      tick_senders,
+      clock_status,
+      tick_length: Duration::from_millis(tick_rate.into()),
+      previous_tick: None,
+      ticks: metrics::counter!(TICKS, &labels),
+      ticks_per_second: metrics::gauge!(TICKS_PER_SECOND, &labels),
+      jitter: metrics::histogram!(JITTER, &labels),
+      receivers: metrics::gauge!(RECEIVERS, &labels),
+      missed_deadlines: metrics::counter!(MISSED_DEADLINES, &labels),
+    }
+  }
+}
+
+impl ClockHook for MetricsExporter {
+  fn before_tick(&mut self, _time: Time) {
+    let now = Instant::now();
+
+    // the time spent paused or stopped isn't jitter
+    if self.clock_status.has_changed().unwrap_or(false) {
+      self.clock_status.borrow_and_update();
+      self.previous_tick = None;
+    }
+
+    if let Some(previous_tick) = self.previous_tick.replace(now) {
+      let interval = now.duration_since(previous_tick);
+      let jitter = interval.abs_diff(self.tick_length);
+
+      self.jitter.record(jitter.as_secs_f64());
+    }
+
+    let (ticks_per_second, missed_deadline) = {
+      let tick_stats = self.tick_stats.lock().unwrap();
+
+      (tick_stats.ticks_per_second(), tick_stats.latest_tick().1)
+    };
+
+    self.ticks.increment(1);
+    self.ticks_per_second.set(ticks_per_second);
+    // the clock's own receiver isn't counted
+    self.receivers.set(self.tick_senders.receiver_count().saturating_sub(1) as f64);
+
+    if missed_deadline {
+      self.missed_deadlines.increment(1);
+    }
+  }
+}
@@ -0,0 +1,132 @@
+use crate::{Time, TimeReceiver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///A beat returned by [`Metronome::next_beat()`](crate::Metronome::next_beat()).
+pub struct BeatEvent {
+  ///The beat within its bar, starting at 0.
+  pub beat: u32,
+
+  ///The bar the beat is in, starting at 0.
+  pub bar: u64,
+
+  ///True for the first beat of every bar.
+  pub is_downbeat: bool,
+
+  ///The time of the tick the beat landed on.
+  pub time: Time,
+}
+
+#[derive(Debug)]
+///Turns the ticks of a clock into the beats and bars of a tempo.
+///
+///The first beat lands on the clock's first tick, and every beat after it lands on the first tick at or
+///after when it's due. A tempo that doesn't divide evenly into the tickrate doesn't drift, as every beat
+///is counted from the start of the clock.
+///If the metronome is read late, the beats that were missed are skipped.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(5).unwrap();
+///// 600 beats per minute in 4/4, so a beat every 100ms
+///let mut metronome = clock.metronome(600.0, 4).unwrap();
+///
+///clock.start();
+///
+///let first_beat = metronome.next_beat().unwrap();
+///let second_beat = metronome.next_beat().unwrap();
+///
+///assert!(first_beat.is_downbeat);
+///assert_eq!((second_beat.bar, second_beat.beat), (0, 1));
+///```
+pub struct Metronome {
+  time_receiver: TimeReceiver,
+  bpm: f64,
+  beats_per_bar: u32,
+  next_beat: u64,
+}
+
+impl Metronome {
+  pub(crate) fn new(time_receiver: TimeReceiver, bpm: f64, beats_per_bar: u32) -> Self {
+    Self {
+      time_receiver,
+      bpm,
+      beats_per_bar,
+      next_beat: 0,
+    }
+  }
+
+  ///Returns the tempo in beats per minute.
+  pub fn bpm(&self) -> f64 {
+    self.bpm
+  }
+
+  ///Returns how many beats make up a bar.
+  pub fn beats_per_bar(&self) -> u32 {
+    self.beats_per_bar
+  }
+
+  ///Blocks until the next beat and returns it.
+  ///
+  ///An error is returned if something went wrong with the clock.
+  pub fn next_beat(&mut self) -> anyhow::Result<BeatEvent> {
+    let due_time = self.tick_of_beat(self.next_beat);
+    let time = self.time_receiver.wait_until_at_least(due_time)?;
+    let beat = self.latest_beat_at(time).max(self.next_beat);
+
+    self.next_beat = beat + 1;
+
+    Ok(self.beat_event(beat, time))
+  }
+
+  ///Returns the beat the metronome is on at the time, without waiting.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::custom(10).unwrap();
+  ///// 120 beats per minute in 3/4, so a beat every 50 ticks
+  ///let metronome = clock.metronome(120.0, 3).unwrap();
+  ///
+  ///let beat_event = metronome.beat_at(160);
+  ///
+  ///assert_eq!((beat_event.bar, beat_event.beat), (1, 0));
+  ///assert!(beat_event.is_downbeat);
+  ///```
+  pub fn beat_at(&self, time: Time) -> BeatEvent {
+    self.beat_event(self.latest_beat_at(time), time)
+  }
+
+  fn beat_event(&self, beat: u64, time: Time) -> BeatEvent {
+    let beats_per_bar = u64::from(self.beats_per_bar);
+    let beat_in_bar = (beat % beats_per_bar) as u32;
+
+    BeatEvent {
+      beat: beat_in_bar,
+      bar: beat / beats_per_bar,
+      is_downbeat: beat_in_bar == 0,
+      time,
+    }
+  }
+
+  ///Returns how many ticks of the clock a beat lasts, which doesn't have to be a whole amount.
+  fn ticks_per_beat(&self) -> f64 {
+    60_000.0 / self.bpm / f64::from(self.time_receiver.tick_rate())
+  }
+
+  ///Returns the first tick at or after when the beat is due.
+  ///
+  ///Tick `t` happens once `t + 1` ticks have passed since the clock started.
+  fn tick_of_beat(&self, beat: u64) -> Time {
+    ((beat as f64 * self.ticks_per_beat()).ceil() as Time).saturating_sub(1)
+  }
+
+  ///Returns the newest beat that's due by the time.
+  fn latest_beat_at(&self, time: Time) -> u64 {
+    ((time + 1) as f64 / self.ticks_per_beat()).floor() as u64
+  }
+}
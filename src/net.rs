@@ -0,0 +1,448 @@
+use crate::logging::{log_debug, log_warn};
+use crate::publisher::TickPublisher;
+use crate::runtime::{ClockRuntime, ThreadOptions};
+use crate::{Clock, ClockMessage, Time};
+use anyhow::anyhow;
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs, UdpSocket};
+use std::ops::{Deref, DerefMut};
+use crate::sync::atomic::{AtomicU64, Ordering};
+use crate::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+
+///How often a follower pings its leader, which keeps it subscribed and measures the latency between them.
+const PING_INTERVAL: Duration = Duration::from_millis(250);
+
+///How long a leader keeps sending ticks to a follower that stopped pinging it.
+const FOLLOWER_TIMEOUT: Duration = Duration::from_secs(5);
+
+///How long a follower waits for its leader to answer and send a tick when connecting.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+///How much of every new latency measurement is taken into the estimate, which smooths out the jitter
+///of single pings.
+const LATENCY_GAIN: f64 = 0.125;
+
+///The largest datagram sent between a leader and its followers.
+const MAX_DATAGRAM_LENGTH: usize = 13;
+
+const PING: u8 = 0;
+const PONG: u8 = 1;
+const TICK: u8 = 2;
+const STOPPED: u8 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///The datagrams sent between a leader and its followers, a byte for their kind followed by big-endian fields.
+enum Datagram {
+  ///Sent by followers with the nanoseconds since they connected.
+  Ping(u64),
+  ///The leader's answer to a ping, with the nanoseconds it was sent with.
+  Pong { sent_at: u64, tick_rate: u32 },
+  Tick(Time),
+  Stopped(Time),
+}
+
+impl Datagram {
+  fn encode(self) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(MAX_DATAGRAM_LENGTH);
+
+    match self {
+      Self::Ping(sent_at) => {
+        encoded.push(PING);
+        encoded.extend_from_slice(&sent_at.to_be_bytes());
+      }
+      Self::Pong { sent_at, tick_rate } => {
+        encoded.push(PONG);
+        encoded.extend_from_slice(&sent_at.to_be_bytes());
+        encoded.extend_from_slice(&tick_rate.to_be_bytes());
+      }
+      Self::Tick(time) => {
+        encoded.push(TICK);
+        encoded.extend_from_slice(&time.to_be_bytes());
+      }
+      Self::Stopped(final_time) => {
+        encoded.push(STOPPED);
+        encoded.extend_from_slice(&final_time.to_be_bytes());
+      }
+    }
+
+    encoded
+  }
+
+  ///Returns None for datagrams that aren't from a leader or follower.
+  fn decode(encoded: &[u8]) -> Option<Self> {
+    let (&kind, fields) = encoded.split_first()?;
+    let first_field = u64::from_be_bytes(fields.get(..8)?.try_into().ok()?);
+
+    match (kind, fields.len()) {
+      (PING, 8) => Some(Self::Ping(first_field)),
+      (PONG, 12) => Some(Self::Pong {
+        sent_at: first_field,
+        tick_rate: u32::from_be_bytes(fields[8..].try_into().ok()?),
+      }),
+      (TICK, 8) => Some(Self::Tick(first_field)),
+      (STOPPED, 8) => Some(Self::Stopped(first_field)),
+      _ => None,
+    }
+  }
+}
+
+///Broadcasts the ticks of a clock over UDP to every [`follower`](crate::ClockFollower) of it, so clocks
+///in other processes or on other machines tick in lockstep with it. Needs the `net` feature.
+///
+///Followers subscribe by pinging the leader, which answers every ping so they can measure the latency
+///between them, and sends each tick to every follower that pinged it within the last 5 seconds.
+///The leader runs on a thread of its own and stops broadcasting once it's dropped.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockLeader};
+///
+///let mut clock = Clock::custom(16).unwrap();
+///let leader = ClockLeader::bind("127.0.0.1:0", &clock).unwrap();
+///
+///println!("leading on {}", leader.local_addr());
+///
+///clock.start();
+///```
+pub struct ClockLeader {
+  local_addr: SocketAddr,
+  _publisher: TickPublisher,
+  broadcast_task: JoinHandle<()>,
+  // dropped last, which shuts down the socket along with the thread it runs on
+  _runtime: ClockRuntime,
+}
+
+impl ClockLeader {
+  ///Starts leading the clock on the address, whether or not the clock has started.
+  ///
+  ///Returns an error if the address couldn't be bound, or the leader's thread couldn't be created.
+  pub fn bind(addr: impl ToSocketAddrs, clock: &Clock) -> anyhow::Result<Self> {
+    let socket = UdpSocket::bind(addr)?;
+    let local_addr = socket.local_addr()?;
+
+    socket.set_nonblocking(true)?;
+
+    let runtime = ClockRuntime::new(ThreadOptions::default())?;
+    let publisher = TickPublisher::new(clock);
+    let mut messages = publisher.subscriptions().subscribe();
+    let tick_rate = clock.tick_rate();
+
+    let broadcast_task = runtime.spawn(async move {
+      let socket = match tokio::net::UdpSocket::from_std(socket) {
+        Ok(socket) => socket,
+        Err(error) => {
+          log_warn!("The clock leader couldn't listen on {local_addr}: {error}");
+
+          return;
+        }
+      };
+      let mut followers: HashMap<SocketAddr, Instant> = HashMap::new();
+      let mut buffer = [0; MAX_DATAGRAM_LENGTH];
+
+      loop {
+        tokio::select! {
+          received = socket.recv_from(&mut buffer) => {
+            let (length, follower) = match received {
+              Ok(received) => received,
+              Err(error) => {
+                log_debug!("The clock leader couldn't receive a datagram: {error}");
+
+                continue;
+              }
+            };
+
+            if let Some(Datagram::Ping(sent_at)) = Datagram::decode(&buffer[..length]) {
+              if followers.insert(follower, Instant::now()).is_none() {
+                log_debug!("{follower} started following the clock leader");
+              }
+
+              let _ = socket.send_to(&Datagram::Pong { sent_at, tick_rate }.encode(), follower).await;
+            }
+          }
+          message = messages.recv() => {
+            let datagram = match message {
+              Ok(ClockMessage::Tick(time)) => Datagram::Tick(time),
+              Ok(ClockMessage::Stopped(final_time)) => Datagram::Stopped(final_time),
+              Err(RecvError::Lagged(_)) => continue,
+              Err(RecvError::Closed) => break,
+            }
+            .encode();
+
+            followers.retain(|_, last_ping| last_ping.elapsed() < FOLLOWER_TIMEOUT);
+
+            for follower in followers.keys() {
+              let _ = socket.send_to(&datagram, follower).await;
+            }
+          }
+        }
+      }
+    });
+
+    Ok(Self {
+      local_addr,
+      _publisher: publisher,
+      broadcast_task,
+      _runtime: runtime,
+    })
+  }
+
+  ///Returns the address the leader is listening on, which has the port that was picked if the leader
+  ///was bound to port 0.
+  pub fn local_addr(&self) -> SocketAddr {
+    self.local_addr
+  }
+}
+
+impl fmt::Debug for ClockLeader {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ClockLeader").field("local_addr", &self.local_addr).finish()
+  }
+}
+
+impl Drop for ClockLeader {
+  fn drop(&mut self) {
+    self.broadcast_task.abort();
+  }
+}
+
+///A local clock slaved to a [`ClockLeader`](crate::ClockLeader), for lockstep simulations spread
+///across processes or machines. Needs the `net` feature.
+///
+///The follower's clock ticks at the leader's tickrate and starts on the tick the leader is on. From then
+///on it's [`synced`](crate::Clock::sync_to()) to the ticks the leader broadcasts, which are taken as
+///having happened half a round trip before they arrived, so the follower lines up with the leader rather
+///than lagging behind it by the network's latency. The latency is measured by pinging the leader every
+///250ms.
+///
+///The follower dereferences to its clock, so it's used the same way the clock would be. If the leader
+///goes away the clock keeps ticking on its own, and is pulled back in line once ticks arrive again.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockFollower, ClockLeader};
+///
+///let mut clock = Clock::custom(16).unwrap();
+///let leader = ClockLeader::bind("127.0.0.1:0", &clock).unwrap();
+///
+///clock.start();
+///
+///// on another machine
+///let mut follower = ClockFollower::connect(leader.local_addr()).unwrap();
+///
+///assert_eq!(follower.tick_rate(), 16);
+///println!("{:?} behind the leader, on tick {}", follower.latency(), follower.time());
+///```
+pub struct ClockFollower {
+  clock: Clock,
+  leader_addr: SocketAddr,
+  ///The estimated one-way latency to the leader in nanoseconds.
+  latency: Arc<AtomicU64>,
+}
+
+impl ClockFollower {
+  ///Connects to the leader and starts following it.
+  ///
+  ///Returns an error if the leader didn't answer or send a tick within 2 seconds, so the leader's clock
+  ///has to be running.
+  pub fn connect(leader_addr: impl ToSocketAddrs) -> anyhow::Result<Self> {
+    let leader_addr = leader_addr
+      .to_socket_addrs()?
+      .next()
+      .ok_or_else(|| anyhow!("A clock follower needs an address to find its leader on"))?;
+    let local_addr: SocketAddr = match leader_addr {
+      SocketAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+      SocketAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+    };
+    let socket = UdpSocket::bind(local_addr)?;
+
+    socket.connect(leader_addr)?;
+
+    let mut connection = LeaderConnection {
+      socket,
+      connected_at: Instant::now(),
+      last_ping: None,
+      latency: Arc::new(AtomicU64::new(0)),
+      measured_latency: false,
+    };
+    let (tick_rate, (leader_time, received_at)) = connection
+      .handshake()
+      .map_err(|error| anyhow!("Couldn't follow the clock leader at {leader_addr}: {error}"))?;
+
+    connection.socket.set_nonblocking(true)?;
+
+    let mut clock = Clock::custom(tick_rate)?;
+    let latency = Arc::clone(&connection.latency);
+    let tick_length = Duration::from_millis(tick_rate.into());
+    let elapsed_ticks = (received_at.elapsed() + connection.latency()).as_nanos() / tick_length.as_nanos().max(1);
+
+    clock.sync_to_reports(Box::new(move || connection.poll_reports()));
+    clock.start_from(leader_time + elapsed_ticks as Time);
+
+    Ok(Self {
+      clock,
+      leader_addr,
+      latency,
+    })
+  }
+
+  ///Returns the address of the leader being followed.
+  pub fn leader_addr(&self) -> SocketAddr {
+    self.leader_addr
+  }
+
+  ///Returns the estimated time it takes a tick to get from the leader to the follower, which is half of
+  ///the smoothed round trip time of its pings.
+  pub fn latency(&self) -> Duration {
+    Duration::from_nanos(self.latency.load(Ordering::SeqCst))
+  }
+
+  ///Takes the clock out of the follower, it keeps following the leader.
+  pub fn into_inner(self) -> Clock {
+    self.clock
+  }
+}
+
+impl Deref for ClockFollower {
+  type Target = Clock;
+
+  fn deref(&self) -> &Clock {
+    &self.clock
+  }
+}
+
+impl DerefMut for ClockFollower {
+  fn deref_mut(&mut self) -> &mut Clock {
+    &mut self.clock
+  }
+}
+
+impl fmt::Debug for ClockFollower {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ClockFollower")
+      .field("clock", &self.clock)
+      .field("leader_addr", &self.leader_addr)
+      .field("latency", &self.latency())
+      .finish()
+  }
+}
+
+///A follower's socket to its leader, which is polled from within the follower's clock task once it's
+///following the leader.
+struct LeaderConnection {
+  socket: UdpSocket,
+  ///What the pings sent to the leader are timed from.
+  connected_at: Instant,
+  last_ping: Option<Instant>,
+  latency: Arc<AtomicU64>,
+  measured_latency: bool,
+}
+
+impl LeaderConnection {
+  ///Pings the leader until it answers with its tickrate and sends a tick, returning them along with
+  ///when the tick arrived.
+  fn handshake(&mut self) -> io::Result<(u32, (Time, Instant))> {
+    let mut tick_rate = None;
+    let mut first_tick = None;
+    let mut buffer = [0; MAX_DATAGRAM_LENGTH];
+
+    self.socket.set_read_timeout(Some(PING_INTERVAL))?;
+
+    while self.connected_at.elapsed() < CONNECT_TIMEOUT {
+      if tick_rate.is_none() && self.ping_is_due() {
+        self.ping()?;
+      }
+
+      let length = match self.socket.recv(&mut buffer) {
+        Ok(length) => length,
+        // the leader's port not being open yet is reported on the next receive on some platforms
+        Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => continue,
+        Err(error) if matches!(error.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => continue,
+        Err(error) => return Err(error),
+      };
+
+      match Datagram::decode(&buffer[..length]) {
+        Some(Datagram::Pong { sent_at, tick_rate: leader_tick_rate }) => {
+          self.measure_latency(sent_at);
+          tick_rate = Some(leader_tick_rate);
+        }
+        Some(Datagram::Tick(time)) => first_tick = Some((time, Instant::now())),
+        _ => (),
+      }
+
+      if let (Some(tick_rate), Some(first_tick)) = (tick_rate, first_tick) {
+        return Ok((tick_rate, first_tick));
+      }
+    }
+
+    let missing = match tick_rate {
+      Some(_) => "send a tick, is its clock running?",
+      None => "answer",
+    };
+
+    Err(io::Error::new(io::ErrorKind::TimedOut, format!("the leader didn't {missing}")))
+  }
+
+  ///Returns the newest tick the leader sent since this was last polled, along with when the leader
+  ///was on it.
+  fn poll_reports(&mut self) -> Option<(Time, Instant)> {
+    if self.ping_is_due() {
+      if let Err(error) = self.ping() {
+        log_debug!("A clock follower couldn't ping its leader: {error}");
+      }
+    }
+
+    let mut buffer = [0; MAX_DATAGRAM_LENGTH];
+    let mut newest_report = None;
+
+    while let Ok(length) = self.socket.recv(&mut buffer) {
+      match Datagram::decode(&buffer[..length]) {
+        Some(Datagram::Tick(time)) => {
+          let received_at = Instant::now();
+
+          newest_report = Some((time, received_at.checked_sub(self.latency()).unwrap_or(received_at)));
+        }
+        Some(Datagram::Pong { sent_at, .. }) => self.measure_latency(sent_at),
+        _ => (),
+      }
+    }
+
+    newest_report
+  }
+
+  fn ping_is_due(&self) -> bool {
+    self.last_ping.is_none_or(|last_ping| last_ping.elapsed() >= PING_INTERVAL)
+  }
+
+  fn ping(&mut self) -> io::Result<()> {
+    let sent_at = self.connected_at.elapsed().as_nanos() as u64;
+
+    self.last_ping = Some(Instant::now());
+    self.socket.send(&Datagram::Ping(sent_at).encode())?;
+
+    Ok(())
+  }
+
+  ///Takes half the round trip of the ping sent at the time into the latency estimate.
+  fn measure_latency(&mut self, sent_at: u64) {
+    let round_trip = self.connected_at.elapsed().saturating_sub(Duration::from_nanos(sent_at));
+    let measured_latency = round_trip.as_nanos() as f64 / 2.0;
+    let latency = match self.measured_latency {
+      true => self.latency.load(Ordering::SeqCst) as f64 * (1.0 - LATENCY_GAIN) + measured_latency * LATENCY_GAIN,
+      false => measured_latency,
+    };
+
+    self.measured_latency = true;
+    self.latency.store(latency as u64, Ordering::SeqCst);
+  }
+
+  fn latency(&self) -> Duration {
+    Duration::from_nanos(self.latency.load(Ordering::SeqCst))
+  }
+}
@@ -0,0 +1,54 @@
+use std::io;
+
+///Gives the current thread real-time scheduling priority, so it runs as soon as it wakes up instead of
+///waiting for other threads to yield.
+///
+///Uses `SCHED_FIFO` halfway between its lowest and highest priority on unix, which usually needs
+///`CAP_SYS_NICE` or an `rtprio` limit, and `THREAD_PRIORITY_TIME_CRITICAL` on Windows.
+#[cfg(unix)]
+pub(crate) fn raise_to_realtime() -> io::Result<()> {
+  // SAFETY: these only read the scheduling policy's bounds and change the calling thread's own scheduling
+  unsafe {
+    let lowest_priority = libc::sched_get_priority_min(libc::SCHED_FIFO);
+    let highest_priority = libc::sched_get_priority_max(libc::SCHED_FIFO);
+
+    if lowest_priority == -1 || highest_priority == -1 {
+      return Err(io::Error::last_os_error());
+    }
+
+    let mut parameters: libc::sched_param = std::mem::zeroed();
+
+    // the highest priority would starve the kernel's own threads if a precise clock spins on it
+    parameters.sched_priority = lowest_priority + (highest_priority - lowest_priority) / 2;
+
+    match libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &parameters) {
+      0 => Ok(()),
+      error => Err(io::Error::from_raw_os_error(error)),
+    }
+  }
+}
+
+///Gives the current thread real-time scheduling priority, so it runs as soon as it wakes up instead of
+///waiting for other threads to yield.
+///
+///Uses `SCHED_FIFO` halfway between its lowest and highest priority on unix, which usually needs
+///`CAP_SYS_NICE` or an `rtprio` limit, and `THREAD_PRIORITY_TIME_CRITICAL` on Windows.
+#[cfg(windows)]
+pub(crate) fn raise_to_realtime() -> io::Result<()> {
+  use windows_sys::Win32::System::Threading::{GetCurrentThread, SetThreadPriority, THREAD_PRIORITY_TIME_CRITICAL};
+
+  // SAFETY: GetCurrentThread returns a pseudo handle to the calling thread that's always valid
+  match unsafe { SetThreadPriority(GetCurrentThread(), THREAD_PRIORITY_TIME_CRITICAL) } {
+    0 => Err(io::Error::last_os_error()),
+    _ => Ok(()),
+  }
+}
+
+///Gives the current thread real-time scheduling priority, which isn't supported on this platform.
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn raise_to_realtime() -> io::Result<()> {
+  Err(io::Error::new(
+    io::ErrorKind::Unsupported,
+    "real-time priority isn't supported on this platform",
+  ))
+}
@@ -0,0 +1,61 @@
+use crate::listener::TickListener;
+use crate::{Clock, ClockMessage, Time};
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::Arc;
+use tokio::sync::broadcast::{self, Sender};
+
+///How many messages can be queued for a connection before the oldest of them are skipped.
+const BACKLOG: usize = 16;
+
+///The ticks of a clock passed on to the connections of something publishing them outside the process,
+///which stops once this is dropped.
+#[derive(Debug)]
+pub(crate) struct TickPublisher {
+  message_sender: Sender<ClockMessage>,
+  closed: Arc<AtomicBool>,
+}
+
+impl TickPublisher {
+  pub(crate) fn new(clock: &Clock) -> Self {
+    let (message_sender, _) = broadcast::channel(BACKLOG);
+    let closed = Arc::new(AtomicBool::new(false));
+
+    clock.add_tick_listener(PublishedTicks {
+      message_sender: message_sender.clone(),
+      closed: Arc::clone(&closed),
+    });
+
+    Self { message_sender, closed }
+  }
+
+  ///Returns a sender that every new connection subscribes to, getting the messages sent from then on.
+  pub(crate) fn subscriptions(&self) -> Sender<ClockMessage> {
+    self.message_sender.clone()
+  }
+}
+
+impl Drop for TickPublisher {
+  fn drop(&mut self) {
+    self.closed.store(true, Ordering::SeqCst);
+  }
+}
+
+///Sends the ticks of a clock to the connections of a publisher.
+#[derive(Debug)]
+struct PublishedTicks {
+  message_sender: Sender<ClockMessage>,
+  closed: Arc<AtomicBool>,
+}
+
+impl TickListener for PublishedTicks {
+  ///Sends the tick to every connection, this is removed once its publisher is dropped.
+  fn tick(&mut self, time: Time) -> bool {
+    let _ = self.message_sender.send(ClockMessage::Tick(time));
+
+    !self.closed.load(Ordering::SeqCst)
+  }
+
+  fn stop(&mut self, final_time: Time) {
+    let _ = self.message_sender.send(ClockMessage::Stopped(final_time));
+  }
+}
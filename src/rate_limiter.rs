@@ -0,0 +1,174 @@
+use crate::status::SharedStatus;
+use crate::listener::TickListener;
+use crate::{ClockError, ClockStatus, Time};
+use crate::sync::{Arc, Condvar, Mutex, Weak};
+
+#[derive(Debug, Clone)]
+///A token bucket whose tokens are refilled by the ticks of a clock.
+///
+///Every clone shares the same bucket, so threads that share a clock can also share
+///a tick-consistent rate limit.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let rate_limiter = clock.rate_limiter(2, 1).unwrap();
+///
+///clock.start();
+///
+///assert!(rate_limiter.try_acquire());
+///assert!(rate_limiter.try_acquire());
+///assert!(!rate_limiter.try_acquire());
+///
+///// blocks until the next tick refills the bucket
+///rate_limiter.acquire().unwrap();
+///```
+pub struct RateLimiter {
+  inner: Arc<RateLimiterInner>,
+}
+
+#[derive(Debug)]
+struct RateLimiterInner {
+  capacity: u64,
+  refill_per_tick: u64,
+  tokens: Mutex<u64>,
+  refilled: Condvar,
+  clock_status: Arc<SharedStatus>,
+}
+
+impl RateLimiter {
+  ///Creates the rate limiter along with the listener that refills it from the clock task.
+  pub(crate) fn new(
+    capacity: u64,
+    refill_per_tick: u64,
+    clock_status: Arc<SharedStatus>,
+  ) -> (Self, RateLimiterRefill) {
+    let inner = Arc::new(RateLimiterInner {
+      capacity,
+      refill_per_tick,
+      tokens: Mutex::new(capacity),
+      refilled: Condvar::new(),
+      clock_status,
+    });
+    let refill = RateLimiterRefill {
+      rate_limiter: Arc::downgrade(&inner),
+    };
+
+    (Self { inner }, refill)
+  }
+
+  ///Returns the most tokens the bucket can hold.
+  pub fn capacity(&self) -> u64 {
+    self.inner.capacity
+  }
+
+  ///Returns how many tokens are added to the bucket every tick.
+  pub fn refill_per_tick(&self) -> u64 {
+    self.inner.refill_per_tick
+  }
+
+  ///Returns how many tokens are currently in the bucket.
+  pub fn available(&self) -> u64 {
+    *self.inner.tokens.lock().unwrap()
+  }
+
+  ///Takes a token from the bucket if there is one.
+  ///
+  ///Returns false without waiting if the bucket is empty.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let clock = Clock::new().unwrap();
+  ///let rate_limiter = clock.rate_limiter(1, 1).unwrap();
+  ///
+  ///assert!(rate_limiter.try_acquire());
+  ///assert!(!rate_limiter.try_acquire());
+  ///```
+  pub fn try_acquire(&self) -> bool {
+    let mut tokens = self.inner.tokens.lock().unwrap();
+
+    if *tokens > 0 {
+      *tokens -= 1;
+
+      true
+    } else {
+      false
+    }
+  }
+
+  ///Takes a token from the bucket, blocking until a tick refills it if it's empty.
+  ///
+  ///An error is returned if the bucket is empty and the clock isn't running, as it would never be refilled.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///
+  ///let mut clock = Clock::custom(1).unwrap();
+  ///let rate_limiter = clock.rate_limiter(1, 1).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///for _ in 0..5 {
+  ///  rate_limiter.acquire().unwrap();
+  ///}
+  ///```
+  pub fn acquire(&self) -> anyhow::Result<()> {
+    let mut tokens = self.inner.tokens.lock().unwrap();
+
+    loop {
+      if *tokens > 0 {
+        *tokens -= 1;
+
+        return Ok(());
+      }
+
+      match self.inner.clock_status.get() {
+        ClockStatus::Created => return Err(ClockError::NotStarted.into()),
+        ClockStatus::Stopped(final_time) => return Err(ClockError::Stopped(final_time).into()),
+        ClockStatus::Running | ClockStatus::Paused => (),
+      }
+
+      tokens = self.inner.refilled.wait(tokens).unwrap();
+    }
+  }
+}
+
+#[derive(Debug)]
+///Refills a rate limiter from within the clock task.
+pub(crate) struct RateLimiterRefill {
+  rate_limiter: Weak<RateLimiterInner>,
+}
+
+impl TickListener for RateLimiterRefill {
+  ///Refills the bucket, the refill is removed once every clone of the rate limiter is dropped.
+  fn tick(&mut self, _time: Time) -> bool {
+    let Some(rate_limiter) = self.rate_limiter.upgrade() else {
+      return false;
+    };
+    let mut tokens = rate_limiter.tokens.lock().unwrap();
+
+    *tokens = tokens
+      .saturating_add(rate_limiter.refill_per_tick)
+      .min(rate_limiter.capacity);
+    rate_limiter.refilled.notify_all();
+
+    true
+  }
+
+  ///Wakes every thread waiting on the rate limiter so they can see the clock has stopped.
+  fn stop(&mut self, _final_time: Time) {
+    if let Some(rate_limiter) = self.rate_limiter.upgrade() {
+      let _tokens = rate_limiter.tokens.lock().unwrap();
+
+      rate_limiter.refilled.notify_all();
+    }
+  }
+}
@@ -0,0 +1,156 @@
+use crate::listener::TickListener;
+use crate::logging::log_warn;
+use crate::Time;
+use anyhow::anyhow;
+use std::fmt;
+use std::fs::File;
+use std::io::{LineWriter, Write};
+use std::path::Path;
+use crate::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+///The first line of every recording, followed by the tickrate of the clock it was recorded from.
+const HEADER: &str = "# thread_clock tick recording, tick_rate";
+
+///The ticks of a clock recorded with [`Clock::record_ticks()`](crate::Clock::record_ticks()), which a clock
+///can be [`built`](crate::ClockBuilder::replay()) to replay.
+///
+///A recording is a text file with a line for every tick, its time followed by the wall-clock instant
+///it happened at in nanoseconds since the unix epoch.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, TickRecording};
+///
+///let path = std::env::temp_dir().join(format!("thread-clock-doc-{}.ticks", std::process::id()));
+///let mut clock = Clock::custom(1).unwrap();
+///
+///clock.record_ticks(&path).unwrap();
+///clock.start();
+///clock.wait_for_time(10).unwrap();
+///clock.stop().unwrap();
+///
+///let recording = TickRecording::load(&path).unwrap();
+///
+///assert!(recording.len() >= 10);
+///```
+#[derive(Debug, Clone)]
+pub struct TickRecording {
+  tick_rate: u32,
+  ///The time of every recorded tick along with when it happened.
+  ticks: Arc<[(Time, SystemTime)]>,
+}
+
+impl TickRecording {
+  ///Loads the recording from the file.
+  ///
+  ///Returns an error if the file couldn't be read, isn't a recording, or has no ticks in it.
+  pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+      .map_err(|error| anyhow!("Couldn't read the tick recording {}: {error}", path.display()))?;
+    let mut lines = contents.lines();
+    let tick_rate = lines
+      .next()
+      .and_then(|header| header.strip_prefix(HEADER))
+      .and_then(|tick_rate| tick_rate.trim().parse().ok())
+      .ok_or_else(|| anyhow!("{} isn't a tick recording", path.display()))?;
+    let ticks = lines
+      .enumerate()
+      .map(|(index, line)| {
+        parse_tick(line).ok_or_else(|| anyhow!("Line {} of the tick recording is invalid: '{line}'", index + 2))
+      })
+      .collect::<anyhow::Result<Arc<[_]>>>()?;
+
+    if ticks.is_empty() {
+      return Err(anyhow!("A tick recording needs at least one tick"));
+    }
+
+    Ok(Self { tick_rate, ticks })
+  }
+
+  ///Returns how many ticks were recorded.
+  pub fn len(&self) -> usize {
+    self.ticks.len()
+  }
+
+  ///Returns true if no ticks were recorded, which a loaded recording never is.
+  pub fn is_empty(&self) -> bool {
+    self.ticks.is_empty()
+  }
+
+  ///Returns the tickrate of the clock the ticks were recorded from.
+  pub fn tick_rate(&self) -> u32 {
+    self.tick_rate
+  }
+
+  ///Returns the time of the first recorded tick.
+  pub fn first_time(&self) -> Time {
+    self.ticks[0].0
+  }
+
+  ///Returns how long to wait before every tick, the first of them a tick length after the clock starts.
+  pub(crate) fn delays(&self) -> Vec<Duration> {
+    let first_delay = Duration::from_millis(self.tick_rate.into());
+    let gaps = self
+      .ticks
+      .windows(2)
+      .map(|ticks| ticks[1].1.duration_since(ticks[0].1).unwrap_or_default());
+
+    std::iter::once(first_delay).chain(gaps).collect()
+  }
+}
+
+fn parse_tick(line: &str) -> Option<(Time, SystemTime)> {
+  let (time, happened_at) = line.split_once(' ')?;
+  let happened_at = UNIX_EPOCH + Duration::from_nanos(happened_at.parse().ok()?);
+
+  Some((time.parse().ok()?, happened_at))
+}
+
+///Writes every tick of a clock to a recording as it happens.
+pub(crate) struct TickRecorder {
+  file: LineWriter<File>,
+  ///When recording started, which the instants of the ticks are measured from so they can't go back in
+  ///time if the system's clock is changed.
+  started_at: (Instant, SystemTime),
+}
+
+impl TickRecorder {
+  pub(crate) fn create(path: &Path, tick_rate: u32) -> anyhow::Result<Self> {
+    let mut file = LineWriter::new(
+      File::create(path).map_err(|error| anyhow!("Couldn't create the tick recording {}: {error}", path.display()))?,
+    );
+
+    writeln!(file, "{HEADER} {tick_rate}")?;
+
+    Ok(Self {
+      file,
+      started_at: (Instant::now(), SystemTime::now()),
+    })
+  }
+}
+
+impl fmt::Debug for TickRecorder {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("TickRecorder").field("started_at", &self.started_at).finish()
+  }
+}
+
+impl TickListener for TickRecorder {
+  ///Writes the tick to the recording, the recorder is removed if the recording can't be written to.
+  fn tick(&mut self, time: Time) -> bool {
+    let (started_at, started_at_wall_clock) = self.started_at;
+    let happened_at = started_at_wall_clock + started_at.elapsed();
+    let since_epoch = happened_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+
+    if let Err(error) = writeln!(self.file, "{time} {since_epoch}") {
+      log_warn!("The clock stopped recording its ticks as the recording couldn't be written to: {error}");
+
+      return false;
+    }
+
+    true
+  }
+}
@@ -0,0 +1,169 @@
+use crate::{Clock, Time, TimeReceiver};
+use anyhow::anyhow;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+///A collection of named clocks that can be started and stopped as a group.
+///
+///Applications with several subsystems can keep every clock in one place and hand out
+///[`time receivers`](crate::TimeReceiver) by name.
+///
+///# Usage
+///
+///```
+///use thread_clock::ClockRegistry;
+///
+///let mut registry = ClockRegistry::new();
+///
+///registry.create("physics", 1).unwrap();
+///registry.create("network", 5).unwrap();
+///registry.start_all();
+///
+///let mut physics_receiver = registry.receiver("physics").unwrap();
+///
+///let time = physics_receiver.time();
+///
+///assert_eq!(time, 0);
+///
+///let final_times = registry.stop_all();
+///
+///assert!(final_times["physics"].is_ok());
+///```
+pub struct ClockRegistry {
+  clocks: HashMap<String, Clock>,
+}
+
+impl ClockRegistry {
+  ///Creates an empty registry.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  ///Creates a new clock with the given tickrate and stores it under the name.
+  ///
+  ///An error is returned if a clock with that name already exists.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::ClockRegistry;
+  ///
+  ///let mut registry = ClockRegistry::new();
+  ///
+  ///registry.create("physics", 16).unwrap();
+  ///
+  ///assert!(registry.create("physics", 16).is_err());
+  ///```
+  pub fn create(&mut self, name: &str, tick_rate: u32) -> anyhow::Result<&mut Clock> {
+    let clock = Clock::custom(tick_rate)?;
+
+    self.insert(name, clock)
+  }
+
+  ///Stores an already created clock under the name.
+  ///
+  ///An error is returned if a clock with that name already exists.
+  pub fn insert(&mut self, name: &str, clock: Clock) -> anyhow::Result<&mut Clock> {
+    if self.clocks.contains_key(name) {
+      return Err(anyhow!("A clock named '{name}' already exists"));
+    }
+
+    Ok(self.clocks.entry(name.to_string()).or_insert(clock))
+  }
+
+  ///Returns a reference to the clock with the given name.
+  pub fn get(&self, name: &str) -> Option<&Clock> {
+    self.clocks.get(name)
+  }
+
+  ///Returns a mutable reference to the clock with the given name.
+  pub fn get_mut(&mut self, name: &str) -> Option<&mut Clock> {
+    self.clocks.get_mut(name)
+  }
+
+  ///Removes the clock from the registry and returns it.
+  pub fn remove(&mut self, name: &str) -> Option<Clock> {
+    self.clocks.remove(name)
+  }
+
+  ///Returns the names of every clock in the registry.
+  pub fn names(&self) -> impl Iterator<Item = &str> {
+    self.clocks.keys().map(String::as_str)
+  }
+
+  ///Returns the amount of clocks in the registry.
+  pub fn len(&self) -> usize {
+    self.clocks.len()
+  }
+
+  ///Returns true if the registry has no clocks.
+  pub fn is_empty(&self) -> bool {
+    self.clocks.is_empty()
+  }
+
+  ///Creates a [`time receiver`](crate::TimeReceiver) for the clock with the given name.
+  ///
+  ///An error is returned if no clock with that name exists.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::ClockRegistry;
+  ///
+  ///let mut registry = ClockRegistry::new();
+  ///
+  ///registry.create("physics", 1).unwrap();
+  ///
+  ///assert!(registry.receiver("physics").is_ok());
+  ///assert!(registry.receiver("rendering").is_err());
+  ///```
+  pub fn receiver(&self, name: &str) -> anyhow::Result<TimeReceiver> {
+    match self.clocks.get(name) {
+      Some(clock) => Ok(clock.spawn_receiver()),
+      None => Err(anyhow!("There is no clock named '{name}'")),
+    }
+  }
+
+  ///Starts the clock with the given name.
+  ///
+  ///An error is returned if no clock with that name exists.
+  pub fn start(&mut self, name: &str) -> anyhow::Result<()> {
+    match self.clocks.get_mut(name) {
+      Some(clock) => {
+        clock.start();
+
+        Ok(())
+      }
+
+      None => Err(anyhow!("There is no clock named '{name}'")),
+    }
+  }
+
+  ///Stops the clock with the given name, removes it from the registry, and returns the final time.
+  ///
+  ///An error is returned if no clock with that name exists or the clock hasn't started.
+  pub fn stop(&mut self, name: &str) -> anyhow::Result<Time> {
+    match self.clocks.remove(name) {
+      Some(clock) => clock.stop(),
+      None => Err(anyhow!("There is no clock named '{name}'")),
+    }
+  }
+
+  ///Starts every clock in the registry.
+  ///
+  ///Clocks that are already running are left untouched.
+  pub fn start_all(&mut self) {
+    self.clocks.values_mut().for_each(Clock::start);
+  }
+
+  ///Stops every clock in the registry and returns the final time of each one by name.
+  ///
+  ///The registry is empty afterwards.
+  pub fn stop_all(&mut self) -> HashMap<String, anyhow::Result<Time>> {
+    self
+      .clocks
+      .drain()
+      .map(|(name, clock)| (name, clock.stop()))
+      .collect()
+  }
+}
@@ -0,0 +1,240 @@
+use crate::{affinity, priority};
+use crate::ClockError;
+use anyhow::anyhow;
+use std::future::Future;
+use std::sync::{mpsc, Arc, OnceLock};
+use std::thread;
+use tokio::runtime::{Builder, Handle, Runtime, RuntimeFlavor};
+use tokio::sync::oneshot::{self, Sender as OneSender};
+use tokio::task::JoinHandle;
+
+///The runtime shared by every clock that doesn't need one of its own, created by the first of them.
+static SHARED_RUNTIME: OnceLock<Arc<ClockRuntime>> = OnceLock::new();
+
+#[derive(Debug)]
+///The runtime the clock task runs on and receivers block on.
+///
+///Blocking works from both synchronous code and from within a multi-threaded tokio runtime,
+///and the runtime can be dropped from within an asynchronous context.
+pub(crate) struct ClockRuntime {
+  handle: Handle,
+  driver: RuntimeDriver,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+///How the thread driving a dedicated runtime is set up.
+pub(crate) struct ThreadOptions {
+  ///Gives the thread real-time scheduling priority.
+  pub(crate) realtime_priority: bool,
+
+  ///Pins the thread to a CPU core.
+  pub(crate) core: Option<usize>,
+}
+
+#[derive(Debug)]
+///What keeps the runtime of a clock running.
+enum RuntimeDriver {
+  ///A current thread runtime driven by its own thread until the sender is dropped.
+  Thread { _shutdown: OneSender<()> },
+
+  ///A multi-threaded runtime with a worker for every core.
+  MultiThread(Option<Runtime>),
+
+  ///The runtime the clock was created in or given, which is kept running by whatever owns it.
+  Enclosing,
+}
+
+impl ClockRuntime {
+  ///Creates a runtime driven by a single dedicated thread.
+  ///
+  ///An error is returned if the thread couldn't be set up the way the options ask for.
+  pub(crate) fn new(thread_options: ThreadOptions) -> anyhow::Result<Self> {
+    let mut builder = Builder::new_current_thread();
+
+    builder.enable_time();
+
+    // a timerfd, kqueue or the sockets ticks are published on are waited on through the runtime's IO driver
+    #[cfg(any(unix, feature = "websocket", feature = "net"))]
+    builder.enable_io();
+
+    let runtime = builder.build()?;
+    let handle = runtime.handle().clone();
+    let (shutdown, shutdown_receiver) = oneshot::channel::<()>();
+    let (setup_sender, setup_receiver) = mpsc::sync_channel(1);
+
+    thread::Builder::new()
+      .name("thread-clock".to_string())
+      .spawn(move || {
+        let setup = set_up_thread(thread_options);
+        let is_set_up = setup.is_ok();
+
+        let _ = setup_sender.send(setup);
+
+        if is_set_up {
+          runtime.block_on(async {
+            let _ = shutdown_receiver.await;
+          });
+        }
+      })?;
+
+    setup_receiver
+      .recv()
+      .map_err(|_| anyhow!("The clock thread exited before it was set up"))??;
+
+    Ok(Self {
+      handle,
+      driver: RuntimeDriver::Thread { _shutdown: shutdown },
+    })
+  }
+
+  ///Returns the runtime shared between clocks, creating it if this is the first clock to use it.
+  pub(crate) fn shared() -> anyhow::Result<Arc<Self>> {
+    if let Some(runtime) = SHARED_RUNTIME.get() {
+      return Ok(Arc::clone(runtime));
+    }
+
+    // if another clock created the runtime first this one is dropped, which shuts its thread down
+    let runtime = Arc::new(Self::new(ThreadOptions::default())?);
+
+    Ok(Arc::clone(SHARED_RUNTIME.get_or_init(|| runtime)))
+  }
+
+  ///Creates a runtime driven by a single dedicated thread that isn't set up in any particular way.
+  pub(crate) fn dedicated() -> anyhow::Result<Arc<Self>> {
+    Ok(Arc::new(Self::new(ThreadOptions::default())?))
+  }
+
+  ///Creates a multi-threaded runtime.
+  pub(crate) fn multi_threaded() -> anyhow::Result<Arc<Self>> {
+    let runtime = Runtime::new()?;
+
+    Ok(Arc::new(Self {
+      handle: runtime.handle().clone(),
+      driver: RuntimeDriver::MultiThread(Some(runtime)),
+    }))
+  }
+
+  ///Uses the runtime the clock is being created in, along with its timer.
+  ///
+  ///An error is returned if there isn't one.
+  pub(crate) fn enclosing() -> anyhow::Result<Self> {
+    let handle = Handle::try_current()
+      .map_err(|_| anyhow!("A clock on the enclosing runtime has to be built from within a tokio runtime"))?;
+
+    Ok(Self::owned_elsewhere(handle))
+  }
+
+  ///Uses a runtime that's kept running by whatever owns it.
+  pub(crate) fn owned_elsewhere(handle: Handle) -> Self {
+    Self {
+      handle,
+      driver: RuntimeDriver::Enclosing,
+    }
+  }
+
+  pub(crate) fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+  where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+  {
+    self.handle.spawn(future)
+  }
+
+  ///Blocks the current thread until the future completes.
+  ///
+  ///Inside of a multi-threaded tokio runtime the worker thread is handed off with
+  ///[`block_in_place`](tokio::task::block_in_place) before blocking. A current thread runtime
+  ///can't block at all, so [`ClockError::CalledFromAsyncContext`](crate::ClockError::CalledFromAsyncContext)
+  ///is returned instead.
+  pub(crate) fn block_on<F: Future>(&self, future: F) -> anyhow::Result<F::Output> {
+    match Handle::try_current() {
+      Err(_) => Ok(self.handle.block_on(future)),
+      Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+        Ok(tokio::task::block_in_place(|| self.handle.block_on(future)))
+      }
+      Ok(_) => Err(ClockError::CalledFromAsyncContext.into()),
+    }
+  }
+}
+
+#[derive(Debug)]
+///A runtime that's only created the first time something runs on it, which for a clock is when it's
+///started or first waited on, so clocks that are built but never used don't cost a thread.
+pub(crate) struct LazyRuntime {
+  runtime: OnceLock<Arc<ClockRuntime>>,
+  create: fn() -> anyhow::Result<Arc<ClockRuntime>>,
+}
+
+impl LazyRuntime {
+  ///Creates the runtime with the function once it's first needed.
+  pub(crate) fn new(create: fn() -> anyhow::Result<Arc<ClockRuntime>>) -> Self {
+    Self {
+      runtime: OnceLock::new(),
+      create,
+    }
+  }
+
+  ///Uses a runtime that has already been created.
+  pub(crate) fn created(runtime: ClockRuntime) -> Self {
+    Self {
+      runtime: OnceLock::from(Arc::new(runtime)),
+      create: || Err(anyhow!("A created runtime is never created again")),
+    }
+  }
+
+  ///Returns the runtime, creating it if nothing has run on it yet.
+  ///
+  ///An error is returned if the runtime couldn't be created, which only happens if its thread couldn't be spawned.
+  pub(crate) fn get(&self) -> anyhow::Result<&Arc<ClockRuntime>> {
+    if let Some(runtime) = self.runtime.get() {
+      return Ok(runtime);
+    }
+
+    // if another thread created the runtime first this one is dropped, which shuts its thread down
+    let runtime = (self.create)().map_err(|error| anyhow!("Couldn't create the runtime of the clock: {error}"))?;
+
+    Ok(self.runtime.get_or_init(|| runtime))
+  }
+
+  ///Spawns the future on the runtime, creating it if it hasn't been yet.
+  ///
+  ///An error is returned if the runtime couldn't be created.
+  pub(crate) fn spawn<F>(&self, future: F) -> anyhow::Result<JoinHandle<F::Output>>
+  where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+  {
+    Ok(self.get()?.spawn(future))
+  }
+
+  ///Blocks the current thread until the future completes, see [`ClockRuntime::block_on()`].
+  pub(crate) fn block_on<F: Future>(&self, future: F) -> anyhow::Result<F::Output> {
+    self.get()?.block_on(future)
+  }
+}
+
+///Sets up the thread driving a dedicated runtime from within it.
+fn set_up_thread(thread_options: ThreadOptions) -> anyhow::Result<()> {
+  if let Some(core) = thread_options.core {
+    affinity::pin_to_core(core).map_err(|error| anyhow!("Couldn't pin the clock thread to core {core}: {error}"))?;
+  }
+
+  if thread_options.realtime_priority {
+    priority::raise_to_realtime()
+      .map_err(|error| anyhow!("Couldn't give the clock thread real-time priority: {error}"))?;
+  }
+
+  Ok(())
+}
+
+impl Drop for ClockRuntime {
+  ///A dedicated thread shuts its runtime down once its shutdown sender is dropped.
+  fn drop(&mut self) {
+    if let RuntimeDriver::MultiThread(runtime) = &mut self.driver {
+      if let Some(runtime) = runtime.take() {
+        // a runtime can't be dropped normally from within an asynchronous context
+        runtime.shutdown_background();
+      }
+    }
+  }
+}
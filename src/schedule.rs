@@ -0,0 +1,66 @@
+use crate::Time;
+use crate::sync::Mutex;
+use std::time::Duration;
+
+///A change to the tickrate that starts on a tick, ramping linearly to its final rate over a span of ticks.
+///
+///An instant change is a ramp whose span is empty.
+#[derive(Debug, Clone, Copy)]
+struct RateChange {
+  start_tick: Time,
+  end_tick: Time,
+  from_rate: f64,
+  to_rate: f64,
+}
+
+impl RateChange {
+  ///Returns the rate in milliseconds that follows the tick.
+  fn rate_after(&self, tick: Time) -> f64 {
+    if tick >= self.end_tick {
+      return self.to_rate;
+    }
+
+    let progress = (tick - self.start_tick) as f64 / (self.end_tick - self.start_tick) as f64;
+
+    self.from_rate + (self.to_rate - self.from_rate) * progress
+  }
+}
+
+#[derive(Debug, Default)]
+///The tickrate changes scheduled on a clock, shared between the clock and its task.
+pub(crate) struct RateSchedule {
+  ///Sorted by the tick they start on, changes scheduled later come after others starting on the same tick.
+  changes: Mutex<Vec<RateChange>>,
+}
+
+impl RateSchedule {
+  ///Schedules the clock's ticks to be `from_rate` milliseconds apart after `start_tick`, ramping linearly
+  ///to `to_rate` by `end_tick`.
+  pub(crate) fn schedule(&self, start_tick: Time, end_tick: Time, from_rate: u32, to_rate: u32) {
+    let mut changes = self.changes.lock().unwrap();
+    let index = changes.partition_point(|change| change.start_tick <= start_tick);
+
+    changes.insert(
+      index,
+      RateChange {
+        start_tick,
+        end_tick,
+        from_rate: from_rate.into(),
+        to_rate: to_rate.into(),
+      },
+    );
+  }
+
+  ///Returns how long the wait after the tick lasts, if a change has started by then.
+  ///
+  ///The latest change to have started overrides every one before it, so those are dropped.
+  pub(crate) fn tick_length_after(&self, tick: Time) -> Option<Duration> {
+    let mut changes = self.changes.lock().unwrap();
+    let started_changes = changes.partition_point(|change| change.start_tick <= tick);
+    let current_change = started_changes.checked_sub(1)?;
+
+    changes.drain(..current_change);
+
+    Some(Duration::from_secs_f64(changes[0].rate_after(tick) / 1000.0))
+  }
+}
@@ -0,0 +1,82 @@
+use crate::{Clock, Time};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+///The callback a [`ScopedClock`] reports the final time of its clock to.
+type OnStop = Box<dyn FnOnce(anyhow::Result<Time>) + Send>;
+
+///A guard that stops its clock once it's dropped, created with [`Clock::scoped()`](crate::Clock::scoped()).
+///
+///The guard dereferences to the clock, so it's used the same way the clock would be. Once the guard goes
+///out of scope, whether that's from the end of a block, an early return or a panic, the clock is stopped
+///and the result of [`stop()`](crate::Clock::stop()) is passed to the callback.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///use std::sync::mpsc;
+///
+///let (final_time_sender, final_time_receiver) = mpsc::channel();
+///
+///{
+///  let mut clock = Clock::custom(1).unwrap().scoped(move |final_time| {
+///    final_time_sender.send(final_time.unwrap()).unwrap();
+///  });
+///
+///  clock.start();
+///  clock.wait_for_time(5).unwrap();
+///}
+///
+///assert_eq!(final_time_receiver.recv().unwrap(), 6);
+///```
+pub struct ScopedClock {
+  clock: Option<Clock>,
+  on_stop: Option<OnStop>,
+}
+
+impl ScopedClock {
+  pub(crate) fn new(clock: Clock, on_stop: impl FnOnce(anyhow::Result<Time>) + Send + 'static) -> Self {
+    Self {
+      clock: Some(clock),
+      on_stop: Some(Box::new(on_stop)),
+    }
+  }
+
+  ///Takes the clock back out of the guard without stopping it, the callback is never called.
+  pub fn into_inner(mut self) -> Clock {
+    self.on_stop = None;
+
+    // the clock is only ever taken out here or when the guard is dropped
+    self.clock.take().unwrap()
+  }
+}
+
+impl Deref for ScopedClock {
+  type Target = Clock;
+
+  fn deref(&self) -> &Clock {
+    self.clock.as_ref().unwrap()
+  }
+}
+
+impl DerefMut for ScopedClock {
+  fn deref_mut(&mut self) -> &mut Clock {
+    self.clock.as_mut().unwrap()
+  }
+}
+
+impl fmt::Debug for ScopedClock {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("ScopedClock").field("clock", &self.clock).finish()
+  }
+}
+
+impl Drop for ScopedClock {
+  ///Stops the clock and reports its final time to the callback.
+  fn drop(&mut self) {
+    if let (Some(clock), Some(on_stop)) = (self.clock.take(), self.on_stop.take()) {
+      on_stop(clock.stop());
+    }
+  }
+}
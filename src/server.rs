@@ -0,0 +1,145 @@
+use crate::logging::{log_debug, log_warn};
+use crate::publisher::TickPublisher;
+use crate::runtime::{ClockRuntime, ThreadOptions};
+use crate::{Clock, ClockMessage};
+use futures_util::{SinkExt, StreamExt};
+use std::net::{SocketAddr, ToSocketAddrs};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+///Serves the ticks of a clock over WebSocket, so browser dashboards and external tools can follow the
+///same ticks as the clock. Needs the `websocket` feature.
+///
+///Every client that connects is sent a text frame for each tick, `{"tick":5}`, and `{"stopped":5}`
+///with the final time whenever the clock stops. A client that can't keep up skips ahead to the ticks
+///that are still queued for it.
+///The server runs on a thread of its own so its connections can't hold the clock's ticks up, and
+///stops serving once it's dropped.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, ClockServer};
+///
+///let mut clock = Clock::custom(16).unwrap();
+///let server = ClockServer::bind("127.0.0.1:0", &clock).unwrap();
+///
+///println!("serving ticks on ws://{}", server.local_addr());
+///
+///clock.start();
+///```
+pub struct ClockServer {
+  local_addr: SocketAddr,
+  _publisher: TickPublisher,
+  accept_task: JoinHandle<()>,
+  // dropped last, which shuts down the connections along with the thread they run on
+  _runtime: ClockRuntime,
+}
+
+impl ClockServer {
+  ///Starts serving the ticks of the clock on the address, whether or not the clock has started.
+  ///
+  ///Returns an error if the address couldn't be bound, or the server's thread couldn't be created.
+  pub fn bind(addr: impl ToSocketAddrs, clock: &Clock) -> anyhow::Result<Self> {
+    let listener = std::net::TcpListener::bind(addr)?;
+    let local_addr = listener.local_addr()?;
+
+    listener.set_nonblocking(true)?;
+
+    let runtime = ClockRuntime::new(ThreadOptions::default())?;
+    let publisher = TickPublisher::new(clock);
+    let subscriptions = publisher.subscriptions();
+
+    let accept_task = runtime.spawn(async move {
+      let listener = match TcpListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(error) => {
+          log_warn!("The clock server couldn't listen on {local_addr}: {error}");
+
+          return;
+        }
+      };
+
+      loop {
+        match listener.accept().await {
+          Ok((stream, peer)) => {
+            log_debug!("The clock server accepted a connection from {peer}");
+            tokio::spawn(serve_connection(stream, subscriptions.subscribe()));
+          }
+          Err(error) => log_warn!("The clock server couldn't accept a connection: {error}"),
+        }
+      }
+    });
+
+    Ok(Self {
+      local_addr,
+      _publisher: publisher,
+      accept_task,
+      _runtime: runtime,
+    })
+  }
+
+  ///Returns the address the server is listening on, which has the port that was picked if the server
+  ///was bound to port 0.
+  pub fn local_addr(&self) -> SocketAddr {
+    self.local_addr
+  }
+}
+
+impl std::fmt::Debug for ClockServer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ClockServer").field("local_addr", &self.local_addr).finish()
+  }
+}
+
+impl Drop for ClockServer {
+  fn drop(&mut self) {
+    self.accept_task.abort();
+  }
+}
+
+///Sends the frames of a clock to a client until either of them stops.
+async fn serve_connection(stream: TcpStream, mut frames: broadcast::Receiver<ClockMessage>) {
+  let mut websocket = match tokio_tungstenite::accept_async(stream).await {
+    Ok(websocket) => websocket,
+    Err(error) => {
+      log_debug!("A connection to the clock server failed its handshake: {error}");
+
+      return;
+    }
+  };
+
+  loop {
+    tokio::select! {
+      frame = frames.recv() => {
+        let message = match frame {
+          Ok(message) => message,
+          Err(RecvError::Lagged(_)) => continue,
+          Err(RecvError::Closed) => break,
+        };
+
+        if websocket.send(Message::text(frame_text(message))).await.is_err() {
+          break;
+        }
+      }
+      incoming = websocket.next() => {
+        // anything the client sends is ignored until it closes the connection
+        if !matches!(incoming, Some(Ok(message)) if !message.is_close()) {
+          break;
+        }
+      }
+    }
+  }
+
+  let _ = websocket.close(None).await;
+}
+
+///Returns the JSON text a message is sent to clients as.
+fn frame_text(message: ClockMessage) -> String {
+  match message {
+    ClockMessage::Tick(time) => format!("{{\"tick\":{time}}}"),
+    ClockMessage::Stopped(final_time) => format!("{{\"stopped\":{final_time}}}"),
+  }
+}
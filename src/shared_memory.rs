@@ -0,0 +1,492 @@
+use crate::listener::TickListener;
+use crate::{Clock, ClockError, ClockMessage, Time};
+use anyhow::anyhow;
+use std::ffi::CString;
+use std::io;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+///Marks a segment as one a leader has finished setting up, "TCK" and the version of its layout.
+const MAGIC: u32 = u32::from_be_bytes(*b"TCK1");
+
+const TICKING: u32 = 0;
+const STOPPED: u32 = 1;
+const CLOSED: u32 = 2;
+
+///How long a receiver waits on the segment before checking that the leader process is still alive.
+const LEADER_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+///How many times a receiver spins on a message the leader is writing before checking that the leader
+///is still alive, as one that exits mid-write leaves the message unfinished forever.
+const WRITE_SPINS: u32 = 1000;
+
+///The layout of the shared memory segment, which every process maps.
+///
+///The sequence is a seqlock: the leader makes it odd while it writes a message and even once it's done,
+///and it's the word receivers wait on with a futex.
+#[repr(C)]
+struct SharedTicks {
+  magic: AtomicU32,
+  sequence: AtomicU32,
+  state: AtomicU32,
+  leader_pid: AtomicU32,
+  time: AtomicU64,
+}
+
+///A mapping of the segment into this process, which is unmapped once it's dropped.
+struct Segment {
+  ticks: NonNull<SharedTicks>,
+}
+
+// SAFETY: the segment is only accessed through atomics, which any thread can use at the same time
+unsafe impl Send for Segment {}
+// SAFETY: as above
+unsafe impl Sync for Segment {}
+
+impl Segment {
+  ///Opens the segment with the name, creating it if this process is its leader.
+  fn open(name: &CString, create: bool) -> io::Result<Self> {
+    let flags = match create {
+      true => libc::O_RDWR | libc::O_CREAT | libc::O_EXCL,
+      false => libc::O_RDWR,
+    };
+
+    // SAFETY: the name is a valid C string, the descriptor returned is closed before this returns
+    let fd = unsafe { libc::shm_open(name.as_ptr(), flags, 0o600) };
+
+    if fd == -1 {
+      return Err(io::Error::last_os_error());
+    }
+
+    let size = std::mem::size_of::<SharedTicks>();
+    // SAFETY: the descriptor was just opened, a new segment is sized and an existing one is checked to be
+    // large enough before it's mapped
+    let mapped = unsafe {
+      let sized = match create {
+        true if libc::ftruncate(fd, size as libc::off_t) == -1 => Err(io::Error::last_os_error()),
+        true => Ok(()),
+        // a segment its leader hasn't sized yet, or any other that's too small, faults once it's accessed
+        false => check_size(fd, size),
+      };
+
+      sized.and_then(|()| {
+        match libc::mmap(ptr::null_mut(), size, libc::PROT_READ | libc::PROT_WRITE, libc::MAP_SHARED, fd, 0) {
+          libc::MAP_FAILED => Err(io::Error::last_os_error()),
+          mapped => Ok(mapped),
+        }
+      })
+    };
+
+    // SAFETY: the descriptor isn't used again, the mapping stays valid after it's closed
+    unsafe { libc::close(fd) };
+
+    let ticks = mapped.inspect_err(|_| {
+      if create {
+        // SAFETY: the segment was created by this call and nothing has mapped it yet
+        unsafe { libc::shm_unlink(name.as_ptr()) };
+      }
+    })?;
+
+    Ok(Self {
+      ticks: NonNull::new(ticks.cast()).ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))?,
+    })
+  }
+
+  fn ticks(&self) -> &SharedTicks {
+    // SAFETY: the mapping is as large as the layout and lives as long as self, a new segment is zeroed
+    unsafe { self.ticks.as_ref() }
+  }
+}
+
+impl Drop for Segment {
+  fn drop(&mut self) {
+    // SAFETY: the mapping was made with this size and nothing refers to it after self is dropped
+    unsafe { libc::munmap(self.ticks.as_ptr().cast(), std::mem::size_of::<SharedTicks>()) };
+  }
+}
+
+///Returns an error if the segment behind the descriptor is smaller than the size.
+fn check_size(fd: libc::c_int, size: usize) -> io::Result<()> {
+  let mut stat = std::mem::MaybeUninit::<libc::stat>::uninit();
+
+  // SAFETY: the descriptor is open and the stat is only read once fstat has filled it in
+  let stat = unsafe {
+    if libc::fstat(fd, stat.as_mut_ptr()) == -1 {
+      return Err(io::Error::last_os_error());
+    }
+
+    stat.assume_init()
+  };
+
+  if stat.st_size < size as libc::off_t {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidData,
+      format!("the segment is {} bytes, a shared clock needs {size}", stat.st_size),
+    ));
+  }
+
+  Ok(())
+}
+
+///Returns the name a segment is opened with, which has to be a single path component.
+fn segment_name(name: &str) -> anyhow::Result<CString> {
+  if name.is_empty() || name.contains('/') {
+    return Err(anyhow!("A shared clock needs a name without slashes, got '{name}'"));
+  }
+
+  CString::new(format!("/{name}")).map_err(|_| anyhow!("A shared clock's name can't contain a nul byte"))
+}
+
+///Removes the segment with the name if it was set up by a leader that has exited without closing it,
+///returning true if it was removed.
+fn remove_stale_segment(name: &CString) -> bool {
+  let Ok(segment) = Segment::open(name, false) else {
+    return false;
+  };
+  let ticks = segment.ticks();
+  let is_stale =
+    ticks.magic.load(Ordering::SeqCst) == MAGIC && leader_has_exited(ticks.leader_pid.load(Ordering::SeqCst));
+
+  if is_stale {
+    // SAFETY: the name is a valid C string, receivers still following the segment keep it until they unmap it
+    unsafe { libc::shm_unlink(name.as_ptr()) };
+  }
+
+  is_stale
+}
+
+///Returns true if no process with the pid exists anymore.
+fn leader_has_exited(leader_pid: u32) -> bool {
+  // SAFETY: a signal of 0 only checks whether the process exists
+  let signaled = unsafe { libc::kill(leader_pid as libc::pid_t, 0) } != -1;
+
+  !signaled && io::Error::last_os_error().raw_os_error() == Some(libc::ESRCH)
+}
+
+///The leader's side of a segment, which only it writes to.
+struct Leader {
+  segment: Segment,
+  closed: bool,
+}
+
+impl Leader {
+  fn write(&self, state: u32, time: Time) {
+    let ticks = self.segment.ticks();
+    let sequence = ticks.sequence.fetch_add(1, Ordering::SeqCst);
+
+    ticks.time.store(time, Ordering::SeqCst);
+    ticks.state.store(state, Ordering::SeqCst);
+    ticks.sequence.store(sequence.wrapping_add(2), Ordering::SeqCst);
+
+    futex_wake(&ticks.sequence);
+  }
+}
+
+///Keeps the ticks of a clock in a shared memory segment for [`shared time receivers`](crate::SharedTimeReceiver)
+///in other processes, returned by [`Clock::publish_shared_memory()`](crate::Clock::publish_shared_memory()).
+///Only available on Linux.
+///
+///Receivers are told the clock was closed and the segment is removed once this is dropped.
+pub struct SharedMemoryPublisher {
+  name: CString,
+  leader: Arc<Mutex<Leader>>,
+}
+
+impl SharedMemoryPublisher {
+  pub(crate) fn create(name: &str, clock: &Clock) -> anyhow::Result<Self> {
+    let name = segment_name(name)?;
+    let segment = match Segment::open(&name, true) {
+      Err(error) if error.kind() == io::ErrorKind::AlreadyExists && remove_stale_segment(&name) => {
+        Segment::open(&name, true)
+      }
+      result => result,
+    }
+    .map_err(|error| anyhow!("Couldn't create the shared clock {}: {error}", name.to_string_lossy()))?;
+    let ticks = segment.ticks();
+
+    ticks.leader_pid.store(std::process::id(), Ordering::SeqCst);
+    ticks.magic.store(MAGIC, Ordering::SeqCst);
+
+    let leader = Arc::new(Mutex::new(Leader { segment, closed: false }));
+
+    clock.add_tick_listener(SharedTicksWriter {
+      leader: Arc::clone(&leader),
+    });
+
+    Ok(Self { name, leader })
+  }
+
+  ///Returns the name of the shared memory segment, with the leading slash it's opened with.
+  pub fn name(&self) -> &str {
+    self.name.to_str().unwrap_or_default()
+  }
+}
+
+impl std::fmt::Debug for SharedMemoryPublisher {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SharedMemoryPublisher").field("name", &self.name).finish()
+  }
+}
+
+impl Drop for SharedMemoryPublisher {
+  fn drop(&mut self) {
+    let mut leader = self.leader.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let final_time = leader.segment.ticks().time.load(Ordering::SeqCst);
+
+    leader.write(CLOSED, final_time);
+    leader.closed = true;
+
+    // SAFETY: the name is a valid C string, processes that mapped the segment keep it until they unmap it
+    unsafe { libc::shm_unlink(self.name.as_ptr()) };
+  }
+}
+
+///Writes the ticks of a clock to its shared memory segment.
+struct SharedTicksWriter {
+  leader: Arc<Mutex<Leader>>,
+}
+
+impl std::fmt::Debug for SharedTicksWriter {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SharedTicksWriter").finish_non_exhaustive()
+  }
+}
+
+impl TickListener for SharedTicksWriter {
+  ///Writes the tick to the segment, this is removed once its publisher is dropped.
+  fn tick(&mut self, time: Time) -> bool {
+    let leader = self.leader.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if !leader.closed {
+      leader.write(TICKING, time);
+    }
+
+    !leader.closed
+  }
+
+  fn stop(&mut self, final_time: Time) {
+    let leader = self.leader.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if !leader.closed {
+      leader.write(STOPPED, final_time);
+    }
+  }
+}
+
+///Follows the ticks of a clock in another process through the shared memory segment its leader keeps
+///them in, published with [`Clock::publish_shared_memory()`](crate::Clock::publish_shared_memory()).
+///Only available on Linux.
+///
+///The segment only holds the newest tick, so waiting always returns the tick after the one that was
+///current when it was called, the same as a [`time receiver`](crate::TimeReceiver) skipping old ticks.
+///Once the clock stops, waiting returns [`ClockError::Stopped`](crate::ClockError::Stopped) with its
+///final time, and keeps following the clock if it's started again.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, SharedTimeReceiver};
+///
+///let name = format!("thread-clock-doc-{}", std::process::id());
+///let mut clock = Clock::custom(1).unwrap();
+///let _publisher = clock.publish_shared_memory(&name).unwrap();
+///
+///// in another process
+///let mut shared_receiver = SharedTimeReceiver::open(&name).unwrap();
+///
+///clock.start();
+///
+///let time = shared_receiver.safe_time().unwrap();
+///
+///assert!(shared_receiver.wait_until_at_least(time + 5).unwrap() >= time + 5);
+///```
+pub struct SharedTimeReceiver {
+  segment: Segment,
+}
+
+impl SharedTimeReceiver {
+  ///Opens the shared memory segment of the clock with the name.
+  ///
+  ///Returns an error if there's no segment with the name, or its leader hasn't finished setting it up.
+  pub fn open(name: &str) -> anyhow::Result<Self> {
+    let name = segment_name(name)?;
+    let segment = Segment::open(&name, false)
+      .map_err(|error| anyhow!("Couldn't open the shared clock {}: {error}", name.to_string_lossy()))?;
+
+    if segment.ticks().magic.load(Ordering::SeqCst) != MAGIC {
+      return Err(anyhow!("The shared clock {} isn't set up by a leader", name.to_string_lossy()));
+    }
+
+    Ok(Self { segment })
+  }
+
+  ///Waits for the next tick and returns the time.
+  ///
+  ///If any problems arise when this is called the receiver will panic, use
+  ///[`safe_time()`](crate::SharedTimeReceiver::safe_time()) for error handling.
+  pub fn time(&mut self) -> Time {
+    self.safe_time().unwrap()
+  }
+
+  ///Waits for the next tick and returns the time.
+  ///
+  ///An error is returned if the clock stopped, or its leader closed the segment or exited.
+  pub fn safe_time(&mut self) -> anyhow::Result<Time> {
+    let (sequence, message) = self.read()?;
+
+    if let ClockMessage::Stopped(final_time) = message {
+      return Err(ClockError::Stopped(final_time).into());
+    }
+
+    self.next_tick(sequence).map(|(_, time)| time)
+  }
+
+  ///Waits for the next tick.
+  pub fn wait_for_tick(&mut self) -> anyhow::Result<()> {
+    self.safe_time().map(|_| ())
+  }
+
+  ///Waits until the clock reaches the input time.
+  ///
+  ///An error is returned if the time has already occurred, or something went wrong with the clock.
+  pub fn wait_for_time(&mut self, time: Time) -> anyhow::Result<()> {
+    if self.current_time()? >= time {
+      return Err(ClockError::TimeHasOccurred.into());
+    }
+
+    self.wait_until_at_least(time).map(|_| ())
+  }
+
+  ///Waits until the clock has reached at least the input time and returns the current time.
+  ///
+  ///A time that has already occurred isn't an error, the current time is returned right away instead.
+  pub fn wait_until_at_least(&mut self, time: Time) -> anyhow::Result<Time> {
+    let (mut sequence, message) = self.read()?;
+
+    match message {
+      ClockMessage::Stopped(final_time) => Err(ClockError::Stopped(final_time).into()),
+      ClockMessage::Tick(current_time) if current_time >= time => Ok(current_time),
+      ClockMessage::Tick(_) => loop {
+        let (new_sequence, current_time) = self.next_tick(sequence)?;
+
+        if current_time >= time {
+          return Ok(current_time);
+        }
+
+        sequence = new_sequence;
+      },
+    }
+  }
+
+  ///Returns the time of the newest tick in the segment without waiting.
+  ///
+  ///An error is returned if the clock stopped, or its leader closed the segment.
+  pub fn current_time(&self) -> anyhow::Result<Time> {
+    match self.read()?.1 {
+      ClockMessage::Tick(time) => Ok(time),
+      ClockMessage::Stopped(final_time) => Err(ClockError::Stopped(final_time).into()),
+    }
+  }
+
+  ///Waits for the message after the one with the sequence, returning the tick's sequence and time, or
+  ///an error if the clock stops first.
+  fn next_tick(&self, sequence: u32) -> anyhow::Result<(u32, Time)> {
+    let ticks = self.segment.ticks();
+
+    loop {
+      if ticks.sequence.load(Ordering::SeqCst) == sequence {
+        futex_wait(&ticks.sequence, sequence, LEADER_CHECK_INTERVAL);
+        self.check_leader()?;
+
+        continue;
+      }
+
+      match self.read()? {
+        (new_sequence, _) if new_sequence == sequence => continue,
+        (new_sequence, ClockMessage::Tick(time)) => return Ok((new_sequence, time)),
+        (_, ClockMessage::Stopped(final_time)) => return Err(ClockError::Stopped(final_time).into()),
+      }
+    }
+  }
+
+  ///Reads the newest message in the segment along with its sequence, retrying while the leader is
+  ///writing one.
+  ///
+  ///An error is returned if the leader exited in the middle of writing a message.
+  fn read(&self) -> anyhow::Result<(u32, ClockMessage)> {
+    let ticks = self.segment.ticks();
+    let mut spins = 0;
+
+    loop {
+      let sequence = ticks.sequence.load(Ordering::SeqCst);
+
+      if sequence % 2 == 1 {
+        spins += 1;
+
+        if spins % WRITE_SPINS == 0 {
+          self.check_leader()?;
+          std::thread::yield_now();
+        } else {
+          std::hint::spin_loop();
+        }
+
+        continue;
+      }
+
+      let time = ticks.time.load(Ordering::SeqCst);
+      let state = ticks.state.load(Ordering::SeqCst);
+
+      if ticks.sequence.load(Ordering::SeqCst) != sequence {
+        continue;
+      }
+
+      return match state {
+        TICKING => Ok((sequence, ClockMessage::Tick(time))),
+        STOPPED => Ok((sequence, ClockMessage::Stopped(time))),
+        _ => Err(anyhow!("The shared clock was closed by its leader")),
+      };
+    }
+  }
+
+  ///Returns an error if the leader process has exited without closing the segment.
+  fn check_leader(&self) -> anyhow::Result<()> {
+    if leader_has_exited(self.segment.ticks().leader_pid.load(Ordering::SeqCst)) {
+      return Err(anyhow!("The leader of the shared clock has exited"));
+    }
+
+    Ok(())
+  }
+}
+
+impl std::fmt::Debug for SharedTimeReceiver {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SharedTimeReceiver").finish_non_exhaustive()
+  }
+}
+
+///Waits until the word no longer holds the expected value, it's woken up or the timeout passes.
+fn futex_wait(word: &AtomicU32, expected: u32, timeout: Duration) {
+  let timeout = libc::timespec {
+    tv_sec: timeout.as_secs() as libc::time_t,
+    tv_nsec: timeout.subsec_nanos() as libc::c_long,
+  };
+
+  // SAFETY: the word is a valid aligned u32 for the duration of the call, as is the timeout
+  unsafe {
+    libc::syscall(
+      libc::SYS_futex,
+      word as *const AtomicU32,
+      libc::FUTEX_WAIT,
+      expected,
+      &timeout as *const libc::timespec,
+    )
+  };
+}
+
+///Wakes every process waiting on the word.
+fn futex_wake(word: &AtomicU32) {
+  // SAFETY: the word is a valid aligned u32 for the duration of the call
+  unsafe { libc::syscall(libc::SYS_futex, word as *const AtomicU32, libc::FUTEX_WAKE, i32::MAX) };
+}
@@ -0,0 +1,42 @@
+use crate::activity::ClockActivity;
+use crate::delivery::TickSenders;
+use crate::sync::Arc;
+
+///Decides when a [`simulated`](crate::Clock::simulated()) clock ticks, which is as soon as everything
+///receiving its ticks is waiting for the next one.
+pub(crate) struct SimulatedTicks {
+  activity: Arc<ClockActivity>,
+  tick_senders: TickSenders,
+  ///How many readers were waiting when the last tick was sent, which all have to finish reading it.
+  woken_readers: u64,
+  ///How many reads had finished when the last tick was sent.
+  finished_reads: u64,
+}
+
+impl SimulatedTicks {
+  pub(crate) fn new(activity: Arc<ClockActivity>, tick_senders: TickSenders) -> Self {
+    let finished_reads = activity.finished_reads();
+
+    Self {
+      activity,
+      tick_senders,
+      woken_readers: 0,
+      finished_reads,
+    }
+  }
+
+  ///Waits until the readers of the last tick are done with it and every receiver waits for the next one.
+  pub(crate) async fn next_tick(&mut self) {
+    while self.activity.finished_reads() - self.finished_reads < self.woken_readers {
+      self.activity.woken().await;
+    }
+
+    // the clock's own receiver is left out, as it's only read from when the clock itself is waited on
+    while self.activity.readers() < self.tick_senders.receiver_count().saturating_sub(1).max(1) {
+      self.activity.woken().await;
+    }
+
+    self.woken_readers = self.activity.readers() as u64;
+    self.finished_reads = self.activity.finished_reads();
+  }
+}
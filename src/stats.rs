@@ -0,0 +1,84 @@
+use crate::Time;
+use std::collections::VecDeque;
+use crate::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+///How far back the ticks used to measure the tick frequency go.
+const TPS_WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///A snapshot of whether a clock is ticking the way it should, returned by [`Clock::health()`](crate::Clock::health()).
+pub struct ClockHealth {
+  ///True while the clock task is running or paused, false before the clock starts and once its task
+  ///has ended, whether by stopping or by panicking.
+  pub task_alive: bool,
+
+  ///The time of the latest tick the clock sent, None until it has ticked.
+  pub last_tick: Option<Time>,
+
+  ///True if the latest tick was late by a whole tick or more, which means the clock can't keep up with its tickrate.
+  pub missed_deadline: bool,
+
+  ///How many times the clock task has been restarted after panicking, see
+  ///[`ClockBuilder::restart_on_panic()`](crate::ClockBuilder::restart_on_panic()).
+  pub restarts: u64,
+}
+
+///The instants of the most recent ticks of a clock, recorded by the clock task.
+#[derive(Debug, Default)]
+pub(crate) struct TickStats {
+  tick_instants: VecDeque<Instant>,
+  latest_tick: Option<Time>,
+  missed_deadline: bool,
+}
+
+///The tick statistics of a clock, shared between the clock and its task.
+pub(crate) type SharedTickStats = Arc<Mutex<TickStats>>;
+
+impl TickStats {
+  ///Records a tick and drops every tick that has fallen out of the window.
+  pub(crate) fn record(&mut self, now: Instant) {
+    self.tick_instants.push_back(now);
+
+    while let Some(oldest) = self.tick_instants.front() {
+      if now.duration_since(*oldest) <= TPS_WINDOW {
+        break;
+      }
+
+      self.tick_instants.pop_front();
+    }
+  }
+
+  ///Records the time of a tick the clock sent and whether it missed its deadline.
+  pub(crate) fn record_sent(&mut self, time: Time, missed_deadline: bool) {
+    self.latest_tick = Some(time);
+    self.missed_deadline = missed_deadline;
+  }
+
+  ///Returns the time of the latest tick the clock sent and whether it missed its deadline.
+  pub(crate) fn latest_tick(&self) -> (Option<Time>, bool) {
+    (self.latest_tick, self.missed_deadline)
+  }
+
+  ///Forgets every recorded tick, so time the clock wasn't ticking isn't measured.
+  pub(crate) fn reset(&mut self) {
+    self.tick_instants.clear();
+  }
+
+  ///Returns the measured ticks per second over the window.
+  ///
+  ///Returns 0 until at least 2 ticks have been recorded.
+  pub(crate) fn ticks_per_second(&self) -> f64 {
+    let (Some(oldest), Some(newest)) = (self.tick_instants.front(), self.tick_instants.back()) else {
+      return 0.0;
+    };
+    let elapsed = newest.duration_since(*oldest).as_secs_f64();
+
+    if elapsed == 0.0 {
+      return 0.0;
+    }
+
+    (self.tick_instants.len() - 1) as f64 / elapsed
+  }
+}
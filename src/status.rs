@@ -0,0 +1,93 @@
+use crate::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use crate::sync::{Mutex, MutexGuard};
+use crate::{ClockStatus, Time};
+use std::sync::PoisonError;
+
+const CREATED: u8 = 0;
+const RUNNING: u8 = 1;
+const PAUSED: u8 = 2;
+const STOPPED: u8 = 3;
+
+#[derive(Debug)]
+///The status of a clock, shared with everything that has to check it.
+///
+///Reading the status never locks, as every wait on the clock checks it first. Changing it does, so
+///changes happen one at a time and in the order everything watching the clock is told about them.
+pub(crate) struct SharedStatus {
+  state: AtomicU8,
+
+  ///The final time of the clock, which is only read while it's stopped.
+  final_time: AtomicU64,
+
+  changing: Mutex<()>,
+}
+
+impl SharedStatus {
+  ///Creates the status of a clock that hasn't started yet.
+  pub(crate) fn new() -> Self {
+    Self {
+      state: AtomicU8::new(CREATED),
+      final_time: AtomicU64::new(0),
+      changing: Mutex::new(()),
+    }
+  }
+
+  ///Returns the status of the clock.
+  pub(crate) fn get(&self) -> ClockStatus {
+    match self.state.load(Ordering::SeqCst) {
+      CREATED => ClockStatus::Created,
+      RUNNING => ClockStatus::Running,
+      PAUSED => ClockStatus::Paused,
+      _ => ClockStatus::Stopped(self.final_time.load(Ordering::SeqCst)),
+    }
+  }
+
+  ///Returns the final time of the clock if it's stopped.
+  pub(crate) fn final_time(&self) -> Option<Time> {
+    match self.get() {
+      ClockStatus::Stopped(final_time) => Some(final_time),
+      _ => None,
+    }
+  }
+
+  ///Locks the status so it can be changed, nothing else can change it until the lock is dropped.
+  ///
+  ///Something panicking while it held the lock doesn't stop the status being locked again, as the
+  ///status is never left half changed.
+  pub(crate) fn lock(&self) -> StatusLock<'_> {
+    StatusLock {
+      status: self,
+      _changing: self.changing.lock().unwrap_or_else(PoisonError::into_inner),
+    }
+  }
+}
+
+///The status of a clock locked for changing, see [`SharedStatus::lock()`].
+pub(crate) struct StatusLock<'a> {
+  status: &'a SharedStatus,
+  _changing: MutexGuard<'a, ()>,
+}
+
+impl StatusLock<'_> {
+  ///Returns the status of the clock.
+  pub(crate) fn get(&self) -> ClockStatus {
+    self.status.get()
+  }
+
+  ///Changes the status of the clock.
+  pub(crate) fn set(&mut self, status: ClockStatus) {
+    let state = match status {
+      ClockStatus::Created => CREATED,
+      ClockStatus::Running => RUNNING,
+      ClockStatus::Paused => PAUSED,
+      ClockStatus::Stopped(final_time) => {
+        // stored first, so a stopped status is never read with the final time of a previous stop
+        self.status.final_time.store(final_time, Ordering::SeqCst);
+
+        STOPPED
+      }
+    };
+
+    self.status.state.store(state, Ordering::SeqCst);
+  }
+}
@@ -0,0 +1,125 @@
+use crate::listener::TickListener;
+use crate::Time;
+use crate::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use crate::sync::{Arc, Weak};
+use std::time::Duration;
+
+#[derive(Debug)]
+///Measures how many ticks of a clock pass, such as for profiling in tick units.
+///
+///The stopwatch counts the ticks of the clock it was created from, so reading it never blocks.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let mut stopwatch = clock.stopwatch();
+///
+///clock.start();
+///stopwatch.start();
+///
+///clock.wait_for_x_ticks(5).unwrap();
+///let first_lap = stopwatch.lap();
+///
+///clock.wait_for_x_ticks(5).unwrap();
+///let second_lap = stopwatch.lap();
+///
+///assert_eq!(stopwatch.elapsed_ticks(), first_lap + second_lap);
+///```
+pub struct Stopwatch {
+  inner: Arc<StopwatchInner>,
+  tick_rate: u32,
+  last_lap: Time,
+}
+
+#[derive(Debug, Default)]
+struct StopwatchInner {
+  ticks: AtomicU64,
+  running: AtomicBool,
+}
+
+impl Stopwatch {
+  ///Creates the stopwatch along with the listener that counts ticks for it from the clock task.
+  pub(crate) fn new(tick_rate: u32) -> (Self, StopwatchTicker) {
+    let inner = Arc::new(StopwatchInner::default());
+    let ticker = StopwatchTicker {
+      stopwatch: Arc::downgrade(&inner),
+    };
+
+    (
+      Self {
+        inner,
+        tick_rate,
+        last_lap: 0,
+      },
+      ticker,
+    )
+  }
+
+  ///Starts counting ticks from 0, restarting the stopwatch if it was already counting.
+  pub fn start(&mut self) {
+    self.inner.ticks.store(0, Ordering::SeqCst);
+    self.inner.running.store(true, Ordering::SeqCst);
+    self.last_lap = 0;
+  }
+
+  ///Returns how many ticks have passed since the previous lap, or since the stopwatch started for the first lap.
+  pub fn lap(&mut self) -> Time {
+    let elapsed_ticks = self.elapsed_ticks();
+    let lap = elapsed_ticks - self.last_lap;
+
+    self.last_lap = elapsed_ticks;
+
+    lap
+  }
+
+  ///Returns how many ticks have passed since the stopwatch started.
+  pub fn elapsed_ticks(&self) -> Time {
+    self.inner.ticks.load(Ordering::SeqCst)
+  }
+
+  ///Returns how much time the ticks since the stopwatch started add up to at the clock's tickrate.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::Clock;
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(2).unwrap();
+  ///let mut stopwatch = clock.stopwatch();
+  ///
+  ///clock.start();
+  ///stopwatch.start();
+  ///
+  ///clock.wait_for_x_ticks(5).unwrap();
+  ///
+  ///assert!(stopwatch.elapsed_duration() >= Duration::from_millis(8));
+  ///```
+  pub fn elapsed_duration(&self) -> Duration {
+    Duration::from_millis(self.tick_rate.into()).saturating_mul(self.elapsed_ticks().try_into().unwrap_or(u32::MAX))
+  }
+}
+
+#[derive(Debug)]
+///Counts ticks for a stopwatch from within the clock task.
+pub(crate) struct StopwatchTicker {
+  stopwatch: Weak<StopwatchInner>,
+}
+
+impl TickListener for StopwatchTicker {
+  ///Counts the tick if the stopwatch is running, the ticker is removed once the stopwatch is dropped.
+  fn tick(&mut self, _time: Time) -> bool {
+    let Some(stopwatch) = self.stopwatch.upgrade() else {
+      return false;
+    };
+
+    if stopwatch.running.load(Ordering::SeqCst) {
+      stopwatch.ticks.fetch_add(1, Ordering::SeqCst);
+    }
+
+    true
+  }
+}
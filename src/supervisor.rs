@@ -0,0 +1,48 @@
+use crate::Time;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use crate::sync::Mutex;
+use std::task::Poll;
+
+#[derive(Debug, Default)]
+///The restarts of a clock task that panicked, shared between the clock, its receivers and its task.
+pub(crate) struct Restarts {
+  ///How many times the task has been restarted, and the time the latest restart carried on from.
+  latest: Mutex<(u64, Time)>,
+}
+
+impl Restarts {
+  ///Records that the task was restarted, carrying on from the time.
+  pub(crate) fn record(&self, time: Time) {
+    let mut latest = self.latest.lock().unwrap();
+
+    *latest = (latest.0 + 1, time);
+  }
+
+  ///Returns how many times the task has been restarted.
+  pub(crate) fn count(&self) -> u64 {
+    self.latest.lock().unwrap().0
+  }
+
+  ///Returns the amount of restarts and the time the latest one carried on from, if there have been any
+  ///since the amount that was already seen.
+  pub(crate) fn since(&self, seen: u64) -> Option<(u64, Time)> {
+    let latest = *self.latest.lock().unwrap();
+
+    (latest.0 > seen).then_some(latest)
+  }
+}
+
+///Runs the future, returning the panic it panicked with instead of unwinding through the task.
+pub(crate) async fn catch_unwind<F: Future>(future: F) -> std::thread::Result<F::Output> {
+  let mut future = std::pin::pin!(future);
+
+  std::future::poll_fn(
+    |context| match panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(context))) {
+      Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+      Ok(Poll::Pending) => Poll::Pending,
+      Err(panic) => Poll::Ready(Err(panic)),
+    },
+  )
+  .await
+}
@@ -0,0 +1,21 @@
+// the crate's own locks and atomics come from here so its concurrency can be model checked, building
+// with `--cfg loom` swaps them for loom's, which explores every interleaving of the threads using them
+
+#[cfg(loom)]
+pub(crate) use loom::sync::{atomic, Condvar, Mutex, MutexGuard};
+
+#[cfg(not(loom))]
+pub(crate) use std::sync::{atomic, Condvar, Mutex, MutexGuard};
+
+///Lets the mutex be locked again after a thread panicked while holding it.
+#[cfg(not(loom))]
+pub(crate) fn clear_poison<T: ?Sized>(mutex: &Mutex<T>) {
+  mutex.clear_poison();
+}
+
+///Loom's mutexes are never poisoned, so there's nothing to clear.
+#[cfg(loom)]
+pub(crate) fn clear_poison<T>(_mutex: &Mutex<T>) {}
+
+// loom has no weak references, and reference counting has nothing to model check
+pub(crate) use std::sync::{Arc, Weak};
@@ -0,0 +1,89 @@
+use crate::listener::TickListener;
+use crate::Time;
+use crate::sync::atomic::{AtomicU64, Ordering};
+use crate::sync::{Arc, Weak};
+
+#[derive(Debug, Clone)]
+///Lets an event through at most once every `n` ticks of a clock.
+///
+///Every clone shares the same throttle, such as to cap how often several threads send over a network.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///
+///let mut clock = Clock::custom(1).unwrap();
+///let throttle = clock.throttle(5);
+///
+///clock.start();
+///
+///assert!(throttle.allow());
+///assert!(!throttle.allow());
+///
+///clock.wait_for_x_ticks(6).unwrap();
+///
+///assert!(throttle.allow());
+///```
+pub struct Throttle {
+  inner: Arc<ThrottleInner>,
+}
+
+#[derive(Debug)]
+struct ThrottleInner {
+  ticks: u64,
+  ticks_since_allowed: AtomicU64,
+}
+
+impl Throttle {
+  ///Creates the throttle along with the listener that counts ticks for it from the clock task.
+  pub(crate) fn new(ticks: u64) -> (Self, ThrottleTicker) {
+    let inner = Arc::new(ThrottleInner {
+      ticks,
+      // the first event is always let through
+      ticks_since_allowed: AtomicU64::new(u64::MAX),
+    });
+    let ticker = ThrottleTicker {
+      throttle: Arc::downgrade(&inner),
+    };
+
+    (Self { inner }, ticker)
+  }
+
+  ///Returns how many ticks have to pass between each allowed event.
+  pub fn ticks(&self) -> u64 {
+    self.inner.ticks
+  }
+
+  ///Returns true and restarts the throttle if `n` ticks have passed since the last allowed event.
+  pub fn allow(&self) -> bool {
+    self
+      .inner
+      .ticks_since_allowed
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |ticks_since_allowed| {
+        (ticks_since_allowed >= self.inner.ticks).then_some(0)
+      })
+      .is_ok()
+  }
+}
+
+#[derive(Debug)]
+///Counts ticks for a throttle from within the clock task.
+pub(crate) struct ThrottleTicker {
+  throttle: Weak<ThrottleInner>,
+}
+
+impl TickListener for ThrottleTicker {
+  ///Counts the tick, the ticker is removed once every clone of the throttle is dropped.
+  fn tick(&mut self, _time: Time) -> bool {
+    let Some(throttle) = self.throttle.upgrade() else {
+      return false;
+    };
+
+    let _ = throttle
+      .ticks_since_allowed
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |ticks| Some(ticks.saturating_add(1)));
+
+    true
+  }
+}
@@ -0,0 +1,240 @@
+use crate::Time;
+use std::fmt;
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::time::Duration;
+
+// every width of tick has the same interface, only the integer it's counted in differs
+macro_rules! tick_width {
+  ($(#[$attribute:meta])* $tick:ident($width:ty)) => {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    $(#[$attribute])*
+    pub struct $tick(pub $width);
+
+    impl $tick {
+      ///The first tick of every clock.
+      pub const ZERO: $tick = $tick(0);
+
+      ///The last tick this width can count to.
+      pub const MAX: $tick = $tick(<$width>::MAX);
+
+      ///Returns how long it takes a clock with the tickrate to get from tick 0 to this tick.
+      ///
+      ///Saturates at the longest duration instead of overflowing.
+      pub fn to_duration(self, tick_rate: u32) -> Duration {
+        let millis = u128::from(self.0).saturating_mul(tick_rate.into());
+
+        Duration::from_millis(millis.try_into().unwrap_or(u64::MAX))
+      }
+
+      ///Returns the tick the amount of ticks after this one, or None if it would overflow.
+      pub fn checked_add(self, ticks: $width) -> Option<$tick> {
+        self.0.checked_add(ticks).map($tick)
+      }
+
+      ///Returns the tick the amount of ticks before this one, or None if it would be before tick 0.
+      pub fn checked_sub(self, ticks: $width) -> Option<$tick> {
+        self.0.checked_sub(ticks).map($tick)
+      }
+
+      ///Returns the amount of ticks since an earlier tick, or None if the tick is later than this one.
+      pub fn checked_ticks_since(self, earlier: $tick) -> Option<$width> {
+        self.0.checked_sub(earlier.0)
+      }
+
+      ///Returns the tick the amount of ticks after this one, stopping at the last tick instead of overflowing.
+      pub fn saturating_add(self, ticks: $width) -> $tick {
+        $tick(self.0.saturating_add(ticks))
+      }
+
+      ///Returns the tick the amount of ticks before this one, stopping at tick 0.
+      pub fn saturating_sub(self, ticks: $width) -> $tick {
+        $tick(self.0.saturating_sub(ticks))
+      }
+    }
+
+    impl fmt::Display for $tick {
+      fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+      }
+    }
+
+    impl Add<$width> for $tick {
+      type Output = $tick;
+
+      ///Panics on overflow the same way adding integers does.
+      fn add(self, ticks: $width) -> $tick {
+        $tick(self.0 + ticks)
+      }
+    }
+
+    impl AddAssign<$width> for $tick {
+      fn add_assign(&mut self, ticks: $width) {
+        self.0 += ticks;
+      }
+    }
+
+    impl Sub<$width> for $tick {
+      type Output = $tick;
+
+      ///Panics on going before tick 0 the same way subtracting integers does.
+      fn sub(self, ticks: $width) -> $tick {
+        $tick(self.0 - ticks)
+      }
+    }
+
+    impl SubAssign<$width> for $tick {
+      fn sub_assign(&mut self, ticks: $width) {
+        self.0 -= ticks;
+      }
+    }
+
+    impl Sub for $tick {
+      type Output = $width;
+
+      ///Returns the amount of ticks between the two, panicking if the other tick is later than this one.
+      fn sub(self, earlier: $tick) -> $width {
+        self.0 - earlier.0
+      }
+    }
+  };
+}
+
+tick_width! {
+  ///A point in a clock's time, counted in ticks.
+  ///
+  ///Unlike a bare [`Time`](crate::Time), a tick can't be mixed up with a tickrate or an amount of
+  ///milliseconds. Amounts of ticks are added to and subtracted from a tick, and subtracting one tick from
+  ///another gives the amount of ticks between them. Adding two ticks together doesn't mean anything, so it
+  ///doesn't compile.
+  ///
+  ///Every [`Time`](crate::Time) converts into a tick and back with [`From`]. A tick is as wide as the
+  ///clock's own counter, which is always a `u64`. [`Tick32`](crate::Tick32) and [`Tick128`](crate::Tick128)
+  ///only change the width a tick is stored in, not how far a clock counts.
+  ///
+  ///# Usage
+  ///
+  ///```
+  ///use thread_clock::{Clock, Tick};
+  ///use std::time::Duration;
+  ///
+  ///let mut clock = Clock::custom(10).unwrap();
+  ///
+  ///clock.start();
+  ///
+  ///let start = Tick::from(clock.time());
+  ///let deadline = start + 5;
+  ///
+  ///assert_eq!(deadline - start, 5);
+  ///assert_eq!((deadline - start) * 10, 50);
+  ///assert_eq!(Tick(5).to_duration(clock.tick_rate()), Duration::from_millis(50));
+  ///```
+  Tick(Time)
+}
+
+tick_width! {
+  ///A [`Tick`](crate::Tick) stored in a `u32`, for keeping ticks in half the space.
+  ///
+  ///The clock still counts in a `u64`. One with a 1ms tickrate runs past the last `u32` tick after about
+  ///49 days, so converting a [`Time`](crate::Time) or a [`Tick`](crate::Tick) into one can fail.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Tick, Tick32};
+  ///
+  ///assert_eq!(Tick32::try_from(Tick(7)), Ok(Tick32(7)));
+  ///assert!(Tick32::try_from(Tick(u64::MAX)).is_err());
+  ///assert_eq!(Tick::from(Tick32(7)), Tick(7));
+  ///```
+  Tick32(u32)
+}
+
+tick_width! {
+  ///A [`Tick`](crate::Tick) stored in a `u128`, for arithmetic on ticks that would overflow a `u64`.
+  ///
+  ///Only the storage is wider. Every clock counts in a `u64`, which lasts for over 500 million years at a
+  ///1ms tickrate, so no tick a clock produces goes past [`Tick::MAX`](crate::Tick::MAX). Values worked
+  ///out from its ticks, such as sums over many clocks, can outgrow it, and converting those back into a
+  ///[`Tick`](crate::Tick) fails.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{Tick, Tick128};
+  ///
+  ///let tick = Tick128::from(Tick(u64::MAX)) + 1;
+  ///
+  ///assert_eq!(tick.0, u64::MAX as u128 + 1);
+  ///assert!(Tick::try_from(tick).is_err());
+  ///```
+  Tick128(u128)
+}
+
+impl From<Time> for Tick {
+  fn from(time: Time) -> Self {
+    Tick(time)
+  }
+}
+
+impl From<Tick> for Time {
+  fn from(tick: Tick) -> Self {
+    tick.0
+  }
+}
+
+impl TryFrom<Time> for Tick32 {
+  type Error = TryFromIntError;
+
+  fn try_from(time: Time) -> Result<Self, Self::Error> {
+    u32::try_from(time).map(Tick32)
+  }
+}
+
+impl From<Time> for Tick128 {
+  fn from(time: Time) -> Self {
+    Tick128(time.into())
+  }
+}
+
+impl From<Tick32> for Tick {
+  fn from(tick: Tick32) -> Self {
+    Tick(tick.0.into())
+  }
+}
+
+impl From<Tick32> for Tick128 {
+  fn from(tick: Tick32) -> Self {
+    Tick128(tick.0.into())
+  }
+}
+
+impl From<Tick> for Tick128 {
+  fn from(tick: Tick) -> Self {
+    Tick128(tick.0.into())
+  }
+}
+
+impl TryFrom<Tick> for Tick32 {
+  type Error = TryFromIntError;
+
+  fn try_from(tick: Tick) -> Result<Self, Self::Error> {
+    u32::try_from(tick.0).map(Tick32)
+  }
+}
+
+impl TryFrom<Tick128> for Tick {
+  type Error = TryFromIntError;
+
+  fn try_from(tick: Tick128) -> Result<Self, Self::Error> {
+    Time::try_from(tick.0).map(Tick)
+  }
+}
+
+impl TryFrom<Tick128> for Tick32 {
+  type Error = TryFromIntError;
+
+  fn try_from(tick: Tick128) -> Result<Self, Self::Error> {
+    u32::try_from(tick.0).map(Tick32)
+  }
+}
@@ -0,0 +1,440 @@
+use crate::driver::{ClockDriver, DriverSlot};
+use crate::external::ExternalDriver;
+#[cfg(all(target_os = "macos", feature = "kqueue"))]
+use crate::kqueue_timer::KqueueTimer;
+#[cfg(any(
+  all(target_os = "linux", feature = "timerfd"),
+  all(target_os = "macos", feature = "kqueue"),
+  windows
+))]
+use crate::logging::log_warn;
+#[cfg(all(target_os = "linux", feature = "timerfd"))]
+use crate::timerfd::TimerFd;
+#[cfg(windows)]
+use crate::waitable_timer::WaitableTimer;
+use crate::recording::TickRecording;
+use crate::simulated::SimulatedTicks;
+use crate::Backend;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+///The resolution of tokio's timers, a sleep can overshoot its deadline by up to this much.
+const TIMER_RESOLUTION: Duration = Duration::from_millis(1);
+
+///Decides when the clock task emits its next tick.
+pub(crate) enum Ticker {
+  ///Sleeps for the tickrate after every tick.
+  Sleep(Duration),
+
+  ///Ticks on fixed deadlines so every tick stays phase-locked to the first one.
+  Interval(Interval),
+
+  ///Ticks on fixed deadlines, sleeping for most of the wait and spinning for the rest.
+  Precise {
+    tick_length: Duration,
+    deadline: Instant,
+    spin_for: Duration,
+  },
+
+  ///Ticks on fixed deadlines, spinning for the whole wait.
+  Spin { tick_length: Duration, deadline: Instant },
+
+  ///Ticks on deadlines whose tick length can change, which the other tickers turn into once it does.
+  Adjustable { tick_length: Duration, deadline: Instant },
+
+  ///Waits for a [`ClockDriver`] to wake the clock up on its deadlines.
+  Driven(DriverSlot),
+
+  ///Waits for an [`ExternalDriver`] to be told to tick, with no deadlines at all.
+  External(ExternalDriver),
+
+  ///Ticks as soon as everything receiving the ticks is waiting for the next one, without any delay.
+  Simulated(SimulatedTicks),
+
+  ///Ticks after the delays between the ticks of a recording, then never again.
+  Replay {
+    delays: Vec<Duration>,
+    ///The index of the delay after the tick due on the deadline.
+    next: usize,
+    deadline: Instant,
+  },
+
+  ///Ticks on fixed deadlines kept by a Linux `timerfd`.
+  #[cfg(all(target_os = "linux", feature = "timerfd"))]
+  TimerFd(TimerFd),
+
+  ///Ticks on fixed deadlines, blocking on a high-resolution Windows waitable timer until each of them.
+  #[cfg(windows)]
+  WaitableTimer {
+    timer: WaitableTimer,
+    tick_length: Duration,
+    deadline: Instant,
+  },
+
+  ///Ticks on fixed deadlines, waiting on a macOS kqueue timer for each of them.
+  #[cfg(all(target_os = "macos", feature = "kqueue"))]
+  Kqueue {
+    timer: KqueueTimer,
+    tick_length: Duration,
+    deadline: Instant,
+  },
+}
+
+impl Ticker {
+  ///Creates the ticker for a clock.
+  ///
+  ///When an alignment is given the first tick lands on the next wall-clock multiple of it,
+  ///such as the start of the next second.
+  ///
+  ///When a precision is given the last part of every wait is spun instead of slept,
+  ///and when spinning the whole wait is, whatever the precision.
+  ///
+  ///When a driver is given the clock is registered with it instead of using a timer of its own,
+  ///which can't be combined with a precision or spinning.
+  ///
+  ///When an external driver is given the clock only ticks when it's told to, whatever else is given.
+  ///
+  ///When the backend is a `timerfd` the kernel keeps the deadlines, which can't be combined with a
+  ///precision, spinning or a driver. Tokio's timer is used instead if the `timerfd` can't be created.
+  ///The same goes for a waitable timer, which blocks the thread the ticker is on while waiting, and
+  ///a kqueue timer.
+  ///
+  ///Has to be called from within the runtime the ticker is used on.
+  pub(crate) fn new(
+    tick_rate: u32,
+    alignment: Option<Duration>,
+    precision: Option<Duration>,
+    spin: bool,
+    driver: Option<&ClockDriver>,
+    external: Option<&ExternalDriver>,
+    backend: Backend,
+  ) -> Self {
+    if let Some(external) = external {
+      return Self::External(external.clone());
+    }
+
+    let tick_length = Duration::from_millis(tick_rate.into());
+
+    let first_tick = match alignment {
+      Some(boundary) if !tick_length.is_zero() => Instant::now() + time_until_boundary(boundary),
+      _ => Instant::now() + tick_length,
+    };
+
+    if let Some(driver) = driver {
+      return Self::Driven(driver.register(tick_length, first_tick));
+    }
+
+    match backend {
+      Backend::Tokio => (),
+      // a timerfd with no interval would only fire once
+      #[cfg(all(target_os = "linux", feature = "timerfd"))]
+      Backend::TimerFd if tick_length.is_zero() => (),
+      #[cfg(all(target_os = "linux", feature = "timerfd"))]
+      Backend::TimerFd => match TimerFd::new(tick_length, first_tick) {
+        Ok(timer) => return Self::TimerFd(timer),
+        Err(error) => log_warn!("Couldn't create a timerfd for the clock, using tokio's timer instead: {error}"),
+      },
+      #[cfg(windows)]
+      Backend::WaitableTimer => match WaitableTimer::new() {
+        Ok(timer) => {
+          return Self::WaitableTimer {
+            timer,
+            tick_length,
+            deadline: first_tick,
+          };
+        }
+        Err(error) => log_warn!("Couldn't create a waitable timer for the clock, using tokio's timer instead: {error}"),
+      },
+      #[cfg(all(target_os = "macos", feature = "kqueue"))]
+      Backend::Kqueue => match KqueueTimer::new() {
+        Ok(timer) => {
+          return Self::Kqueue {
+            timer,
+            tick_length,
+            deadline: first_tick,
+          };
+        }
+        Err(error) => log_warn!("Couldn't create a kqueue timer for the clock, using tokio's timer instead: {error}"),
+      },
+    }
+
+    if spin {
+      return Self::Spin {
+        tick_length,
+        deadline: first_tick,
+      };
+    }
+
+    if let Some(spin_for) = precision {
+      return Self::Precise {
+        tick_length,
+        deadline: first_tick,
+        spin_for,
+      };
+    }
+
+    match alignment {
+      Some(boundary) if !tick_length.is_zero() => {
+        let first_tick = Instant::now() + time_until_boundary(boundary);
+        let mut interval = tokio::time::interval_at(first_tick, tick_length);
+
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        Self::Interval(interval)
+      }
+
+      _ => Self::Sleep(tick_length),
+    }
+  }
+
+  ///Creates the ticker for a clock replaying a recording, whose first tick is the recording's tickrate
+  ///after this is called.
+  pub(crate) fn replay(recording: &TickRecording) -> Self {
+    let delays = recording.delays();
+
+    Self::Replay {
+      deadline: Instant::now() + delays[0],
+      delays,
+      next: 1,
+    }
+  }
+
+  ///Waits until the next tick is due, returning how long after its deadline the tick happened.
+  ///
+  ///Deadlines that were missed are skipped, unless asked to catch up on them, in which case the ticks due
+  ///on them follow right after each other. Sleeping after every tick can't catch up, so it's moved onto
+  ///deadlines first.
+  pub(crate) async fn tick(&mut self, catch_up: bool) -> Duration {
+    if let (Self::Sleep(tick_length), true) = (&*self, catch_up) {
+      *self = Self::Adjustable {
+        tick_length: *tick_length,
+        deadline: Instant::now() + *tick_length,
+      };
+    }
+
+    match self {
+      Self::Sleep(tick_length) => {
+        let deadline = Instant::now() + *tick_length;
+
+        tokio::time::sleep(*tick_length).await;
+
+        Instant::now().saturating_duration_since(deadline)
+      }
+      Self::Interval(interval) => {
+        interval.set_missed_tick_behavior(match catch_up {
+          true => MissedTickBehavior::Burst,
+          false => MissedTickBehavior::Skip,
+        });
+
+        let deadline = interval.tick().await;
+
+        Instant::now().saturating_duration_since(deadline)
+      }
+      Self::Precise {
+        tick_length,
+        deadline,
+        spin_for,
+      } => {
+        // the sleep is cut short by the timer's resolution so it can't overshoot into the spin
+        if let Some(wake_up) = deadline.checked_sub(*spin_for + TIMER_RESOLUTION) {
+          tokio::time::sleep_until(wake_up).await;
+        }
+
+        while Instant::now() < *deadline {
+          std::hint::spin_loop();
+        }
+
+        advance_deadline(deadline, *tick_length, catch_up)
+      }
+      Self::Spin { tick_length, deadline } => {
+        // spinning never returns to the runtime on its own, so it gets a chance to run its timers first
+        tokio::task::yield_now().await;
+
+        while Instant::now() < *deadline {
+          std::hint::spin_loop();
+        }
+
+        advance_deadline(deadline, *tick_length, catch_up)
+      }
+      Self::Adjustable { tick_length, deadline } => {
+        tokio::time::sleep_until(*deadline).await;
+
+        advance_deadline(deadline, *tick_length, catch_up)
+      }
+      Self::Driven(driver_slot) => driver_slot.tick().await,
+      Self::External(external) => {
+        external.next_tick().await;
+
+        // with no deadline a tick can't be late
+        Duration::ZERO
+      }
+      Self::Simulated(simulated_ticks) => {
+        simulated_ticks.next_tick().await;
+
+        Duration::ZERO
+      }
+      Self::Replay { delays, next, deadline } => {
+        if *next > delays.len() {
+          return std::future::pending().await;
+        }
+
+        tokio::time::sleep_until(*deadline).await;
+
+        let late_by = Instant::now().saturating_duration_since(*deadline);
+
+        // every deadline follows on from the one before, so a late tick doesn't shift the ones after it
+        if let Some(delay) = delays.get(*next) {
+          *deadline += *delay;
+        }
+
+        *next += 1;
+
+        late_by
+      }
+      #[cfg(windows)]
+      Self::WaitableTimer {
+        timer,
+        tick_length,
+        deadline,
+      } => {
+        // blocking never returns to the runtime on its own, so it gets a chance to run its timers first
+        tokio::task::yield_now().await;
+
+        if let Err(error) = timer.wait_until(*deadline) {
+          log_warn!("The clock's waitable timer failed, using tokio's timer instead: {error}");
+
+          let (tick_length, mut deadline) = (*tick_length, *deadline);
+
+          tokio::time::sleep_until(deadline).await;
+
+          let late_by = advance_deadline(&mut deadline, tick_length, catch_up);
+
+          *self = Self::Adjustable { tick_length, deadline };
+
+          return late_by;
+        }
+
+        advance_deadline(deadline, *tick_length, catch_up)
+      }
+      #[cfg(all(target_os = "macos", feature = "kqueue"))]
+      Self::Kqueue {
+        timer,
+        tick_length,
+        deadline,
+      } => {
+        if let Err(error) = timer.wait_until(*deadline).await {
+          log_warn!("The clock's kqueue timer failed, using tokio's timer instead: {error}");
+
+          let (tick_length, mut deadline) = (*tick_length, *deadline);
+
+          tokio::time::sleep_until(deadline).await;
+
+          let late_by = advance_deadline(&mut deadline, tick_length, catch_up);
+
+          *self = Self::Adjustable { tick_length, deadline };
+
+          return late_by;
+        }
+
+        advance_deadline(deadline, *tick_length, catch_up)
+      }
+      #[cfg(all(target_os = "linux", feature = "timerfd"))]
+      Self::TimerFd(timer) => match timer.tick().await {
+        Ok(late_by) => late_by,
+        Err(error) => {
+          log_warn!("The clock's timerfd failed, using tokio's timer instead: {error}");
+
+          let tick_length = timer.tick_length();
+          let mut deadline = timer.deadline();
+
+          tokio::time::sleep_until(deadline).await;
+
+          let late_by = advance_deadline(&mut deadline, tick_length, catch_up);
+
+          *self = Self::Adjustable { tick_length, deadline };
+
+          late_by
+        }
+      },
+    }
+  }
+
+  ///Changes how long every tick after the next one lasts.
+  pub(crate) fn set_tick_length(&mut self, new_tick_length: Duration) {
+    match self {
+      // sleeping after every tick drifts too much to be adjusted, and an interval's period can't change
+      Self::Sleep(_) | Self::Interval(_) => {
+        *self = Self::Adjustable {
+          tick_length: new_tick_length,
+          deadline: Instant::now() + new_tick_length,
+        };
+      }
+      Self::Precise {
+        tick_length, deadline, ..
+      }
+      | Self::Spin { tick_length, deadline }
+      | Self::Adjustable { tick_length, deadline } => {
+        // the deadline was already moved ahead by the old tick length
+        *deadline = *deadline - *tick_length + new_tick_length;
+        *tick_length = new_tick_length;
+      }
+      #[cfg(windows)]
+      Self::WaitableTimer {
+        tick_length, deadline, ..
+      } => {
+        *deadline = *deadline - *tick_length + new_tick_length;
+        *tick_length = new_tick_length;
+      }
+      #[cfg(all(target_os = "macos", feature = "kqueue"))]
+      Self::Kqueue {
+        tick_length, deadline, ..
+      } => {
+        *deadline = *deadline - *tick_length + new_tick_length;
+        *tick_length = new_tick_length;
+      }
+      Self::Driven(driver_slot) => driver_slot.set_tick_length(new_tick_length),
+      // the driver, receivers or recording decide when the ticks happen
+      Self::External(_) | Self::Simulated(_) | Self::Replay { .. } => (),
+      #[cfg(all(target_os = "linux", feature = "timerfd"))]
+      Self::TimerFd(timer) => {
+        if let Err(error) = timer.set_tick_length(new_tick_length) {
+          log_warn!("The clock's timerfd couldn't change its tick length, using tokio's timer instead: {error}");
+
+          *self = Self::Adjustable {
+            tick_length: new_tick_length,
+            deadline: timer.deadline(),
+          };
+        }
+      }
+    }
+  }
+}
+
+///Moves the deadline on to the next tick, returning how late the tick that was due on it is.
+fn advance_deadline(deadline: &mut Instant, tick_length: Duration, catch_up: bool) -> Duration {
+  let now = Instant::now();
+  let late_by = now.saturating_duration_since(*deadline);
+
+  *deadline += tick_length;
+
+  // ticks that were missed are skipped instead of being rushed through, unless they're caught up on
+  if *deadline <= now && !catch_up {
+    *deadline = now + tick_length;
+  }
+
+  late_by
+}
+
+///Returns how long it is until the wall-clock time is next a multiple of the boundary.
+fn time_until_boundary(boundary: Duration) -> Duration {
+  let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+  let boundary = boundary.as_nanos();
+
+  if boundary == 0 {
+    return Duration::ZERO;
+  }
+
+  let remaining = boundary - since_epoch.as_nanos() % boundary;
+
+  Duration::from_nanos(remaining.try_into().unwrap_or(u64::MAX))
+}
@@ -0,0 +1,195 @@
+use crate::Time;
+use anyhow::anyhow;
+
+///How many frames are dropped at the start of every minute in 29.97fps drop-frame timecode.
+const DROPPED_FRAMES: Time = 2;
+
+///How many frames 29.97fps drop-frame timecode counts in a minute that drops frames.
+const DROP_FRAME_MINUTE: Time = 30 * 60 - DROPPED_FRAMES;
+
+///How many frames 29.97fps drop-frame timecode counts in 10 minutes, of which only the first doesn't drop frames.
+const DROP_FRAME_TEN_MINUTES: Time = 30 * 60 * 10 - DROPPED_FRAMES * 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///A frame rate a [`Timecode`](crate::Timecode) counts frames at.
+pub enum FrameRate {
+  ///24 frames per second, used for film.
+  Fps24,
+
+  ///25 frames per second, used for PAL video.
+  Fps25,
+
+  ///29.97 frames per second with drop-frame counting, used for NTSC video.
+  ///
+  ///The frame numbers 0 and 1 are skipped at the start of every minute except every tenth, so the
+  ///timecode keeps up with the wall-clock time.
+  Fps29_97DropFrame,
+
+  ///30 frames per second.
+  Fps30,
+}
+
+impl FrameRate {
+  ///Returns how many frames are shown every second.
+  pub fn frames_per_second(&self) -> f64 {
+    match self {
+      Self::Fps24 => 24.0,
+      Self::Fps25 => 25.0,
+      Self::Fps29_97DropFrame => 30_000.0 / 1001.0,
+      Self::Fps30 => 30.0,
+    }
+  }
+
+  ///Returns true if the frame rate skips frame numbers to keep up with the wall-clock time.
+  pub fn is_drop_frame(&self) -> bool {
+    *self == Self::Fps29_97DropFrame
+  }
+
+  ///Returns how many frame numbers a second of timecode counts through.
+  fn nominal_frames(&self) -> Time {
+    match self {
+      Self::Fps24 => 24,
+      Self::Fps25 => 25,
+      Self::Fps29_97DropFrame | Self::Fps30 => 30,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Converts tick counts into SMPTE timecode and back, treating every tick as a frame.
+///
+///Timecode is written as `HH:MM:SS:FF`, or `HH:MM:SS;FF` for drop-frame timecode.
+///Hours aren't wrapped at 24, so every tick count has its own timecode.
+///
+///# Usage
+///
+///```
+///use thread_clock::{FrameRate, Timecode};
+///
+///let timecode = Timecode::new(FrameRate::Fps29_97DropFrame);
+///
+///// the first minute is one that drops frame numbers 0 and 1
+///assert_eq!(timecode.format(1800), "00:01:00;02");
+///assert_eq!(timecode.parse("00:01:00;02").unwrap(), 1800);
+///```
+pub struct Timecode {
+  frame_rate: FrameRate,
+}
+
+impl Timecode {
+  ///Creates a timecode counting frames at the frame rate.
+  pub fn new(frame_rate: FrameRate) -> Self {
+    Self { frame_rate }
+  }
+
+  ///Returns the frame rate the timecode counts frames at.
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  ///Returns the timecode of the tick.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{FrameRate, Timecode};
+  ///
+  ///let timecode = Timecode::new(FrameRate::Fps25);
+  ///
+  ///assert_eq!(timecode.format(90_061), "01:00:02:11");
+  ///```
+  pub fn format(&self, ticks: Time) -> String {
+    let frame_number = if self.frame_rate.is_drop_frame() {
+      drop_frame_number(ticks)
+    } else {
+      ticks
+    };
+    let nominal_frames = self.frame_rate.nominal_frames();
+    let separator = if self.frame_rate.is_drop_frame() { ';' } else { ':' };
+    let seconds = frame_number / nominal_frames;
+
+    format!(
+      "{:02}:{:02}:{:02}{separator}{:02}",
+      seconds / 3600,
+      seconds / 60 % 60,
+      seconds % 60,
+      frame_number % nominal_frames,
+    )
+  }
+
+  ///Parses a timecode back into the tick it's the timecode of.
+  ///
+  ///Either separator is accepted before the frames.
+  ///An error is returned if the timecode isn't in the `HH:MM:SS:FF` format, is out of range for the
+  ///frame rate, or is a frame number that drop-frame timecode skips.
+  ///
+  ///# Example
+  ///
+  ///```
+  ///use thread_clock::{FrameRate, Timecode};
+  ///
+  ///let timecode = Timecode::new(FrameRate::Fps24);
+  ///
+  ///assert_eq!(timecode.parse("00:00:10:12").unwrap(), 252);
+  ///assert!(timecode.parse("00:00:10:24").is_err());
+  ///```
+  pub fn parse(&self, timecode: &str) -> anyhow::Result<Time> {
+    let invalid_format = || anyhow!("'{timecode}' isn't a timecode in the HH:MM:SS:FF format");
+    let (time, frames) = timecode.rsplit_once([':', ';']).ok_or_else(invalid_format)?;
+    let fields: Vec<Option<Time>> = time.split(':').chain([frames]).map(parse_field).collect();
+    let [Some(hours), Some(minutes), Some(seconds), Some(frames)] = fields[..] else {
+      return Err(invalid_format());
+    };
+    let nominal_frames = self.frame_rate.nominal_frames();
+
+    if minutes >= 60 || seconds >= 60 || frames >= nominal_frames {
+      return Err(anyhow!(
+        "'{timecode}' is out of range for {} frames per second",
+        self.frame_rate.frames_per_second()
+      ));
+    }
+
+    let total_minutes = hours
+      .checked_mul(60)
+      .and_then(|minutes_in_hours| minutes_in_hours.checked_add(minutes))
+      .ok_or_else(|| anyhow!("'{timecode}' is out of range"))?;
+    let frame_number = total_minutes
+      .checked_mul(60)
+      .and_then(|total_seconds| total_seconds.checked_add(seconds))
+      .and_then(|total_seconds| total_seconds.checked_mul(nominal_frames))
+      .and_then(|frame_number| frame_number.checked_add(frames))
+      .ok_or_else(|| anyhow!("'{timecode}' is out of range"))?;
+
+    if !self.frame_rate.is_drop_frame() {
+      return Ok(frame_number);
+    }
+
+    if seconds == 0 && frames < DROPPED_FRAMES && minutes % 10 != 0 {
+      return Err(anyhow!("'{timecode}' is a frame number that drop-frame timecode skips"));
+    }
+
+    // every minute except every tenth skipped two frame numbers
+    Ok(frame_number - DROPPED_FRAMES * (total_minutes - total_minutes / 10))
+  }
+}
+
+///Returns the frame number drop-frame timecode shows for the tick, counting the frame numbers that were skipped.
+fn drop_frame_number(ticks: Time) -> Time {
+  let ten_minutes = ticks / DROP_FRAME_TEN_MINUTES;
+  let into_ten_minutes = ticks % DROP_FRAME_TEN_MINUTES;
+  // the first minute of every 10 doesn't drop frames, the minutes after it drop 2 at their start
+  let dropping_minutes = into_ten_minutes.saturating_sub(DROPPED_FRAMES) / DROP_FRAME_MINUTE;
+
+  ticks
+    .saturating_add(DROPPED_FRAMES * 9 * ten_minutes)
+    .saturating_add(DROPPED_FRAMES * dropping_minutes)
+}
+
+///Parses a field of a timecode, which only has digits in it.
+fn parse_field(field: &str) -> Option<Time> {
+  if field.is_empty() || !field.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+
+  field.parse().ok()
+}
@@ -0,0 +1,50 @@
+#[cfg(all(windows, feature = "windows-timer-resolution"))]
+use windows_sys::Win32::Media::{timeBeginPeriod, timeEndPeriod, TIMERR_NOERROR};
+
+///The timer resolution in milliseconds requested while a clock is running.
+#[cfg(all(windows, feature = "windows-timer-resolution"))]
+const TIMER_PERIOD: u32 = 1;
+
+///Windows' timers tick every ~15.6ms by default, so anything shorter would be rounded up to that.
+#[cfg(all(windows, feature = "windows-timer-resolution"))]
+const DEFAULT_TIMER_PERIOD: u32 = 15;
+
+#[derive(Debug)]
+///Raises the resolution of the system's timers for as long as it's held.
+///
+///Only does anything on Windows with the `windows-timer-resolution` feature enabled,
+///everywhere else the timers are already precise enough for any tickrate.
+pub(crate) struct TimerResolution {
+  #[cfg_attr(not(all(windows, feature = "windows-timer-resolution")), allow(dead_code))]
+  raised: bool,
+}
+
+impl TimerResolution {
+  ///Raises the timer resolution if the tickrate is shorter than the default resolution.
+  #[cfg(all(windows, feature = "windows-timer-resolution"))]
+  pub(crate) fn raise_for(tick_rate: u32) -> Self {
+    // SAFETY: timeBeginPeriod has no preconditions, every successful call is paired with timeEndPeriod on drop
+    let raised = tick_rate < DEFAULT_TIMER_PERIOD && unsafe { timeBeginPeriod(TIMER_PERIOD) } == TIMERR_NOERROR;
+
+    Self { raised }
+  }
+
+  ///Raises the timer resolution if the tickrate is shorter than the default resolution.
+  #[cfg(not(all(windows, feature = "windows-timer-resolution")))]
+  pub(crate) fn raise_for(_tick_rate: u32) -> Self {
+    Self { raised: false }
+  }
+}
+
+impl Drop for TimerResolution {
+  ///Restores the timer resolution once the clock is done with it.
+  fn drop(&mut self) {
+    #[cfg(all(windows, feature = "windows-timer-resolution"))]
+    if self.raised {
+      // SAFETY: matches the successful timeBeginPeriod call made when this was created
+      unsafe {
+        timeEndPeriod(TIMER_PERIOD);
+      }
+    }
+  }
+}
@@ -0,0 +1,310 @@
+use crate::Time;
+use std::collections::VecDeque;
+
+///How many bits of a tick each level of the wheel covers.
+const SLOT_BITS: u32 = 6;
+
+///The amount of slots in each level of the wheel.
+const SLOTS: usize = 1 << SLOT_BITS;
+
+///The amount of levels in the wheel, deadlines further out than they cover are kept in an overflow list.
+const LEVELS: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///Identifies a timer in a [`TimerWheel`](crate::TimerWheel), returned when the timer is inserted.
+pub struct TimerKey {
+  index: usize,
+  generation: u64,
+}
+
+#[derive(Debug)]
+///A hierarchical timing wheel holding timers that expire on ticks.
+///
+///Inserting and cancelling a timer takes constant time and each tick only touches the timers due
+///around it, so the wheel scales to hundreds of thousands of pending timers. Timers are kept in
+///levels of 64 slots, each level covering 64 times the ticks of the one below it, and are moved
+///down a level as their deadline gets closer.
+///
+///The wheel doesn't follow a clock by itself, it's advanced to a time by polling it.
+///[`TickDelayQueue`](crate::TickDelayQueue) is built on top of it.
+///
+///# Usage
+///
+///```
+///use thread_clock::TimerWheel;
+///
+///let mut timer_wheel = TimerWheel::new();
+///
+///timer_wheel.insert("respawn", 100);
+///let timer_key = timer_wheel.insert("despawn", 50);
+///timer_wheel.insert("heal", 20);
+///
+///timer_wheel.cancel(timer_key);
+///
+///assert_eq!(timer_wheel.poll(10), None);
+///assert_eq!(timer_wheel.poll(1_000), Some("heal"));
+///assert_eq!(timer_wheel.poll(1_000), Some("respawn"));
+///assert_eq!(timer_wheel.poll(1_000), None);
+///```
+pub struct TimerWheel<T> {
+  ///The next tick that hasn't been processed, every timer due before it has expired.
+  next_tick: Time,
+  levels: Vec<Vec<Vec<TimerKey>>>,
+  level_lens: [usize; LEVELS],
+  overflow: Vec<TimerKey>,
+  expired: VecDeque<TimerKey>,
+  timers: Vec<TimerSlot<T>>,
+  free_slots: Vec<usize>,
+  len: usize,
+}
+
+#[derive(Debug)]
+struct TimerSlot<T> {
+  generation: u64,
+  timer: Option<Timer<T>>,
+}
+
+#[derive(Debug)]
+struct Timer<T> {
+  value: T,
+  deadline: Time,
+}
+
+impl<T> Default for TimerWheel<T> {
+  fn default() -> Self {
+    Self {
+      next_tick: 0,
+      levels: (0..LEVELS).map(|_| (0..SLOTS).map(|_| Vec::new()).collect()).collect(),
+      level_lens: [0; LEVELS],
+      overflow: Vec::new(),
+      expired: VecDeque::new(),
+      timers: Vec::new(),
+      free_slots: Vec::new(),
+      len: 0,
+    }
+  }
+}
+
+impl<T> TimerWheel<T> {
+  ///Creates an empty timer wheel starting at tick 0.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  ///Returns how many timers haven't been taken out of the wheel yet, including expired ones.
+  pub fn len(&self) -> usize {
+    self.len
+  }
+
+  ///Returns true if the wheel has no timers.
+  pub fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  ///Adds a timer that expires once the wheel reaches `at_tick`.
+  ///
+  ///A timer for a tick the wheel has already passed expires on the next poll.
+  pub fn insert(&mut self, value: T, at_tick: Time) -> TimerKey {
+    let timer = Timer {
+      value,
+      deadline: at_tick,
+    };
+    let index = match self.free_slots.pop() {
+      Some(index) => {
+        self.timers[index].timer = Some(timer);
+
+        index
+      }
+      None => {
+        self.timers.push(TimerSlot {
+          generation: 0,
+          timer: Some(timer),
+        });
+
+        self.timers.len() - 1
+      }
+    };
+    let timer_key = TimerKey {
+      index,
+      generation: self.timers[index].generation,
+    };
+
+    self.len += 1;
+    self.place(timer_key, at_tick);
+
+    timer_key
+  }
+
+  ///Removes a timer before it's taken out of the wheel, returning its value.
+  pub fn cancel(&mut self, timer_key: TimerKey) -> Option<T> {
+    // the key is left in its slot and skipped once it's reached
+    self.take(timer_key).map(|timer| timer.value)
+  }
+
+  ///Returns the tick a timer expires on.
+  pub fn deadline(&self, timer_key: TimerKey) -> Option<Time> {
+    self.timer(timer_key).map(|timer| timer.deadline)
+  }
+
+  ///Returns the earliest tick a timer in the wheel expires on.
+  pub fn next_deadline(&self) -> Option<Time> {
+    if let Some(deadline) = self.earliest_deadline(&self.expired) {
+      return Some(deadline);
+    }
+
+    // lower levels always expire before higher ones, and slots within a level expire in order
+    for (level, slots) in self.levels.iter().enumerate() {
+      if self.level_lens[level] == 0 {
+        continue;
+      }
+
+      let current_slot = slot_for(self.next_tick, level);
+
+      if let Some(deadline) = slots[current_slot..].iter().find_map(|slot| self.earliest_deadline(slot)) {
+        return Some(deadline);
+      }
+    }
+
+    self.earliest_deadline(&self.overflow)
+  }
+
+  ///Advances the wheel to `now` and takes the next timer that has expired.
+  ///
+  ///Timers come out in the order they expired in. Returns None if no timer has expired yet.
+  pub fn poll(&mut self, now: Time) -> Option<T> {
+    self.advance(now);
+
+    while let Some(timer_key) = self.expired.pop_front() {
+      if let Some(timer) = self.take(timer_key) {
+        return Some(timer.value);
+      }
+    }
+
+    None
+  }
+
+  ///Processes every tick up to and including `now`, moving the timers due by then to the expired queue.
+  fn advance(&mut self, now: Time) {
+    while self.next_tick <= now {
+      let tick = self.next_tick;
+
+      if tick.trailing_zeros() >= SLOT_BITS * LEVELS as u32 {
+        for timer_key in std::mem::take(&mut self.overflow) {
+          self.replace(timer_key);
+        }
+      }
+
+      // higher levels cascade first so their timers can cascade again on the way down
+      for level in (1..LEVELS).rev() {
+        if tick.trailing_zeros() >= SLOT_BITS * level as u32 {
+          let timer_keys = std::mem::take(&mut self.levels[level][slot_for(tick, level)]);
+
+          self.level_lens[level] -= timer_keys.len();
+
+          for timer_key in timer_keys {
+            self.replace(timer_key);
+          }
+        }
+      }
+
+      let timer_keys = std::mem::take(&mut self.levels[0][slot_for(tick, 0)]);
+
+      self.level_lens[0] -= timer_keys.len();
+      self.expired.extend(timer_keys);
+
+      let Some(next_tick) = tick.checked_add(1) else {
+        return;
+      };
+
+      self.next_tick = next_tick.max(self.next_cascade(next_tick).min(now.saturating_add(1)));
+    }
+  }
+
+  ///Returns the earliest deadline of the timers that haven't been cancelled.
+  fn earliest_deadline<'a>(&self, timer_keys: impl IntoIterator<Item = &'a TimerKey>) -> Option<Time> {
+    timer_keys.into_iter().filter_map(|timer_key| self.deadline(*timer_key)).min()
+  }
+
+  ///Returns the first tick from `tick` on where anything can happen in the wheel.
+  fn next_cascade(&self, tick: Time) -> Time {
+    if self.level_lens[0] > 0 {
+      return tick;
+    }
+
+    let Some(lowest_level) = (1..LEVELS).find(|level| self.level_lens[*level] > 0) else {
+      // only the overflow is left, so jump straight to the span of its earliest timer
+      return match self.earliest_deadline(&self.overflow) {
+        Some(deadline) => tick.max(deadline & !((1 << (SLOT_BITS * LEVELS as u32)) - 1)),
+        None => Time::MAX,
+      };
+    };
+    let level_bits = SLOT_BITS * lowest_level as u32;
+    let mask = (1 << level_bits) - 1;
+
+    match tick & mask {
+      0 => tick,
+      offset => tick.checked_add((1 << level_bits) - offset).unwrap_or(Time::MAX),
+    }
+  }
+
+  ///Puts a timer into the slot for its deadline relative to the next tick.
+  fn place(&mut self, timer_key: TimerKey, deadline: Time) {
+    if deadline < self.next_tick {
+      self.expired.push_back(timer_key);
+
+      return;
+    }
+
+    // the highest bit the deadline and next tick differ in decides how far out the timer is
+    let differing_bits = self.next_tick ^ deadline;
+    let level = if differing_bits == 0 {
+      0
+    } else {
+      ((Time::BITS - 1 - differing_bits.leading_zeros()) / SLOT_BITS) as usize
+    };
+
+    if level >= LEVELS {
+      self.overflow.push(timer_key);
+    } else {
+      self.levels[level][slot_for(deadline, level)].push(timer_key);
+      self.level_lens[level] += 1;
+    }
+  }
+
+  ///Places a timer again after its slot was reached, if it hasn't been cancelled.
+  fn replace(&mut self, timer_key: TimerKey) {
+    if let Some(deadline) = self.deadline(timer_key) {
+      self.place(timer_key, deadline);
+    }
+  }
+
+  fn timer(&self, timer_key: TimerKey) -> Option<&Timer<T>> {
+    let timer_slot = self.timers.get(timer_key.index)?;
+
+    if timer_slot.generation != timer_key.generation {
+      return None;
+    }
+
+    timer_slot.timer.as_ref()
+  }
+
+  ///Takes a timer out of the wheel and frees its slot.
+  fn take(&mut self, timer_key: TimerKey) -> Option<Timer<T>> {
+    self.timer(timer_key)?;
+
+    let timer_slot = &mut self.timers[timer_key.index];
+    let timer = timer_slot.timer.take();
+
+    // keys still pointing at the slot are stale from now on
+    timer_slot.generation += 1;
+    self.free_slots.push(timer_key.index);
+    self.len -= 1;
+
+    timer
+  }
+}
+
+///Returns the slot of a level the tick falls in.
+fn slot_for(tick: Time, level: usize) -> usize {
+  ((tick >> (SLOT_BITS * level as u32)) as usize) & (SLOTS - 1)
+}
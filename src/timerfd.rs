@@ -0,0 +1,133 @@
+use std::io;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::time::Duration;
+use tokio::io::unix::AsyncFd;
+use tokio::time::Instant;
+
+///A timer of the kernel's that fires on fixed deadlines, set as absolute times so the kernel keeps them
+///from drifting however late the clock task gets around to each tick.
+pub(crate) struct TimerFd {
+  fd: AsyncFd<OwnedFd>,
+  tick_length: Duration,
+  ///The deadline of the tick the timer fires for next.
+  deadline: Instant,
+}
+
+impl TimerFd {
+  ///Creates a timer whose first tick is due on the deadline.
+  ///
+  ///Has to be called from within a runtime with its IO driver enabled.
+  pub(crate) fn new(tick_length: Duration, first_deadline: Instant) -> io::Result<Self> {
+    // SAFETY: timerfd_create has no preconditions, the descriptor it returns is owned from here on
+    let fd = unsafe { libc::timerfd_create(libc::CLOCK_MONOTONIC, libc::TFD_NONBLOCK | libc::TFD_CLOEXEC) };
+
+    if fd == -1 {
+      return Err(io::Error::last_os_error());
+    }
+
+    // SAFETY: the descriptor was just created and nothing else owns it
+    let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+    let timer = Self {
+      fd: AsyncFd::new(fd)?,
+      tick_length,
+      deadline: first_deadline,
+    };
+
+    timer.arm()?;
+
+    Ok(timer)
+  }
+
+  pub(crate) fn tick_length(&self) -> Duration {
+    self.tick_length
+  }
+
+  pub(crate) fn deadline(&self) -> Instant {
+    self.deadline
+  }
+
+  ///Waits until the timer fires, returning how long after its deadline the tick happened.
+  pub(crate) async fn tick(&mut self) -> io::Result<Duration> {
+    loop {
+      let mut guard = self.fd.readable().await?;
+      let mut expirations = [0_u8; 8];
+      let read = guard.try_io(|fd| {
+        // SAFETY: a timerfd reads the amount of times it fired into exactly 8 bytes
+        let read = unsafe { libc::read(fd.as_raw_fd(), expirations.as_mut_ptr().cast(), expirations.len()) };
+
+        if read == -1 {
+          return Err(io::Error::last_os_error());
+        }
+
+        Ok(())
+      });
+
+      let Ok(read) = read else {
+        continue;
+      };
+
+      read?;
+
+      // ticks that were missed are skipped instead of being rushed through
+      let missed_ticks = u64::from_ne_bytes(expirations).saturating_sub(1);
+      let due_deadline = self.deadline + self.tick_length.saturating_mul(missed_ticks.try_into().unwrap_or(u32::MAX));
+
+      self.deadline = due_deadline + self.tick_length;
+
+      return Ok(Instant::now().saturating_duration_since(due_deadline));
+    }
+  }
+
+  ///Changes how long every tick after the next one lasts.
+  pub(crate) fn set_tick_length(&mut self, new_tick_length: Duration) -> io::Result<()> {
+    // the deadline was already moved ahead by the old tick length
+    self.deadline = self.deadline - self.tick_length + new_tick_length;
+    self.tick_length = new_tick_length;
+
+    self.arm()
+  }
+
+  ///Sets the timer off on the deadline, and every tick length after it.
+  fn arm(&self) -> io::Result<()> {
+    let first_expiration = monotonic_now()? + self.deadline.saturating_duration_since(Instant::now());
+    let timer_spec = libc::itimerspec {
+      it_interval: to_timespec(self.tick_length),
+      it_value: to_timespec(first_expiration),
+    };
+
+    // SAFETY: the descriptor is a timerfd owned by this timer, and the spec outlives the call
+    let result = unsafe {
+      libc::timerfd_settime(
+        self.fd.as_raw_fd(),
+        libc::TFD_TIMER_ABSTIME,
+        &timer_spec,
+        std::ptr::null_mut(),
+      )
+    };
+
+    if result == -1 {
+      return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+  }
+}
+
+///Returns the time of the monotonic clock the timer's deadlines are set on.
+fn monotonic_now() -> io::Result<Duration> {
+  let mut now = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+
+  // SAFETY: clock_gettime only writes to the timespec it's given
+  if unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) } == -1 {
+    return Err(io::Error::last_os_error());
+  }
+
+  Ok(Duration::new(now.tv_sec as u64, now.tv_nsec as u32))
+}
+
+fn to_timespec(duration: Duration) -> libc::timespec {
+  libc::timespec {
+    tv_sec: duration.as_secs() as libc::time_t,
+    tv_nsec: duration.subsec_nanos() as libc::c_long,
+  }
+}
@@ -0,0 +1,315 @@
+use crate::logging::{log_debug, log_warn};
+use crate::publisher::TickPublisher;
+use crate::runtime::{ClockRuntime, ThreadOptions};
+use crate::{Clock, ClockError, ClockMessage, Time};
+use anyhow::anyhow;
+use std::io::{self, Read};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio::net::UnixListener;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::task::JoinHandle;
+
+///How many bytes every message takes up on the socket, a byte for its kind and 8 for its time.
+const MESSAGE_LENGTH: usize = 9;
+
+const TICK: u8 = 0;
+const STOPPED: u8 = 1;
+
+///Publishes the ticks of a clock on a unix domain socket for [`remote receivers`](crate::RemoteTimeReceiver)
+///in other processes, returned by [`Clock::publish_unix_socket()`](crate::Clock::publish_unix_socket()).
+///
+///Publishing stops and the socket file is removed once this is dropped.
+pub struct UnixSocketPublisher {
+  path: PathBuf,
+  _publisher: TickPublisher,
+  accept_task: JoinHandle<()>,
+  // dropped last, which shuts down the connections along with the thread they run on
+  _runtime: ClockRuntime,
+}
+
+impl UnixSocketPublisher {
+  pub(crate) fn bind(path: &Path, clock: &Clock) -> anyhow::Result<Self> {
+    let listener = std::os::unix::net::UnixListener::bind(path)
+      .map_err(|error| anyhow!("Couldn't publish the clock on {}: {error}", path.display()))?;
+
+    listener.set_nonblocking(true)?;
+
+    let runtime = ClockRuntime::new(ThreadOptions::default())?;
+    let publisher = TickPublisher::new(clock);
+    let subscriptions = publisher.subscriptions();
+
+    let accept_task = runtime.spawn(async move {
+      let listener = match UnixListener::from_std(listener) {
+        Ok(listener) => listener,
+        Err(error) => {
+          log_warn!("The clock's unix socket couldn't be listened on: {error}");
+
+          return;
+        }
+      };
+
+      loop {
+        match listener.accept().await {
+          Ok((stream, _)) => {
+            log_debug!("A remote receiver connected to the clock's unix socket");
+            tokio::spawn(publish_to(stream, subscriptions.subscribe()));
+          }
+          Err(error) => log_warn!("The clock's unix socket couldn't accept a connection: {error}"),
+        }
+      }
+    });
+
+    Ok(Self {
+      path: path.to_owned(),
+      _publisher: publisher,
+      accept_task,
+      _runtime: runtime,
+    })
+  }
+
+  ///Returns the path of the socket the ticks are published on.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+}
+
+impl std::fmt::Debug for UnixSocketPublisher {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("UnixSocketPublisher").field("path", &self.path).finish()
+  }
+}
+
+impl Drop for UnixSocketPublisher {
+  fn drop(&mut self) {
+    self.accept_task.abort();
+
+    let _ = std::fs::remove_file(&self.path);
+  }
+}
+
+///Writes the messages of a clock to a remote receiver until it disconnects.
+async fn publish_to(mut stream: tokio::net::UnixStream, mut messages: broadcast::Receiver<ClockMessage>) {
+  loop {
+    let message = match messages.recv().await {
+      Ok(message) => message,
+      Err(RecvError::Lagged(_)) => continue,
+      Err(RecvError::Closed) => break,
+    };
+
+    if stream.write_all(&encode(message)).await.is_err() {
+      break;
+    }
+  }
+}
+
+fn encode(message: ClockMessage) -> [u8; MESSAGE_LENGTH] {
+  let (kind, time) = match message {
+    ClockMessage::Tick(time) => (TICK, time),
+    ClockMessage::Stopped(final_time) => (STOPPED, final_time),
+  };
+  let mut encoded = [kind; MESSAGE_LENGTH];
+
+  encoded[1..].copy_from_slice(&time.to_be_bytes());
+
+  encoded
+}
+
+fn decode(encoded: &[u8]) -> anyhow::Result<ClockMessage> {
+  let time = Time::from_be_bytes(encoded[1..MESSAGE_LENGTH].try_into()?);
+
+  match encoded[0] {
+    TICK => Ok(ClockMessage::Tick(time)),
+    STOPPED => Ok(ClockMessage::Stopped(time)),
+    kind => Err(anyhow!("The clock's unix socket sent a message of unknown kind {kind}")),
+  }
+}
+
+///Receives the ticks of a clock in another process, published with
+///[`Clock::publish_unix_socket()`](crate::Clock::publish_unix_socket()).
+///
+///This waits on ticks the same way as a [`time receiver`](crate::TimeReceiver), a tick that arrived
+///before it was waited for is an old one and the tick after it is waited for. Once the clock stops,
+///waiting returns [`ClockError::Stopped`](crate::ClockError::Stopped) with its final time, and keeps
+///following the clock if it's started again.
+///
+///# Usage
+///
+///```
+///use thread_clock::{Clock, RemoteTimeReceiver};
+///
+///let path = std::env::temp_dir().join(format!("thread-clock-doc-{}.sock", std::process::id()));
+///let mut clock = Clock::custom(1).unwrap();
+///let _publisher = clock.publish_unix_socket(&path).unwrap();
+///
+///// in another process
+///let mut remote_receiver = RemoteTimeReceiver::connect(&path).unwrap();
+///
+///clock.start();
+///
+///let time = remote_receiver.safe_time().unwrap();
+///
+///assert!(remote_receiver.wait_until_at_least(time + 5).unwrap() >= time + 5);
+///```
+#[derive(Debug)]
+pub struct RemoteTimeReceiver {
+  stream: UnixStream,
+  ///Bytes read from the socket that don't make up a whole message yet.
+  buffer: Vec<u8>,
+  latest_time: Option<Time>,
+}
+
+impl RemoteTimeReceiver {
+  ///Connects to the socket a clock is published on.
+  pub fn connect(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+    let path = path.as_ref();
+    let stream = UnixStream::connect(path)
+      .map_err(|error| anyhow!("Couldn't connect to the clock on {}: {error}", path.display()))?;
+
+    Ok(Self {
+      stream,
+      buffer: Vec::with_capacity(MESSAGE_LENGTH),
+      latest_time: None,
+    })
+  }
+
+  ///Waits for the next tick and returns the time.
+  ///
+  ///If any problems arise when this is called the receiver will panic, use
+  ///[`safe_time()`](crate::RemoteTimeReceiver::safe_time()) for error handling.
+  pub fn time(&mut self) -> Time {
+    self.safe_time().unwrap()
+  }
+
+  ///Waits for the next tick and returns the time, skipping the ticks that arrived before this was called.
+  ///
+  ///An error is returned if the clock stopped, or the connection to it was lost.
+  pub fn safe_time(&mut self) -> anyhow::Result<Time> {
+    self.skip_old_messages()?;
+    self.next_tick()
+  }
+
+  ///Waits for the next tick.
+  pub fn wait_for_tick(&mut self) -> anyhow::Result<()> {
+    self.safe_time().map(|_| ())
+  }
+
+  ///Waits until the clock reaches the input time.
+  ///
+  ///An error is returned if the time has already occurred, or something went wrong with the clock.
+  pub fn wait_for_time(&mut self, time: Time) -> anyhow::Result<()> {
+    let mut current_time = self.safe_time()?;
+
+    if current_time >= time {
+      return Err(ClockError::TimeHasOccurred.into());
+    }
+
+    while current_time < time {
+      current_time = self.next_tick()?;
+    }
+
+    Ok(())
+  }
+
+  ///Waits until the clock has reached at least the input time and returns the current time.
+  ///
+  ///A time that has already occurred isn't an error, the latest time is returned right away instead.
+  pub fn wait_until_at_least(&mut self, time: Time) -> anyhow::Result<Time> {
+    self.skip_old_messages()?;
+
+    if let Some(latest_time) = self.latest_time.filter(|latest_time| *latest_time >= time) {
+      return Ok(latest_time);
+    }
+
+    let mut current_time = self.next_tick()?;
+
+    while current_time < time {
+      current_time = self.next_tick()?;
+    }
+
+    Ok(current_time)
+  }
+
+  ///Returns the time of the newest tick this receiver has seen, or None if it hasn't seen one.
+  pub fn latest_time(&self) -> Option<Time> {
+    self.latest_time
+  }
+
+  ///Reads every message that has already arrived, returning an error if the newest of them is the
+  ///clock stopping.
+  fn skip_old_messages(&mut self) -> anyhow::Result<()> {
+    self.stream.set_nonblocking(true)?;
+
+    let filled = self.fill_buffer();
+
+    self.stream.set_nonblocking(false)?;
+
+    match filled {
+      Ok(()) => (),
+      Err(error) if error.kind() == io::ErrorKind::WouldBlock => (),
+      Err(error) => return Err(error.into()),
+    }
+
+    let mut newest_message = None;
+
+    while let Some(message) = self.take_message()? {
+      newest_message = Some(message);
+    }
+
+    match newest_message {
+      Some(ClockMessage::Stopped(final_time)) => Err(ClockError::Stopped(final_time).into()),
+      _ => Ok(()),
+    }
+  }
+
+  ///Waits for the next tick, returning an error if the clock stops first.
+  fn next_tick(&mut self) -> anyhow::Result<Time> {
+    loop {
+      if let Some(message) = self.take_message()? {
+        match message {
+          ClockMessage::Tick(time) => return Ok(time),
+          ClockMessage::Stopped(final_time) => return Err(ClockError::Stopped(final_time).into()),
+        }
+      }
+
+      let mut bytes = [0; MESSAGE_LENGTH];
+      let read = self.stream.read(&mut bytes)?;
+
+      if read == 0 {
+        return Err(anyhow!("The clock's unix socket was closed"));
+      }
+
+      self.buffer.extend_from_slice(&bytes[..read]);
+    }
+  }
+
+  ///Reads whatever has arrived on the socket into the buffer without waiting.
+  fn fill_buffer(&mut self) -> io::Result<()> {
+    let mut bytes = [0; MESSAGE_LENGTH * 16];
+
+    loop {
+      match self.stream.read(&mut bytes)? {
+        0 => return Ok(()),
+        read => self.buffer.extend_from_slice(&bytes[..read]),
+      }
+    }
+  }
+
+  ///Takes the oldest whole message out of the buffer.
+  fn take_message(&mut self) -> anyhow::Result<Option<ClockMessage>> {
+    if self.buffer.len() < MESSAGE_LENGTH {
+      return Ok(None);
+    }
+
+    let message = decode(&self.buffer[..MESSAGE_LENGTH])?;
+
+    self.buffer.drain(..MESSAGE_LENGTH);
+
+    if let ClockMessage::Tick(time) = message {
+      self.latest_time = Some(time);
+    }
+
+    Ok(Some(message))
+  }
+}
@@ -0,0 +1,186 @@
+use crate::discipline::{RateAdjustment, MAX_SLEW};
+use crate::listener::TickListener;
+use crate::{ClockStatus, Time};
+use std::fmt;
+use crate::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::watch;
+
+///How much of the steady drift seen at every check is added to the rate the clock keeps to.
+const FREQUENCY_GAIN: f64 = 0.25;
+
+///A source of real time that a clock can be [`disciplined to`](crate::Clock::sync_to_wall_clock()).
+///
+///The clock reads it from within its task, so reading can't block. Any `FnMut() -> SystemTime` is a
+///wall clock, such as [`SystemTime::now`] itself, or a function returning a time corrected by NTP.
+///
+///# Usage
+///
+///```
+///use thread_clock::WallClock;
+///use std::time::{Duration, SystemTime};
+///
+///struct NtpCorrected {
+///  offset: Duration,
+///}
+///
+///impl WallClock for NtpCorrected {
+///  fn now(&mut self) -> SystemTime {
+///    SystemTime::now() + self.offset
+///  }
+///}
+///```
+pub trait WallClock: Send + 'static {
+  ///Returns the current real time.
+  fn now(&mut self) -> SystemTime;
+}
+
+impl<F> WallClock for F
+where
+  F: FnMut() -> SystemTime + Send + 'static,
+{
+  fn now(&mut self) -> SystemTime {
+    self()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+///A correction made by a clock [`disciplined to a wall clock`](crate::Clock::sync_to_wall_clock()).
+pub struct CorrectionEvent {
+  ///The tick the check was made on.
+  pub time: Time,
+
+  ///How many ticks the clock is behind real time, negative when it's ahead.
+  pub offset: f64,
+
+  ///The fraction of its tickrate the clock now speeds up by, negative when it slows down.
+  pub rate_adjustment: f64,
+}
+
+///Slews the tickrate of a clock so its ticks stay lined up with a wall clock.
+pub(crate) struct WallClockDiscipline {
+  wall_clock: Box<dyn WallClock>,
+  on_correction: Box<dyn FnMut(CorrectionEvent) + Send>,
+  rate_adjustment: Arc<RateAdjustment>,
+  generation: u64,
+  clock_status: watch::Receiver<ClockStatus>,
+  tick_length: Duration,
+  ticks_per_check: u64,
+
+  ///The tick the clock was lined up with the wall clock on, and the wall clock's time then.
+  baseline: Option<(Time, SystemTime)>,
+  ticks_since_check: u64,
+
+  ///The rate the clock has to keep to for the wall clock's time to pass at the same speed as its own.
+  frequency: f64,
+}
+
+impl WallClockDiscipline {
+  pub(crate) fn new(
+    wall_clock: impl WallClock,
+    on_correction: impl FnMut(CorrectionEvent) + Send + 'static,
+    rate_adjustment: Arc<RateAdjustment>,
+    clock_status: watch::Receiver<ClockStatus>,
+    tick_rate: u32,
+    check_every: Duration,
+  ) -> Self {
+    let generation = rate_adjustment.next_generation();
+    let tick_length = Duration::from_millis(tick_rate.into());
+    let ticks_per_check = match tick_length.is_zero() {
+      true => 1,
+      false => (check_every.as_nanos() / tick_length.as_nanos()).clamp(1, u64::MAX.into()) as u64,
+    };
+
+    Self {
+      wall_clock: Box::new(wall_clock),
+      on_correction: Box::new(on_correction),
+      rate_adjustment,
+      generation,
+      clock_status,
+      tick_length,
+      ticks_per_check,
+      baseline: None,
+      ticks_since_check: 0,
+      frequency: 0.0,
+    }
+  }
+
+  ///Returns how many ticks of the clock the wall clock says have passed since the baseline.
+  fn wall_clock_ticks_since(&mut self, baseline: SystemTime) -> f64 {
+    let elapsed = match self.wall_clock.now().duration_since(baseline) {
+      Ok(elapsed) => elapsed.as_secs_f64(),
+      // a wall clock that was set back is behind the baseline
+      Err(error) => -error.duration().as_secs_f64(),
+    };
+
+    elapsed / self.tick_length.as_secs_f64()
+  }
+}
+
+impl fmt::Debug for WallClockDiscipline {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("WallClockDiscipline")
+      .field("generation", &self.generation)
+      .field("ticks_per_check", &self.ticks_per_check)
+      .field("baseline", &self.baseline)
+      .field("frequency", &self.frequency)
+      .finish()
+  }
+}
+
+impl TickListener for WallClockDiscipline {
+  ///Checks the clock against the wall clock every so many ticks, the discipline is removed once the clock
+  ///is synced to something else.
+  fn tick(&mut self, time: Time) -> bool {
+    if self.rate_adjustment.generation() != self.generation {
+      return false;
+    }
+
+    if self.tick_length.is_zero() {
+      return true;
+    }
+
+    // time the clock spent paused or stopped isn't something to catch up on, so it's lined up again
+    if self.clock_status.has_changed().unwrap_or(false) {
+      self.clock_status.borrow_and_update();
+      self.baseline = None;
+    }
+
+    let Some((baseline_time, baseline_wall_time)) = self.baseline else {
+      self.baseline = Some((time, self.wall_clock.now()));
+      self.ticks_since_check = 0;
+
+      return true;
+    };
+
+    self.ticks_since_check += 1;
+
+    if self.ticks_since_check < self.ticks_per_check {
+      return true;
+    }
+
+    self.ticks_since_check = 0;
+
+    let ticks_per_check = self.ticks_per_check as f64;
+    let wall_clock_time = baseline_time as f64 + self.wall_clock_ticks_since(baseline_wall_time);
+
+    // positive when the clock is behind real time and has to speed up
+    let offset = wall_clock_time - time as f64;
+
+    // whatever offset is left after a check is drift the current rate doesn't account for
+    self.frequency = (self.frequency + FREQUENCY_GAIN * offset / ticks_per_check).clamp(-MAX_SLEW, MAX_SLEW);
+
+    // the offset is slewed away over the next check
+    let rate_adjustment = (self.frequency + offset / ticks_per_check).clamp(-MAX_SLEW, MAX_SLEW);
+
+    self.rate_adjustment.set(rate_adjustment);
+
+    (self.on_correction)(CorrectionEvent {
+      time,
+      offset,
+      rate_adjustment,
+    });
+
+    true
+  }
+}
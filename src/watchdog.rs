@@ -0,0 +1,198 @@
+use crate::status::SharedStatus;
+use crate::listener::TickListener;
+use crate::{ClockStatus, Time};
+use std::fmt;
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::{Arc, Mutex, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///A stall of a clock reported by a [`watchdog`](crate::Watchdog).
+pub struct StallEvent {
+  ///The time of the latest tick before the stall, None if the clock stalled before its first tick.
+  pub last_tick: Option<Time>,
+
+  ///How long it had been since the latest tick when the stall was reported, which is just over the threshold.
+  pub stalled_for: Duration,
+}
+
+///Watches the gaps between the ticks of a clock, calling back whenever one grows longer than a threshold.
+///
+///The watchdog runs on a thread of its own, so a clock task that stopped ticking altogether is reported
+///as soon as the threshold passes instead of once it ticks again. Every stall is reported once, and time
+///the clock spends paused or before it starts isn't counted.
+///The watchdog stops once it's dropped or the clock stops.
+///
+///# Usage
+///
+///```
+///use thread_clock::Clock;
+///use std::time::Duration;
+///
+///let mut clock = Clock::custom(16).unwrap();
+///let _watchdog = clock.watchdog(Duration::from_millis(100), |stall| {
+///  eprintln!("the clock has stalled for {:?} since tick {:?}", stall.stalled_for, stall.last_tick);
+///});
+///
+///clock.start();
+///```
+pub struct Watchdog {
+  inner: Arc<WatchdogInner>,
+  thread: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug)]
+struct WatchdogInner {
+  threshold: Duration,
+  latest_tick: Mutex<LatestTick>,
+  clock_status: Arc<SharedStatus>,
+  dropped: AtomicBool,
+}
+
+#[derive(Debug)]
+struct LatestTick {
+  time: Option<Time>,
+  ///When the latest tick happened, or when the clock started or resumed if it hasn't ticked since.
+  at: Instant,
+  reported: bool,
+}
+
+impl Watchdog {
+  ///Creates the watchdog along with the listener that tells it about ticks from the clock task.
+  pub(crate) fn new(
+    threshold: Duration,
+    clock_status: Arc<SharedStatus>,
+    callback: impl FnMut(StallEvent) + Send + 'static,
+  ) -> anyhow::Result<(Self, WatchdogTicker)> {
+    let inner = Arc::new(WatchdogInner {
+      threshold,
+      latest_tick: Mutex::new(LatestTick {
+        time: None,
+        at: Instant::now(),
+        reported: false,
+      }),
+      clock_status,
+      dropped: AtomicBool::new(false),
+    });
+    let thread = thread::Builder::new().name("thread-clock-watchdog".into()).spawn({
+      let inner = Arc::clone(&inner);
+
+      move || inner.watch(callback)
+    })?;
+    let ticker = WatchdogTicker {
+      watchdog: Arc::downgrade(&inner),
+    };
+
+    Ok((
+      Self {
+        inner,
+        thread: Some(thread),
+      },
+      ticker,
+    ))
+  }
+
+  ///Returns how long a gap between ticks has to be before it's reported.
+  pub fn threshold(&self) -> Duration {
+    self.inner.threshold
+  }
+}
+
+impl fmt::Debug for Watchdog {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("Watchdog").field("threshold", &self.inner.threshold).finish()
+  }
+}
+
+impl Drop for Watchdog {
+  ///Stops the watchdog's thread.
+  fn drop(&mut self) {
+    self.inner.dropped.store(true, Ordering::SeqCst);
+
+    if let Some(thread) = self.thread.take() {
+      thread.thread().unpark();
+      let _ = thread.join();
+    }
+  }
+}
+
+impl WatchdogInner {
+  ///Checks the gap since the latest tick until the watchdog is dropped or the clock stops.
+  fn watch(&self, mut callback: impl FnMut(StallEvent)) {
+    let mut was_running = false;
+
+    while !self.dropped.load(Ordering::SeqCst) {
+      let clock_status = self.clock_status.get();
+
+      match clock_status {
+        ClockStatus::Stopped(_) => break,
+        ClockStatus::Created | ClockStatus::Paused => {
+          was_running = false;
+          thread::park_timeout(self.threshold);
+
+          continue;
+        }
+        ClockStatus::Running => (),
+      }
+
+      let mut latest_tick = self.latest_tick.lock().unwrap();
+
+      // the time before starting or while paused isn't a stall
+      if !was_running {
+        was_running = true;
+        latest_tick.at = Instant::now();
+        latest_tick.reported = false;
+      }
+
+      let gap = latest_tick.at.elapsed();
+
+      if gap <= self.threshold || latest_tick.reported {
+        // once a stall was reported, the watchdog checks back for the next tick now and then
+        let wait = if latest_tick.reported {
+          self.threshold / 4
+        } else {
+          self.threshold - gap
+        };
+
+        drop(latest_tick);
+        thread::park_timeout(wait.max(Duration::from_millis(1)));
+
+        continue;
+      }
+
+      latest_tick.reported = true;
+
+      let stall = StallEvent {
+        last_tick: latest_tick.time,
+        stalled_for: gap,
+      };
+
+      drop(latest_tick);
+      callback(stall);
+    }
+  }
+}
+
+#[derive(Debug)]
+///Tells a watchdog about the ticks of its clock from within the clock task.
+pub(crate) struct WatchdogTicker {
+  watchdog: Weak<WatchdogInner>,
+}
+
+impl TickListener for WatchdogTicker {
+  ///Records the tick, the ticker is removed once the watchdog is dropped.
+  fn tick(&mut self, time: Time) -> bool {
+    let Some(watchdog) = self.watchdog.upgrade() else {
+      return false;
+    };
+
+    *watchdog.latest_tick.lock().unwrap() = LatestTick {
+      time: Some(time),
+      at: Instant::now(),
+      reported: false,
+    };
+
+    true
+  }
+}
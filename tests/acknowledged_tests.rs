@@ -0,0 +1,98 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockError, StragglerPolicy};
+
+#[cfg(test)]
+mod acknowledged {
+  use super::*;
+
+  fn acknowledged_clock(timeout: Duration, stragglers: StragglerPolicy) -> Clock {
+    Clock::builder()
+      .tick_rate(1)
+      .acknowledged_ticks(timeout, stragglers)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"))
+  }
+
+  #[test]
+  fn critical_receivers_get_every_tick() {
+    let mut clock = acknowledged_clock(Duration::from_secs(5), StragglerPolicy::Stop);
+    let mut critical_receiver = clock.critical_receiver().unwrap();
+
+    clock.start();
+
+    let first_time = critical_receiver.wait_for_tick().unwrap();
+
+    for expected_time in first_time + 1..first_time + 20 {
+      // falling behind the tickrate doesn't skip anything, the clock waits instead
+      thread::sleep(Duration::from_millis(3));
+      critical_receiver.acknowledge();
+
+      assert_eq!(critical_receiver.wait_for_tick().unwrap(), expected_time);
+    }
+
+    drop(critical_receiver);
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn the_clock_waits_for_acknowledgements() {
+    let mut clock = acknowledged_clock(Duration::from_secs(5), StragglerPolicy::Proceed);
+    let mut critical_receiver = clock.critical_receiver().unwrap();
+
+    clock.start();
+
+    let time = critical_receiver.wait_for_tick().unwrap();
+
+    thread::sleep(Duration::from_millis(30));
+
+    assert_eq!(clock.health().last_tick, Some(time));
+
+    critical_receiver.acknowledge();
+
+    assert_eq!(critical_receiver.wait_for_tick().unwrap(), time + 1);
+
+    drop(critical_receiver);
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn stragglers_can_be_evicted() {
+    let mut clock = acknowledged_clock(Duration::from_millis(20), StragglerPolicy::Evict);
+    let mut critical_receiver = clock.critical_receiver().unwrap();
+
+    clock.start();
+
+    let time = critical_receiver.wait_for_tick().unwrap();
+
+    thread::sleep(Duration::from_millis(60));
+
+    assert!(!critical_receiver.is_critical());
+    assert!(critical_receiver.wait_for_tick().unwrap() > time + 1);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn stragglers_can_stop_the_clock() {
+    let mut clock = acknowledged_clock(Duration::from_millis(20), StragglerPolicy::Stop);
+    let mut critical_receiver = clock.critical_receiver().unwrap();
+
+    clock.start();
+
+    let time = critical_receiver.wait_for_tick().unwrap();
+    let error = critical_receiver.wait_for_tick().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(time)));
+    assert_eq!(clock.stop().unwrap(), time);
+  }
+
+  #[test]
+  fn acknowledging_needs_a_timeout() {
+    let clock = Clock::builder()
+      .acknowledged_ticks(Duration::ZERO, StragglerPolicy::Proceed)
+      .build();
+
+    assert!(clock.is_err());
+  }
+}
@@ -0,0 +1,133 @@
+use std::time::Duration;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod async_context {
+  use super::*;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn waiting_inside_a_multi_threaded_runtime() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let handle = tokio::spawn(async move {
+      time_receiver.wait_for_time(5).unwrap();
+
+      time_receiver.time()
+    });
+
+    clock.wait_for_x_ticks(3).unwrap();
+
+    assert_eq!(handle.await.unwrap(), 6);
+
+    clock.stop().unwrap();
+  }
+
+  #[tokio::test]
+  async fn waiting_inside_a_current_thread_runtime_errors() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+
+    let error = clock.safe_time().unwrap_err();
+
+    assert_eq!(
+      error.downcast_ref::<ClockError>(),
+      Some(&ClockError::CalledFromAsyncContext)
+    );
+
+    drop(clock);
+  }
+
+  #[tokio::test]
+  async fn next_tick_doesnt_block_the_runtime() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let first_time = time_receiver.next_tick().await.unwrap();
+    let second_time = time_receiver.next_tick().await.unwrap();
+
+    assert!(second_time > first_time);
+
+    drop(clock);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn next_tick_can_be_raced_in_select() {
+    let mut clock = Clock::custom(5).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let mut times = Vec::new();
+    let mut interruptions = 0;
+
+    clock.start();
+
+    while times.len() < 5 {
+      tokio::select! {
+        time = time_receiver.next_tick() => times.push(time.unwrap()),
+        _ = tokio::time::sleep(Duration::from_millis(1)) => interruptions += 1,
+      }
+    }
+
+    assert!(interruptions > 0);
+    assert!(times.windows(2).all(|times| times[0] < times[1]));
+
+    clock.stop().unwrap();
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn next_tick_errors_once_the_clock_stops() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    let error = time_receiver.next_tick().await.unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+
+    clock.start();
+
+    let final_time = clock.stop().unwrap();
+    let error = time_receiver.next_tick().await.unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+  }
+
+  #[tokio::test]
+  async fn clocks_can_be_spawned_on_a_current_thread_runtime() {
+    let mut clock = Clock::spawn_on_current(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.wait_for_x_ticks_async(3).await.unwrap();
+    time_receiver.wait_for_time_async(10).await.unwrap();
+
+    assert_eq!(time_receiver.next_tick().await.unwrap(), 11);
+
+    let time = clock.wait_until_at_least_async(12).await.unwrap();
+
+    assert!(time >= 12);
+    assert!(clock.stop_async().await.unwrap() > time);
+  }
+
+  #[tokio::test]
+  async fn waiting_async_for_a_time_that_occurred_errors() {
+    let mut clock = Clock::spawn_on_current(1).unwrap();
+
+    clock.wait_for_time_async(5).await.unwrap();
+
+    let error = clock.wait_for_time_async(2).await.unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::TimeHasOccurred));
+    assert!(clock.wait_until_at_least_async(2).await.unwrap() >= 6);
+  }
+
+  #[tokio::test]
+  async fn stopping_async_a_clock_that_never_started_errors() {
+    let clock = Clock::custom(1).unwrap();
+    let error = clock.stop_async().await.unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+  }
+}
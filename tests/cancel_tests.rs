@@ -0,0 +1,110 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::{CancelHandle, Clock, ClockError};
+
+#[cfg(test)]
+mod cancel_handle {
+  use super::*;
+
+  #[test]
+  fn cancelling_wakes_up_waits() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+    let cancel_handle = CancelHandle::new();
+    let worker_cancel_handle = cancel_handle.clone();
+
+    clock.start();
+
+    let handle = thread::spawn(move || {
+      let start = Instant::now();
+      let result = time_receiver.wait_for_time_cancellable(1_000_000, &worker_cancel_handle);
+
+      (result, start.elapsed())
+    });
+
+    thread::sleep(Duration::from_millis(20));
+    cancel_handle.cancel();
+
+    let (result, waited_for) = handle.join().unwrap();
+    let error = result.unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Cancelled));
+    assert!(waited_for < Duration::from_secs(1));
+  }
+
+  #[test]
+  fn cancelled_handles_stay_cancelled() {
+    let mut clock = Clock::custom(1).unwrap();
+    let cancel_handle = CancelHandle::new();
+
+    clock.start();
+    cancel_handle.cancel();
+
+    assert!(cancel_handle.is_cancelled());
+
+    let error = clock.wait_for_x_ticks_cancellable(1, &cancel_handle).unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Cancelled));
+  }
+
+  #[test]
+  fn uncancelled_waits_finish() {
+    let mut clock = Clock::custom(1).unwrap();
+    let cancel_handle = CancelHandle::new();
+
+    clock.start();
+    clock.wait_for_time_cancellable(5, &cancel_handle).unwrap();
+
+    assert_eq!(clock.time(), 6);
+  }
+}
+
+#[cfg(test)]
+mod timeout {
+  use super::*;
+
+  #[test]
+  fn waits_finish_within_the_timeout() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+
+    clock
+      .wait_for_time_or_timeout(5, Duration::from_secs(5))
+      .unwrap_or_else(|error| panic!("An error has occurred while waiting: '{error}'"));
+  }
+
+  #[test]
+  fn paused_clocks_time_out() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    clock.pause();
+
+    let start = Instant::now();
+    let error = time_receiver
+      .wait_for_time_or_timeout(1_000_000, Duration::from_millis(50))
+      .unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::TimedOut));
+    assert!(start.elapsed() >= Duration::from_millis(50));
+    assert!(start.elapsed() < Duration::from_secs(1));
+  }
+
+  #[test]
+  fn stopped_clocks_still_report_stopping() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let final_time = clock.stop().unwrap();
+    let error = time_receiver
+      .wait_for_time_or_timeout(1_000_000, Duration::from_secs(5))
+      .unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+  }
+}
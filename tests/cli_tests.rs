@@ -0,0 +1,101 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const BINARY: &str = env!("CARGO_BIN_EXE_thread-clock");
+
+#[cfg(test)]
+mod cli {
+  use super::*;
+
+  #[test]
+  fn prints_the_requested_amount_of_ticks() {
+    let output = Command::new(BINARY)
+      .args(["--rate", "1", "--ticks", "5"])
+      .output()
+      .unwrap_or_else(|error| panic!("An error has occurred while running the binary: '{error}'"));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let ticks: Vec<u64> = stdout.lines().map(|line| line.parse().unwrap()).collect();
+
+    assert!(output.status.success());
+    assert_eq!(ticks.len(), 5);
+    assert!(ticks.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+
+  #[test]
+  fn prints_json_ticks() {
+    let output = Command::new(BINARY).args(["--rate=1", "-n", "3", "-f", "json"]).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(stdout.lines().count(), 3);
+    assert!(stdout
+      .lines()
+      .all(|line| line.starts_with("{\"tick\":") && line.contains(",\"elapsed_ms\":") && line.ends_with('}')));
+  }
+
+  #[test]
+  fn rates_can_be_durations() {
+    let started_at = Instant::now();
+    let output = Command::new(BINARY).args(["--rate", "20ms", "--ticks", "5"]).output().unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().lines().count(), 5);
+    assert!(started_at.elapsed() >= Duration::from_millis(80));
+  }
+
+  #[test]
+  fn quiet_runs_print_nothing() {
+    let started_at = Instant::now();
+    let output = Command::new(BINARY).args(["-r", "20", "-n", "5", "--quiet"]).output().unwrap();
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+    assert!(started_at.elapsed() >= Duration::from_millis(80));
+  }
+
+  #[test]
+  fn invalid_arguments_are_rejected() {
+    let arguments: [&[&str]; 6] = [
+      &["--rate", "fast"],
+      &["--rate", "1.5ms"],
+      &["--format", "xml"],
+      &["--ticks"],
+      &["--unknown"],
+      &["--quiet=yes"],
+    ];
+
+    for arguments in arguments {
+      let output = Command::new(BINARY).args(arguments).output().unwrap();
+
+      assert_eq!(output.status.code(), Some(2), "{arguments:?}");
+      assert!(output.stdout.is_empty());
+    }
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn termination_stops_the_clock_cleanly() {
+    let child = Command::new(BINARY)
+      .args(["--rate", "5"])
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .spawn()
+      .unwrap();
+
+    thread::sleep(Duration::from_millis(200));
+
+    let killed = Command::new("kill").args(["-TERM", &child.id().to_string()]).status().unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let last_tick: u64 = String::from_utf8(output.stdout).unwrap().lines().last().unwrap().parse().unwrap();
+
+    assert!(killed.success());
+    assert!(output.status.success());
+    assert!(stderr.contains("stopped at tick"), "{stderr}");
+    assert!(last_tick > 0);
+  }
+}
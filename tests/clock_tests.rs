@@ -1,5 +1,6 @@
 use std::thread;
-use thread_clock::Clock;
+use std::time::{Duration, Instant, SystemTime};
+use thread_clock::{Clock, ClockError, ClockStatus, Rounding};
 
 #[cfg(test)]
 mod clock {
@@ -71,6 +72,94 @@ mod clock {
     assert_eq!(expected_final_time, final_time);
   }
 
+  #[test]
+  fn wait_until_at_least_tolerates_past_times() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    assert!(clock.wait_until_at_least(5).is_err());
+
+    clock.start();
+
+    assert_eq!(clock.wait_until_at_least(10).unwrap(), 10);
+
+    thread::sleep(Duration::from_millis(10));
+
+    let time = clock.wait_until_at_least(3).unwrap();
+
+    assert!(time > 10);
+  }
+
+  #[test]
+  fn wait_for_multiple_of_logic() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+
+    assert!(clock.wait_for_multiple_of(0).is_err());
+
+    let first_time = clock.wait_for_multiple_of(5).unwrap();
+    let second_time = clock.wait_for_multiple_of(5).unwrap();
+
+    assert_eq!(first_time % 5, 0);
+    assert_eq!(second_time, first_time + 5);
+  }
+
+  #[test]
+  fn wait_for_duration_rounding() {
+    for (rounding, expected_final_time) in [(Rounding::Up, 3), (Rounding::Down, 2), (Rounding::Nearest, 3)] {
+      let mut clock = Clock::builder().tick_rate(2).rounding(rounding).build().unwrap();
+
+      clock.start();
+      clock
+        .wait_for_duration(Duration::from_millis(5))
+        .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+
+      let final_time = clock.stop().unwrap();
+
+      assert_eq!(expected_final_time, final_time);
+    }
+  }
+
+  #[test]
+  fn wait_until_system_time_logic() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+
+    let deadline = SystemTime::now() + Duration::from_millis(20);
+
+    clock
+      .wait_until_system_time(deadline)
+      .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+
+    let error = clock.wait_until_system_time(deadline).unwrap_err();
+
+    // the first tick waited for can arrive right away
+    assert!(SystemTime::now() + Duration::from_millis(1) >= deadline);
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::TimeHasOccurred));
+
+    clock.stop().unwrap();
+  }
+
+  #[cfg(feature = "chrono")]
+  #[test]
+  fn schedule_at_datetime_logic() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+
+    let deadline = SystemTime::now() + Duration::from_millis(20);
+
+    clock
+      .schedule_at_datetime(deadline.into())
+      .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+
+    // the first tick waited for can arrive right away
+    assert!(SystemTime::now() + Duration::from_millis(1) >= deadline);
+
+    clock.stop().unwrap();
+  }
+
   #[test]
   fn stop_clock_before_starting() {
     let clock = Clock::new()
@@ -118,6 +207,26 @@ mod clock {
     assert!(wait_for_x_ticks_error.is_err());
   }
 
+  #[test]
+  fn tick_rate_and_receiver_count() {
+    let clock = Clock::custom(16).unwrap();
+    let derived_clock = clock.derive(4).unwrap();
+
+    assert_eq!(clock.tick_rate(), 16);
+    assert_eq!(derived_clock.tick_rate(), 64);
+    assert_eq!(clock.receiver_count(), 0);
+
+    let time_receivers: Vec<_> = (0..3).map(|_| clock.spawn_receiver()).collect();
+
+    assert_eq!(clock.receiver_count(), 3);
+    assert!(time_receivers.iter().all(|time_receiver| time_receiver.tick_rate() == 16));
+    assert_eq!(derived_clock.spawn_receiver().tick_rate(), 64);
+
+    drop(time_receivers);
+
+    assert_eq!(clock.receiver_count(), 0);
+  }
+
   #[test]
   fn time_has_already_occurred_error() {
     let mut clock = Clock::custom(1).unwrap();
@@ -129,6 +238,314 @@ mod clock {
     assert!(wait_x_ticks.is_ok());
     assert!(wait_for_time_error.is_err());
   }
+
+  #[test]
+  fn actual_tps_is_measured() {
+    let mut clock = Clock::custom(10).unwrap();
+
+    assert_eq!(clock.actual_tps(), 0.0);
+
+    clock.start();
+    clock.wait_for_x_ticks(20).unwrap();
+
+    let actual_tps = clock.actual_tps();
+
+    // a 10ms tickrate can't tick more than 100 times a second, but shouldn't be far off either
+    assert!(actual_tps > 20.0 && actual_tps <= 101.0, "measured {actual_tps} ticks per second");
+  }
+
+  #[test]
+  fn multi_threaded_runtime_counts() {
+    let mut clock = Clock::builder().tick_rate(1).multi_threaded(true).build().unwrap();
+
+    clock.start();
+    clock.wait_for_time(10).unwrap();
+
+    let final_time = clock.stop().unwrap();
+
+    assert_eq!(final_time, 11);
+  }
+
+  #[test]
+  fn many_clocks_share_a_runtime() {
+    let mut clocks: Vec<Clock> = (0..20).map(|_| Clock::custom(1).unwrap()).collect();
+
+    clocks.iter_mut().for_each(Clock::start);
+
+    for clock in &mut clocks {
+      clock.wait_for_x_ticks(5).unwrap();
+    }
+
+    for clock in clocks {
+      assert!(clock.stop().unwrap() >= 5);
+    }
+  }
+
+  #[test]
+  fn dedicated_runtime_counts() {
+    let mut clock = Clock::builder().tick_rate(1).dedicated_runtime(true).build().unwrap();
+
+    clock.start();
+    clock.wait_for_time(10).unwrap();
+
+    assert_eq!(clock.stop().unwrap(), 11);
+  }
+
+  #[test]
+  fn realtime_priority_counts_or_explains_why_it_cant() {
+    // raising the priority needs permissions that the machine running the tests might not have
+    match Clock::builder().tick_rate(1).realtime_priority(true).build() {
+      Ok(mut clock) => {
+        clock.start();
+        clock.wait_for_time(10).unwrap();
+
+        // the clock's thread can keep the test's thread from stopping it before the tick after
+        assert!(clock.stop().unwrap() >= 11);
+      }
+      Err(error) => assert!(error.to_string().contains("real-time priority"), "{error}"),
+    }
+  }
+
+  #[test]
+  #[cfg(any(target_os = "linux", target_os = "android", windows))]
+  fn pinned_clocks_count() {
+    let mut clock = Clock::builder().tick_rate(1).pin_to_core(0).build().unwrap();
+
+    clock.start();
+    clock.wait_for_time(10).unwrap();
+
+    assert_eq!(clock.stop().unwrap(), 11);
+  }
+
+  #[test]
+  fn pinning_to_a_missing_core_fails() {
+    let error = Clock::builder().pin_to_core(usize::MAX).build().unwrap_err();
+
+    assert!(error.to_string().contains("core"), "{error}");
+  }
+}
+
+#[cfg(test)]
+mod status {
+  use super::*;
+
+  #[test]
+  fn status_transitions() {
+    let mut clock = Clock::custom(1).unwrap();
+    let time_receiver = clock.spawn_receiver();
+
+    assert_eq!(clock.status(), ClockStatus::Created);
+    assert!(!clock.is_running());
+
+    clock.pause();
+
+    assert_eq!(clock.status(), ClockStatus::Created);
+
+    clock.start();
+
+    assert!(clock.is_running());
+    assert!(time_receiver.is_running());
+
+    clock.pause();
+
+    assert!(clock.is_paused());
+    assert!(time_receiver.is_paused());
+
+    clock.resume();
+
+    assert!(clock.is_running());
+
+    let final_time = clock.stop().unwrap();
+
+    assert_eq!(time_receiver.status(), ClockStatus::Stopped(final_time));
+  }
+
+  #[test]
+  fn paused_clock_doesnt_advance() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let paused_time = clock.time();
+
+    clock.pause();
+    thread::sleep(Duration::from_millis(20));
+    clock.resume();
+
+    let resumed_time = time_receiver.time();
+
+    assert!(resumed_time <= paused_time + 2);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn paused_time_is_tracked() {
+    let mut clock = Clock::custom(1).unwrap();
+    let time_receiver = clock.spawn_receiver();
+
+    assert_eq!(clock.elapsed(), Duration::ZERO);
+
+    clock.start();
+    clock.pause();
+    thread::sleep(Duration::from_millis(20));
+
+    assert!(time_receiver.paused_duration() >= Duration::from_millis(20));
+
+    clock.resume();
+
+    let paused_duration = clock.paused_duration();
+    thread::sleep(Duration::from_millis(10));
+
+    assert_eq!(clock.paused_duration(), paused_duration);
+    assert!(clock.elapsed() >= paused_duration + Duration::from_millis(10));
+
+    clock.stop().unwrap();
+
+    let elapsed = time_receiver.elapsed();
+    thread::sleep(Duration::from_millis(10));
+
+    assert_eq!(time_receiver.elapsed(), elapsed);
+    assert_eq!(time_receiver.paused_duration(), paused_duration);
+  }
+
+  #[test]
+  fn stopping_a_paused_clock() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+    clock.wait_for_x_ticks(3).unwrap();
+    clock.pause();
+
+    let final_time = clock.stop().unwrap();
+
+    assert!(final_time >= 2);
+  }
+
+  #[test]
+  fn clocks_can_start_paused() {
+    let mut clock = Clock::builder().tick_rate(1).start_paused(true).build().unwrap();
+
+    clock.start();
+
+    assert!(clock.is_paused());
+
+    let workers: Vec<_> = (0..3)
+      .map(|_| {
+        let mut time_receiver = clock.spawn_receiver();
+
+        thread::spawn(move || time_receiver.time())
+      })
+      .collect();
+
+    thread::sleep(Duration::from_millis(20));
+    clock.resume();
+
+    for worker in workers {
+      assert_eq!(worker.join().unwrap(), 0);
+    }
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn clocks_started_paused_can_stop_before_ticking() {
+    let mut clock = Clock::builder().tick_rate(1).start_paused(true).build().unwrap();
+    let time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    assert_eq!(clock.stop().unwrap(), 0);
+    assert_eq!(time_receiver.status(), ClockStatus::Stopped(0));
+  }
+
+  #[test]
+  fn status_changes_can_be_watched() {
+    let mut clock = Clock::custom(1).unwrap();
+    let status = clock.spawn_receiver().watch_status();
+    let clock_status = clock.watch_status();
+
+    assert_eq!(*status.borrow(), ClockStatus::Created);
+
+    clock.start();
+
+    assert!(status.has_changed().unwrap());
+    assert_eq!(*status.borrow(), ClockStatus::Running);
+
+    clock.pause();
+
+    assert_eq!(*clock_status.borrow(), ClockStatus::Paused);
+
+    clock.resume();
+
+    assert_eq!(*status.borrow(), ClockStatus::Running);
+
+    let final_time = clock.stop().unwrap();
+
+    assert_eq!(*status.borrow(), ClockStatus::Stopped(final_time));
+  }
+
+  #[test]
+  fn statuses_are_read_while_they_change() {
+    let mut clock = Clock::custom(1).unwrap();
+    let time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let reader = thread::spawn(move || {
+      let mut statuses = Vec::new();
+
+      while !matches!(statuses.last(), Some(ClockStatus::Stopped(_))) {
+        statuses.push(time_receiver.status());
+      }
+
+      statuses
+    });
+
+    for _ in 0..1000 {
+      clock.pause();
+      clock.resume();
+    }
+
+    let final_time = clock.stop().unwrap();
+    let statuses = reader.join().unwrap();
+
+    assert!(statuses[..statuses.len() - 1]
+      .iter()
+      .all(|status| matches!(status, ClockStatus::Running | ClockStatus::Paused)));
+    assert_eq!(statuses.last(), Some(&ClockStatus::Stopped(final_time)));
+  }
+
+  #[test]
+  fn threads_can_wait_on_status_changes() {
+    let mut clock = Clock::builder().tick_rate(1).start_paused(true).build().unwrap();
+    let mut status = clock.watch_status();
+
+    let watcher = thread::spawn(move || {
+      let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+      let mut seen = Vec::new();
+
+      while !matches!(seen.last(), Some(ClockStatus::Stopped(_))) {
+        runtime.block_on(status.changed()).unwrap();
+        seen.push(*status.borrow_and_update());
+      }
+
+      seen
+    });
+
+    clock.start();
+    thread::sleep(Duration::from_millis(10));
+    clock.resume();
+    thread::sleep(Duration::from_millis(10));
+
+    let final_time = clock.stop().unwrap();
+    let seen = watcher.join().unwrap();
+
+    // changes that happen between reads are only seen as the latest one
+    assert!(!seen.contains(&ClockStatus::Created));
+    assert_eq!(seen.last(), Some(&ClockStatus::Stopped(final_time)));
+  }
 }
 
 #[cfg(test)]
@@ -207,6 +624,34 @@ mod time_receiver {
     assert_eq!(expected_final_time, final_time);
   }
 
+  #[test]
+  fn receivers_spawned_from_receivers() {
+    let mut clock = Clock::custom(1).unwrap();
+    let time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let handles: Vec<_> = (0..3)
+      .map(|_| {
+        let mut worker_receiver = time_receiver.spawn_receiver();
+
+        thread::spawn(move || {
+          worker_receiver.wait_for_time(10).unwrap();
+
+          worker_receiver.time()
+        })
+      })
+      .collect();
+
+    assert_eq!(clock.receiver_count(), 4);
+
+    for handle in handles {
+      assert_eq!(handle.join().unwrap(), 11);
+    }
+
+    clock.stop().unwrap();
+  }
+
   #[test]
   fn clock_not_started_errors() {
     let clock = Clock::new().unwrap();
@@ -224,6 +669,124 @@ mod time_receiver {
     assert!(wait_for_x_ticks_error.is_err());
   }
 
+  #[test]
+  fn stop_unblocks_receivers_with_the_final_time() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let handle = thread::spawn(move || {
+      let mut last_time = 0;
+
+      loop {
+        match time_receiver.safe_time() {
+          Ok(time) => last_time = time,
+          Err(error) => {
+            let stopped_error = error.downcast_ref::<ClockError>().copied();
+            let next_error = time_receiver.safe_time().unwrap_err();
+
+            assert_eq!(next_error.downcast_ref::<ClockError>().copied(), stopped_error);
+
+            return (last_time, stopped_error);
+          }
+        }
+      }
+    });
+
+    clock.wait_for_x_ticks(10).unwrap();
+
+    let final_time = clock.stop().unwrap();
+    let (last_time, stopped_error) = handle.join().unwrap();
+
+    match stopped_error {
+      Some(ClockError::Stopped(receiver_final_time)) => {
+        assert_eq!(receiver_final_time, final_time);
+        assert!(receiver_final_time >= last_time);
+      }
+
+      error => panic!("Expected the clock to have stopped, got {error:?}"),
+    }
+  }
+
+  #[test]
+  fn ticks_since_last_call_counts_missed_ticks() {
+    let mut clock = Clock::custom(2).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    assert_eq!(time_receiver.ticks_since_last_call(), 0);
+
+    clock.start();
+    time_receiver.wait_for_time(4).unwrap();
+
+    // ticks 0 through 4 have happened
+    assert!(time_receiver.ticks_since_last_call() >= 5);
+
+    let time = time_receiver.safe_time().unwrap();
+    time_receiver.ticks_since_last_call();
+    thread::sleep(Duration::from_millis(30));
+
+    let missed_ticks = time_receiver.ticks_since_last_call();
+    let time_after = time_receiver.safe_time().unwrap();
+
+    assert!(missed_ticks >= 5);
+    assert!(time + missed_ticks < time_after);
+  }
+
+  #[test]
+  fn interrupting_a_waiting_receiver() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let interrupt_handle = time_receiver.interrupt_handle();
+
+    clock.start();
+
+    let handle = thread::spawn(move || {
+      let error = time_receiver.wait_for_time(1_000_000).unwrap_err();
+
+      // only the pending wait is interrupted
+      (error.downcast_ref::<ClockError>().copied(), time_receiver.safe_time().is_ok())
+    });
+
+    thread::sleep(Duration::from_millis(20));
+    interrupt_handle.interrupt();
+
+    let (error, next_wait_is_ok) = handle.join().unwrap();
+
+    assert_eq!(error, Some(ClockError::Interrupted));
+    assert!(next_wait_is_ok);
+  }
+
+  #[test]
+  fn pending_interrupts_dont_cut_stopping_short() {
+    let mut clock = Clock::custom(10).unwrap();
+
+    clock.start();
+
+    let time = clock.time();
+
+    clock.interrupt_handle().interrupt();
+
+    // the interrupt is cleared instead of skipping the wait for the final tick
+    assert_eq!(clock.stop_in_place().unwrap(), time + 1);
+    assert!(!clock.is_running());
+  }
+
+  #[test]
+  fn dropping_the_clock_stops_it() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    time_receiver.wait_for_x_ticks(3).unwrap();
+
+    drop(clock);
+
+    let error = time_receiver.safe_time().unwrap_err();
+
+    assert!(matches!(error.downcast_ref::<ClockError>(), Some(ClockError::Stopped(_))));
+  }
+
   #[test]
   fn time_has_already_occurred_error() {
     let mut clock = Clock::custom(1).unwrap();
@@ -237,4 +800,383 @@ mod time_receiver {
     assert!(wait_x_ticks.is_ok());
     assert!(wait_for_time_error.is_err());
   }
+
+  #[test]
+  fn next_time_returns_the_tick_it_waited_for() {
+    let mut clock = Clock::builder().tick_rate(10).receivers_wait_for_start(true).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    assert_eq!(time_receiver.next_time().unwrap(), 0);
+
+    // the tick waited for is gone, so the time is the one after it
+    time_receiver.wait_for_tick().unwrap();
+
+    assert_eq!(time_receiver.time(), 2);
+    assert_eq!(time_receiver.next_time().unwrap(), 3);
+  }
+
+  #[test]
+  fn next_time_skips_the_ticks_sent_before_it() {
+    let mut clock = Clock::custom(10).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let time = time_receiver.next_time().unwrap();
+
+    thread::sleep(Duration::from_millis(35));
+
+    // time carries on from the last tick, while next_time waits for a new one
+    assert_eq!(time_receiver.time(), time + 1);
+    assert!(time_receiver.next_time().unwrap() > time + 2);
+
+    clock.stop().unwrap();
+  }
+}
+
+#[cfg(test)]
+mod alignment {
+  use super::*;
+  use std::time::UNIX_EPOCH;
+
+  #[test]
+  fn ticks_line_up_with_the_boundary() {
+    let boundary = Duration::from_millis(100);
+    let mut clock = Clock::builder().tick_rate(50).align_to(boundary).build().unwrap();
+
+    clock.start();
+
+    for _ in 0..4 {
+      clock
+        .wait_for_tick()
+        .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+
+      let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+      let offset = since_epoch.as_millis() % 50;
+
+      assert!(offset < 15, "tick was {offset}ms away from the boundary");
+    }
+
+    clock.stop().unwrap();
+  }
+}
+
+#[cfg(test)]
+mod precision {
+  use super::*;
+  use std::time::Instant;
+
+  ///Spinning needs a core to itself, with a single one the clock task competes with the test's
+  ///own thread and the timing can't be checked.
+  fn has_spare_core() -> bool {
+    thread::available_parallelism().is_ok_and(|cores| cores.get() > 1)
+  }
+
+  #[test]
+  fn precise_ticks_keep_to_the_tickrate() {
+    if !has_spare_core() {
+      eprintln!("skipped, spinning clocks need a spare core to keep to their tickrate");
+
+      return;
+    }
+
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .precision(Duration::from_micros(200))
+      .build()
+      .unwrap();
+
+    clock.start();
+    clock.wait_for_tick().unwrap();
+
+    let start = Instant::now();
+
+    clock.wait_for_x_ticks(100).unwrap();
+
+    let elapsed = start.elapsed();
+
+    assert!(
+      elapsed >= Duration::from_millis(95) && elapsed < Duration::from_millis(150),
+      "100 ticks took {elapsed:?}"
+    );
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn spinning_clocks_keep_to_the_tickrate() {
+    if !has_spare_core() {
+      eprintln!("skipped, spinning clocks need a spare core to keep to their tickrate");
+
+      return;
+    }
+
+    let mut clock = Clock::builder().tick_rate(1).spin(true).build().unwrap();
+
+    clock.start();
+    clock.wait_for_tick().unwrap();
+
+    let start = Instant::now();
+
+    clock.wait_for_x_ticks(100).unwrap();
+
+    let elapsed = start.elapsed();
+
+    assert!(
+      elapsed >= Duration::from_millis(95) && elapsed < Duration::from_millis(150),
+      "100 ticks took {elapsed:?}"
+    );
+
+    clock.stop().unwrap();
+  }
+}
+
+#[cfg(test)]
+mod idle {
+  use super::*;
+
+  #[test]
+  fn unobserved_clocks_idle_but_keep_counting() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .idle_when_unobserved(true)
+      .build()
+      .unwrap();
+
+    clock.start();
+
+    let time = clock.time();
+
+    thread::sleep(Duration::from_millis(50));
+
+    // nothing ticked while idle
+    assert_eq!(clock.actual_tps(), 0.0);
+
+    let time_after_idling = clock.time();
+
+    assert!(time_after_idling >= time + 40, "{time} -> {time_after_idling}");
+  }
+
+  #[test]
+  fn idle_clocks_stop_at_their_stop_time_once_woken() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .idle_when_unobserved(true)
+      .stop_at(20)
+      .build()
+      .unwrap();
+
+    clock.start();
+    clock.time();
+    thread::sleep(Duration::from_millis(50));
+
+    let error = clock.safe_time().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(20)));
+  }
+
+  #[test]
+  fn receivers_keep_the_clock_awake() {
+    let mut clock = Clock::builder()
+      .tick_rate(5)
+      .idle_when_unobserved(true)
+      .build()
+      .unwrap();
+    let _time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    thread::sleep(Duration::from_millis(50));
+
+    assert!(clock.actual_tps() > 0.0);
+  }
+}
+
+#[cfg(test)]
+mod stop_in_place {
+  use super::*;
+
+  #[test]
+  fn receivers_carry_on_after_starting_again() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    time_receiver.wait_for_time(20).unwrap();
+
+    let final_time = clock.stop_in_place().unwrap();
+    let error = time_receiver.safe_time().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+
+    clock.start();
+
+    // the count starts over, and waits compare against it instead of the previous run
+    assert!(time_receiver.time() < final_time);
+    assert!(time_receiver.wait_until_at_least(5).unwrap() >= 5);
+
+    let final_time = clock.stop().unwrap();
+
+    assert!(time_receiver.safe_time().is_err());
+    assert_eq!(time_receiver.status(), ClockStatus::Stopped(final_time));
+  }
+
+  #[test]
+  fn clocks_can_continue_their_count() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    clock.wait_for_x_ticks(5).unwrap();
+
+    let final_time = clock.stop_in_place().unwrap();
+
+    clock.start_from(final_time + 1);
+
+    assert_eq!(time_receiver.time(), final_time + 1);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn stopping_in_place_twice_errors() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+    clock.stop_in_place().unwrap();
+
+    let error = clock.stop_in_place().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+  }
+
+  #[test]
+  fn stopping_now_doesnt_wait_for_the_next_tick() {
+    let mut clock = Clock::custom(10_000).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let waiter = thread::spawn(move || time_receiver.safe_time());
+
+    thread::sleep(Duration::from_millis(50));
+
+    let stopping_at = Instant::now();
+    let final_time = clock.stop_now().unwrap();
+    let error = waiter.join().unwrap().unwrap_err();
+
+    assert_eq!(final_time, 0);
+    assert!(stopping_at.elapsed() < Duration::from_secs(1));
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(0)));
+  }
+
+  #[test]
+  fn stopping_now_before_starting_errors() {
+    let clock = Clock::custom(10_000).unwrap();
+    let error = clock.stop_now().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+  }
+}
+
+#[cfg(test)]
+mod rebind {
+  use super::*;
+
+  #[test]
+  fn receivers_follow_the_clock_they_are_rebound_to() {
+    let mut menu_clock = Clock::custom(10).unwrap();
+    let mut gameplay_clock = Clock::builder().tick_rate(1).start_at(500).build().unwrap();
+    let mut time_receiver = menu_clock.spawn_receiver();
+
+    menu_clock.start();
+    gameplay_clock.start();
+    time_receiver.set_divisor(2).unwrap();
+
+    assert!(time_receiver.time() < 500);
+
+    time_receiver.rebind(&gameplay_clock);
+
+    assert_eq!(time_receiver.tick_rate(), 1);
+    assert_eq!(time_receiver.divisor(), 1);
+    assert!(time_receiver.time() >= 500);
+
+    menu_clock.stop().unwrap();
+
+    // the old clock stopping doesn't reach the rebound receiver
+    assert!(time_receiver.safe_time().is_ok());
+
+    let final_time = gameplay_clock.stop().unwrap();
+    let error = time_receiver.safe_time().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+  }
+
+  #[test]
+  fn interrupt_handles_carry_over() {
+    let mut first_clock = Clock::custom(1).unwrap();
+    let mut second_clock = Clock::custom(1).unwrap();
+    let mut time_receiver = first_clock.spawn_receiver();
+    let interrupt_handle = time_receiver.interrupt_handle();
+
+    first_clock.start();
+    second_clock.start();
+    time_receiver.rebind(&second_clock);
+    interrupt_handle.interrupt();
+
+    let error = time_receiver.wait_for_time(1_000_000).unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Interrupted));
+  }
+}
+
+#[cfg(test)]
+mod tick_now {
+  use super::*;
+
+  #[test]
+  fn injected_ticks_dont_wait_for_the_timer() {
+    let mut clock = Clock::custom(5000).unwrap();
+
+    clock.start();
+
+    for _ in 0..5 {
+      clock.tick_now();
+    }
+
+    thread::sleep(Duration::from_millis(50));
+    clock.pause();
+
+    assert_eq!(clock.stop().unwrap(), 4);
+  }
+
+  #[test]
+  fn timed_ticks_carry_on_after_injected_ones() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    time_receiver.wait_for_time(5).unwrap();
+
+    for _ in 0..100 {
+      clock.tick_now();
+    }
+
+    assert!(time_receiver.wait_until_at_least(150).unwrap() >= 150);
+  }
+
+  #[test]
+  fn only_running_clocks_are_ticked() {
+    let mut clock = Clock::custom(5000).unwrap();
+
+    clock.tick_now();
+    clock.start_from(10);
+    clock.pause();
+    clock.tick_now();
+    thread::sleep(Duration::from_millis(50));
+
+    // no tick happened, so the clock stops on the time before its first one
+    assert_eq!(clock.stop().unwrap(), 9);
+  }
 }
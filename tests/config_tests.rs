@@ -0,0 +1,112 @@
+use std::time::Duration;
+use thread_clock::{Clock, ClockConfig, ClockError, Precision};
+
+#[cfg(test)]
+mod config {
+  use super::*;
+
+  #[test]
+  fn configs_set_up_the_clock() {
+    let mut clock = Clock::from_config(ClockConfig {
+      tick_rate: 2,
+      channel_capacity: 4,
+      precision: Precision::SpinFor(100),
+      stop_at: Some(3),
+    })
+    .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    assert_eq!(clock.tick_rate(), 2);
+
+    clock.start();
+
+    assert!(clock.wait_for_time(10).is_err());
+    assert_eq!(clock.stop().unwrap(), 3);
+  }
+
+  #[test]
+  fn invalid_configs_are_rejected() {
+    let config = ClockConfig {
+      channel_capacity: 0,
+      ..ClockConfig::default()
+    };
+
+    assert!(Clock::from_config(config).is_err());
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn configs_deserialize_from_toml() {
+    let config: ClockConfig = toml::from_str(
+      r#"
+        tick_rate = 10
+        precision = { spin_for = 200 }
+        stop_at = 1000
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      config,
+      ClockConfig {
+        tick_rate: 10,
+        precision: Precision::SpinFor(200),
+        stop_at: Some(1000),
+        ..ClockConfig::default()
+      }
+    );
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn configs_deserialize_from_json() {
+    let config: ClockConfig = serde_json::from_str(r#"{ "channel_capacity": 8, "precision": "spin" }"#).unwrap();
+
+    assert_eq!(config.channel_capacity, 8);
+    assert_eq!(config.precision, Precision::Spin);
+    assert_eq!(config.tick_rate, thread_clock::DEFAULT_TICKRATE);
+  }
+
+  #[cfg(feature = "serde")]
+  #[test]
+  fn unknown_settings_are_rejected() {
+    assert!(serde_json::from_str::<ClockConfig>(r#"{ "tickrate": 10 }"#).is_err());
+  }
+}
+
+#[cfg(test)]
+mod stop_at {
+  use super::*;
+
+  #[test]
+  fn clocks_stop_themselves_after_the_tick() {
+    let mut clock = Clock::builder().tick_rate(1).stop_at(5).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let error = time_receiver.wait_for_time(10).unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(5)));
+    assert_eq!(clock.stop().unwrap(), 5);
+  }
+
+  #[test]
+  fn stopping_before_the_tick_stops_early() {
+    let mut clock = Clock::builder().tick_rate(1).stop_at(10_000).build().unwrap();
+
+    clock.start();
+    std::thread::sleep(Duration::from_millis(10));
+
+    assert!(clock.stop().unwrap() < 10_000);
+  }
+
+  #[test]
+  fn clocks_started_past_the_tick_stop_after_their_first() {
+    let mut clock = Clock::builder().tick_rate(1).start_at(20).stop_at(5).build().unwrap();
+
+    clock.start();
+
+    assert!(clock.wait_for_time(30).is_err());
+    assert_eq!(clock.stop().unwrap(), 20);
+  }
+}
@@ -0,0 +1,82 @@
+use std::thread;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod countdown {
+  use super::*;
+
+  #[test]
+  fn countdown_finishes_after_its_ticks() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let countdown = clock.countdown(10);
+
+    clock.start();
+
+    let start_time = clock.time();
+
+    countdown
+      .wait()
+      .unwrap_or_else(|error| panic!("An error has occurred while waiting: '{error}'"));
+
+    assert!(countdown.is_finished());
+    assert!(clock.time() >= start_time + 9);
+  }
+
+  #[test]
+  fn countdown_can_be_waited_on_from_threads() {
+    let mut clock = Clock::custom(1).unwrap();
+    let countdown = clock.countdown(5);
+
+    clock.start();
+
+    let handles: Vec<_> = (0..3)
+      .map(|_| {
+        let countdown = countdown.clone();
+
+        thread::spawn(move || countdown.wait())
+      })
+      .collect();
+
+    for handle in handles {
+      assert!(handle.join().unwrap().is_ok());
+    }
+
+    assert_eq!(countdown.remaining(), 0);
+  }
+
+  #[test]
+  fn waiting_on_a_stopped_clock_errors() {
+    let mut clock = Clock::custom(1).unwrap();
+    let countdown = clock.countdown(1_000);
+
+    let error = countdown.wait().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+
+    clock.start();
+    clock.wait_for_x_ticks(2).unwrap();
+
+    let waiting_countdown = countdown.clone();
+    let handle = thread::spawn(move || waiting_countdown.wait());
+
+    clock.stop().unwrap();
+
+    let error = handle.join().unwrap().unwrap_err();
+
+    assert!(matches!(error.downcast_ref::<ClockError>(), Some(ClockError::Stopped(_))));
+    assert!(countdown.remaining() > 0);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn countdown_can_be_awaited() {
+    let mut clock = Clock::custom(1).unwrap();
+    let countdown = clock.countdown(5);
+
+    clock.start();
+
+    countdown.finished().await.unwrap();
+
+    assert!(countdown.is_finished());
+  }
+}
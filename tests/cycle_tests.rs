@@ -0,0 +1,85 @@
+use thread_clock::{Clock, ClockError, CycleEvent};
+
+#[cfg(test)]
+mod cycle {
+  use super::*;
+
+  #[test]
+  fn cycles_start_and_end_in_order() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let cycle = clock.cycle(3).unwrap();
+
+    clock.start();
+
+    let events: Vec<CycleEvent> = (0..6).map(|_| cycle.next_event().unwrap()).collect();
+
+    assert_eq!(
+      events,
+      [
+        CycleEvent::Start(0),
+        CycleEvent::End(0),
+        CycleEvent::Start(1),
+        CycleEvent::End(1),
+        CycleEvent::Start(2),
+        CycleEvent::End(2),
+      ]
+    );
+    assert!(cycle.cycles_completed() >= 3);
+  }
+
+  #[test]
+  fn phase_follows_the_clock() {
+    let mut clock = Clock::custom(1).unwrap();
+    let cycle = clock.cycle(4).unwrap();
+
+    assert_eq!(cycle.phase(), 0);
+    assert_eq!(cycle.cycles_completed(), 0);
+
+    clock.start();
+    clock.pause();
+
+    let time = clock.stop().unwrap();
+
+    assert_eq!(cycle.phase(), time % 4);
+    assert_eq!(cycle.cycles_completed(), (time + 1) / 4);
+  }
+
+  #[test]
+  fn single_tick_cycles_start_and_end_on_every_tick() {
+    let mut clock = Clock::custom(1).unwrap();
+    let cycle = clock.cycle(1).unwrap();
+
+    clock.start();
+
+    assert_eq!(cycle.next_event().unwrap(), CycleEvent::Start(0));
+    assert_eq!(cycle.next_event().unwrap(), CycleEvent::End(0));
+    assert_eq!(cycle.next_event().unwrap(), CycleEvent::Start(1));
+  }
+
+  #[test]
+  fn cycles_need_a_length() {
+    let clock = Clock::new().unwrap();
+
+    assert!(clock.cycle(0).is_err());
+  }
+
+  #[test]
+  fn events_end_with_the_clock() {
+    let mut clock = Clock::custom(1).unwrap();
+    let cycle = clock.cycle(1000).unwrap();
+
+    let error = cycle.next_event().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+
+    clock.start();
+
+    assert_eq!(cycle.next_event().unwrap(), CycleEvent::Start(0));
+
+    let final_time = clock.stop().unwrap();
+    let error = cycle.next_event().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+  }
+}
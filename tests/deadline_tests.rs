@@ -0,0 +1,93 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockError, Deadline};
+
+#[cfg(test)]
+mod deadline {
+  use super::*;
+
+  #[test]
+  fn waiting_finishes_on_the_tick() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let deadline = Deadline::at(&clock, 10);
+
+    assert_eq!(deadline.tick(), 10);
+    assert!(!deadline.is_reached());
+
+    clock.start();
+
+    assert_eq!(deadline.wait().unwrap(), 10);
+    assert!(deadline.is_reached());
+  }
+
+  #[test]
+  fn late_waits_return_the_tick_the_deadline_finished_on() {
+    let mut clock = Clock::custom(1).unwrap();
+    let deadline = Deadline::at(&clock, 3);
+
+    clock.start();
+    clock.wait_for_time(20).unwrap();
+
+    assert_eq!(deadline.wait().unwrap(), 3);
+  }
+
+  #[test]
+  fn passed_deadlines_finish_on_the_next_tick() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+    clock.wait_for_time(10).unwrap();
+
+    let deadline = Deadline::at(&clock, 2);
+
+    assert!(deadline.wait().unwrap() > 10);
+  }
+
+  #[test]
+  fn deadlines_error_once_the_clock_stops() {
+    let mut clock = Clock::custom(1).unwrap();
+    let deadline = Deadline::at(&clock, 1_000_000);
+
+    let error = deadline.wait().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+
+    clock.start();
+
+    let waiter = thread::spawn(move || deadline.wait());
+
+    thread::sleep(Duration::from_millis(20));
+
+    let final_time = clock.stop().unwrap();
+    let error = waiter.join().unwrap().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn deadlines_can_be_awaited() {
+    let mut clock = Clock::custom(1).unwrap();
+    let deadline = Deadline::at(&clock, 5);
+
+    clock.start();
+
+    assert_eq!(deadline.await.unwrap(), 5);
+
+    clock.stop().unwrap();
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn deadlines_compose_with_timeouts() {
+    let mut clock = Clock::custom(1).unwrap();
+    let deadline = Deadline::at(&clock, 1_000_000);
+
+    clock.start();
+
+    let timed_out = tokio::time::timeout(Duration::from_millis(20), deadline).await;
+
+    assert!(timed_out.is_err());
+
+    clock.stop().unwrap();
+  }
+}
@@ -0,0 +1,94 @@
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod delay_queue {
+  use super::*;
+
+  #[test]
+  fn entries_expire_in_deadline_order() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut delay_queue = clock.delay_queue();
+
+    for entity in 0..1_000_u64 {
+      delay_queue.insert(entity, entity * 2, 20 - entity % 20);
+    }
+
+    clock.start();
+
+    let mut previous_deadline = 0;
+    let mut expired = 0;
+
+    while let Some((entity, value)) = delay_queue
+      .wait_for_expired()
+      .unwrap_or_else(|error| panic!("An error has occurred while waiting: '{error}'"))
+    {
+      let deadline = 20 - entity % 20;
+
+      assert_eq!(value, entity * 2);
+      assert!(deadline >= previous_deadline);
+
+      previous_deadline = deadline;
+      expired += 1;
+    }
+
+    assert_eq!(expired, 1_000);
+    assert!(delay_queue.is_empty());
+  }
+
+  #[test]
+  fn entries_can_be_replaced_reset_and_removed() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut delay_queue = clock.delay_queue();
+
+    assert_eq!(delay_queue.insert("a", 1, 5), None);
+    assert_eq!(delay_queue.insert("a", 2, 5), Some(1));
+    assert_eq!(delay_queue.len(), 1);
+
+    delay_queue.insert("b", 3, 10);
+
+    assert!(delay_queue.reset(&"b", 2));
+    assert!(!delay_queue.reset(&"c", 2));
+    assert_eq!(delay_queue.deadline(&"b"), Some(2));
+    assert_eq!(delay_queue.next_deadline(), Some(2));
+
+    assert_eq!(delay_queue.remove(&"a"), Some(2));
+    assert_eq!(delay_queue.remove(&"a"), None);
+
+    clock.start();
+
+    assert_eq!(delay_queue.wait_for_expired().unwrap(), Some(("b", 3)));
+    assert_eq!(delay_queue.wait_for_expired().unwrap(), None);
+  }
+
+  #[test]
+  fn nothing_expires_early() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut delay_queue = clock.delay_queue();
+
+    delay_queue.insert(0, (), 1_000);
+
+    assert_eq!(delay_queue.poll_expired(), None);
+
+    clock.start();
+    clock.wait_for_x_ticks(5).unwrap();
+
+    assert_eq!(delay_queue.poll_expired(), None);
+  }
+
+  #[test]
+  fn waiting_on_a_stopped_clock_errors() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut delay_queue = clock.delay_queue();
+
+    delay_queue.insert(0, (), 1_000_000);
+
+    clock.start();
+    clock.wait_for_x_ticks(2).unwrap();
+    clock.stop().unwrap();
+
+    let error = delay_queue.wait_for_expired().unwrap_err();
+
+    assert!(matches!(error.downcast_ref::<ClockError>(), Some(ClockError::Stopped(_))));
+  }
+}
@@ -0,0 +1,119 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod derived_clock {
+  use super::*;
+
+  #[test]
+  fn derived_clock_counts_every_nth_tick() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut derived_clock = clock.derive(5).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let mut previous_time = derived_clock.time();
+
+    for _ in 0..10 {
+      let time = derived_clock.time();
+
+      assert_eq!(time, previous_time + 1);
+
+      previous_time = time;
+    }
+
+    let parent_time = time_receiver.time();
+
+    assert!(parent_time >= (previous_time + 1) * 5 - 1);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn derived_receivers_share_the_parent_task() {
+    let mut clock = Clock::custom(1).unwrap();
+    let derived_clock = clock.derive(2).unwrap();
+    let mut time_receiver = derived_clock.spawn_receiver();
+
+    assert_eq!(derived_clock.divisor(), 2);
+    assert!(time_receiver.safe_time().is_err());
+
+    clock.start();
+
+    time_receiver
+      .wait_for_time(5)
+      .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+
+    assert_eq!(time_receiver.time(), 6);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn derived_clocks_count_up_to_the_parents_last_tick() {
+    let mut clock = Clock::builder().tick_rate(1).start_at(u64::MAX - 3).build().unwrap();
+    let mut derived_clock = clock.derive(2).unwrap();
+
+    clock.start();
+
+    assert_eq!(derived_clock.safe_time().unwrap(), 0);
+
+    // a saturated parent stays at its last tick, and so does the derived clock
+    assert_eq!(derived_clock.safe_time().unwrap(), 1);
+    assert_eq!(derived_clock.safe_time().unwrap(), 1);
+    assert!(clock.health().task_alive);
+    assert_eq!(clock.stop().unwrap(), u64::MAX);
+
+    let error = derived_clock.safe_time().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(1)));
+  }
+
+  #[test]
+  fn derived_clocks_count_from_the_time_the_parent_started_from() {
+    let mut clock = Clock::custom(5).unwrap();
+    let mut derived_clock = clock.derive(10).unwrap();
+
+    clock.start_from(1000);
+
+    assert_eq!(derived_clock.time(), 0);
+    assert_eq!(derived_clock.time(), 1);
+    assert!(clock.stop().unwrap() >= 1019);
+  }
+
+  #[test]
+  fn slow_derived_receivers_see_every_tick() {
+    let mut clock = Clock::custom(5000).unwrap();
+    let mut derived_clock = clock.derive(2).unwrap();
+
+    clock.start();
+
+    let receiver = thread::spawn(move || {
+      let mut times = vec![derived_clock.time()];
+
+      // comes back once every tick has been sent
+      thread::sleep(Duration::from_millis(100));
+      times.extend((0..3).map(|_| derived_clock.time()));
+
+      times
+    });
+
+    thread::sleep(Duration::from_millis(50));
+
+    for _ in 0..8 {
+      clock.tick_now();
+    }
+
+    assert_eq!(receiver.join().unwrap(), [0, 1, 2, 3]);
+  }
+
+  #[test]
+  fn zero_divisor_errors() {
+    let clock = Clock::new().unwrap();
+
+    assert!(clock.derive(0).is_err());
+  }
+}
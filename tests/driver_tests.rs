@@ -0,0 +1,111 @@
+use std::time::Duration;
+use tokio::time::Instant;
+use thread_clock::{Clock, ClockDriver};
+
+#[cfg(test)]
+mod driver {
+  use super::*;
+
+  #[tokio::test(start_paused = true)]
+  async fn driven_clocks_keep_their_own_tickrates() {
+    let driver = ClockDriver::enclosing()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the driver: '{error}'"));
+    let mut clocks: Vec<Clock> = [2, 4, 8]
+      .iter()
+      .cycle()
+      .take(30)
+      .map(|&tick_rate| {
+        Clock::builder()
+          .tick_rate(tick_rate)
+          .driver(&driver)
+          .enclosing_runtime(true)
+          .build()
+          .unwrap()
+      })
+      .collect();
+
+    clocks.iter_mut().for_each(Clock::start);
+    tokio::time::sleep(Duration::from_millis(1)).await;
+
+    assert_eq!(driver.clock_count(), 30);
+
+    // the wait ends a millisecond after a tick of every clock, so each has made exactly its share of ticks
+    tokio::time::sleep(Duration::from_millis(400)).await;
+
+    for clock in &mut clocks {
+      let tick_rate = clock.tick_rate();
+
+      assert_eq!(clock.ticks_since_last_call(), 400 / u64::from(tick_rate), "a {tick_rate}ms clock");
+    }
+
+    for clock in clocks {
+      clock.stop_async().await.unwrap();
+    }
+
+    assert_eq!(driver.clock_count(), 0);
+  }
+
+  #[test]
+  fn clocks_outlive_their_driver_handle() {
+    let driver = ClockDriver::new().unwrap();
+    let mut clock = Clock::builder().tick_rate(1).driver(&driver).build().unwrap();
+
+    drop(driver);
+    clock.start();
+    clock.wait_for_time(10).unwrap();
+
+    assert!(clock.stop().unwrap() >= 11);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn driven_clocks_follow_rate_changes() {
+    let driver = ClockDriver::enclosing().unwrap();
+    let mut clock = Clock::builder()
+      .tick_rate(20)
+      .driver(&driver)
+      .enclosing_runtime(true)
+      .build()
+      .unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.schedule_rate_change(0, 1);
+    clock.start();
+
+    let started_at = Instant::now();
+
+    while time_receiver.next_tick().await.unwrap() < 50 {}
+
+    // the first tick is a whole tick of the old rate in, the 50 after it one of the new rate apart
+    assert_eq!(started_at.elapsed(), Duration::from_millis(20 + 50));
+
+    clock.stop_async().await.unwrap();
+  }
+
+  #[tokio::test]
+  async fn clocks_and_drivers_share_a_runtime() {
+    let shared_driver = ClockDriver::new().unwrap();
+    let enclosing_driver = ClockDriver::enclosing().unwrap();
+
+    assert!(Clock::builder().driver(&shared_driver).enclosing_runtime(true).build().is_err());
+    assert!(Clock::builder().driver(&enclosing_driver).build().is_err());
+  }
+
+  #[test]
+  fn driven_clocks_cant_be_precise() {
+    let driver = ClockDriver::new().unwrap();
+    let clock = Clock::builder()
+      .driver(&driver)
+      .precision(Duration::from_micros(100))
+      .build();
+
+    assert!(clock.is_err());
+  }
+
+  #[test]
+  fn driven_clocks_cant_spin() {
+    let driver = ClockDriver::new().unwrap();
+    let clock = Clock::builder().driver(&driver).spin(true).build();
+
+    assert!(clock.is_err());
+  }
+}
@@ -0,0 +1,106 @@
+use std::env;
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockBuilder};
+
+// every test sets variables with a prefix of its own, as the environment is shared between them
+
+#[cfg(test)]
+mod env_overrides {
+  use super::*;
+
+  #[test]
+  fn unset_variables_keep_the_defaults() {
+    let clock = Clock::from_env_with_prefix("ENV_TESTS_UNSET")
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    assert_eq!(clock.tick_rate(), thread_clock::DEFAULT_TICKRATE);
+  }
+
+  #[test]
+  fn variables_override_the_builder() {
+    env::set_var("ENV_TESTS_SET_TICK_RATE", "5");
+    env::set_var("ENV_TESTS_SET_CHANNEL_CAPACITY", "4");
+    env::set_var("ENV_TESTS_SET_PRECISION", "sleep");
+
+    let mut clock = ClockBuilder::new().tick_rate(50).env_overrides("ENV_TESTS_SET").unwrap().build().unwrap();
+
+    assert_eq!(clock.tick_rate(), 5);
+
+    clock.start();
+    clock.wait_for_time(3).unwrap();
+
+    assert!(clock.stop().unwrap() >= 4);
+  }
+
+  #[test]
+  fn precision_accepts_microseconds_and_spinning() {
+    env::set_var("ENV_TESTS_MICROS_PRECISION", "200");
+    env::set_var("ENV_TESTS_SPIN_PRECISION", "spin");
+
+    assert!(Clock::from_env_with_prefix("ENV_TESTS_MICROS").is_ok());
+    assert!(Clock::from_env_with_prefix("ENV_TESTS_SPIN").is_ok());
+  }
+
+  #[test]
+  fn invalid_variables_are_errors() {
+    env::set_var("ENV_TESTS_BAD_RATE_TICK_RATE", "fast");
+    env::set_var("ENV_TESTS_BAD_CAPACITY_CHANNEL_CAPACITY", "-1");
+    env::set_var("ENV_TESTS_BAD_PRECISION_PRECISION", "sometimes");
+
+    let error = Clock::from_env_with_prefix("ENV_TESTS_BAD_RATE").unwrap_err();
+
+    assert!(error.to_string().contains("ENV_TESTS_BAD_RATE_TICK_RATE"), "{error}");
+    assert!(Clock::from_env_with_prefix("ENV_TESTS_BAD_CAPACITY").is_err());
+    assert!(Clock::from_env_with_prefix("ENV_TESTS_BAD_PRECISION").is_err());
+  }
+
+  #[test]
+  fn channels_need_a_capacity() {
+    assert!(ClockBuilder::new().channel_capacity(0).build().is_err());
+  }
+
+  #[test]
+  fn larger_channels_still_skip_old_ticks() {
+    let mut clock = Clock::builder().tick_rate(5000).channel_capacity(8).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    for _ in 0..5 {
+      clock.tick_now();
+    }
+
+    thread::sleep(Duration::from_millis(50));
+
+    let waiter = thread::spawn(move || time_receiver.time());
+
+    thread::sleep(Duration::from_millis(50));
+    clock.tick_now();
+
+    // every tick queued up in the channel is an old one
+    assert_eq!(waiter.join().unwrap(), 5);
+  }
+
+  #[test]
+  fn receivers_lagging_behind_get_the_next_tick() {
+    let mut clock = Clock::builder().tick_rate(5000).channel_capacity(2).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    // more ticks than the channel holds, so the receiver has lagged behind
+    for _ in 0..10 {
+      clock.tick_now();
+    }
+
+    thread::sleep(Duration::from_millis(50));
+
+    let waiter = thread::spawn(move || time_receiver.time());
+
+    thread::sleep(Duration::from_millis(50));
+    clock.tick_now();
+
+    assert_eq!(waiter.join().unwrap(), 10);
+  }
+}
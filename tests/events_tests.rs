@@ -0,0 +1,95 @@
+use std::time::Duration;
+use thread_clock::{Clock, ClockEvent, ClockStatus};
+
+#[cfg(test)]
+mod events {
+  use super::*;
+
+  #[test]
+  fn lifecycle_changes_are_received_in_order() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .start_paused(true)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut events = clock.events();
+
+    assert_eq!(events.try_recv(), None);
+
+    clock.start();
+    clock.resume();
+    clock.pause();
+
+    // pausing an already paused clock changes nothing, so there's nothing to hear about
+    clock.pause();
+    clock.resume();
+
+    let final_time = clock.stop().unwrap();
+
+    for expected_event in [
+      ClockEvent::Started,
+      ClockEvent::Paused,
+      ClockEvent::Resumed,
+      ClockEvent::Paused,
+      ClockEvent::Resumed,
+      ClockEvent::Stopped(final_time),
+    ] {
+      // a tick can run late on a busy machine
+      let event = std::iter::repeat_with(|| events.recv().unwrap())
+        .find(|event| !matches!(event, ClockEvent::TickMissed(_)))
+        .unwrap();
+
+      assert_eq!(event, expected_event);
+    }
+
+    assert_eq!(events.try_recv(), None);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn rate_changes_are_received() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut events = clock.events();
+    let mut status = clock.watch_status();
+
+    clock.schedule_rate_change(2, 5);
+    clock.start();
+
+    assert_eq!(events.next_event().await.unwrap(), ClockEvent::Started);
+
+    let rate_changed = tokio::time::timeout(Duration::from_secs(1), async {
+      loop {
+        match events.next_event().await.unwrap() {
+          ClockEvent::RateChanged(tick_length) => return tick_length,
+          ClockEvent::TickMissed(_) => (),
+          event => panic!("Expected the rate to change, got {event:?}"),
+        }
+      }
+    });
+
+    assert_eq!(rate_changed.await.unwrap(), Duration::from_millis(5));
+
+    let final_time = clock.stop().unwrap();
+
+    status.wait_for(|status| matches!(status, ClockStatus::Stopped(_))).await.unwrap();
+
+    loop {
+      match events.next_event().await.unwrap() {
+        ClockEvent::Stopped(time) => break assert_eq!(time, final_time),
+        ClockEvent::TickMissed(_) => (),
+        event => panic!("Expected the clock to stop, got {event:?}"),
+      }
+    }
+  }
+
+  #[test]
+  fn events_end_once_the_clock_is_dropped() {
+    let clock = Clock::custom(1).unwrap();
+    let time_receiver = clock.spawn_receiver();
+    let mut events = clock.events();
+
+    drop(clock);
+    drop(time_receiver);
+
+    assert!(events.recv().is_err());
+  }
+}
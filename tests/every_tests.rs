@@ -0,0 +1,80 @@
+use std::thread;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod every {
+  use super::*;
+
+  #[test]
+  fn only_every_nth_tick_is_let_through() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let mut every_third_tick = time_receiver.every(3).unwrap();
+    let first_time = every_third_tick.next().unwrap().unwrap();
+    let times: Vec<_> = every_third_tick.take(4).map(Result::unwrap).collect();
+
+    assert_eq!(times, [3, 6, 9, 12].map(|ticks| first_time + ticks));
+
+    // the receiver gets every tick again once it's done
+    assert_eq!(time_receiver.time(), times[3] + 1);
+  }
+
+  #[test]
+  fn iterating_ends_when_the_clock_stops() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let handle = thread::spawn(move || time_receiver.every(2).unwrap().map(Result::unwrap).count());
+
+    clock.wait_for_x_ticks(10).unwrap();
+    clock.stop().unwrap();
+
+    assert!(handle.join().unwrap() >= 5);
+  }
+
+  #[tokio::test]
+  async fn every_nth_tick_can_be_awaited() {
+    let mut clock = Clock::builder().tick_rate(1).enclosing_runtime(true).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let mut every_fifth_tick = time_receiver.every(5).unwrap();
+    let first_time = every_fifth_tick.next_tick().await.unwrap();
+
+    assert_eq!(every_fifth_tick.next_tick().await.unwrap(), first_time + 5);
+    assert_eq!(every_fifth_tick.n(), 5);
+  }
+
+  #[test]
+  fn errors_are_yielded_without_ending_the_iteration() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    time_receiver.error_on_gaps(true);
+    clock.start();
+
+    let mut every_other_tick = time_receiver.every(2).unwrap();
+    let first_time = every_other_tick.next().unwrap().unwrap();
+
+    clock.wait_for_time(first_time + 10).unwrap();
+
+    let error = every_other_tick.next().unwrap().unwrap_err();
+
+    assert!(matches!(error.downcast_ref::<ClockError>(), Some(ClockError::MissedTicks { .. })));
+    assert!(every_other_tick.next().unwrap().is_ok());
+  }
+
+  #[test]
+  fn every_0th_tick_is_an_error() {
+    let mut clock = Clock::new().unwrap();
+
+    assert!(clock.every(0).is_err());
+  }
+}
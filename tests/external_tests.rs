@@ -0,0 +1,140 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use thread_clock::Clock;
+
+///How long the clock task is given to emit the ticks it was told about.
+const SETTLE_TIME: Duration = Duration::from_millis(50);
+
+#[cfg(test)]
+mod external {
+  use super::*;
+
+  #[test]
+  fn every_call_ticks_the_clock_once() {
+    let (mut clock, driver) = Clock::external()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.start();
+
+    for _ in 0..10 {
+      driver.tick();
+    }
+
+    thread::sleep(SETTLE_TIME);
+
+    assert_eq!(clock.stop().unwrap(), 9);
+  }
+
+  #[test]
+  fn nothing_ticks_without_the_driver() {
+    let (mut clock, driver) = Clock::external().unwrap();
+
+    clock.start();
+
+    assert!(clock.wait_for_time_or_timeout(0, SETTLE_TIME).is_err());
+
+    driver.tick();
+    thread::sleep(SETTLE_TIME);
+
+    assert_eq!(clock.stop().unwrap(), 0);
+  }
+
+  #[test]
+  fn receivers_see_every_tick_in_order() {
+    let (mut clock, driver) = Clock::external().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let waiter = thread::spawn(move || (time_receiver.time(), time_receiver));
+
+    thread::sleep(SETTLE_TIME);
+    driver.tick();
+
+    let (time, mut time_receiver) = waiter.join().unwrap();
+
+    assert_eq!(time, 0);
+
+    for _ in 0..3 {
+      driver.tick();
+    }
+
+    thread::sleep(SETTLE_TIME);
+
+    let times: Vec<_> = (0..3).map(|_| time_receiver.time()).collect();
+
+    assert_eq!(times, [1, 2, 3]);
+    assert_eq!(time_receiver.gaps(), 0);
+  }
+
+  #[test]
+  fn ticks_while_paused_are_skipped() {
+    let (mut clock, driver) = Clock::external().unwrap();
+
+    clock.start();
+    driver.tick();
+    thread::sleep(SETTLE_TIME);
+    clock.pause();
+
+    for _ in 0..5 {
+      driver.tick();
+    }
+
+    thread::sleep(SETTLE_TIME);
+    clock.resume();
+    driver.tick();
+    thread::sleep(SETTLE_TIME);
+
+    assert_eq!(clock.stop().unwrap(), 1);
+  }
+
+  #[test]
+  fn ticks_dont_carry_over_to_the_next_run() {
+    let (mut clock, driver) = Clock::external().unwrap();
+
+    clock.start();
+    driver.tick();
+    thread::sleep(SETTLE_TIME);
+
+    assert_eq!(clock.stop_in_place().unwrap(), 0);
+
+    for _ in 0..5 {
+      driver.tick();
+    }
+
+    clock.start_from(100);
+    driver.tick();
+    thread::sleep(SETTLE_TIME);
+
+    assert_eq!(clock.stop().unwrap(), 100);
+  }
+
+  #[test]
+  fn receivers_wait_on_the_driver() {
+    let (mut clock, driver) = Clock::external().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let done = Arc::new(AtomicBool::new(false));
+
+    time_receiver.set_divisor(5).unwrap();
+    clock.start();
+
+    let ticker = thread::spawn({
+      let done = Arc::clone(&done);
+
+      move || {
+        while !done.load(Ordering::SeqCst) {
+          thread::sleep(Duration::from_millis(1));
+          driver.tick();
+        }
+      }
+    });
+
+    assert!(time_receiver.wait_until_at_least(3).unwrap() >= 3);
+    assert!(clock.stop().unwrap() >= 15);
+
+    done.store(true, Ordering::SeqCst);
+    ticker.join().unwrap();
+  }
+}
@@ -0,0 +1,66 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod fixed_timestep {
+  use super::*;
+
+  #[test]
+  fn every_tick_is_updated_once() {
+    let mut clock = Clock::custom(2)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut updated_times = Vec::new();
+    let mut renders = 0;
+
+    clock.start();
+
+    clock
+      .run_fixed_timestep(
+        |time| {
+          updated_times.push(time);
+
+          // simulate a slow update so ticks have to be caught up on
+          if time % 5 == 0 {
+            thread::sleep(Duration::from_millis(7));
+          }
+
+          updated_times.len() < 20
+        },
+        |alpha| {
+          assert!((0.0..=1.0).contains(&alpha));
+
+          renders += 1;
+        },
+      )
+      .unwrap_or_else(|error| panic!("An error has occurred while running the loop: '{error}'"));
+
+    let first_time = updated_times[0];
+    let expected_times: Vec<_> = (first_time..first_time + 20).collect();
+
+    assert_eq!(updated_times, expected_times);
+    assert!(renders > 0);
+  }
+
+  #[test]
+  fn loop_ends_when_the_clock_stops() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let handle = thread::spawn(move || time_receiver.run_fixed_timestep(|_| true, |_| ()));
+
+    clock.wait_for_x_ticks(5).unwrap();
+    clock.stop().unwrap();
+
+    assert!(handle.join().unwrap().is_ok());
+  }
+
+  #[test]
+  fn loop_errors_before_the_clock_starts() {
+    let mut clock = Clock::new().unwrap();
+
+    assert!(clock.run_fixed_timestep(|_| true, |_| ()).is_err());
+  }
+}
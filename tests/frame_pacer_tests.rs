@@ -0,0 +1,60 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod frame_pacer {
+  use super::*;
+
+  #[test]
+  fn frames_are_paced_to_the_target_rate() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut frame_pacer = clock.frame_pacer(250.0).unwrap();
+
+    clock.start();
+
+    let first_frame = frame_pacer.wait_for_next_frame().unwrap();
+
+    for frame_number in 1..10 {
+      let frame_info = frame_pacer.wait_for_next_frame().unwrap();
+
+      assert_eq!(frame_info.frame_number, frame_number);
+      assert_eq!(frame_info.time, first_frame.time + frame_number * 4);
+      assert!(frame_info.delta >= Duration::from_millis(2));
+    }
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn late_frames_report_how_late_they_are() {
+    let mut clock = Clock::custom(5).unwrap();
+    let mut frame_pacer = clock.frame_pacer(20.0).unwrap();
+
+    clock.start();
+
+    let first_frame = frame_pacer.wait_for_next_frame().unwrap();
+
+    thread::sleep(Duration::from_millis(200));
+
+    let late_frame = frame_pacer.wait_for_next_frame().unwrap();
+    let next_frame = frame_pacer.wait_for_next_frame().unwrap();
+
+    assert!(late_frame.late_by >= frame_pacer.frame_length());
+    assert!(late_frame.time > first_frame.time + 10);
+    assert_eq!(next_frame.time, late_frame.time + 10);
+    assert_eq!(next_frame.late_by, Duration::ZERO);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn invalid_frame_rates_error() {
+    let clock = Clock::new().unwrap();
+
+    assert!(clock.frame_pacer(0.0).is_err());
+    assert!(clock.frame_pacer(-60.0).is_err());
+    assert!(clock.frame_pacer(f64::NAN).is_err());
+  }
+}
@@ -0,0 +1,52 @@
+use std::sync::Once;
+use std::thread;
+use thread_clock::{Clock, Time};
+
+static CONFIGURE: Once = Once::new();
+
+///Configures the global clock once for every test in this file, as it's shared by the whole process.
+fn configure() {
+  CONFIGURE.call_once(|| {
+    thread_clock::configure_global(Clock::builder().tick_rate(2))
+      .unwrap_or_else(|error| panic!("An error has occurred while configuring the clock: '{error}'"));
+  });
+}
+
+#[cfg(test)]
+mod global {
+  use super::*;
+
+  #[test]
+  fn every_thread_shares_the_global_clock() {
+    configure();
+
+    let handles: Vec<_> = (0..4)
+      .map(|_| {
+        thread::spawn(|| {
+          let clock = thread_clock::global().unwrap();
+          let mut time_receiver = clock.spawn_receiver();
+
+          time_receiver.wait_for_x_ticks(2).unwrap();
+
+          (clock as *const Clock as usize, time_receiver.time())
+        })
+      })
+      .collect();
+    let results: Vec<(usize, Time)> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+    assert!(results.iter().all(|(clock, _)| *clock == results[0].0));
+    assert!(results.iter().all(|(_, time)| *time >= 2));
+    assert_eq!(thread_clock::global().unwrap().tick_rate(), 2);
+  }
+
+  #[test]
+  fn the_global_clock_is_only_configured_once() {
+    configure();
+
+    assert!(thread_clock::configure_global(Clock::builder().tick_rate(5)).is_err());
+
+    thread_clock::global().unwrap();
+
+    assert!(thread_clock::configure_global(Clock::builder().tick_rate(5)).is_err());
+  }
+}
@@ -0,0 +1,86 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::{Clock, ClockError, ClockHook, ClockStatus, Time};
+
+struct PanicAt(Time);
+
+impl ClockHook for PanicAt {
+  fn before_tick(&mut self, time: Time) {
+    if time == self.0 {
+      panic!("the hook panicked at tick {time}");
+    }
+  }
+}
+
+#[cfg(test)]
+mod health {
+  use super::*;
+
+  #[test]
+  fn running_clocks_are_healthy() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    let health = clock.health();
+
+    assert!(!health.task_alive);
+    assert_eq!(health.last_tick, None);
+
+    clock.start();
+    clock.wait_for_time(5).unwrap();
+
+    let health = clock.health();
+
+    assert!(health.task_alive);
+    assert!(health.last_tick >= Some(5));
+  }
+
+  #[test]
+  fn paused_clocks_keep_their_last_tick() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    time_receiver.wait_for_time(5).unwrap();
+    clock.pause();
+
+    let last_tick = clock.health().last_tick;
+
+    assert!(last_tick >= Some(5));
+    assert!(clock.health().task_alive);
+  }
+
+  #[test]
+  fn panicked_tasks_are_reported() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.add_hook(PanicAt(3));
+    clock.start();
+
+    let start = Instant::now();
+
+    while clock.health().task_alive {
+      assert!(start.elapsed() < Duration::from_secs(5), "the clock task didn't end");
+
+      thread::sleep(Duration::from_millis(1));
+    }
+
+    assert_eq!(clock.health().last_tick, Some(3));
+  }
+
+  #[test]
+  fn panicked_tasks_stop_the_clock() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.add_hook(PanicAt(3));
+    clock.start();
+
+    let waiter = thread::spawn(move || time_receiver.wait_for_time(10));
+    let error = waiter.join().unwrap().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(3)));
+    assert_eq!(clock.status(), ClockStatus::Stopped(3));
+    assert!(clock.safe_time().is_err());
+  }
+}
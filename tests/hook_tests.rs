@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use thread_clock::{Clock, ClockHook, Time};
+
+#[derive(Debug, PartialEq, Eq)]
+enum HookCall {
+  Before(Time),
+  After(Time),
+}
+
+struct RecordingHook {
+  calls: Arc<Mutex<Vec<HookCall>>>,
+}
+
+impl ClockHook for RecordingHook {
+  fn before_tick(&mut self, time: Time) {
+    self.calls.lock().unwrap().push(HookCall::Before(time));
+  }
+
+  fn after_tick(&mut self, time: Time, _took: Duration) {
+    self.calls.lock().unwrap().push(HookCall::After(time));
+  }
+}
+
+#[cfg(test)]
+mod hook {
+  use super::*;
+
+  #[test]
+  fn hooks_run_around_every_tick() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    clock.add_hook(RecordingHook {
+      calls: Arc::clone(&calls),
+    });
+    clock.start();
+    clock.wait_for_x_ticks(5).unwrap();
+    clock.stop().unwrap();
+
+    let calls = calls.lock().unwrap();
+
+    assert!(calls.len() >= 10);
+
+    for (time, pair) in calls.chunks(2).enumerate() {
+      let time = time as Time;
+
+      assert_eq!(pair, [HookCall::Before(time), HookCall::After(time)]);
+    }
+  }
+
+  #[test]
+  fn hooks_can_be_added_while_running() {
+    let mut clock = Clock::custom(1).unwrap();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    clock.start();
+    clock.wait_for_x_ticks(3).unwrap();
+
+    clock.add_hook(RecordingHook {
+      calls: Arc::clone(&calls),
+    });
+    clock.wait_for_x_ticks(3).unwrap();
+
+    let first_call = calls.lock().unwrap().first().map(|call| match call {
+      HookCall::Before(time) | HookCall::After(time) => *time,
+    });
+
+    assert!(first_call.is_some_and(|time| time >= 2));
+  }
+
+  #[test]
+  fn before_tick_delays_the_tick() {
+    struct SlowTicks;
+
+    impl ClockHook for SlowTicks {
+      fn before_tick(&mut self, _time: Time) {
+        std::thread::sleep(Duration::from_millis(20));
+      }
+    }
+
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.add_hook(SlowTicks);
+    clock.start();
+
+    let started_at = std::time::Instant::now();
+
+    clock.wait_for_x_ticks(3).unwrap();
+
+    assert!(started_at.elapsed() >= Duration::from_millis(40));
+  }
+}
@@ -0,0 +1,54 @@
+#![cfg(all(target_os = "macos", feature = "kqueue"))]
+
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::{Backend, Clock};
+
+#[cfg(test)]
+mod kqueue {
+  use super::*;
+
+  #[test]
+  fn kqueue_clocks_keep_their_tickrate() {
+    let mut clock = Clock::builder()
+      .tick_rate(2)
+      .backend(Backend::Kqueue)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.start();
+
+    let started_at = Instant::now();
+
+    thread::sleep(Duration::from_millis(300));
+
+    let expected_time = started_at.elapsed().as_millis() as u64 / 2;
+    let final_time = clock.stop().unwrap();
+
+    assert!(
+      final_time >= expected_time * 8 / 10 && final_time <= expected_time + 2,
+      "the clock reached {final_time} instead of {expected_time}"
+    );
+  }
+
+  #[test]
+  fn kqueue_clocks_follow_rate_changes() {
+    let mut clock = Clock::builder().tick_rate(20).backend(Backend::Kqueue).build().unwrap();
+
+    clock.schedule_rate_change(0, 1);
+    clock.start();
+
+    let started_at = Instant::now();
+
+    clock.wait_for_time(50).unwrap();
+
+    assert!(started_at.elapsed() < Duration::from_millis(300), "{:?}", started_at.elapsed());
+  }
+
+  #[test]
+  fn kqueue_clocks_cant_spin() {
+    let clock = Clock::builder().backend(Backend::Kqueue).spin(true).build();
+
+    assert!(clock.is_err());
+  }
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "log")]
+
+use log::{Level, Log, Metadata, Record};
+use std::sync::Mutex;
+use thread_clock::Clock;
+
+static RECORDS: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+
+struct RecordingLogger;
+
+impl Log for RecordingLogger {
+  fn enabled(&self, metadata: &Metadata) -> bool {
+    metadata.target() == "thread_clock"
+  }
+
+  fn log(&self, record: &Record) {
+    if self.enabled(record.metadata()) {
+      RECORDS.lock().unwrap().push((record.level(), record.args().to_string()));
+    }
+  }
+
+  fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod logging {
+  use super::*;
+
+  #[test]
+  fn start_and_stop_are_logged() {
+    log::set_logger(&RecordingLogger).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.start();
+    clock.wait_for_x_ticks(3).unwrap();
+
+    let final_time = clock.stop().unwrap();
+    let records = RECORDS.lock().unwrap();
+
+    assert!(records.contains(&(Level::Debug, "Started a clock ticking every 1ms".to_owned())));
+    assert!(records.contains(&(Level::Debug, format!("Stopped a clock at tick {final_time}"))));
+  }
+}
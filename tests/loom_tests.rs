@@ -0,0 +1,163 @@
+#![cfg(loom)]
+
+// run with `RUSTFLAGS="--cfg loom" cargo test --no-default-features --release --test loom_tests`, the cli
+// isn't built as tokio leaves its signals out under loom
+
+use loom::future::block_on;
+use loom::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use loom::sync::{Arc, Mutex};
+use loom::thread;
+use thread_clock::model::{next_message, ClockMessage};
+use tokio::sync::broadcast;
+
+///A clock's side of the channel, which sends its messages once something is reading the way a simulated
+///clock waits for its receivers.
+#[derive(Clone)]
+struct ModelClock {
+  readers: Arc<AtomicUsize>,
+  ///The final time plus one once the clock has stopped, which is set before the stop is sent.
+  stopped_at: Arc<AtomicU64>,
+  ///Stands in for the lock tokio's channel takes to send and to move a receiver to its end, which loom
+  ///can't see into, so what was done before a message was sent is seen by everything receiving it.
+  channel: Arc<Mutex<()>>,
+  clock_sender: broadcast::Sender<ClockMessage>,
+}
+
+impl ModelClock {
+  fn new(capacity: usize) -> (Self, broadcast::Receiver<ClockMessage>) {
+    let (clock_sender, time_receiver) = broadcast::channel(capacity);
+    let clock = Self {
+      readers: Arc::new(AtomicUsize::new(0)),
+      stopped_at: Arc::new(AtomicU64::new(0)),
+      channel: Arc::new(Mutex::new(())),
+      clock_sender,
+    };
+
+    (clock, time_receiver)
+  }
+
+  fn send(&self, message: ClockMessage) {
+    if let ClockMessage::Stopped(final_time) = message {
+      self.stopped_at.store(final_time + 1, Ordering::SeqCst);
+    }
+
+    let _channel = self.channel.lock().unwrap();
+    let _ = self.clock_sender.send(message);
+  }
+
+  ///Sends the messages on another thread once something is reading.
+  fn send_once_read(&self, messages: Vec<ClockMessage>) -> thread::JoinHandle<()> {
+    let clock = self.clone();
+
+    thread::spawn(move || {
+      while clock.readers.load(Ordering::SeqCst) == 0 {
+        thread::yield_now();
+      }
+
+      for message in messages {
+        clock.send(message);
+      }
+    })
+  }
+
+  ///Receives the next message the way a time receiver does, which checks the clock hasn't stopped first.
+  fn next_message(&self, time_receiver: &mut broadcast::Receiver<ClockMessage>) -> ClockMessage {
+    if let Some(final_time) = self.stopped_at.load(Ordering::SeqCst).checked_sub(1) {
+      return ClockMessage::Stopped(final_time);
+    }
+
+    next_message(
+      time_receiver,
+      || {
+        // reading starts right after the receiver has moved to the end of the channel
+        drop(self.channel.lock().unwrap());
+        self.readers.fetch_add(1, Ordering::SeqCst)
+      },
+      || self.stopped_at.load(Ordering::SeqCst).checked_sub(1),
+      |time_receiver| Ok(block_on(time_receiver.recv())),
+    )
+    .unwrap()
+  }
+}
+
+#[cfg(test)]
+mod next_message {
+  use super::*;
+
+  #[test]
+  fn old_ticks_are_skipped() {
+    loom::model(|| {
+      let (clock, mut time_receiver) = ModelClock::new(2);
+
+      clock.send(ClockMessage::Tick(0));
+
+      let sender_handle = clock.send_once_read(vec![ClockMessage::Tick(1)]);
+
+      assert_eq!(clock.next_message(&mut time_receiver), ClockMessage::Tick(1));
+      sender_handle.join().unwrap();
+    });
+  }
+
+  #[test]
+  fn the_first_fresh_tick_is_received() {
+    loom::model(|| {
+      let (clock, mut time_receiver) = ModelClock::new(1);
+
+      clock.send(ClockMessage::Tick(0));
+
+      let sender_handle = clock.send_once_read(vec![ClockMessage::Tick(1), ClockMessage::Tick(2)]);
+      let message = clock.next_message(&mut time_receiver);
+
+      // the second tick can overwrite the first before the receiver is woken up
+      assert!(matches!(message, ClockMessage::Tick(1 | 2)), "{message:?}");
+      sender_handle.join().unwrap();
+    });
+  }
+
+  #[test]
+  fn lagging_receivers_catch_up_to_the_newest_tick() {
+    loom::model(|| {
+      let (clock, mut time_receiver) = ModelClock::new(2);
+
+      clock.send(ClockMessage::Tick(0));
+
+      let sender_handle =
+        clock.send_once_read(vec![ClockMessage::Tick(1), ClockMessage::Tick(2), ClockMessage::Tick(3)]);
+      let message = clock.next_message(&mut time_receiver);
+
+      // the second tick is only ever the oldest one left once the receiver has lagged behind the third
+      assert!(matches!(message, ClockMessage::Tick(1 | 3)), "{message:?}");
+      sender_handle.join().unwrap();
+    });
+  }
+
+  #[test]
+  fn stopping_over_an_old_tick_is_seen() {
+    loom::model(|| {
+      let (clock, mut time_receiver) = ModelClock::new(1);
+
+      clock.send(ClockMessage::Tick(0));
+
+      let sender_handle = clock.send_once_read(vec![ClockMessage::Stopped(0)]);
+
+      assert_eq!(clock.next_message(&mut time_receiver), ClockMessage::Stopped(0));
+      sender_handle.join().unwrap();
+    });
+  }
+
+  #[test]
+  fn stopping_while_starting_to_read_is_seen() {
+    loom::model(|| {
+      let (clock, mut time_receiver) = ModelClock::new(1);
+      let stopping_clock = clock.clone();
+
+      clock.send(ClockMessage::Tick(0));
+
+      // the stop doesn't wait for anything to be reading, so it can happen before or after the receiver moves
+      let sender_handle = thread::spawn(move || stopping_clock.send(ClockMessage::Stopped(0)));
+
+      assert_eq!(clock.next_message(&mut time_receiver), ClockMessage::Stopped(0));
+      sender_handle.join().unwrap();
+    });
+  }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "metrics")]
+
+use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+use std::collections::HashMap;
+use thread_clock::Clock;
+
+///Returns the value of every metric recorded so far by its name, checking that they're all labelled
+///with the clock's name.
+fn recorded_metrics(snapshotter: &Snapshotter, clock_name: &str) -> HashMap<String, DebugValue> {
+  snapshotter
+    .snapshot()
+    .into_vec()
+    .into_iter()
+    .map(|(key, _, _, value)| {
+      let key = key.key();
+
+      assert!(key.labels().any(|label| label.key() == "clock" && label.value() == clock_name));
+
+      (key.name().to_owned(), value)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod metrics_exporter {
+  use super::*;
+
+  #[test]
+  fn ticks_are_recorded() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    let mut clock = Clock::custom(2)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let _time_receiver = clock.spawn_receiver();
+
+    metrics::with_local_recorder(&recorder, || clock.export_metrics("test_clock"));
+    clock.start();
+    clock.wait_for_x_ticks(10).unwrap();
+    clock.stop().unwrap();
+
+    let metrics = recorded_metrics(&snapshotter, "test_clock");
+
+    assert!(matches!(metrics["thread_clock_ticks_total"], DebugValue::Counter(ticks) if ticks >= 10));
+    assert!(matches!(metrics["thread_clock_ticks_per_second"], DebugValue::Gauge(tps) if tps.0 > 0.0));
+    assert!(matches!(metrics["thread_clock_receivers"], DebugValue::Gauge(receivers) if receivers.0 == 1.0));
+    assert!(matches!(&metrics["thread_clock_tick_jitter_seconds"], DebugValue::Histogram(jitter) if jitter.len() >= 9));
+    assert!(metrics.contains_key("thread_clock_missed_deadlines_total"));
+  }
+
+  #[test]
+  fn nothing_is_recorded_before_starting() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    let clock = Clock::custom(2).unwrap();
+
+    metrics::with_local_recorder(&recorder, || clock.export_metrics("idle_clock"));
+
+    let metrics = recorded_metrics(&snapshotter, "idle_clock");
+
+    assert_eq!(metrics["thread_clock_ticks_total"], DebugValue::Counter(0));
+  }
+}
@@ -0,0 +1,75 @@
+use thread_clock::{BeatEvent, Clock};
+
+#[cfg(test)]
+mod metronome {
+  use super::*;
+
+  #[test]
+  fn beats_count_up_through_bars() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    // a beat every 10 ticks
+    let mut metronome = clock.metronome(6_000.0, 3).unwrap();
+
+    clock.start();
+
+    let beat_events: Vec<BeatEvent> = (0..7).map(|_| metronome.next_beat().unwrap()).collect();
+    let beats: Vec<(u64, u32, bool)> = beat_events
+      .iter()
+      .map(|beat_event| (beat_event.bar, beat_event.beat, beat_event.is_downbeat))
+      .collect();
+
+    // nothing else reads the clock, so no beat is late enough to be skipped
+    assert_eq!(
+      beats,
+      [
+        (0, 0, true),
+        (0, 1, false),
+        (0, 2, false),
+        (1, 0, true),
+        (1, 1, false),
+        (1, 2, false),
+        (2, 0, true)
+      ]
+    );
+    assert!(beat_events.windows(2).all(|pair| pair[1].time >= pair[0].time + 9));
+  }
+
+  #[test]
+  fn uneven_tempos_dont_drift() {
+    let clock = Clock::custom(10).unwrap();
+    // 140 beats per minute is a beat every 42.857 ticks
+    let metronome = clock.metronome(140.0, 4).unwrap();
+
+    assert_eq!(metronome.beat_at(0).bar, 0);
+    assert_eq!(metronome.beat_at(4_284).bar, 24);
+    assert_eq!(metronome.beat_at(4_284).beat, 3);
+    // beat 100 is due after 4285.7 ticks
+    assert_eq!(metronome.beat_at(4_285).bar, 25);
+    assert!(metronome.beat_at(4_285).is_downbeat);
+  }
+
+  #[test]
+  fn late_reads_skip_missed_beats() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut metronome = clock.metronome(30_000.0, 4).unwrap();
+
+    clock.start();
+    metronome.next_beat().unwrap();
+    clock.wait_for_x_ticks(20).unwrap();
+
+    let beat_event = metronome.next_beat().unwrap();
+
+    assert!(beat_event.bar >= 2, "{beat_event:?}");
+  }
+
+  #[test]
+  fn invalid_tempos_are_rejected() {
+    let clock = Clock::custom(1).unwrap();
+
+    assert!(clock.metronome(0.0, 4).is_err());
+    assert!(clock.metronome(f64::NAN, 4).is_err());
+    assert!(clock.metronome(120.0, 0).is_err());
+    assert!(Clock::custom(0).unwrap().metronome(120.0, 4).is_err());
+  }
+}
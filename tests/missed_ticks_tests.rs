@@ -0,0 +1,173 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockError, DEFAULT_CHANNEL_CAPACITY};
+
+#[cfg(test)]
+mod missed_ticks {
+  use super::*;
+
+  #[test]
+  fn skipped_ticks_are_reported() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+    let (missed_sender, missed_ticks) = mpsc::channel();
+
+    time_receiver.on_missed_ticks(move |missed| missed_sender.send(missed).unwrap());
+    clock.start();
+
+    let time = time_receiver.time();
+
+    thread::sleep(Duration::from_millis(20));
+
+    let later_time = time_receiver.time();
+    let missed = missed_ticks.try_recv().unwrap();
+
+    assert_eq!(missed, time + 1..later_time);
+    assert!(!missed.is_empty());
+  }
+
+  #[test]
+  fn receivers_keeping_up_miss_nothing() {
+    let mut clock = Clock::custom(10).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let (missed_sender, missed_ticks) = mpsc::channel();
+
+    time_receiver.on_missed_ticks(move |missed| missed_sender.send(missed).unwrap());
+    clock.start();
+    time_receiver.wait_for_x_ticks(5).unwrap();
+
+    assert!(missed_ticks.try_recv().is_err());
+  }
+
+  #[test]
+  fn receivers_at_different_speeds_see_every_tick() {
+    let mut clock = Clock::custom(5000).unwrap();
+
+    clock.start();
+
+    let receivers = [Duration::ZERO, Duration::from_millis(100)].map(|delay| {
+      let mut time_receiver = clock.spawn_receiver();
+
+      thread::spawn(move || {
+        let mut times = vec![time_receiver.time()];
+
+        // the slower receiver comes back once every tick has been sent
+        thread::sleep(delay);
+        times.extend((0..3).map(|_| time_receiver.time()));
+
+        (times, time_receiver.gaps())
+      })
+    });
+
+    thread::sleep(Duration::from_millis(50));
+
+    for _ in 0..4 {
+      clock.tick_now();
+    }
+
+    for receiver in receivers {
+      assert_eq!(receiver.join().unwrap(), (vec![0, 1, 2, 3], 0));
+    }
+  }
+
+  #[test]
+  fn divided_receivers_report_their_own_ticks() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let (missed_sender, missed_ticks) = mpsc::channel();
+
+    time_receiver.set_divisor(5).unwrap();
+    time_receiver.on_missed_ticks(move |missed| missed_sender.send(missed).unwrap());
+    clock.start();
+
+    let time = time_receiver.time();
+
+    thread::sleep(Duration::from_millis(30));
+
+    let later_time = time_receiver.time();
+
+    assert_eq!(missed_ticks.try_recv().unwrap(), time + 1..later_time);
+    assert!(later_time - time < 30);
+  }
+
+  #[test]
+  fn skipped_ticks_are_counted() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let time = time_receiver.time();
+
+    assert_eq!(time_receiver.gaps(), 0);
+
+    thread::sleep(Duration::from_millis(20));
+
+    let later_time = time_receiver.time();
+
+    assert_eq!(time_receiver.gaps(), later_time - time - 1);
+  }
+
+  #[test]
+  fn receivers_can_error_on_gaps() {
+    let mut clock = Clock::custom(5000).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let (first_sender, first_time) = mpsc::channel();
+    let (skipped_sender, skipped) = mpsc::channel();
+
+    time_receiver.error_on_gaps(true);
+    clock.start();
+
+    let handle = thread::spawn(move || {
+      first_sender.send(time_receiver.time()).unwrap();
+      skipped.recv().unwrap();
+
+      let error = time_receiver.safe_time().unwrap_err();
+
+      (error.downcast::<ClockError>().unwrap(), time_receiver.gaps())
+    });
+
+    // the sleeps give the receiver time to start waiting, if it hasn't it gets the clock's own tick instead
+    thread::sleep(Duration::from_millis(50));
+    clock.tick_now();
+
+    let time = first_time.recv().unwrap();
+    let skipped_ticks = DEFAULT_CHANNEL_CAPACITY as u64 + 2;
+
+    // more ticks than the channel holds are sent while the receiver isn't reading
+    for _ in 0..skipped_ticks {
+      clock.tick_now();
+    }
+
+    clock.wait_until_at_least(time + skipped_ticks).unwrap();
+    skipped_sender.send(()).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    clock.tick_now();
+
+    let (error, gaps) = handle.join().unwrap();
+    let ClockError::MissedTicks { first_missed, time: received_time } = error else {
+      panic!("Expected the gap to be an error, got '{error}'");
+    };
+
+    assert_eq!(first_missed, time + 1);
+    assert!(received_time > time + skipped_ticks, "{received_time}");
+    assert_eq!(gaps, received_time - first_missed);
+  }
+
+  #[test]
+  fn gaps_dont_keep_the_clock_from_stopping() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.error_on_gaps(true);
+    clock.start();
+    clock.time();
+
+    thread::sleep(Duration::from_millis(50));
+
+    assert!(clock.stop_in_place().is_ok());
+    assert!(clock.stop_in_place().is_err());
+    assert!(!clock.is_running());
+  }
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "net")]
+
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockFollower, ClockLeader};
+
+#[cfg(test)]
+mod clock_follower {
+  use super::*;
+
+  #[test]
+  fn followers_start_on_the_leaders_tick() {
+    let mut clock = Clock::custom(5)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let leader = ClockLeader::bind("127.0.0.1:0", &clock).unwrap();
+
+    clock.start_from(1000);
+
+    let mut follower = ClockFollower::connect(leader.local_addr()).unwrap();
+
+    assert_eq!(follower.tick_rate(), 5);
+    assert_eq!(follower.leader_addr(), leader.local_addr());
+    assert!(follower.time().abs_diff(clock.time()) <= 2);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn followers_stay_in_lockstep() {
+    let mut clock = Clock::custom(20).unwrap();
+    let leader = ClockLeader::bind("127.0.0.1:0", &clock).unwrap();
+
+    clock.start();
+
+    let mut follower = ClockFollower::connect(leader.local_addr()).unwrap();
+
+    thread::sleep(Duration::from_millis(300));
+
+    assert!(follower.time().abs_diff(clock.time()) <= 2);
+    assert!(follower.latency() < Duration::from_millis(50));
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn followers_are_pulled_towards_the_leader() {
+    let mut clock = Clock::custom(10).unwrap();
+    let leader = ClockLeader::bind("127.0.0.1:0", &clock).unwrap();
+
+    clock.start();
+
+    let mut follower = ClockFollower::connect(leader.local_addr()).unwrap();
+
+    // the follower falls behind while it's paused, then ticks faster than the leader to catch up
+    follower.pause();
+    thread::sleep(Duration::from_millis(200));
+    follower.resume();
+
+    let behind = clock.time().saturating_sub(follower.time());
+
+    thread::sleep(Duration::from_millis(1000));
+
+    assert!(clock.time().saturating_sub(follower.time()) < behind);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn leaders_that_arent_running_are_errors() {
+    let clock = Clock::custom(5).unwrap();
+    let leader = ClockLeader::bind("127.0.0.1:0", &clock).unwrap();
+
+    assert!(ClockFollower::connect(leader.local_addr()).is_err());
+  }
+}
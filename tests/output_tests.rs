@@ -0,0 +1,70 @@
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod output {
+  use super::*;
+
+  #[test]
+  fn outputs_tick_at_their_own_rates() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut fast_output = clock.output(2).unwrap();
+    let mut slow_output = clock.output(10).unwrap();
+
+    clock.start();
+
+    slow_output.wait_for_time(2).unwrap();
+
+    let fast_time = fast_output.time();
+    let clock_time = clock.time();
+
+    assert!(fast_time >= 14, "{fast_time}");
+    assert!(clock_time >= 30, "{clock_time}");
+    assert_eq!(slow_output.tick_rate(), 10);
+  }
+
+  #[test]
+  fn outputs_count_from_when_the_clock_started() {
+    let mut clock = Clock::custom(1).unwrap();
+
+    clock.start();
+    clock.wait_for_time(20).unwrap();
+
+    let mut output = clock.output(5).unwrap();
+
+    assert!(output.time() >= 4);
+  }
+
+  #[test]
+  fn outputs_count_from_the_time_the_clock_started_from() {
+    let mut clock = Clock::custom(5).unwrap();
+    let mut output = clock.output(50).unwrap();
+
+    clock.start_from(1000);
+
+    assert_eq!(output.time(), 0);
+    assert_eq!(output.time(), 1);
+  }
+
+  #[test]
+  fn outputs_stop_with_the_clock() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut output = clock.output(3).unwrap();
+
+    clock.start();
+    clock.wait_for_x_ticks(7).unwrap();
+    clock.stop().unwrap();
+
+    assert!(output.wait_for_tick().is_err());
+  }
+
+  #[test]
+  fn periods_have_to_be_multiples_of_the_tickrate() {
+    let clock = Clock::custom(4).unwrap();
+
+    assert!(clock.output(0).is_err());
+    assert!(clock.output(6).is_err());
+    assert!(clock.output(4).is_ok());
+    assert!(clock.output(12).is_ok());
+  }
+}
@@ -0,0 +1,139 @@
+use thread_clock::{Clock, ClockError, ClockStatus, Overflow, Time};
+
+#[cfg(test)]
+mod overflow {
+  use super::*;
+
+  #[test]
+  fn clocks_start_at_the_start_time() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .start_at(1000)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.start();
+
+    assert_eq!(clock.time(), 1000);
+
+    clock.wait_for_time(1005).unwrap();
+
+    assert_eq!(clock.stop().unwrap(), 1006);
+  }
+
+  #[test]
+  fn ticks_are_counted_from_the_start_time() {
+    let mut clock = Clock::builder().tick_rate(1000).start_at(1000).build().unwrap();
+
+    clock.start();
+
+    assert_eq!(clock.time(), 1000);
+    assert_eq!(clock.ticks_since_last_call(), 1);
+
+    clock.stop_now().unwrap();
+
+    // a clock that starts at the maximum time has made a single tick as well
+    let mut clock = Clock::builder().tick_rate(1000).start_at(Time::MAX).build().unwrap();
+
+    clock.start();
+
+    assert_eq!(clock.time(), Time::MAX);
+    assert_eq!(clock.ticks_since_last_call(), 1);
+  }
+
+  #[test]
+  fn saturating_clocks_stay_at_the_maximum_time() {
+    let mut clock = Clock::builder().tick_rate(1).start_at(Time::MAX - 2).build().unwrap();
+
+    clock.start();
+    clock.wait_until_at_least(Time::MAX).unwrap();
+
+    for _ in 0..5 {
+      assert_eq!(clock.time(), Time::MAX);
+    }
+
+    assert!(clock.wait_for_time(Time::MAX).is_err());
+    assert_eq!(clock.stop().unwrap(), Time::MAX);
+  }
+
+  #[test]
+  fn wrapping_clocks_count_from_0_again() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .start_at(Time::MAX - 2)
+      .overflow(Overflow::Wrap)
+      .build()
+      .unwrap();
+
+    clock.start();
+
+    assert_eq!(clock.time(), Time::MAX - 2);
+
+    clock.wait_for_time(3).unwrap();
+
+    assert_eq!(clock.time(), 4);
+  }
+
+  #[test]
+  fn wrapping_clocks_count_ticks_across_the_wrap() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .start_at(Time::MAX - 2)
+      .overflow(Overflow::Wrap)
+      .build()
+      .unwrap();
+
+    clock.start();
+
+    assert_eq!(clock.time(), Time::MAX - 2);
+    assert_eq!(clock.ticks_since_last_call(), 1);
+
+    clock.wait_for_time(3).unwrap();
+
+    assert!(clock.ticks_since_last_call() >= 6);
+  }
+
+  #[test]
+  fn wrapping_clocks_compare_times_across_the_wrap() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .start_at(Time::MAX - 5)
+      .overflow(Overflow::Wrap)
+      .build()
+      .unwrap();
+
+    clock.start();
+    clock.wait_until_at_least(2).unwrap();
+
+    let error = clock.wait_for_time(Time::MAX - 1).unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::TimeHasOccurred));
+
+    let time = clock.wait_until_at_least(Time::MAX).unwrap();
+
+    assert!((3..Time::MAX / 2).contains(&time));
+  }
+
+  #[test]
+  fn erroring_clocks_stop_after_the_maximum_time() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .start_at(Time::MAX - 1)
+      .overflow(Overflow::Error)
+      .build()
+      .unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let error = loop {
+      if let Err(error) = time_receiver.safe_time() {
+        break error;
+      }
+    };
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Overflowed));
+    assert_eq!(clock.status(), ClockStatus::Stopped(Time::MAX));
+    assert_eq!(clock.stop().unwrap(), Time::MAX);
+  }
+}
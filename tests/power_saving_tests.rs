@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::{Clock, ClockHook, ClockStatus, Time};
+
+type SentTickLog = Arc<Mutex<Vec<(Time, Instant)>>>;
+
+struct SentTicks(SentTickLog);
+
+impl ClockHook for SentTicks {
+  fn before_tick(&mut self, time: Time) {
+    self.0.lock().unwrap().push((time, Instant::now()));
+  }
+}
+
+///Creates a 1ms clock that lowers its rate to 50ms after 30ms without being read.
+fn power_saving_clock() -> (Clock, SentTickLog) {
+  let clock = Clock::builder()
+    .tick_rate(1)
+    .power_saving(Duration::from_millis(30), 50)
+    .build()
+    .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+  let sent_ticks = Arc::new(Mutex::new(Vec::new()));
+
+  clock.add_hook(SentTicks(Arc::clone(&sent_ticks)));
+
+  (clock, sent_ticks)
+}
+
+#[cfg(test)]
+mod power_saving {
+  use super::*;
+
+  #[test]
+  fn unread_clocks_tick_less_often() {
+    let (mut clock, sent_ticks) = power_saving_clock();
+
+    clock.start();
+    clock.wait_for_x_ticks(5).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let lowered_from = Instant::now();
+
+    thread::sleep(Duration::from_millis(300));
+
+    let lowered_ticks: Vec<Time> = sent_ticks
+      .lock()
+      .unwrap()
+      .iter()
+      .filter(|(_, sent_at)| *sent_at >= lowered_from)
+      .map(|(time, _)| *time)
+      .collect();
+
+    // 300ms at 50ms a tick, while each tick skips ahead over the ticks that weren't sent
+    assert!((3..=8).contains(&lowered_ticks.len()), "{lowered_ticks:?}");
+    assert!(lowered_ticks.windows(2).all(|pair| pair[1] > pair[0] + 20), "{lowered_ticks:?}");
+  }
+
+  #[test]
+  fn time_keeps_counting_while_lowered() {
+    let (mut clock, _) = power_saving_clock();
+
+    clock.start();
+
+    let started_at = Instant::now();
+    let first_time = clock.time();
+
+    thread::sleep(Duration::from_millis(300));
+
+    let time = clock.time();
+    let elapsed_ticks = started_at.elapsed().as_millis() as Time;
+
+    // a sleeping 1ms clock ticks a bit slower than 1ms, but not by more than a third
+    assert!(time > first_time + elapsed_ticks * 2 / 3, "{time} after {elapsed_ticks}ms");
+    assert!(time <= first_time + elapsed_ticks + 1, "{time} after {elapsed_ticks}ms");
+  }
+
+  #[test]
+  fn reading_snaps_back_to_the_full_rate() {
+    let (mut clock, _) = power_saving_clock();
+
+    clock.start();
+    clock.time();
+    thread::sleep(Duration::from_millis(200));
+
+    let snapped_at = Instant::now();
+    let time = clock.time();
+
+    clock.wait_for_x_ticks(20).unwrap();
+
+    // at 50ms a tick, 21 ticks would take over a second
+    assert!(snapped_at.elapsed() < Duration::from_millis(200), "{:?}", snapped_at.elapsed());
+    assert_eq!(clock.time(), time + 21);
+  }
+
+  #[test]
+  fn lowered_clocks_stop_at_their_stop_time() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .power_saving(Duration::from_millis(5), 20)
+      .stop_at(50)
+      .build()
+      .unwrap();
+
+    clock.start();
+    thread::sleep(Duration::from_millis(400));
+
+    assert_eq!(clock.status(), ClockStatus::Stopped(50));
+  }
+}
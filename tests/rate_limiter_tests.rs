@@ -0,0 +1,90 @@
+use std::thread;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod rate_limiter {
+  use super::*;
+
+  #[test]
+  fn tokens_refill_every_tick() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let rate_limiter = clock.rate_limiter(3, 1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    while rate_limiter.try_acquire() {}
+
+    assert_eq!(rate_limiter.available(), 0);
+
+    clock.start();
+    time_receiver.wait_for_x_ticks(5).unwrap();
+
+    assert_eq!(rate_limiter.available(), 3);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn acquire_is_shared_between_threads() {
+    let mut clock = Clock::custom(1).unwrap();
+    let rate_limiter = clock.rate_limiter(1, 1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let start_time = time_receiver.time();
+    let handles: Vec<_> = (0..4)
+      .map(|_| {
+        let rate_limiter = rate_limiter.clone();
+
+        thread::spawn(move || {
+          for _ in 0..5 {
+            rate_limiter.acquire().unwrap();
+          }
+        })
+      })
+      .collect();
+
+    for handle in handles {
+      handle.join().unwrap();
+    }
+
+    // 20 tokens were taken with a single token refilled every tick
+    assert!(time_receiver.time() >= start_time + 18);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn acquire_errors_when_the_clock_isnt_running() {
+    let mut clock = Clock::custom(1).unwrap();
+    let rate_limiter = clock.rate_limiter(1, 1).unwrap();
+
+    rate_limiter.acquire().unwrap();
+
+    let not_started_error = rate_limiter.acquire().unwrap_err();
+
+    assert_eq!(not_started_error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+
+    clock.start();
+
+    let worker_rate_limiter = rate_limiter.clone();
+    let handle = thread::spawn(move || loop {
+      if let Err(error) = worker_rate_limiter.acquire() {
+        return error;
+      }
+    });
+
+    let final_time = clock.stop().unwrap();
+    let stopped_error = handle.join().unwrap();
+
+    assert_eq!(stopped_error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+  }
+
+  #[test]
+  fn zero_capacity_errors() {
+    let clock = Clock::new().unwrap();
+
+    assert!(clock.rate_limiter(0, 1).is_err());
+  }
+}
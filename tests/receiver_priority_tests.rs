@@ -0,0 +1,52 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use thread_clock::{Clock, ReceiverPriority};
+
+#[cfg(test)]
+mod receiver_priority {
+  use super::*;
+
+  // only a single threaded runtime wakes tasks in the order the ticks are sent, others can run them in any order
+  #[tokio::test(flavor = "current_thread")]
+  async fn receivers_on_one_thread_are_woken_up_in_order_of_priority() {
+    let mut clock = Clock::builder()
+      .tick_rate(20)
+      .enclosing_runtime(true)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let woken = Arc::new(Mutex::new(vec![]));
+
+    // spawned from the lowest priority up, so the order they're woken in isn't the order they wait in
+    let receivers = [ReceiverPriority::Low, ReceiverPriority::Normal, ReceiverPriority::High].map(|priority| {
+      let mut time_receiver = clock.spawn_receiver_with_priority(priority);
+      let woken = Arc::clone(&woken);
+
+      tokio::spawn(async move {
+        time_receiver.next_tick().await.unwrap();
+        woken.lock().unwrap().push(priority);
+      })
+    });
+
+    clock.start();
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    for receiver in receivers {
+      receiver.await.unwrap();
+    }
+
+    assert_eq!(
+      *woken.lock().unwrap(),
+      [ReceiverPriority::High, ReceiverPriority::Normal, ReceiverPriority::Low]
+    );
+  }
+
+  #[test]
+  fn spawned_receivers_keep_their_priority() {
+    let clock = Clock::new().unwrap();
+    let time_receiver = clock.spawn_receiver_with_priority(ReceiverPriority::High);
+
+    assert_eq!(time_receiver.spawn_receiver().priority(), ReceiverPriority::High);
+    assert_eq!(clock.spawn_receiver().priority(), ReceiverPriority::Normal);
+    assert_eq!(clock.receiver_count(), 1);
+  }
+}
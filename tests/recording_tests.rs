@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use thread_clock::{Clock, TickRecording};
+
+///Returns a path in the temp directory that no other test or test run uses.
+fn recording_path(test_name: &str) -> PathBuf {
+  std::env::temp_dir().join(format!("thread-clock-{}-{test_name}.ticks", std::process::id()))
+}
+
+///Writes a recording of a clock with the tickrate whose ticks, starting at `first_time`, were the
+///delays in milliseconds apart.
+fn write_recording(test_name: &str, tick_rate: u32, first_time: u64, delays: &[u64]) -> TickRecording {
+  let path = recording_path(test_name);
+  let mut happened_at = 1_700_000_000_000_000_000_u64;
+  let mut contents = format!("# thread_clock tick recording, tick_rate {tick_rate}\n{first_time} {happened_at}\n");
+
+  for (index, delay) in delays.iter().enumerate() {
+    happened_at += delay * 1_000_000;
+    contents.push_str(&format!("{} {happened_at}\n", first_time + index as u64 + 1));
+  }
+
+  std::fs::write(&path, contents).unwrap();
+
+  TickRecording::load(&path).unwrap()
+}
+
+#[cfg(test)]
+mod recording {
+  use super::*;
+
+  #[test]
+  fn every_tick_is_recorded() {
+    let path = recording_path("every_tick");
+    let mut clock = Clock::custom(2)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.record_ticks(&path).unwrap();
+    clock.start_from(50);
+    clock.wait_for_time(60).unwrap();
+
+    let final_time = clock.stop().unwrap();
+    let recording = TickRecording::load(&path).unwrap();
+
+    assert_eq!(recording.tick_rate(), 2);
+    assert_eq!(recording.first_time(), 50);
+    assert_eq!(recording.len() as u64, final_time - 49);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn replays_keep_the_recorded_delays() {
+    let recording = write_recording("delays", 5, 10, &[5, 60, 5, 5]);
+    let mut clock = Clock::builder().replay(recording).enclosing_runtime(true).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let mut tick_instants = vec![];
+
+    clock.start();
+
+    for expected_time in 10..15 {
+      assert_eq!(time_receiver.next_tick().await.unwrap(), expected_time);
+      tick_instants.push(tokio::time::Instant::now());
+
+      // a slow receiver doesn't shift the ticks after it
+      if expected_time == 11 {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+      }
+    }
+
+    let gaps: Vec<_> = tick_instants.windows(2).map(|instants| instants[1] - instants[0]).collect();
+
+    assert_eq!(gaps, [5, 60, 5, 5].map(Duration::from_millis));
+    assert_eq!(clock.stop_async().await.unwrap(), 14);
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn replays_are_instant_in_virtual_time() {
+    let recording = write_recording("virtual", 1000, 0, &[1000, 5000, 250]);
+    let mut clock = Clock::builder().replay(recording).enclosing_runtime(true).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let started_at = Instant::now();
+    let mut tick_instants = vec![];
+
+    clock.start();
+
+    for expected_time in 0..3 {
+      assert_eq!(time_receiver.next_tick().await.unwrap(), expected_time);
+      tick_instants.push(tokio::time::Instant::now());
+    }
+
+    let gaps: Vec<_> = tick_instants.windows(2).map(|instants| instants[1] - instants[0]).collect();
+
+    // the clock stops itself right after its last tick, which is still received in order
+    assert_eq!(time_receiver.next_tick().await.unwrap(), 3);
+    assert!(time_receiver.next_tick().await.is_err());
+    assert_eq!(gaps, [1000, 5000].map(Duration::from_millis));
+    assert!(started_at.elapsed() < Duration::from_secs(1));
+  }
+
+  #[test]
+  fn files_that_arent_recordings_are_errors() {
+    let path = recording_path("invalid");
+
+    std::fs::write(&path, "not a recording\n").unwrap();
+    assert!(TickRecording::load(&path).is_err());
+
+    std::fs::write(&path, "# thread_clock tick recording, tick_rate 5\n").unwrap();
+    assert!(TickRecording::load(&path).is_err());
+
+    std::fs::write(&path, "# thread_clock tick recording, tick_rate 5\n1 two\n").unwrap();
+    assert!(TickRecording::load(&path).is_err());
+
+    assert!(TickRecording::load(recording_path("missing")).is_err());
+  }
+
+  #[test]
+  fn replays_cant_spin() {
+    let recording = write_recording("spin", 5, 0, &[5]);
+
+    assert!(Clock::builder().replay(recording).spin(true).build().is_err());
+  }
+}
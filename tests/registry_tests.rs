@@ -0,0 +1,60 @@
+use thread_clock::{Clock, ClockRegistry};
+
+#[cfg(test)]
+mod registry {
+  use super::*;
+
+  #[test]
+  fn group_start_and_stop() {
+    let mut registry = ClockRegistry::new();
+
+    registry.create("physics", 1).unwrap();
+    registry.create("network", 2).unwrap();
+    registry.start_all();
+
+    let mut physics_receiver = registry.receiver("physics").unwrap();
+    let mut network_receiver = registry.receiver("network").unwrap();
+
+    physics_receiver
+      .wait_for_x_ticks(3)
+      .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+    network_receiver
+      .wait_for_x_ticks(3)
+      .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+
+    let final_times = registry.stop_all();
+
+    assert_eq!(final_times.len(), 2);
+    assert!(final_times.values().all(|final_time| final_time.is_ok()));
+    assert!(registry.is_empty());
+  }
+
+  #[test]
+  fn duplicate_and_missing_names_error() {
+    let mut registry = ClockRegistry::new();
+
+    registry.create("physics", 1).unwrap();
+
+    assert!(registry.create("physics", 1).is_err());
+    assert!(registry.insert("physics", Clock::new().unwrap()).is_err());
+    assert!(registry.receiver("audio").is_err());
+    assert!(registry.start("audio").is_err());
+    assert!(registry.stop("audio").is_err());
+  }
+
+  #[test]
+  fn stop_single_clock() {
+    let mut registry = ClockRegistry::new();
+
+    registry.create("physics", 1).unwrap();
+    registry.create("network", 1).unwrap();
+    registry.start("physics").unwrap();
+
+    let physics_time = registry.stop("physics");
+    let network_time = registry.stop("network");
+
+    assert!(physics_time.is_ok());
+    assert!(network_time.is_err());
+    assert!(registry.is_empty());
+  }
+}
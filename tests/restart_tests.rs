@@ -0,0 +1,117 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::{Clock, ClockError, ClockHook, Time};
+
+///Panics on every tick from the first time onwards.
+struct PanicFrom(Time);
+
+impl ClockHook for PanicFrom {
+  fn before_tick(&mut self, time: Time) {
+    if time >= self.0 {
+      panic!("the hook panicked at tick {time}");
+    }
+  }
+}
+
+///Panics once, on the tick.
+struct PanicOnce(Time);
+
+impl ClockHook for PanicOnce {
+  fn before_tick(&mut self, time: Time) {
+    if time == self.0 {
+      panic!("the hook panicked at tick {time}");
+    }
+  }
+}
+
+#[cfg(test)]
+mod restart {
+  use super::*;
+
+  #[test]
+  fn panicked_tasks_carry_on_after_the_tick() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .restart_on_panic(1)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.add_hook(PanicOnce(3));
+    clock.start();
+
+    let error = loop {
+      if let Err(error) = time_receiver.safe_time() {
+        break error;
+      }
+    };
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Restarted(4)));
+    assert!(time_receiver.time() >= 4);
+    assert!(clock.health().task_alive);
+    assert_eq!(clock.health().restarts, 1);
+    assert!(clock.stop().unwrap() >= 4);
+  }
+
+  #[test]
+  fn panics_get_through_once_the_restarts_are_used_up() {
+    let mut clock = Clock::builder().tick_rate(1).restart_on_panic(2).build().unwrap();
+
+    clock.add_hook(PanicFrom(3));
+    clock.start();
+
+    let start = Instant::now();
+
+    while clock.health().task_alive {
+      assert!(start.elapsed() < Duration::from_secs(5), "the clock task didn't end");
+
+      thread::sleep(Duration::from_millis(1));
+    }
+
+    let health = clock.health();
+
+    assert_eq!(health.restarts, 2);
+    assert_eq!(health.last_tick, Some(5));
+  }
+
+  #[test]
+  fn receivers_get_the_final_time_once_the_restarts_are_used_up() {
+    let mut clock = Clock::builder().tick_rate(1).restart_on_panic(2).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.add_hook(PanicFrom(3));
+    clock.start();
+
+    let waiter = thread::spawn(move || loop {
+      match time_receiver.wait_for_time(10) {
+        Err(error) if error.downcast_ref::<ClockError>() == Some(&ClockError::Restarted(4)) => (),
+        Err(error) if error.downcast_ref::<ClockError>() == Some(&ClockError::Restarted(5)) => (),
+        waited => break waited,
+      }
+    });
+    let error = waiter.join().unwrap().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(5)));
+    assert_eq!(clock.stop().unwrap(), 5);
+  }
+
+  #[test]
+  fn receivers_created_after_a_restart_dont_see_it() {
+    let mut clock = Clock::builder().tick_rate(1).restart_on_panic(1).build().unwrap();
+
+    clock.add_hook(PanicOnce(0));
+    clock.start();
+
+    let start = Instant::now();
+
+    while clock.health().restarts == 0 {
+      assert!(start.elapsed() < Duration::from_secs(5), "the clock task wasn't restarted");
+
+      thread::sleep(Duration::from_millis(1));
+    }
+
+    let mut time_receiver = clock.spawn_receiver();
+
+    assert!(time_receiver.safe_time().is_ok());
+  }
+}
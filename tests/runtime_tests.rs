@@ -0,0 +1,60 @@
+#![cfg(target_os = "linux")]
+
+use thread_clock::Clock;
+
+///Returns how many threads the crate's runtimes have running in this process.
+fn clock_threads() -> usize {
+  std::fs::read_dir("/proc/self/task")
+    .unwrap()
+    .filter_map(|task| std::fs::read_to_string(task.ok()?.path().join("comm")).ok())
+    .filter(|name| name.trim_end() == "thread-clock")
+    .count()
+}
+
+// the runtime clocks share is created once for the whole process, so everything about when runtimes
+// are created is checked in the one test
+
+#[cfg(test)]
+mod runtime {
+  use super::*;
+
+  #[test]
+  fn runtimes_are_created_once_clocks_start() {
+    let threads = clock_threads();
+    let mut dedicated_clocks: Vec<Clock> = (0..10)
+      .map(|_| {
+        Clock::builder()
+          .tick_rate(1)
+          .dedicated_runtime(true)
+          .build()
+          .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"))
+      })
+      .collect();
+    let mut shared_clocks = [Clock::custom(1).unwrap(), Clock::custom(1).unwrap()];
+
+    assert_eq!(clock_threads(), threads);
+
+    dedicated_clocks[0].start();
+
+    assert_eq!(clock_threads(), threads + 1);
+
+    for clock in &mut shared_clocks {
+      clock.start();
+    }
+
+    assert_eq!(clock_threads(), threads + 2);
+
+    let given_runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .runtime(given_runtime.handle().clone())
+      .build()
+      .unwrap();
+
+    clock.start();
+    clock.wait_for_time(5).unwrap();
+
+    assert_eq!(clock_threads(), threads + 2);
+    assert!(Clock::builder().runtime(given_runtime.handle().clone()).spin(true).build().is_err());
+  }
+}
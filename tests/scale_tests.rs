@@ -0,0 +1,105 @@
+use std::time::{Duration, Instant};
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod divisor {
+  use super::*;
+
+  #[test]
+  fn divided_receivers_tick_once_every_n_clock_ticks() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+
+    time_receiver.set_divisor(5).unwrap();
+    clock.start();
+
+    let times: Vec<_> = (0..4).map(|_| time_receiver.time()).collect();
+    let clock_time = clock.time();
+
+    assert!(times.windows(2).all(|pair| pair[1] == pair[0] + 1), "{times:?}");
+    assert!(clock_time >= (times[3] + 1) * 5 - 1);
+    assert!(clock_time < (times[3] + 3) * 5);
+  }
+
+  #[test]
+  fn divided_durations_are_in_receiver_ticks() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    time_receiver.set_divisor(4).unwrap();
+    clock.start();
+
+    let time = time_receiver.time();
+
+    time_receiver.wait_for_duration(Duration::from_millis(8)).unwrap();
+
+    assert_eq!(time_receiver.time(), time + 3);
+  }
+
+  #[test]
+  fn other_receivers_are_not_affected() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut divided_receiver = clock.spawn_receiver();
+    let mut time_receiver = clock.spawn_receiver();
+
+    divided_receiver.set_divisor(100).unwrap();
+    clock.start();
+
+    let time = time_receiver.time();
+    let next_time = time_receiver.time();
+
+    assert_eq!(next_time, time + 1);
+    assert_eq!(divided_receiver.divisor(), 100);
+    assert_eq!(time_receiver.divisor(), 1);
+    assert_eq!(divided_receiver.spawn_receiver().divisor(), 1);
+  }
+
+  #[test]
+  fn zero_is_rejected() {
+    let clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    assert!(time_receiver.set_divisor(0).is_err());
+    assert!(time_receiver.set_multiplier(0).is_err());
+    assert_eq!(time_receiver.divisor(), 1);
+    assert_eq!(time_receiver.multiplier(), 1);
+  }
+}
+
+#[cfg(test)]
+mod multiplier {
+  use super::*;
+
+  #[test]
+  fn multiplied_receivers_tick_in_between_clock_ticks() {
+    let mut clock = Clock::custom(40)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+
+    time_receiver.set_multiplier(4).unwrap();
+    clock.start();
+
+    let time = time_receiver.time();
+    let started_at = Instant::now();
+    let sub_ticks: Vec<_> = (0..3).map(|_| time_receiver.time()).collect();
+    let next_time = time_receiver.time();
+
+    assert_eq!(time % 4, 0);
+    assert_eq!(sub_ticks, [time + 1, time + 2, time + 3]);
+    assert_eq!(next_time, time + 4);
+    assert!(started_at.elapsed() >= Duration::from_millis(30));
+  }
+
+  #[test]
+  fn setting_a_divisor_replaces_the_multiplier() {
+    let clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    time_receiver.set_multiplier(3).unwrap();
+    time_receiver.set_divisor(2).unwrap();
+
+    assert_eq!(time_receiver.multiplier(), 1);
+    assert_eq!(time_receiver.divisor(), 2);
+  }
+}
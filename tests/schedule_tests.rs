@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use thread_clock::{Clock, ClockHook, Time};
+
+struct TickInstants(Arc<Mutex<Vec<Instant>>>);
+
+impl ClockHook for TickInstants {
+  fn before_tick(&mut self, _time: Time) {
+    self.0.lock().unwrap().push(Instant::now());
+  }
+}
+
+///Runs the clock until the tick, returning how many milliseconds passed after every tick.
+fn gaps_until(mut clock: Clock, time: Time) -> Vec<f64> {
+  let tick_instants = Arc::new(Mutex::new(Vec::new()));
+
+  clock.add_hook(TickInstants(Arc::clone(&tick_instants)));
+  clock.start();
+  clock.wait_for_time(time).unwrap();
+  clock.stop().unwrap();
+
+  let tick_instants = tick_instants.lock().unwrap();
+
+  tick_instants
+    .windows(2)
+    .map(|pair| (pair[1] - pair[0]).as_secs_f64() * 1000.0)
+    .collect()
+}
+
+#[cfg(test)]
+mod schedule {
+  use super::*;
+
+  #[test]
+  fn rate_changes_on_its_tick() {
+    let clock = Clock::custom(4)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.schedule_rate_change(10, 25);
+
+    let gaps = gaps_until(clock, 16);
+
+    assert!(gaps[2..10].iter().all(|&gap| gap < 15.0), "{gaps:?}");
+    assert!(gaps[11..16].iter().all(|&gap| gap > 20.0), "{gaps:?}");
+  }
+
+  #[test]
+  fn later_changes_replace_earlier_ones() {
+    let clock = Clock::custom(30).unwrap();
+
+    clock.schedule_rate_change(0, 30);
+    clock.schedule_rate_change(0, 2);
+
+    let gaps = gaps_until(clock, 10);
+
+    assert!(gaps[1..10].iter().all(|&gap| gap < 15.0), "{gaps:?}");
+  }
+
+  #[test]
+  fn ramps_change_the_rate_gradually() {
+    let clock = Clock::custom(2).unwrap();
+
+    clock.schedule_rate_ramp(0..20, 2, 22).unwrap();
+
+    let gaps = gaps_until(clock, 24);
+    let ramp_start: f64 = gaps[1..4].iter().sum::<f64>() / 3.0;
+    let ramp_middle: f64 = gaps[9..12].iter().sum::<f64>() / 3.0;
+
+    assert!(ramp_middle > ramp_start + 5.0, "{gaps:?}");
+    let ramp_end: f64 = gaps[20..24].iter().sum::<f64>() / 4.0;
+
+    assert!(ramp_end > 18.0, "{gaps:?}");
+  }
+
+  #[test]
+  fn empty_ramps_are_rejected() {
+    let clock = Clock::custom(2).unwrap();
+
+    assert!(clock.schedule_rate_ramp(5..5, 2, 10).is_err());
+  }
+}
@@ -0,0 +1,70 @@
+use std::panic;
+use std::sync::mpsc;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod scoped {
+  use super::*;
+
+  #[test]
+  fn dropping_the_guard_stops_the_clock() {
+    let (final_time_sender, final_time_receiver) = mpsc::channel();
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"))
+      .scoped(move |final_time| final_time_sender.send(final_time.unwrap()).unwrap());
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+    clock.wait_for_time(3).unwrap();
+    drop(clock);
+
+    assert_eq!(final_time_receiver.recv().unwrap(), 4);
+    assert!(time_receiver.safe_time().is_err());
+  }
+
+  #[test]
+  fn panics_still_stop_the_clock() {
+    let (final_time_sender, final_time_receiver) = mpsc::channel();
+
+    let result = panic::catch_unwind(move || {
+      let mut clock = Clock::custom(1)
+        .unwrap()
+        .scoped(move |final_time| final_time_sender.send(final_time.is_ok()).unwrap());
+
+      clock.start();
+      clock.wait_for_time(2).unwrap();
+
+      panic!("the test panicked while the clock was running");
+    });
+
+    assert!(result.is_err());
+    assert!(final_time_receiver.recv().unwrap());
+  }
+
+  #[test]
+  fn clocks_that_never_started_report_it() {
+    let (error_sender, error_receiver) = mpsc::channel();
+
+    drop(Clock::custom(1).unwrap().scoped(move |final_time| {
+      let error = final_time.unwrap_err().downcast::<ClockError>().unwrap();
+
+      error_sender.send(error).unwrap();
+    }));
+
+    assert_eq!(error_receiver.recv().unwrap(), ClockError::NotStarted);
+  }
+
+  #[test]
+  fn taking_the_clock_out_disarms_the_guard() {
+    let (final_time_sender, final_time_receiver) = mpsc::channel::<()>();
+    let clock = Clock::custom(1)
+      .unwrap()
+      .scoped(move |_| final_time_sender.send(()).unwrap());
+    let mut clock = clock.into_inner();
+
+    clock.start();
+
+    assert!(final_time_receiver.recv().is_err());
+    assert!(clock.stop().is_ok());
+  }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "websocket")]
+
+use std::net::TcpStream;
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockServer};
+use tokio_tungstenite::tungstenite::stream::MaybeTlsStream;
+use tokio_tungstenite::tungstenite::{connect, Message, WebSocket};
+
+type Client = WebSocket<MaybeTlsStream<TcpStream>>;
+
+fn connect_to(server: &ClockServer) -> Client {
+  let (client, _) = connect(format!("ws://{}", server.local_addr())).unwrap();
+
+  client
+}
+
+///Returns the text of the next frame the client is sent.
+fn next_frame(client: &mut Client) -> String {
+  match client.read().unwrap() {
+    Message::Text(text) => text.to_string(),
+    message => panic!("Expected a text frame, got {message:?}"),
+  }
+}
+
+#[cfg(test)]
+mod clock_server {
+  use super::*;
+
+  #[test]
+  fn clients_are_sent_every_tick() {
+    let mut clock = Clock::custom(5).unwrap();
+    let server = ClockServer::bind("127.0.0.1:0", &clock)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the server: '{error}'"));
+    let mut client = connect_to(&server);
+
+    clock.start();
+
+    let ticks: Vec<String> = (0..3).map(|_| next_frame(&mut client)).collect();
+    let first_tick: u64 = ticks[0].trim_start_matches("{\"tick\":").trim_end_matches('}').parse().unwrap();
+
+    assert_eq!(
+      ticks,
+      (first_tick..first_tick + 3).map(|time| format!("{{\"tick\":{time}}}")).collect::<Vec<_>>()
+    );
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn clients_are_told_the_final_time() {
+    let mut clock = Clock::custom(5).unwrap();
+    let server = ClockServer::bind("127.0.0.1:0", &clock).unwrap();
+    let mut client = connect_to(&server);
+
+    clock.start();
+    next_frame(&mut client);
+
+    let final_time = clock.stop().unwrap();
+    let stopped = loop {
+      let frame = next_frame(&mut client);
+
+      if frame.starts_with("{\"stopped\":") {
+        break frame;
+      }
+    };
+
+    assert_eq!(stopped, format!("{{\"stopped\":{final_time}}}"));
+  }
+
+  #[test]
+  fn every_client_follows_the_same_clock() {
+    let mut clock = Clock::custom(5).unwrap();
+    let server = ClockServer::bind("127.0.0.1:0", &clock).unwrap();
+    let mut first_client = connect_to(&server);
+    let mut second_client = connect_to(&server);
+
+    clock.start();
+
+    let first_tick = next_frame(&mut first_client);
+    let second_tick = next_frame(&mut second_client);
+
+    assert_eq!(first_tick, second_tick);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn dropped_servers_stop_listening() {
+    let clock = Clock::custom(5).unwrap();
+    let server = ClockServer::bind("127.0.0.1:0", &clock).unwrap();
+    let address = server.local_addr();
+
+    drop(server);
+
+    // the server's thread shuts down in the background
+    let stopped_listening = (0..100).any(|_| {
+      thread::sleep(Duration::from_millis(10));
+
+      connect(format!("ws://{address}")).is_err()
+    });
+
+    assert!(stopped_listening);
+  }
+}
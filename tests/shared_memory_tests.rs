@@ -0,0 +1,202 @@
+#![cfg(target_os = "linux")]
+
+use std::ffi::CString;
+use thread_clock::{Clock, ClockError, SharedTimeReceiver};
+
+///The size of a segment's layout: its magic, sequence, state and leader pid, followed by the time.
+const SEGMENT_SIZE: usize = 24;
+
+///Returns a segment name that no other test or test run uses.
+fn segment_name(test_name: &str) -> String {
+  format!("thread-clock-{}-{test_name}", std::process::id())
+}
+
+///Creates a segment of the size without a leader behind it, returning the words at its start mapped
+///into this process if it's large enough to hold them, or null otherwise.
+///
+///The mapping is never unmapped, so the words stay valid for the rest of the test.
+fn create_raw_segment(name: &str, size: usize) -> *mut u32 {
+  let name = CString::new(format!("/{name}")).unwrap();
+
+  // SAFETY: the name is a valid C string, and the descriptor is closed once the segment is mapped
+  unsafe {
+    let fd = libc::shm_open(name.as_ptr(), libc::O_RDWR | libc::O_CREAT | libc::O_EXCL, 0o600);
+
+    assert_ne!(fd, -1, "the segment couldn't be created");
+    assert_ne!(libc::ftruncate(fd, size as libc::off_t), -1);
+
+    let mut words = std::ptr::null_mut();
+
+    if size >= SEGMENT_SIZE {
+      let mapped = libc::mmap(
+        std::ptr::null_mut(),
+        SEGMENT_SIZE,
+        libc::PROT_READ | libc::PROT_WRITE,
+        libc::MAP_SHARED,
+        fd,
+        0,
+      );
+
+      assert_ne!(mapped, libc::MAP_FAILED);
+
+      words = mapped.cast();
+    }
+
+    libc::close(fd);
+
+    words
+  }
+}
+
+fn unlink_raw_segment(name: &str) {
+  let name = CString::new(format!("/{name}")).unwrap();
+
+  // SAFETY: the name is a valid C string
+  unsafe { libc::shm_unlink(name.as_ptr()) };
+}
+
+#[cfg(test)]
+mod shared_memory {
+  use super::*;
+
+  #[test]
+  fn shared_receivers_follow_the_leader() {
+    let name = segment_name("follow");
+    let mut clock = Clock::custom(5)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let _publisher = clock.publish_shared_memory(&name).unwrap();
+    let mut shared_receiver = SharedTimeReceiver::open(&name).unwrap();
+
+    assert_eq!(shared_receiver.current_time().unwrap(), 0);
+
+    clock.start();
+
+    let times: Vec<_> = (0..4).map(|_| shared_receiver.time()).collect();
+
+    assert!(times.windows(2).all(|times| times[0] < times[1]));
+    assert_eq!(shared_receiver.current_time().unwrap(), *times.last().unwrap());
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn every_receiver_sees_the_same_tick() {
+    let name = segment_name("same_tick");
+    let mut clock = Clock::custom(20).unwrap();
+    let _publisher = clock.publish_shared_memory(&name).unwrap();
+    let mut first_receiver = SharedTimeReceiver::open(&name).unwrap();
+    let second_receiver = SharedTimeReceiver::open(&name).unwrap();
+
+    clock.start();
+
+    let time = first_receiver.safe_time().unwrap();
+
+    assert_eq!(second_receiver.current_time().unwrap(), time);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn shared_receivers_are_told_the_final_time() {
+    let name = segment_name("final_time");
+    let mut clock = Clock::custom(5).unwrap();
+    let _publisher = clock.publish_shared_memory(&name).unwrap();
+    let mut shared_receiver = SharedTimeReceiver::open(&name).unwrap();
+
+    clock.start();
+    shared_receiver.wait_for_time(2).unwrap();
+
+    assert!(shared_receiver.wait_for_time(1).is_err());
+    assert!(shared_receiver.wait_until_at_least(1).unwrap() >= 2);
+
+    let final_time = clock.stop().unwrap();
+    let error = shared_receiver.safe_time().unwrap_err();
+
+    assert!(matches!(error.downcast_ref(), Some(ClockError::Stopped(time)) if *time == final_time));
+  }
+
+  #[test]
+  fn dropped_publishers_close_the_segment() {
+    let name = segment_name("dropped");
+    let clock = Clock::custom(5).unwrap();
+    let publisher = clock.publish_shared_memory(&name).unwrap();
+    let mut shared_receiver = SharedTimeReceiver::open(&name).unwrap();
+
+    assert!(clock.publish_shared_memory(&name).is_err());
+
+    drop(publisher);
+
+    assert!(shared_receiver.safe_time().is_err());
+    assert!(SharedTimeReceiver::open(&name).is_err());
+  }
+
+  #[test]
+  fn segments_too_small_to_be_clocks_are_errors() {
+    let name = segment_name("too-small");
+
+    // a leader that hasn't sized its segment yet
+    create_raw_segment(&name, 0);
+
+    assert!(SharedTimeReceiver::open(&name).is_err());
+
+    unlink_raw_segment(&name);
+  }
+
+  #[test]
+  fn leaders_that_exit_mid_write_are_errors() {
+    let name = segment_name("exited-mid-write");
+    let mut exited = std::process::Command::new("true").spawn().unwrap();
+
+    exited.wait().unwrap();
+
+    let words = create_raw_segment(&name, SEGMENT_SIZE);
+
+    // SAFETY: the segment holds the four words, and nothing else writes to it yet
+    unsafe {
+      words.write_volatile(u32::from_be_bytes(*b"TCK1"));
+      // an odd sequence is a message the leader never finished writing
+      words.add(1).write_volatile(1);
+      words.add(3).write_volatile(exited.id());
+    }
+
+    let shared_receiver = SharedTimeReceiver::open(&name).unwrap();
+
+    assert!(shared_receiver.current_time().is_err());
+
+    unlink_raw_segment(&name);
+  }
+
+  #[test]
+  fn segments_of_exited_leaders_are_taken_over() {
+    let name = segment_name("exited-leader");
+    let mut exited = std::process::Command::new("true").spawn().unwrap();
+
+    exited.wait().unwrap();
+
+    let words = create_raw_segment(&name, SEGMENT_SIZE);
+
+    // SAFETY: the segment holds the four words, and nothing else writes to it yet
+    unsafe {
+      words.write_volatile(u32::from_be_bytes(*b"TCK1"));
+      words.add(3).write_volatile(exited.id());
+    }
+
+    let mut clock = Clock::custom(5).unwrap();
+    let _publisher = clock.publish_shared_memory(&name).unwrap();
+    let mut shared_receiver = SharedTimeReceiver::open(&name).unwrap();
+
+    clock.start();
+
+    assert!(shared_receiver.safe_time().is_ok());
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn names_with_slashes_are_errors() {
+    let clock = Clock::custom(5).unwrap();
+
+    assert!(clock.publish_shared_memory("nested/clock").is_err());
+    assert!(clock.publish_shared_memory("").is_err());
+  }
+}
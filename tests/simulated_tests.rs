@@ -0,0 +1,84 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod simulated_clock {
+  use super::*;
+
+  #[test]
+  fn ticks_dont_wait_on_the_tickrate() {
+    let mut clock = Clock::simulated()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+    let started_at = Instant::now();
+
+    clock.start();
+
+    for expected_time in 0..2000 {
+      assert_eq!(time_receiver.time(), expected_time);
+    }
+
+    assert!(started_at.elapsed() < Duration::from_secs(5));
+  }
+
+  #[test]
+  fn every_receiver_gets_every_tick_in_order() {
+    let mut clock = Clock::simulated().unwrap();
+    let receivers: Vec<_> = (0..3).map(|_| clock.spawn_receiver()).collect();
+
+    clock.start();
+
+    let handles: Vec<_> = receivers
+      .into_iter()
+      .map(|mut time_receiver| {
+        thread::spawn(move || (0..500).map(|_| time_receiver.time()).collect::<Vec<_>>())
+      })
+      .collect();
+
+    for handle in handles {
+      assert_eq!(handle.join().unwrap(), (0..500).collect::<Vec<_>>());
+    }
+  }
+
+  #[test]
+  fn slow_receivers_hold_the_clock_back() {
+    let mut clock = Clock::simulated().unwrap();
+    let mut fast_receiver = clock.spawn_receiver();
+    let mut slow_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let slow_handle = thread::spawn(move || {
+      (0..5)
+        .map(|_| {
+          let time = slow_receiver.time();
+
+          thread::sleep(Duration::from_millis(20));
+
+          time
+        })
+        .collect::<Vec<_>>()
+    });
+    let fast_times: Vec<_> = (0..5).map(|_| fast_receiver.time()).collect();
+
+    assert_eq!(fast_times, (0..5).collect::<Vec<_>>());
+    assert_eq!(slow_handle.join().unwrap(), (0..5).collect::<Vec<_>>());
+  }
+
+  #[test]
+  fn simulated_clocks_can_be_stopped() {
+    let mut clock = Clock::simulated().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    assert_eq!(time_receiver.time(), 0);
+    assert!(clock.stop().is_ok());
+  }
+
+  #[test]
+  fn simulated_clocks_cant_spin() {
+    assert!(Clock::builder().simulated(true).spin(true).build().is_err());
+  }
+}
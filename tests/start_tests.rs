@@ -0,0 +1,89 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod start {
+  use super::*;
+
+  #[test]
+  fn receivers_can_wait_for_the_start() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+
+    let worker = thread::spawn(move || {
+      time_receiver.wait_for_start().unwrap();
+
+      time_receiver.safe_time()
+    });
+
+    thread::sleep(Duration::from_millis(20));
+    clock.start();
+
+    assert!(worker.join().unwrap().is_ok());
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn waiting_for_a_started_clock_returns_right_away() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    assert!(time_receiver.wait_for_start().is_ok());
+  }
+
+  #[test]
+  fn waiting_for_a_dropped_clock_errors() {
+    let clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    let worker = thread::spawn(move || time_receiver.wait_for_start());
+
+    thread::sleep(Duration::from_millis(20));
+    drop(clock);
+
+    let error = worker.join().unwrap().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+  }
+
+  #[test]
+  fn receivers_wait_for_the_start_when_built_to() {
+    let mut clock = Clock::builder().tick_rate(1).receivers_wait_for_start(true).build().unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    let worker = thread::spawn(move || time_receiver.wait_for_x_ticks(3));
+
+    thread::sleep(Duration::from_millis(20));
+    clock.start();
+
+    assert!(worker.join().unwrap().is_ok());
+
+    // the clock's own receiver still errors, as nothing could start it while it waits
+    let mut clock = Clock::builder().tick_rate(1).receivers_wait_for_start(true).build().unwrap();
+    let error = clock.safe_time().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+  }
+
+  #[tokio::test]
+  async fn async_receivers_wait_for_the_start_when_built_to() {
+    let mut clock = Clock::builder()
+      .tick_rate(1)
+      .enclosing_runtime(true)
+      .receivers_wait_for_start(true)
+      .build()
+      .unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+    let worker = tokio::spawn(async move { time_receiver.next_tick().await });
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    clock.start();
+
+    assert!(worker.await.unwrap().is_ok());
+  }
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod stopwatch {
+  use super::*;
+
+  #[test]
+  fn stopwatch_counts_ticks_once_started() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut stopwatch = clock.stopwatch();
+
+    clock.start();
+    clock.wait_for_x_ticks(5).unwrap();
+
+    assert_eq!(stopwatch.elapsed_ticks(), 0);
+
+    stopwatch.start();
+    clock.wait_for_x_ticks(10).unwrap();
+
+    let elapsed_ticks = stopwatch.elapsed_ticks();
+
+    // the clock's task may not have counted the latest tick yet
+    assert!((9..=11).contains(&elapsed_ticks), "counted {elapsed_ticks} ticks");
+    assert_eq!(stopwatch.elapsed_duration(), Duration::from_millis(elapsed_ticks));
+  }
+
+  #[test]
+  fn laps_add_up_to_the_elapsed_ticks() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut stopwatch = clock.stopwatch();
+
+    clock.start();
+    stopwatch.start();
+
+    let laps: Vec<_> = (0..5)
+      .map(|_| {
+        clock.wait_for_x_ticks(3).unwrap();
+
+        stopwatch.lap()
+      })
+      .collect();
+
+    let lap_total: u64 = laps.iter().sum();
+
+    assert!(laps.iter().all(|lap| *lap > 0));
+    assert!(lap_total >= 14 && lap_total <= stopwatch.elapsed_ticks());
+  }
+
+  #[test]
+  fn restarting_resets_the_count() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut stopwatch = clock.stopwatch();
+
+    clock.start();
+    stopwatch.start();
+    clock.wait_for_x_ticks(10).unwrap();
+
+    stopwatch.start();
+
+    assert!(stopwatch.elapsed_ticks() <= 1);
+  }
+}
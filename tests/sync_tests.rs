@@ -0,0 +1,100 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use thread_clock::{Clock, CorrectionEvent, TickReference};
+
+///Syncs a 10ms clock to a reference that starts `offset` ticks away from it, returning how far apart
+///the two are after a while.
+///
+///The tickrate is well above the resolution of tokio's timer, so the clock's own ticks don't drift.
+fn difference_after_syncing(offset: i64) -> f64 {
+  let mut clock = Clock::custom(10)
+    .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+  let (reference_sender, reference) = mpsc::channel();
+
+  clock.sync_to(reference);
+  clock.start();
+
+  let reference_start = clock.time().saturating_add_signed(offset);
+  let reported_at = Instant::now();
+
+  reference_sender.send(reference_start).unwrap();
+  thread::sleep(Duration::from_secs(5));
+
+  let time = clock.time();
+  let reference_time = reference_start as f64 + reported_at.elapsed().as_secs_f64() / 0.01;
+
+  reference_time - time as f64
+}
+
+///Disciplines a 10ms clock to a wall clock running at `speed` times real time, returning the corrections
+///it made over a while and the average rate it settled on over the latter half of them.
+fn corrections_for_wall_clock(speed: f64) -> (Vec<CorrectionEvent>, f64) {
+  let mut clock = Clock::custom(10).unwrap();
+  let (correction_sender, corrections) = mpsc::channel();
+  let started_at = Instant::now();
+  let start = SystemTime::now();
+  let wall_clock = move || start + started_at.elapsed().mul_f64(speed);
+
+  clock.sync_to_wall_clock(wall_clock, Duration::from_millis(200), move |correction| {
+    let _ = correction_sender.send(correction);
+  });
+  clock.start();
+
+  thread::sleep(Duration::from_secs(3));
+  clock.stop().unwrap();
+
+  let corrections: Vec<CorrectionEvent> = corrections.try_iter().collect();
+  let settled = &corrections[corrections.len() / 2..];
+  let settled_rate = settled.iter().map(|correction| correction.rate_adjustment).sum::<f64>() / settled.len() as f64;
+
+  (corrections, settled_rate)
+}
+
+#[cfg(test)]
+mod sync {
+  use super::*;
+
+  #[test]
+  fn clocks_behind_their_reference_catch_up() {
+    let difference = difference_after_syncing(10);
+
+    assert!(difference.abs() < 2.0, "{difference}");
+  }
+
+  #[test]
+  fn clocks_ahead_of_their_reference_fall_back() {
+    let difference = difference_after_syncing(-10);
+
+    assert!(difference.abs() < 2.0, "{difference}");
+  }
+
+  #[test]
+  fn clocks_speed_up_for_fast_wall_clocks() {
+    let (corrections, settled_rate) = corrections_for_wall_clock(1.1);
+
+    assert!(corrections.len() > 5, "{corrections:?}");
+    assert!(settled_rate > 0.0, "{corrections:?}");
+  }
+
+  #[test]
+  fn clocks_slow_down_for_slow_wall_clocks() {
+    let (corrections, settled_rate) = corrections_for_wall_clock(0.9);
+
+    assert!(corrections.len() > 5, "{corrections:?}");
+    assert!(settled_rate < 0.0, "{corrections:?}");
+  }
+
+  #[test]
+  fn channels_report_their_newest_tick() {
+    let (reference_sender, mut reference) = mpsc::channel();
+
+    assert_eq!(reference.poll_reference(), None);
+
+    reference_sender.send(3).unwrap();
+    reference_sender.send(7).unwrap();
+
+    assert_eq!(reference.poll_reference(), Some(7));
+    assert_eq!(reference.poll_reference(), None);
+  }
+}
@@ -0,0 +1,75 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod throttle {
+  use super::*;
+
+  #[test]
+  fn throttle_allows_once_per_ticks() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let throttle = clock.throttle(5);
+
+    clock.start();
+
+    assert!(throttle.allow());
+    assert!(!throttle.allow());
+
+    clock.wait_for_x_ticks(7).unwrap();
+
+    assert!(throttle.allow());
+    assert!(!throttle.allow());
+  }
+
+  #[test]
+  fn throttle_is_shared_between_clones() {
+    let mut clock = Clock::custom(1).unwrap();
+    let throttle = clock.throttle(1_000);
+
+    clock.start();
+
+    let handles: Vec<_> = (0..4)
+      .map(|_| {
+        let throttle = throttle.clone();
+
+        thread::spawn(move || throttle.allow())
+      })
+      .collect();
+    let allowed = handles
+      .into_iter()
+      .map(|handle| handle.join().unwrap())
+      .filter(|allowed| *allowed)
+      .count();
+
+    assert_eq!(allowed, 1);
+  }
+}
+
+#[cfg(test)]
+mod debounce {
+  use super::*;
+
+  #[test]
+  fn debounce_settles_after_quiet_ticks() {
+    let mut clock = Clock::custom(1).unwrap();
+    let debounce = clock.debounce(5);
+
+    clock.start();
+
+    assert!(!debounce.settled());
+
+    for _ in 0..10 {
+      debounce.trigger();
+      thread::sleep(Duration::from_micros(500));
+    }
+
+    assert!(!debounce.settled());
+
+    clock.wait_for_x_ticks(7).unwrap();
+
+    assert!(debounce.settled());
+    assert!(!debounce.settled());
+  }
+}
@@ -0,0 +1,108 @@
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod tick_barrier {
+  use super::*;
+
+  #[test]
+  fn releases_every_party_on_the_same_tick() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let barrier = clock.tick_barrier(4).unwrap();
+
+    clock.start();
+
+    for _ in 0..5 {
+      let handles: Vec<_> = (0..3)
+        .map(|_| {
+          let barrier = barrier.clone();
+
+          thread::spawn(move || barrier.wait().unwrap())
+        })
+        .collect();
+
+      let release_time = barrier
+        .wait()
+        .unwrap_or_else(|err| panic!("An error has occurred while waiting: {err}"));
+
+      for handle in handles {
+        assert_eq!(handle.join().unwrap(), release_time);
+      }
+    }
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn releases_on_a_tick_after_everyone_arrived() {
+    let mut clock = Clock::custom(10).unwrap();
+    let barrier = clock.tick_barrier(1).unwrap();
+
+    clock.start();
+
+    let first_release = barrier.wait().unwrap();
+
+    // the ticks sent in the meantime are old by the time the barrier is waited on again
+    thread::sleep(Duration::from_millis(35));
+
+    let second_release = barrier.wait().unwrap();
+
+    assert!(second_release > first_release + 2, "{first_release} {second_release}");
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn parties_arriving_during_a_release_wait_for_the_next_one() {
+    let mut clock = Clock::custom(10).unwrap();
+    let barrier = clock.tick_barrier(2).unwrap();
+
+    clock.start();
+
+    let handles: Vec<_> = (0..6)
+      .map(|_| {
+        let barrier = barrier.clone();
+
+        thread::spawn(move || barrier.wait().unwrap())
+      })
+      .collect();
+    let mut release_times: Vec<_> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+
+    release_times.sort();
+
+    // every round releases its own two parties on a tick of its own
+    for round in release_times.chunks(2) {
+      assert_eq!(round[0], round[1], "{release_times:?}");
+    }
+
+    release_times.dedup();
+
+    assert_eq!(release_times.len(), 3);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn every_party_errors_when_the_clock_isnt_running() {
+    let clock = Clock::new().unwrap();
+    let barrier = clock.tick_barrier(2).unwrap();
+    let worker_barrier = barrier.clone();
+
+    let handle = thread::spawn(move || worker_barrier.wait().unwrap_err().downcast::<ClockError>().unwrap());
+
+    let error = barrier.wait().unwrap_err();
+
+    // every party gets the error the clock was waited on with, not just the one that waited on it
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::NotStarted));
+    assert_eq!(handle.join().unwrap(), ClockError::NotStarted);
+  }
+
+  #[test]
+  fn zero_parties_errors() {
+    let clock = Clock::new().unwrap();
+
+    assert!(clock.tick_barrier(0).is_err());
+  }
+}
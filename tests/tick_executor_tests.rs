@@ -0,0 +1,106 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use thread_clock::Clock;
+
+#[cfg(test)]
+mod tick_executor {
+  use super::*;
+
+  #[test]
+  fn closures_run_every_tick() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let tick_executor = clock.tick_executor();
+    let times = Arc::new(Mutex::new(Vec::new()));
+    let task_times = Arc::clone(&times);
+
+    tick_executor.run_every_tick(move |time| {
+      let mut times = task_times.lock().unwrap();
+
+      times.push(time);
+
+      times.len() < 10
+    });
+
+    clock.start();
+    clock.wait_for_x_ticks(15).unwrap();
+
+    let times = times.lock().unwrap();
+    let expected_times: Vec<_> = (0..10).collect();
+
+    assert_eq!(*times, expected_times);
+  }
+
+  #[test]
+  fn futures_are_polled_until_done() {
+    let mut clock = Clock::custom(1).unwrap();
+    let tick_executor = clock.tick_executor();
+    let polls = Arc::new(AtomicU64::new(0));
+    let future_polls = Arc::clone(&polls);
+
+    tick_executor.spawn(std::future::poll_fn(move |_| {
+      if future_polls.fetch_add(1, Ordering::SeqCst) < 4 {
+        std::task::Poll::Pending
+      } else {
+        std::task::Poll::Ready(())
+      }
+    }));
+
+    clock.start();
+    clock.wait_for_x_ticks(10).unwrap();
+
+    assert_eq!(polls.load(Ordering::SeqCst), 5);
+  }
+
+  #[test]
+  fn deregistered_tasks_stop_running() {
+    let mut clock = Clock::custom(1).unwrap();
+    let tick_executor = clock.tick_executor();
+    let runs = Arc::new(AtomicU64::new(0));
+    let task_runs = Arc::clone(&runs);
+
+    let task_handle = tick_executor.run_every_tick(move |_| {
+      task_runs.fetch_add(1, Ordering::SeqCst);
+
+      true
+    });
+
+    clock.start();
+    clock.wait_for_x_ticks(5).unwrap();
+
+    task_handle.deregister();
+
+    let runs_when_deregistered = runs.load(Ordering::SeqCst);
+
+    clock.wait_for_x_ticks(5).unwrap();
+
+    // the task may have been running while it was deregistered
+    assert!(runs.load(Ordering::SeqCst) <= runs_when_deregistered + 1);
+  }
+
+  #[test]
+  fn tasks_can_register_tasks() {
+    let mut clock = Clock::custom(1).unwrap();
+    let tick_executor = clock.tick_executor();
+    let inner_executor = tick_executor.clone();
+    let runs = Arc::new(AtomicU64::new(0));
+    let task_runs = Arc::clone(&runs);
+
+    tick_executor.run_every_tick(move |_| {
+      let task_runs = Arc::clone(&task_runs);
+
+      inner_executor.run_every_tick(move |_| {
+        task_runs.fetch_add(1, Ordering::SeqCst);
+
+        false
+      });
+
+      false
+    });
+
+    clock.start();
+    clock.wait_for_x_ticks(5).unwrap();
+
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+  }
+}
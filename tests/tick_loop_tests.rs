@@ -0,0 +1,66 @@
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::{Clock, ClockError};
+
+#[cfg(test)]
+mod tick_loops {
+  use super::*;
+
+  #[test]
+  fn each_tick_is_seen_in_order() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut times = vec![];
+
+    clock.start();
+    clock
+      .for_each_tick(10, |time| times.push(time))
+      .unwrap_or_else(|error| panic!("An error has occurred while running the loop: '{error}'"));
+
+    assert_eq!(times, (0..10).collect::<Vec<_>>());
+  }
+
+  #[test]
+  fn stopping_before_every_tick_is_seen_errors() {
+    let mut clock = Clock::custom(1).unwrap();
+    let mut time_receiver = clock.spawn_receiver();
+
+    clock.start();
+
+    let handle = thread::spawn(move || time_receiver.for_each_tick(u32::MAX, |_| ()));
+
+    clock.wait_for_x_ticks(5).unwrap();
+
+    let final_time = clock.stop().unwrap();
+    let error = handle.join().unwrap().unwrap_err();
+
+    assert_eq!(error.downcast_ref::<ClockError>(), Some(&ClockError::Stopped(final_time)));
+  }
+
+  #[test]
+  fn running_for_a_duration_waits_it_out() {
+    let mut clock = Clock::custom(5).unwrap();
+    let duration = Duration::from_millis(50);
+    let mut ticks = 0;
+
+    assert!(clock.run_for(duration, |_| ()).is_err());
+
+    clock.start();
+
+    let started_at = Instant::now();
+
+    clock.run_for(duration, |_| ticks += 1).unwrap();
+
+    assert!(started_at.elapsed() >= duration);
+    assert!(ticks > 0 && ticks <= 10, "saw {ticks} ticks");
+
+    // a paused clock has no new ticks to see, but the duration still runs out
+    clock.pause();
+
+    let last_tick = clock.health().last_tick.unwrap();
+
+    clock
+      .run_for(duration, |time| assert!(time <= last_tick, "A paused clock ticked at {time}"))
+      .unwrap();
+  }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "humantime")]
+
+use thread_clock::{Clock, ClockBuilder};
+
+#[cfg(test)]
+mod tick_rate_str {
+  use super::*;
+
+  #[test]
+  fn durations_become_tickrates() {
+    let clock = Clock::from_str_rate("16ms")
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    assert_eq!(clock.tick_rate(), 16);
+
+    let clock = ClockBuilder::new().tick_rate_str("1.5s").unwrap().build().unwrap();
+
+    assert_eq!(clock.tick_rate(), 1500);
+
+    let clock = ClockBuilder::new().tick_rate_str("1s 250ms").unwrap().build().unwrap();
+
+    assert_eq!(clock.tick_rate(), 1250);
+  }
+
+  #[test]
+  fn invalid_durations_are_rejected() {
+    assert!(Clock::from_str_rate("").is_err());
+    assert!(Clock::from_str_rate("16").is_err());
+    assert!(Clock::from_str_rate("fast").is_err());
+  }
+
+  #[test]
+  fn durations_that_arent_whole_milliseconds_are_rejected() {
+    assert!(Clock::from_str_rate("1500us").is_err());
+    assert!(Clock::from_str_rate("1.5ms").is_err());
+    assert!(Clock::from_str_rate("2000us").is_ok());
+  }
+
+  #[test]
+  fn durations_longer_than_a_tickrate_are_rejected() {
+    assert!(Clock::from_str_rate("50days").is_err());
+    assert!(Clock::from_str_rate("49days").is_ok());
+  }
+}
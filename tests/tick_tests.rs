@@ -0,0 +1,92 @@
+use std::collections::BTreeSet;
+use std::time::Duration;
+use thread_clock::{Clock, Tick, Time};
+
+#[cfg(test)]
+mod tick {
+  use super::*;
+
+  #[test]
+  fn ticks_convert_to_and_from_time() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.start();
+
+    let time = clock.time();
+    let tick = Tick::from(time);
+
+    assert_eq!(Time::from(tick), time);
+    assert_eq!(tick.to_string(), time.to_string());
+  }
+
+  #[test]
+  fn arithmetic_keeps_ticks_and_amounts_apart() {
+    let mut tick = Tick(10);
+
+    tick += 5;
+    tick -= 3;
+
+    assert_eq!(tick, Tick(12));
+    assert_eq!(tick + 8, Tick(20));
+    assert_eq!(tick - 2, Tick(10));
+    assert_eq!(Tick(20) - tick, 8);
+  }
+
+  #[test]
+  fn checked_arithmetic_catches_overflow() {
+    assert_eq!(Tick(u64::MAX).checked_add(1), None);
+    assert_eq!(Tick(1).checked_add(1), Some(Tick(2)));
+    assert_eq!(Tick::ZERO.checked_sub(1), None);
+    assert_eq!(Tick(3).checked_ticks_since(Tick(5)), None);
+    assert_eq!(Tick(5).checked_ticks_since(Tick(3)), Some(2));
+    assert_eq!(Tick(u64::MAX).saturating_add(1), Tick(u64::MAX));
+    assert_eq!(Tick(1).saturating_sub(5), Tick::ZERO);
+  }
+
+  #[test]
+  fn ticks_are_ordered() {
+    let ticks: BTreeSet<Tick> = [Tick(3), Tick(1), Tick(2)].into_iter().collect();
+
+    assert_eq!(ticks.into_iter().collect::<Vec<_>>(), [Tick(1), Tick(2), Tick(3)]);
+    assert!(Tick(1) < Tick(2));
+  }
+
+  #[test]
+  fn ticks_convert_to_durations() {
+    assert_eq!(Tick(4).to_duration(24), Duration::from_millis(96));
+    assert_eq!(Tick::ZERO.to_duration(24), Duration::ZERO);
+    assert_eq!(Tick(u64::MAX).to_duration(2), Duration::from_millis(u64::MAX));
+  }
+}
+
+#[cfg(test)]
+mod tick_width {
+  use super::*;
+  use thread_clock::{Tick128, Tick32};
+
+  #[test]
+  fn ticks_widen_without_loss() {
+    assert_eq!(Tick::from(Tick32(u32::MAX)), Tick(u32::MAX as u64));
+    assert_eq!(Tick128::from(Tick(u64::MAX)), Tick128(u64::MAX as u128));
+    assert_eq!(Tick128::from(Tick32(3)), Tick128(3));
+  }
+
+  #[test]
+  fn ticks_only_narrow_when_they_fit() {
+    assert_eq!(Tick32::try_from(Tick(5)), Ok(Tick32(5)));
+    assert!(Tick32::try_from(Tick(u32::MAX as u64 + 1)).is_err());
+    assert_eq!(Tick32::try_from(40 as Time), Ok(Tick32(40)));
+    assert!(Tick::try_from(Tick128::MAX).is_err());
+    assert_eq!(Tick::try_from(Tick128(9)), Ok(Tick(9)));
+  }
+
+  #[test]
+  fn every_width_has_the_same_arithmetic() {
+    assert_eq!(Tick32(10) + 5 - Tick32(3), 12);
+    assert_eq!(Tick32::MAX.checked_add(1), None);
+    assert_eq!(Tick128(u64::MAX as u128) + 1, Tick128(1 << 64));
+    assert_eq!(Tick32(2).to_duration(16), Duration::from_millis(32));
+    assert_eq!(Tick128::MAX.to_duration(1), Duration::from_millis(u64::MAX));
+  }
+}
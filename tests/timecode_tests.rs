@@ -0,0 +1,70 @@
+use thread_clock::{FrameRate, Timecode};
+
+#[cfg(test)]
+mod timecode {
+  use super::*;
+
+  #[test]
+  fn non_drop_frame_rates_count_every_frame() {
+    let film = Timecode::new(FrameRate::Fps24);
+    let pal = Timecode::new(FrameRate::Fps25);
+    let video = Timecode::new(FrameRate::Fps30);
+
+    assert_eq!(film.format(0), "00:00:00:00");
+    assert_eq!(film.format(23), "00:00:00:23");
+    assert_eq!(film.format(24 * 3600), "01:00:00:00");
+    assert_eq!(pal.format(25 * 60 + 3), "00:01:00:03");
+    assert_eq!(video.format(30 * 3600 * 30 + 29), "30:00:00:29");
+  }
+
+  #[test]
+  fn drop_frame_skips_frame_numbers() {
+    let timecode = Timecode::new(FrameRate::Fps29_97DropFrame);
+
+    assert_eq!(timecode.format(1799), "00:00:59;29");
+    assert_eq!(timecode.format(1800), "00:01:00;02");
+    assert_eq!(timecode.format(3597), "00:01:59;29");
+    assert_eq!(timecode.format(3598), "00:02:00;02");
+    // the tenth minute doesn't drop frames
+    assert_eq!(timecode.format(17_981), "00:09:59;29");
+    assert_eq!(timecode.format(17_982), "00:10:00;00");
+    assert_eq!(timecode.format(17_983), "00:10:00;01");
+    // an hour of drop-frame timecode is 107892 frames
+    assert_eq!(timecode.format(107_892), "01:00:00;00");
+  }
+
+  #[test]
+  fn parsing_reverses_formatting() {
+    for frame_rate in [
+      FrameRate::Fps24,
+      FrameRate::Fps25,
+      FrameRate::Fps29_97DropFrame,
+      FrameRate::Fps30,
+    ] {
+      let timecode = Timecode::new(frame_rate);
+
+      for ticks in (0..250_000).chain([u32::MAX as u64, 1 << 40]) {
+        let formatted = timecode.format(ticks);
+
+        assert_eq!(timecode.parse(&formatted).unwrap(), ticks, "{frame_rate:?} {formatted}");
+      }
+    }
+  }
+
+  #[test]
+  fn invalid_timecodes_are_rejected() {
+    let timecode = Timecode::new(FrameRate::Fps29_97DropFrame);
+
+    assert!(timecode.parse("00:01:00;00").is_err());
+    assert!(timecode.parse("00:01:00;01").is_err());
+    assert_eq!(timecode.parse("00:10:00;00").unwrap(), 17_982);
+    assert_eq!(timecode.parse("00:01:00:02").unwrap(), 1800);
+    assert!(timecode.parse("00:60:00;00").is_err());
+    assert!(timecode.parse("00:00:00;30").is_err());
+    assert!(timecode.parse("00:00:00").is_err());
+    assert!(timecode.parse("00:00:00:00:00").is_err());
+    assert!(timecode.parse("00:+1:00;00").is_err());
+    assert!(timecode.parse("00;00:00;00").is_err());
+    assert!(timecode.parse("99999999999999999999:00:00;00").is_err());
+  }
+}
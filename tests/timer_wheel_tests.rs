@@ -0,0 +1,106 @@
+use thread_clock::TimerWheel;
+
+#[cfg(test)]
+mod timer_wheel {
+  use super::*;
+
+  #[test]
+  fn timers_expire_in_deadline_order() {
+    let mut timer_wheel = TimerWheel::new();
+
+    // spread the deadlines across every level of the wheel and past it
+    let deadlines: Vec<u64> = (0..20_000_u64)
+      .map(|timer| timer.wrapping_mul(2_654_435_761) % 100_000_000)
+      .chain([1 << 36, (1 << 40) + 7, u64::MAX])
+      .collect();
+
+    for deadline in &deadlines {
+      timer_wheel.insert(*deadline, *deadline);
+    }
+
+    let mut previous_deadline = 0;
+    let mut expired = 0;
+
+    while let Some(deadline) = timer_wheel.poll(u64::MAX) {
+      assert!(deadline >= previous_deadline);
+
+      previous_deadline = deadline;
+      expired += 1;
+    }
+
+    assert_eq!(expired, deadlines.len());
+    assert!(timer_wheel.is_empty());
+  }
+
+  #[test]
+  fn timers_only_expire_once_reached() {
+    let mut timer_wheel = TimerWheel::new();
+
+    timer_wheel.insert("far", 5_000);
+    timer_wheel.insert("near", 70);
+
+    assert_eq!(timer_wheel.poll(69), None);
+    assert_eq!(timer_wheel.poll(70), Some("near"));
+    assert_eq!(timer_wheel.poll(4_999), None);
+    assert_eq!(timer_wheel.poll(5_000), Some("far"));
+  }
+
+  #[test]
+  fn passed_deadlines_expire_on_the_next_poll() {
+    let mut timer_wheel = TimerWheel::new();
+
+    assert_eq!(timer_wheel.poll(100), None);
+
+    timer_wheel.insert("late", 50);
+
+    assert_eq!(timer_wheel.poll(100), Some("late"));
+  }
+
+  #[test]
+  fn cancelled_timers_never_expire() {
+    let mut timer_wheel = TimerWheel::new();
+
+    let cancelled_key = timer_wheel.insert(1, 10);
+    timer_wheel.insert(2, 20);
+
+    assert_eq!(timer_wheel.cancel(cancelled_key), Some(1));
+    assert_eq!(timer_wheel.cancel(cancelled_key), None);
+    assert_eq!(timer_wheel.deadline(cancelled_key), None);
+    assert_eq!(timer_wheel.len(), 1);
+
+    // the freed slot is reused without the old key reaching the new timer
+    let new_key = timer_wheel.insert(3, 30);
+
+    assert_eq!(timer_wheel.deadline(cancelled_key), None);
+    assert_eq!(timer_wheel.deadline(new_key), Some(30));
+
+    assert_eq!(timer_wheel.poll(100), Some(2));
+    assert_eq!(timer_wheel.poll(100), Some(3));
+    assert_eq!(timer_wheel.poll(100), None);
+  }
+
+  #[test]
+  fn next_deadline_is_the_earliest_timer() {
+    let mut timer_wheel = TimerWheel::new();
+
+    assert_eq!(timer_wheel.next_deadline(), None);
+
+    timer_wheel.insert((), 1 << 40);
+    timer_wheel.insert((), 300_000);
+    let cancelled_key = timer_wheel.insert((), 4_000);
+
+    assert_eq!(timer_wheel.next_deadline(), Some(4_000));
+
+    timer_wheel.cancel(cancelled_key);
+
+    assert_eq!(timer_wheel.next_deadline(), Some(300_000));
+
+    timer_wheel.poll(250_000);
+
+    assert_eq!(timer_wheel.next_deadline(), Some(300_000));
+
+    timer_wheel.poll(300_000);
+
+    assert_eq!(timer_wheel.next_deadline(), Some(1 << 40));
+  }
+}
@@ -0,0 +1,107 @@
+#![cfg(all(unix, not(loom)))]
+
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockError, RemoteTimeReceiver};
+
+///Returns a socket path in the temp directory that no other test or test run uses.
+fn socket_path(test_name: &str) -> PathBuf {
+  let path = std::env::temp_dir().join(format!("thread-clock-{}-{test_name}.sock", std::process::id()));
+  let _ = std::fs::remove_file(&path);
+
+  path
+}
+
+#[cfg(test)]
+mod unix_socket {
+  use super::*;
+
+  #[test]
+  fn remote_receivers_get_every_tick() {
+    let path = socket_path("every_tick");
+    let mut clock = Clock::custom(5)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let _publisher = clock.publish_unix_socket(&path).unwrap();
+    let mut remote_receiver = RemoteTimeReceiver::connect(&path).unwrap();
+
+    clock.start();
+
+    let first_time = remote_receiver.safe_time().unwrap();
+    let next_times: Vec<_> = (0..3).map(|_| remote_receiver.time()).collect();
+
+    assert!(next_times.windows(2).all(|times| times[0] < times[1]));
+    assert!(next_times[0] > first_time);
+    assert_eq!(remote_receiver.latest_time(), Some(next_times[2]));
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn old_ticks_are_skipped() {
+    let path = socket_path("old_ticks");
+    let mut clock = Clock::custom(2).unwrap();
+    let _publisher = clock.publish_unix_socket(&path).unwrap();
+    let mut remote_receiver = RemoteTimeReceiver::connect(&path).unwrap();
+
+    clock.start();
+
+    let first_time = remote_receiver.safe_time().unwrap();
+
+    thread::sleep(Duration::from_millis(30));
+
+    let time = remote_receiver.safe_time().unwrap();
+
+    // the ticks sent while sleeping would have been the next ones if they weren't skipped
+    assert!(time > first_time + 5);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn remote_receivers_are_told_the_final_time() {
+    let path = socket_path("final_time");
+    let mut clock = Clock::custom(5).unwrap();
+    let _publisher = clock.publish_unix_socket(&path).unwrap();
+    let mut remote_receiver = RemoteTimeReceiver::connect(&path).unwrap();
+
+    clock.start();
+    remote_receiver.wait_for_tick().unwrap();
+
+    let final_time = clock.stop().unwrap();
+    let error = remote_receiver.wait_until_at_least(u64::MAX).unwrap_err();
+
+    assert!(matches!(error.downcast_ref(), Some(ClockError::Stopped(time)) if *time == final_time));
+  }
+
+  #[test]
+  fn times_that_have_occurred_are_errors() {
+    let path = socket_path("occurred");
+    let mut clock = Clock::custom(2).unwrap();
+    let _publisher = clock.publish_unix_socket(&path).unwrap();
+    let mut remote_receiver = RemoteTimeReceiver::connect(&path).unwrap();
+
+    clock.start();
+    remote_receiver.wait_for_time(3).unwrap();
+
+    assert!(remote_receiver.wait_for_time(1).is_err());
+    assert!(remote_receiver.wait_until_at_least(1).unwrap() >= 3);
+
+    clock.stop().unwrap();
+  }
+
+  #[test]
+  fn dropped_publishers_remove_the_socket() {
+    let path = socket_path("dropped");
+    let clock = Clock::custom(5).unwrap();
+    let publisher = clock.publish_unix_socket(&path).unwrap();
+
+    assert!(path.exists());
+    assert!(clock.publish_unix_socket(&path).is_err());
+
+    drop(publisher);
+
+    assert!(!path.exists());
+    assert!(RemoteTimeReceiver::connect(&path).is_err());
+  }
+}
@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+use thread_clock::{Clock, Deadline};
+
+#[cfg(test)]
+mod virtual_time {
+  use super::*;
+
+  #[tokio::test(start_paused = true)]
+  async fn clocks_tick_on_paused_time() {
+    let mut clock = Clock::builder()
+      .tick_rate(1000)
+      .enclosing_runtime(true)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let mut time_receiver = clock.spawn_receiver();
+    let started_at = Instant::now();
+    let virtual_start = tokio::time::Instant::now();
+
+    clock.start();
+
+    for expected_time in 0..5 {
+      assert_eq!(time_receiver.next_tick().await.unwrap(), expected_time);
+    }
+
+    assert!(virtual_start.elapsed() >= Duration::from_secs(5));
+    assert!(started_at.elapsed() < Duration::from_secs(1));
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn deadlines_finish_on_paused_time() {
+    let mut clock = Clock::builder().tick_rate(60_000).enclosing_runtime(true).build().unwrap();
+    let deadline = Deadline::at(&clock, 60);
+    let started_at = Instant::now();
+
+    clock.start();
+
+    assert_eq!(deadline.await.unwrap(), 60);
+    assert!(started_at.elapsed() < Duration::from_secs(5));
+  }
+
+  #[test]
+  fn clocks_on_the_enclosing_runtime_need_a_runtime() {
+    assert!(Clock::builder().enclosing_runtime(true).build().is_err());
+  }
+
+  #[tokio::test]
+  async fn clocks_on_the_enclosing_runtime_cant_have_their_own() {
+    let clock = Clock::builder()
+      .enclosing_runtime(true)
+      .dedicated_runtime(true)
+      .build();
+
+    assert!(clock.is_err());
+  }
+}
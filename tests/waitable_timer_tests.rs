@@ -0,0 +1,54 @@
+#![cfg(windows)]
+
+use std::thread;
+use std::time::{Duration, Instant};
+use thread_clock::{Backend, Clock};
+
+#[cfg(test)]
+mod waitable_timer {
+  use super::*;
+
+  #[test]
+  fn waitable_timer_clocks_keep_short_tickrates() {
+    let mut clock = Clock::builder()
+      .tick_rate(2)
+      .backend(Backend::WaitableTimer)
+      .build()
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+
+    clock.start();
+
+    let started_at = Instant::now();
+
+    thread::sleep(Duration::from_millis(300));
+
+    let expected_time = started_at.elapsed().as_millis() as u64 / 2;
+    let final_time = clock.stop().unwrap();
+
+    // tokio's timer alone would only manage a tick every ~15ms
+    assert!(
+      final_time >= expected_time * 8 / 10 && final_time <= expected_time + 2,
+      "the clock reached {final_time} instead of {expected_time}"
+    );
+  }
+
+  #[test]
+  fn waitable_timer_clocks_cant_be_precise() {
+    let clock = Clock::builder()
+      .backend(Backend::WaitableTimer)
+      .precision(Duration::from_micros(100))
+      .build();
+
+    assert!(clock.is_err());
+  }
+
+  #[tokio::test]
+  async fn waitable_timer_clocks_cant_use_the_enclosing_runtime() {
+    let clock = Clock::builder()
+      .backend(Backend::WaitableTimer)
+      .enclosing_runtime(true)
+      .build();
+
+    assert!(clock.is_err());
+  }
+}
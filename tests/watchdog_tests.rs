@@ -0,0 +1,66 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use thread_clock::{Clock, ClockHook, Time};
+
+///Blocks the clock task for a while before the tick.
+struct StallAt(Time, Duration);
+
+impl ClockHook for StallAt {
+  fn before_tick(&mut self, time: Time) {
+    if time == self.0 {
+      thread::sleep(self.1);
+    }
+  }
+}
+
+#[cfg(test)]
+mod watchdog {
+  use super::*;
+
+  #[test]
+  fn stalls_are_reported_once() {
+    let mut clock = Clock::custom(1)
+      .unwrap_or_else(|error| panic!("An error has occurred while creating the clock: '{error}'"));
+    let (stall_sender, stalls) = mpsc::channel();
+    let _watchdog = clock
+      .watchdog(Duration::from_millis(100), move |stall| {
+        let _ = stall_sender.send(stall);
+      })
+      .unwrap();
+
+    clock.add_hook(StallAt(5, Duration::from_millis(500)));
+    clock.start();
+
+    let stall = stalls.recv_timeout(Duration::from_secs(5)).unwrap();
+
+    assert_eq!(stall.last_tick, Some(4));
+    assert!(stall.stalled_for > Duration::from_millis(100));
+
+    clock.wait_for_time(10).unwrap();
+    clock.stop().unwrap();
+
+    assert!(stalls.try_iter().all(|stall| stall.last_tick != Some(4)));
+  }
+
+  #[test]
+  fn paused_clocks_arent_stalled() {
+    let mut clock = Clock::custom(1).unwrap();
+    let (stall_sender, stalls) = mpsc::channel();
+    let _watchdog = clock
+      .watchdog(Duration::from_millis(500), move |stall| {
+        let _ = stall_sender.send(stall);
+      })
+      .unwrap();
+
+    clock.start();
+    clock.wait_for_time(3).unwrap();
+    clock.pause();
+    thread::sleep(Duration::from_millis(1000));
+    clock.resume();
+    clock.wait_for_x_ticks(3).unwrap();
+    clock.stop().unwrap();
+
+    assert!(stalls.try_recv().is_err());
+  }
+}